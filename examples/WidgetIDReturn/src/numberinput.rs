@@ -1,6 +1,6 @@
 use iced::{Element, Length};
 use iced_aw::{NumberInput, NumberInputStyles};
-use num_traits::{Num, NumAssignOps};
+use num_traits::{Bounded, Num, NumAssignOps};
 use std::fmt::Display;
 use std::marker::PhantomData;
 use std::str::FromStr;
@@ -38,7 +38,7 @@ where
 
 impl<V, M> NumInput<V, M>
 where
-    V: Num + NumAssignOps + PartialOrd + Display + FromStr + Copy,
+    V: Num + NumAssignOps + PartialOrd + Display + FromStr + Copy + Bounded,
     M: Clone,
 {
     pub fn new(value: V) -> NumInput<V, M>
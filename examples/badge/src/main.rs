@@ -1,9 +1,13 @@
 use iced::{
-    widget::{Column, Container, Row, Text},
+    widget::{Button, Column, Container, Row, Text},
     Alignment, Element, Length, Sandbox, Settings,
 };
 
-use iced_aw::{helpers::badge, style::BadgeStyles};
+use iced_aw::{
+    badge::{anchored, Corner},
+    helpers::badge,
+    style::BadgeStyles,
+};
 
 const BADGE_TEXT_SIZE: u16 = 15;
 
@@ -12,7 +16,9 @@ fn main() -> iced::Result {
 }
 
 #[derive(Debug, Clone)]
-enum Message {}
+enum Message {
+    OpenInbox,
+}
 
 struct BadgeExample {
     messages: Vec<(String, usize)>,
@@ -39,7 +45,12 @@ impl Sandbox for BadgeExample {
 
     fn view(&self) -> Element<Message> {
         let content = Column::new()
-            .push(Text::new("Messages").size(32))
+            .push(anchored(
+                Button::new(Text::new("Messages").size(32)).on_press(Message::OpenInbox),
+                badge(Text::new(self.messages.len().to_string()).size(BADGE_TEXT_SIZE))
+                    .style(BadgeStyles::Danger),
+                Corner::TopRight,
+            ))
             .spacing(10)
             .max_width(300);
 
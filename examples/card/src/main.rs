@@ -14,6 +14,7 @@ fn main() -> iced::Result {
 enum Message {
     CloseCard,
     OpenCard,
+    PinCard,
     #[allow(dead_code)]
     Loaded(Result<(), String>),
     FontLoaded(Result<(), font::Error>),
@@ -28,6 +29,7 @@ enum CardExample {
 #[derive(Debug)]
 struct State {
     card_open: bool,
+    card_pinned: bool,
 }
 
 async fn load() -> Result<(), String> {
@@ -58,13 +60,22 @@ impl Application for CardExample {
         match self {
             CardExample::Loading => {
                 if let Message::Loaded(_) = message {
-                    *self = CardExample::Loaded(State { card_open: false })
+                    *self = CardExample::Loaded(State {
+                        card_open: false,
+                        card_pinned: false,
+                    })
                 }
             }
-            CardExample::Loaded(State { card_open }) => match message {
+            CardExample::Loaded(State {
+                card_open,
+                card_pinned,
+            }) => match message {
                 Message::CloseCard | Message::OpenCard => {
                     *card_open = !*card_open;
                 }
+                Message::PinCard => {
+                    *card_pinned = !*card_pinned;
+                }
                 _ => {}
             },
         }
@@ -84,13 +95,23 @@ impl Application for CardExample {
             .center_y()
             .center_x()
             .into(),
-            CardExample::Loaded(State { card_open }) => {
+            CardExample::Loaded(State {
+                card_open,
+                card_pinned,
+            }) => {
                 let element: Element<'_, Message> = if *card_open {
                     card(
                         Text::new("Head X"),
                         Column::new()
                             .push(Text::new("Zombie ipsum reversus ab viral inferno, nam rick grimes malum cerebro. De carne lumbering animata corpora quaeritis. Summus brains sit, morbo vel maleficia? De apocalypsi gorger omero undead survivor dictum mauris. Hi mindless mortuis soulless creaturas, imo evil stalking monstra adventus resi dentevil vultus comedat cerebella viventium. Qui animated corpse, cricket bat max brucks terribilem incessu zomby. The voodoo sacerdos flesh eater, suscitat mortuos comedere carnem virus. Zonbi tattered for solum oculi eorum defunctis go lum cerebro. Nescio brains an Undead zombies. Sicut malus putrid voodoo horror. Nigh tofth eliv ingdead."))
                     )
+                    .head_actions(vec![Button::new(Text::new(if *card_pinned {
+                        "Unpin"
+                    } else {
+                        "Pin"
+                    }))
+                    .on_press(Message::PinCard)
+                    .into()])
                     .foot(Text::new("Foot"))
                     .style(CardStyles::Primary)
                     .on_close(Message::CloseCard)
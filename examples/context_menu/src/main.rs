@@ -59,7 +59,7 @@ impl Sandbox for ContextMenuExample {
                 ))),
         );
 
-        ContextMenu::new(underlay, || {
+        ContextMenu::new(underlay, |_| {
             column(vec![
                 iced::widget::button("Choice 1")
                     .on_press(Message::Choice1)
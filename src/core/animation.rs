@@ -0,0 +1,206 @@
+//! Timing and easing of widget animations.
+//!
+//! An [`Animation`] is started at some instant and tells how far it has
+//! progressed at any later instant, e.g. the one of a
+//! [`RedrawRequested`](iced_widget::core::window::Event::RedrawRequested)
+//! event. While it runs, [`request_next_frame`] keeps the redraws coming.
+//!
+//! *This API requires the following crate features to be activated: `animations`*
+
+use iced_widget::core::{
+    time::{Duration, Instant},
+    window, Shell,
+};
+
+/// The time between two frames of an animation.
+pub const FRAME_DURATION: Duration = Duration::from_millis(1000 / 60);
+
+/// The curve mapping the elapsed time of an [`Animation`] to its progress.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Easing {
+    /// Progresses at a constant speed.
+    #[default]
+    Linear,
+    /// Starts slow and speeds up (cubic).
+    EaseIn,
+    /// Starts fast and slows down (cubic).
+    EaseOut,
+    /// Starts and ends slow (cubic).
+    EaseInOut,
+}
+
+impl Easing {
+    /// Applies the curve to the given linear progress between `0.0` and `1.0`.
+    #[must_use]
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+
+        match self {
+            Self::Linear => t,
+            Self::EaseIn => t.powi(3),
+            Self::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Self::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t.powi(3)
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+        }
+    }
+}
+
+/// An animation running for a given duration from a starting instant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Animation {
+    /// When the [`Animation`] started.
+    start: Instant,
+    /// How long the [`Animation`] takes.
+    duration: Duration,
+    /// The curve of the [`Animation`].
+    easing: Easing,
+    /// Whether the [`Animation`] starts over once done.
+    repeat: bool,
+}
+
+impl Animation {
+    /// Creates a new linear [`Animation`] starting at the given instant.
+    #[must_use]
+    pub const fn new(start: Instant, duration: Duration) -> Self {
+        Self {
+            start,
+            duration,
+            easing: Easing::Linear,
+            repeat: false,
+        }
+    }
+
+    /// Sets the [`Easing`] of the [`Animation`].
+    #[must_use]
+    pub const fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Makes the [`Animation`] start over each time it is done.
+    #[must_use]
+    pub const fn repeat(mut self) -> Self {
+        self.repeat = true;
+        self
+    }
+
+    /// The instant the [`Animation`] started.
+    #[must_use]
+    pub const fn start(&self) -> Instant {
+        self.start
+    }
+
+    /// The duration of the [`Animation`].
+    #[must_use]
+    pub const fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// The eased progress of the [`Animation`] at the given instant, from
+    /// `0.0` at the start to `1.0` once done.
+    ///
+    /// An [`Animation`] without duration is done right away, or stays at
+    /// `0.0` if it repeats.
+    #[must_use]
+    pub fn progress(&self, now: Instant) -> f32 {
+        let elapsed = now.saturating_duration_since(self.start).as_secs_f32();
+        let duration = self.duration.as_secs_f32();
+
+        let t = if duration <= 0.0 {
+            if self.repeat {
+                0.0
+            } else {
+                1.0
+            }
+        } else if self.repeat {
+            (elapsed / duration).fract()
+        } else {
+            (elapsed / duration).min(1.0)
+        };
+
+        self.easing.apply(t)
+    }
+
+    /// Whether the [`Animation`] is done at the given instant.
+    ///
+    /// A repeating [`Animation`] is never done.
+    #[must_use]
+    pub fn is_done(&self, now: Instant) -> bool {
+        !self.repeat && now.saturating_duration_since(self.start) >= self.duration
+    }
+}
+
+/// Requests a redraw for the frame after the given instant.
+pub fn request_next_frame<Message>(shell: &mut Shell<'_, Message>, now: Instant) {
+    shell.request_redraw(window::RedrawRequest::At(now + FRAME_DURATION));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Animation, Easing};
+    use iced_widget::core::time::{Duration, Instant};
+
+    #[test]
+    fn easing_test() {
+        for easing in [
+            Easing::Linear,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+        ] {
+            assert!(easing.apply(0.0).abs() < f32::EPSILON);
+            assert!((easing.apply(1.0) - 1.0).abs() < f32::EPSILON);
+            assert!((easing.apply(2.0) - 1.0).abs() < f32::EPSILON);
+        }
+
+        assert!((Easing::Linear.apply(0.25) - 0.25).abs() < f32::EPSILON);
+        assert!((Easing::EaseIn.apply(0.5) - 0.125).abs() < f32::EPSILON);
+        assert!((Easing::EaseOut.apply(0.5) - 0.875).abs() < f32::EPSILON);
+        assert!((Easing::EaseInOut.apply(0.5) - 0.5).abs() < f32::EPSILON);
+        assert!(Easing::EaseIn.apply(0.3) < Easing::Linear.apply(0.3));
+        assert!(Easing::EaseOut.apply(0.3) > Easing::Linear.apply(0.3));
+    }
+
+    #[test]
+    fn progress_test() {
+        let start = Instant::now();
+        let animation = Animation::new(start, Duration::from_millis(100));
+
+        assert!(animation.progress(start).abs() < f32::EPSILON);
+        assert!((animation.progress(start + Duration::from_millis(50)) - 0.5).abs() < 1e-4);
+        assert!(!animation.is_done(start + Duration::from_millis(99)));
+        assert!(animation.is_done(start + Duration::from_millis(100)));
+        assert!(
+            (animation.progress(start + Duration::from_millis(300)) - 1.0).abs() < f32::EPSILON
+        );
+    }
+
+    #[test]
+    fn repeat_test() {
+        let start = Instant::now();
+        let animation = Animation::new(start, Duration::from_millis(100)).repeat();
+
+        assert!((animation.progress(start + Duration::from_millis(125)) - 0.25).abs() < 1e-4);
+        assert!(!animation.is_done(start + Duration::from_secs(10)));
+    }
+
+    #[test]
+    fn zero_duration_test() {
+        let start = Instant::now();
+
+        assert!((Animation::new(start, Duration::ZERO).progress(start) - 1.0).abs() < f32::EPSILON);
+        assert!(Animation::new(start, Duration::ZERO).is_done(start));
+        assert!(
+            Animation::new(start, Duration::ZERO)
+                .repeat()
+                .progress(start)
+                .abs()
+                < f32::EPSILON
+        );
+    }
+}
@@ -121,11 +121,91 @@ pub fn nearest_radius(
     distance_vec[0].1.clone()
 }
 
+/// The radii of the rings of the period, the hours, the minutes and, if
+/// shown, the seconds of a clock with the given radius.
+#[must_use]
+pub fn rings(radius: f32, show_seconds: bool) -> Vec<(f32, NearestRadius)> {
+    if show_seconds {
+        vec![
+            (radius * PERIOD_PERCENTAGE, NearestRadius::Period),
+            (radius * HOUR_RADIUS_PERCENTAGE, NearestRadius::Hour),
+            (radius * MINUTE_RADIUS_PERCENTAGE, NearestRadius::Minute),
+            (radius * SECOND_RADIUS_PERCENTAGE, NearestRadius::Second),
+        ]
+    } else {
+        vec![
+            (radius * PERIOD_PERCENTAGE, NearestRadius::Period),
+            (
+                radius * HOUR_RADIUS_PERCENTAGE_NO_SECONDS,
+                NearestRadius::Hour,
+            ),
+            (
+                radius * MINUTE_RADIUS_PERCENTAGE_NO_SECONDS,
+                NearestRadius::Minute,
+            ),
+        ]
+    }
+}
+
+/// Determines the hour (0 to 11), the minute or the second the cursor
+/// position points at on the given ring of the clock, if the clock has it.
+///
+/// The minutes are rounded to a multiple of the given step. Both picking and
+/// highlighting a value on the clock use it, so they always agree.
+#[must_use]
+pub fn ring_value(
+    rings: &[(f32, NearestRadius)],
+    ring: &NearestRadius,
+    center: Point,
+    cursor_position: Point,
+    minute_step: u32,
+) -> Option<u32> {
+    let amount = match ring {
+        NearestRadius::Hour => 12,
+        NearestRadius::Minute | NearestRadius::Second => 60,
+        NearestRadius::Period | NearestRadius::None => return None,
+    };
+    let (radius, _) = rings.iter().find(|(_, nearest)| nearest == ring)?;
+
+    let value = nearest_point(&circle_points(*radius, center, amount), cursor_position) as u32;
+
+    Some(if *ring == NearestRadius::Minute {
+        nearest_minute_step(value, minute_step)
+    } else {
+        value
+    })
+}
+
+/// Determines the minute on the clock face nearest to the given one that is a
+/// multiple of the given step, going around the face past the full hour.
+#[must_use]
+pub fn nearest_minute_step(minute: u32, step: u32) -> u32 {
+    let step = step.max(1);
+    let below = minute / step * step;
+    let above = below + step;
+
+    // The minute after the last multiple is 0 again, past the full hour
+    let (above, distance_above) = if above < 60 {
+        (above, above - minute)
+    } else {
+        (0, 60 - minute)
+    };
+
+    if distance_above < minute - below {
+        above
+    } else {
+        below
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use iced_widget::core::{Point, Vector};
 
-    use super::{circle_points, nearest_point, nearest_radius, NearestRadius};
+    use super::{
+        circle_points, nearest_minute_step, nearest_point, nearest_radius, ring_value, rings,
+        NearestRadius,
+    };
 
     #[test]
     fn circle_points_test() {
@@ -215,4 +295,52 @@ mod tests {
         result = nearest_point(&points, cursor_position);
         assert_eq!(index, result);
     }
+
+    #[test]
+    fn nearest_minute_step_test() {
+        assert_eq!(nearest_minute_step(7, 1), 7);
+        assert_eq!(nearest_minute_step(7, 15), 0);
+        assert_eq!(nearest_minute_step(8, 15), 15);
+        assert_eq!(nearest_minute_step(53, 15), 0);
+        assert_eq!(nearest_minute_step(52, 15), 45);
+        assert_eq!(nearest_minute_step(59, 25), 0);
+        assert_eq!(nearest_minute_step(54, 25), 50);
+    }
+
+    #[test]
+    fn ring_value_test() {
+        let center = Point::new(0.0, 0.0);
+        let rings = rings(100.0, false);
+
+        // Right of the center: 3 o'clock, 15 minutes
+        let cursor = Point::new(50.0, 0.0);
+        assert_eq!(
+            ring_value(&rings, &NearestRadius::Hour, center, cursor, 1),
+            Some(3)
+        );
+        assert_eq!(
+            ring_value(&rings, &NearestRadius::Minute, center, cursor, 1),
+            Some(15)
+        );
+        assert_eq!(
+            ring_value(
+                &rings,
+                &NearestRadius::Minute,
+                center,
+                Point::new(40.0, 80.0),
+                10
+            ),
+            Some(30)
+        );
+
+        // No seconds without the ring of the seconds
+        assert_eq!(
+            ring_value(&rings, &NearestRadius::Second, center, cursor, 1),
+            None
+        );
+        assert_eq!(
+            ring_value(&rings, &NearestRadius::Period, center, cursor, 1),
+            None
+        );
+    }
 }
@@ -43,6 +43,189 @@ impl HexString for Color {
     }
 }
 
+/// The hexadecimal representation of the given color: `#RRGGBBAA`, or
+/// `#RRGGBB` without `alpha`.
+#[must_use]
+pub fn hex_string(color: Color, alpha: bool) -> String {
+    let mut hex = color.as_hex_string();
+    if !alpha {
+        hex.truncate(7);
+    }
+    hex
+}
+
+/// Parses the given hexadecimal representation of a color, with or without
+/// the leading `#`.
+///
+/// It takes the 3-digit shorthand (`#abc`) and `#RRGGBB`, and with `alpha`
+/// also `#abcd` and `#RRGGBBAA`. Colors without alpha are opaque.
+#[must_use]
+pub fn parse_hex(text: &str, alpha: bool) -> Option<Color> {
+    let text = text.trim();
+    let digits = text.strip_prefix('#').unwrap_or(text);
+
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let channels: Vec<u8> = match digits.len() {
+        3 | 4 => digits
+            .chars()
+            .map(|c| u8::from_str_radix(&c.to_string().repeat(2), 16))
+            .collect::<Result<_, _>>()
+            .ok()?,
+        6 | 8 => (0..digits.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&digits[i..i + 2], 16))
+            .collect::<Result<_, _>>()
+            .ok()?,
+        _ => return None,
+    };
+
+    match channels[..] {
+        [r, g, b] => Some(Color::from_rgb8(r, g, b)),
+        [r, g, b, a] if alpha => Some(Color::from_rgba8(r, g, b, f32::from(a) / 255.0)),
+        _ => None,
+    }
+}
+
+/// The channels shown by the numeric fields of the color picker.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ChannelMode {
+    /// Red, green and blue (0–255), and alpha (0–100 %).
+    #[default]
+    Rgb,
+    /// Hue (0–360), saturation and value (0–100 %), and alpha (0–100 %).
+    Hsv,
+}
+
+impl ChannelMode {
+    /// The other mode.
+    #[must_use]
+    pub const fn toggled(self) -> Self {
+        match self {
+            Self::Rgb => Self::Hsv,
+            Self::Hsv => Self::Rgb,
+        }
+    }
+
+    /// The label of the mode.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Rgb => "RGB",
+            Self::Hsv => "HSV",
+        }
+    }
+
+    /// The maximum value of the given channel, the 4. one being the alpha.
+    #[must_use]
+    pub const fn max(self, channel: usize) -> u16 {
+        match (self, channel) {
+            (Self::Rgb, 0..=2) => 255,
+            (Self::Hsv, 0) => 360,
+            _ => 100,
+        }
+    }
+
+    /// The values of the channels of the given color.
+    #[must_use]
+    pub fn values(self, color: Color) -> [u16; 4] {
+        let percentage = |value: f32| (value * 100.0).round() as u16;
+        let alpha = percentage(color.a);
+
+        match self {
+            Self::Rgb => {
+                let byte = |value: f32| (value * 255.0).round() as u16;
+                [byte(color.r), byte(color.g), byte(color.b), alpha]
+            }
+            Self::Hsv => {
+                let hsv: Hsv = color.into();
+                [
+                    hsv.hue,
+                    percentage(hsv.saturation),
+                    percentage(hsv.value),
+                    alpha,
+                ]
+            }
+        }
+    }
+
+    /// The given color with the given channel set to the given value, clamped to the
+    /// range of the channel.
+    #[must_use]
+    pub fn with_value(self, color: Color, channel: usize, value: u16) -> Color {
+        let value = value.min(self.max(channel));
+        let percentage = f32::from(value) / 100.0;
+
+        match (self, channel) {
+            (_, 3) => Color {
+                a: percentage,
+                ..color
+            },
+            (Self::Rgb, _) => {
+                let byte = f32::from(value) / 255.0;
+                match channel {
+                    0 => Color { r: byte, ..color },
+                    1 => Color { g: byte, ..color },
+                    _ => Color { b: byte, ..color },
+                }
+            }
+            (Self::Hsv, _) => {
+                let hsv: Hsv = color.into();
+                let hsv = match channel {
+                    0 => Hsv {
+                        hue: value % 360,
+                        ..hsv
+                    },
+                    1 => Hsv {
+                        saturation: percentage,
+                        ..hsv
+                    },
+                    _ => Hsv {
+                        value: percentage,
+                        ..hsv
+                    },
+                };
+
+                Color {
+                    a: color.a,
+                    ..hsv.into()
+                }
+            }
+        }
+    }
+
+    /// The text of the given channel of the given color.
+    #[must_use]
+    pub fn text(self, color: Color, channel: usize) -> String {
+        let value = self.values(color)[channel];
+
+        if self.max(channel) == 100 {
+            format!("{value}%")
+        } else {
+            value.to_string()
+        }
+    }
+
+    /// Parses the text typed into the field of the given channel, with or without a `%`.
+    ///
+    /// Values above the range of the channel are clamped.
+    #[must_use]
+    pub fn parse(self, text: &str, channel: usize) -> Option<u16> {
+        let text = text.trim();
+        let digits = text.strip_suffix('%').unwrap_or(text).trim_end();
+
+        if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+
+        // Too many digits are clamped as well
+        let value = digits.parse::<u32>().unwrap_or(u32::MAX);
+        Some(value.min(u32::from(self.max(channel))) as u16)
+    }
+}
+
 impl From<Color> for Hsv {
     // https://de.wikipedia.org/wiki/HSV-Farbraum#Umrechnung_RGB_in_HSV/HSL
     fn from(color: Color) -> Self {
@@ -101,7 +284,7 @@ impl From<Hsv> for Color {
 mod tests {
     use iced_widget::core::Color;
 
-    use super::Hsv;
+    use super::{hex_string, parse_hex, ChannelMode, Hsv};
 
     #[allow(clippy::cognitive_complexity)]
     #[test]
@@ -336,4 +519,111 @@ mod tests {
         let light_blue_red_rgb = Color::from_rgb(1.0, 0.0, 0.25);
         assert_eq!(light_blue_red_rgb, light_blue_red_hsv.into());
     }
+
+    #[test]
+    fn hex_string_test() {
+        let color = Color::from_rgba8(0x12, 0xAB, 0xFF, 1.0);
+        assert_eq!(hex_string(color, true), "#12ABFFFF");
+        assert_eq!(hex_string(color, false), "#12ABFF");
+    }
+
+    #[test]
+    fn parse_hex_test() {
+        let color = Color::from_rgb8(0x12, 0xAB, 0xFF);
+        assert_eq!(parse_hex("#12abff", false), Some(color));
+        assert_eq!(parse_hex("12ABFF", false), Some(color));
+        assert_eq!(parse_hex(" #12ABFF ", true), Some(color));
+
+        // Shorthand
+        assert_eq!(
+            parse_hex("#abc", false),
+            Some(Color::from_rgb8(0xAA, 0xBB, 0xCC))
+        );
+        assert_eq!(
+            parse_hex("abc8", true),
+            Some(Color::from_rgba8(
+                0xAA,
+                0xBB,
+                0xCC,
+                f32::from(0x88_u8) / 255.0
+            ))
+        );
+
+        // Alpha
+        assert_eq!(
+            parse_hex("#12ABFF80", true),
+            Some(Color::from_rgba8(
+                0x12,
+                0xAB,
+                0xFF,
+                f32::from(0x80_u8) / 255.0
+            ))
+        );
+        assert_eq!(parse_hex("#12ABFF80", false), None);
+        assert_eq!(parse_hex("#abc8", false), None);
+
+        // Invalid
+        assert_eq!(parse_hex("", true), None);
+        assert_eq!(parse_hex("#", true), None);
+        assert_eq!(parse_hex("#12AB", false), None);
+        assert_eq!(parse_hex("#12ABF", true), None);
+        assert_eq!(parse_hex("#12ABFG", true), None);
+        assert_eq!(parse_hex("##12ABFF", true), None);
+    }
+
+    #[test]
+    fn channel_values_test() {
+        let color = Color::from_rgba8(255, 128, 0, 0.5);
+        assert_eq!(ChannelMode::Rgb.values(color), [255, 128, 0, 50]);
+        assert_eq!(ChannelMode::Hsv.values(color), [30, 100, 100, 50]);
+
+        assert_eq!(ChannelMode::Rgb.text(color, 1), "128");
+        assert_eq!(ChannelMode::Rgb.text(color, 3), "50%");
+        assert_eq!(ChannelMode::Hsv.text(color, 0), "30");
+        assert_eq!(ChannelMode::Hsv.text(color, 2), "100%");
+    }
+
+    #[test]
+    fn channel_with_value_test() {
+        let color = Color::from_rgba8(255, 128, 0, 0.5);
+
+        let green = ChannelMode::Rgb.with_value(color, 1, 64);
+        assert_eq!(ChannelMode::Rgb.values(green), [255, 64, 0, 50]);
+
+        // Clamped to the range of the channel
+        let blue = ChannelMode::Rgb.with_value(color, 2, 300);
+        assert_eq!(ChannelMode::Rgb.values(blue), [255, 128, 255, 50]);
+        let opaque = ChannelMode::Hsv.with_value(color, 3, 120);
+        assert_eq!(ChannelMode::Rgb.values(opaque), [255, 128, 0, 100]);
+
+        let hue = ChannelMode::Hsv.with_value(color, 0, 240);
+        assert_eq!(ChannelMode::Hsv.values(hue), [240, 100, 100, 50]);
+        assert_eq!(
+            ChannelMode::Hsv.values(ChannelMode::Hsv.with_value(hue, 0, 360))[0],
+            0
+        );
+
+        let value = ChannelMode::Hsv.with_value(color, 2, 0);
+        assert_eq!(ChannelMode::Rgb.values(value), [0, 0, 0, 50]);
+    }
+
+    #[test]
+    fn channel_parse_test() {
+        assert_eq!(ChannelMode::Rgb.parse("128", 0), Some(128));
+        assert_eq!(ChannelMode::Rgb.parse(" 50 % ", 3), Some(50));
+        assert_eq!(ChannelMode::Hsv.parse("45%", 1), Some(45));
+
+        // Clamped
+        assert_eq!(ChannelMode::Rgb.parse("300", 1), Some(255));
+        assert_eq!(ChannelMode::Hsv.parse("400", 0), Some(360));
+        assert_eq!(ChannelMode::Rgb.parse("99999999999", 3), Some(100));
+
+        // Invalid
+        assert_eq!(ChannelMode::Rgb.parse("", 0), None);
+        assert_eq!(ChannelMode::Rgb.parse("%", 3), None);
+        assert_eq!(ChannelMode::Rgb.parse("-5", 0), None);
+        assert_eq!(ChannelMode::Rgb.parse("+5", 0), None);
+        assert_eq!(ChannelMode::Rgb.parse("1.5", 0), None);
+        assert_eq!(ChannelMode::Rgb.parse("ff", 0), None);
+    }
 }
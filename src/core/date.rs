@@ -1,11 +1,18 @@
 //! Helper functions for calculating dates
+//!
+//! The date arithmetic is done with [`chrono`], the [`Date`] converts from
+//! and to a [`NaiveDate`].
 
-use chrono::{Datelike, Duration, Local, NaiveDate};
+use chrono::{Datelike, Days, Duration, Local, NaiveDate, Weekday};
 use once_cell::sync::Lazy;
 use std::fmt::Display;
 
 /// The date value
-#[derive(Clone, Copy, Debug, Default)]
+///
+/// The fields aren't checked, so a [`Date`] may not exist, e.g. February 30th.
+/// [`Date::from_ymd_opt`] only creates existing dates.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Date {
     /// The year value of the date.
     pub year: i32,
@@ -28,6 +35,36 @@ impl Date {
     pub const fn from_ymd(year: i32, month: u32, day: u32) -> Self {
         Self { year, month, day }
     }
+
+    /// Creates a new date if it exists.
+    #[must_use]
+    pub fn from_ymd_opt(year: i32, month: u32, day: u32) -> Option<Self> {
+        NaiveDate::from_ymd_opt(year, month, day).map(Self::from)
+    }
+
+    /// Gets the day of the week of the date, if it exists.
+    #[must_use]
+    pub fn weekday(self) -> Option<Weekday> {
+        NaiveDate::try_from(self).ok().map(|date| date.weekday())
+    }
+
+    /// Adds the given number of days to the date, going back for a negative
+    /// number.
+    ///
+    /// Returns `None` if the date doesn't exist or if the result is out of
+    /// range.
+    #[must_use]
+    pub fn checked_add_days(self, days: i64) -> Option<Self> {
+        let date = NaiveDate::try_from(self).ok()?;
+        let days_abs = Days::new(days.unsigned_abs());
+
+        if days < 0 {
+            date.checked_sub_days(days_abs)
+        } else {
+            date.checked_add_days(days_abs)
+        }
+        .map(Self::from)
+    }
 }
 
 impl Display for Date {
@@ -36,10 +73,11 @@ impl Display for Date {
     }
 }
 
-impl From<Date> for NaiveDate {
-    fn from(date: Date) -> Self {
-        Self::from_ymd_opt(date.year, date.month, date.day)
-            .expect("Year, Month or Day doesnt Exist")
+impl TryFrom<Date> for NaiveDate {
+    type Error = InvalidDate;
+
+    fn try_from(date: Date) -> Result<Self, Self::Error> {
+        Self::from_ymd_opt(date.year, date.month, date.day).ok_or(InvalidDate(date))
     }
 }
 
@@ -49,6 +87,18 @@ impl From<NaiveDate> for Date {
     }
 }
 
+/// The error of converting a [`Date`] that doesn't exist.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvalidDate(pub Date);
+
+impl Display for InvalidDate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "the date {} doesn't exist", self.0)
+    }
+}
+
+impl std::error::Error for InvalidDate {}
+
 /// # Panics
 /// Creates a date with the previous month based on the given date.
 /// panics if year, month or day doesnt exist.
@@ -105,6 +155,18 @@ pub fn succ_year(date: NaiveDate) -> NaiveDate {
     NaiveDate::from_ymd_opt(year, date.month(), day).expect("Year, Month or Day doesnt Exist")
 }
 
+/// Creates a date in the month of the given date with the given day,
+/// clamped to the last day of the month.
+/// # Panics
+/// panics if year, month or day doesnt exist.
+#[must_use]
+pub fn with_day_clamped(date: NaiveDate, day: u32) -> NaiveDate {
+    let day = day.clamp(1, num_days_of_month(date.year(), date.month()));
+
+    NaiveDate::from_ymd_opt(date.year(), date.month(), day)
+        .expect("Year, Month or Day doesnt Exist")
+}
+
 /// Calculates a date with the previous week based on the given date.
 
 #[must_use]
@@ -154,11 +216,30 @@ pub enum IsInMonth {
 /// panics if year, month or day does not exist.
 #[must_use]
 pub fn position_to_day(x: usize, y: usize, year: i32, month: u32) -> (usize, IsInMonth) {
+    position_to_day_from(x, y, year, month, 0)
+}
+
+/// # Panics
+/// Calculates the day number at the given position in the calendar table based
+/// on the given year and month for weeks starting on the given weekday
+/// (0 = Monday).
+///
+/// The first row holds the first day of the month, so it never consists of
+/// days of the previous month only.
+/// panics if year, month or day does not exist.
+#[must_use]
+pub fn position_to_day_from(
+    x: usize,
+    y: usize,
+    year: i32,
+    month: u32,
+    first_weekday: u32,
+) -> (usize, IsInMonth) {
     let (x, y) = (x as isize, y as isize);
     let first_day =
         NaiveDate::from_ymd_opt(year, month, 1).expect("Year, Month or Day doesnt Exist");
-    let day_of_week = first_day.weekday().num_days_from_monday() as isize;
-    let day_of_week = if day_of_week == 0 { 7 } else { day_of_week };
+    let day_of_week =
+        ((first_day.weekday().num_days_from_monday() + 7 - first_weekday % 7) % 7) as isize;
 
     let day = (x + 7 * y) + 1 - day_of_week;
 
@@ -274,11 +355,11 @@ pub static WEEKDAY_LABELS: Lazy<Vec<String>> = Lazy::new(|| {
 #[cfg(test)]
 
 mod tests {
-    use chrono::NaiveDate;
+    use chrono::{Datelike, NaiveDate, Weekday};
 
     use super::{
-        is_leap_year, num_days_of_month, position_to_day, pred_month, pred_year, succ_month,
-        succ_year, IsInMonth,
+        is_leap_year, num_days_of_month, position_to_day, position_to_day_from, pred_month,
+        pred_year, succ_month, succ_year, with_day_clamped, Date, InvalidDate, IsInMonth,
     };
 
     #[test]
@@ -400,21 +481,48 @@ mod tests {
         assert_eq!(day, 6);
         assert_eq!(is_in_month, IsInMonth::Next);
 
+        // February 2021 starts on a Monday, without a leading week
         let (day, is_in_month) = position_to_day(0, 0, 2021, 2);
-        assert_eq!(day, 25);
-        assert_eq!(is_in_month, IsInMonth::Previous);
-
-        let (day, is_in_month) = position_to_day(0, 1, 2021, 2);
         assert_eq!(day, 1);
         assert_eq!(is_in_month, IsInMonth::Same);
 
-        let (day, is_in_month) = position_to_day(6, 4, 2021, 2);
+        let (day, is_in_month) = position_to_day(6, 3, 2021, 2);
         assert_eq!(day, 28);
         assert_eq!(is_in_month, IsInMonth::Same);
 
-        let (day, is_in_month) = position_to_day(0, 5, 2021, 2);
+        let (day, is_in_month) = position_to_day(0, 4, 2021, 2);
         assert_eq!(day, 1);
         assert_eq!(is_in_month, IsInMonth::Next);
+
+        let (day, is_in_month) = position_to_day(6, 5, 2021, 2);
+        assert_eq!(day, 14);
+        assert_eq!(is_in_month, IsInMonth::Next);
+    }
+
+    #[allow(clippy::shadow_unrelated)]
+    #[test]
+    fn position_to_day_from_test() {
+        // December 2020 starts on a Tuesday
+        let (day, is_in_month) = position_to_day_from(0, 0, 2020, 12, 6);
+        assert_eq!(day, 29);
+        assert_eq!(is_in_month, IsInMonth::Previous);
+
+        let (day, is_in_month) = position_to_day_from(2, 0, 2020, 12, 6);
+        assert_eq!(day, 1);
+        assert_eq!(is_in_month, IsInMonth::Same);
+
+        // November 2020 starts on a Sunday, without a leading week
+        let (day, is_in_month) = position_to_day_from(0, 0, 2020, 11, 6);
+        assert_eq!(day, 1);
+        assert_eq!(is_in_month, IsInMonth::Same);
+
+        let (day, is_in_month) = position_to_day_from(1, 4, 2020, 11, 6);
+        assert_eq!(day, 30);
+        assert_eq!(is_in_month, IsInMonth::Same);
+
+        let (day, is_in_month) = position_to_day_from(1, 0, 2020, 12, 0);
+        assert_eq!(day, 1);
+        assert_eq!(is_in_month, IsInMonth::Same);
     }
 
     #[test]
@@ -442,4 +550,57 @@ mod tests {
         assert_eq!(num_days_of_month(2020, 11), 30);
         assert_eq!(num_days_of_month(2020, 12), 31);
     }
+
+    #[test]
+    fn with_day_clamped_test() {
+        let date = NaiveDate::from_ymd_opt(2021, 2, 10).expect("Year, Month or Day doesnt Exist");
+        assert_eq!(
+            with_day_clamped(date, 31),
+            NaiveDate::from_ymd_opt(2021, 2, 28).expect("Year, Month or Day doesnt Exist")
+        );
+        assert_eq!(
+            with_day_clamped(date, 5),
+            NaiveDate::from_ymd_opt(2021, 2, 5).expect("Year, Month or Day doesnt Exist")
+        );
+
+        // Jan 31 -> Feb 28 -> Mar 31 keeps the day once it fits again
+        let january =
+            NaiveDate::from_ymd_opt(2021, 1, 31).expect("Year, Month or Day doesnt Exist");
+        let february = with_day_clamped(succ_month(january), january.day());
+        let march = with_day_clamped(succ_month(february), january.day());
+        assert_eq!(february.day(), 28);
+        assert_eq!(march.day(), 31);
+    }
+
+    #[test]
+    fn date_conversion_test() {
+        let date = NaiveDate::from_ymd_opt(2024, 2, 29).expect("Year, Month or Day doesnt Exist");
+        assert_eq!(Date::from(date), Date::from_ymd(2024, 2, 29));
+        assert_eq!(NaiveDate::try_from(Date::from_ymd(2024, 2, 29)), Ok(date));
+
+        let invalid = Date::from_ymd(2023, 2, 29);
+        assert_eq!(NaiveDate::try_from(invalid), Err(InvalidDate(invalid)));
+        assert_eq!(Date::from_ymd_opt(2023, 2, 29), None);
+        assert_eq!(
+            Date::from_ymd_opt(2024, 2, 29),
+            Some(Date::from_ymd(2024, 2, 29))
+        );
+    }
+
+    #[test]
+    fn date_arithmetic_test() {
+        assert_eq!(Date::from_ymd(2024, 2, 29).weekday(), Some(Weekday::Thu));
+        assert_eq!(Date::from_ymd(2024, 2, 30).weekday(), None);
+
+        assert_eq!(
+            Date::from_ymd(2024, 2, 28).checked_add_days(2),
+            Some(Date::from_ymd(2024, 3, 1))
+        );
+        assert_eq!(
+            Date::from_ymd(2024, 1, 1).checked_add_days(-1),
+            Some(Date::from_ymd(2023, 12, 31))
+        );
+        assert_eq!(Date::from_ymd(2024, 2, 30).checked_add_days(1), None);
+        assert_eq!(Date::from_ymd(2024, 1, 1).checked_add_days(i64::MAX), None);
+    }
 }
@@ -0,0 +1,85 @@
+//! Shortening a text with an ellipsis until it fits a width.
+
+use std::cell::RefCell;
+
+/// The ellipsis ending a shortened text.
+const ELLIPSIS: &str = "\u{2026}";
+
+/// Shortens texts with an ellipsis until they fit a width, keeping the last
+/// result so a text is only measured again once it or the width changes.
+#[derive(Debug, Default)]
+pub struct Ellipsis {
+    /// The last text and width with their shortened text.
+    last: RefCell<Option<(String, f32, String)>>,
+}
+
+impl Ellipsis {
+    /// Returns the given text if it fits the given width, else its longest start
+    /// that fits once followed by an ellipsis.
+    ///
+    /// The texts are measured with `width_of`.
+    pub fn shorten(&self, content: &str, width: f32, width_of: impl Fn(&str) -> f32) -> String {
+        if let Some((_, _, shortened)) =
+            self.last.borrow().as_ref().filter(|(last, last_width, _)| {
+                last == content && (last_width - width).abs() < f32::EPSILON
+            })
+        {
+            return shortened.clone();
+        }
+
+        let shortened = shorten(content, width, width_of);
+        *self.last.borrow_mut() = Some((content.to_owned(), width, shortened.clone()));
+
+        shortened
+    }
+}
+
+/// Shortens the given text with an ellipsis until its width measured by
+/// `width_of` fits the given width.
+fn shorten(content: &str, width: f32, width_of: impl Fn(&str) -> f32) -> String {
+    if width_of(content) <= width {
+        return content.to_owned();
+    }
+
+    let shortened = |end: usize| format!("{}{ELLIPSIS}", content[..end].trim_end());
+
+    // The longer the kept part, the wider the text
+    let ends: Vec<usize> = content.char_indices().map(|(i, _)| i).collect();
+    let kept = ends.partition_point(|&end| width_of(&shortened(end)) <= width);
+
+    shortened(kept.checked_sub(1).map_or(0, |i| ends[i]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Ellipsis;
+    use std::cell::Cell;
+
+    #[test]
+    fn shorten_test() {
+        let measured = Cell::new(0);
+        let width_of = |content: &str| {
+            measured.set(measured.get() + 1);
+            content.chars().count() as f32
+        };
+        let ellipsis = Ellipsis::default();
+
+        assert_eq!(ellipsis.shorten("Monday", 6.0, width_of), "Monday");
+        assert_eq!(
+            ellipsis.shorten("Monday, 4 March", 7.0, width_of),
+            "Monday\u{2026}"
+        );
+        assert_eq!(
+            ellipsis.shorten("Monday, 4 March", 1.0, width_of),
+            "\u{2026}"
+        );
+
+        // The same text at the same width isn't measured again
+        let count = measured.get();
+        assert_eq!(
+            ellipsis.shorten("Monday, 4 March", 1.0, width_of),
+            "\u{2026}"
+        );
+        assert_eq!(measured.get(), count);
+    }
+}
@@ -0,0 +1,281 @@
+//! Locale information shared by the calendar, time and number widgets.
+//!
+//! A [`Provider`] supplies the month and weekday names, the number separators
+//! and the calendar and clock conventions used by the
+//! [`DatePicker`](crate::native::DatePicker),
+//! [`TimePicker`](crate::native::TimePicker) and
+//! [`NumberInput`](crate::native::NumberInput).
+//!
+//! Widgets use the provider set with [`set_default`], which is [`EnUs`] unless
+//! changed, and each of them can be given its own provider instead.
+use std::sync::{Arc, RwLock};
+
+/// A lock holding a [`Provider`], if one is set.
+type Slot = RwLock<Option<Arc<dyn Provider>>>;
+
+/// The default [`Provider`] set with [`set_default`].
+static DEFAULT: Slot = RwLock::new(None);
+
+/// The locale information used by the widgets.
+///
+/// Months are numbered from 1 (January) to 12 (December), weekdays from
+/// 0 (Monday) to 6 (Sunday).
+pub trait Provider: Send + Sync {
+    /// The full name of the given month.
+    fn month_name(&self, month: u32) -> String;
+
+    /// The full name of the given weekday.
+    fn weekday_name(&self, weekday: u32) -> String;
+
+    /// The short name of the given weekday shown above the days of a calendar.
+    ///
+    /// Defaults to the first two characters of the full name.
+    fn weekday_short_name(&self, weekday: u32) -> String {
+        self.weekday_name(weekday).chars().take(2).collect()
+    }
+
+    /// The character separating the integer part from the fraction of a number.
+    fn decimal_separator(&self) -> char {
+        '.'
+    }
+
+    /// The character grouping the digits of a number, if any.
+    ///
+    /// It is accepted and ignored when parsing numbers.
+    fn grouping_separator(&self) -> Option<char> {
+        None
+    }
+
+    /// The weekday a calendar week starts with.
+    fn first_weekday(&self) -> u32 {
+        0
+    }
+
+    /// Whether times are shown on a 24 hour clock instead of using AM/PM.
+    fn use_24h(&self) -> bool {
+        false
+    }
+
+    /// The label of the button jumping to the current time.
+    fn now_label(&self) -> String {
+        "Now".to_owned()
+    }
+
+    /// The label of the button jumping to the current day.
+    fn today_label(&self) -> String {
+        "Today".to_owned()
+    }
+
+    /// The text of the button submitting a picker, its icon if `None`.
+    fn submit_label(&self) -> Option<String> {
+        None
+    }
+
+    /// The text of the button cancelling a picker, its icon if `None`.
+    fn cancel_label(&self) -> Option<String> {
+        None
+    }
+
+    /// Whether calendar headers show the year before the month, e.g. `2024年 3月`.
+    fn year_first(&self) -> bool {
+        false
+    }
+
+    /// Formats a number written with `.` as decimal separator.
+    fn format_number(&self, number: &str) -> String {
+        number.replace('.', &self.decimal_separator().to_string())
+    }
+
+    /// Converts a number formatted for this locale back to a number written
+    /// with `.` as decimal separator.
+    fn parse_number(&self, text: &str) -> String {
+        let grouping = self.grouping_separator();
+
+        text.chars()
+            .filter(|c| Some(*c) != grouping)
+            .map(|c| {
+                if c == self.decimal_separator() {
+                    '.'
+                } else {
+                    c
+                }
+            })
+            .collect()
+    }
+}
+
+/// Sets the [`Provider`] used by all widgets without a provider of their own.
+///
+/// # Panics
+/// panics if the lock of the default provider is poisoned.
+pub fn set_default(provider: impl Provider + 'static) {
+    set_in(&DEFAULT, Arc::new(provider));
+}
+
+/// Gets the [`Provider`] used by all widgets without a provider of their own.
+///
+/// # Panics
+/// panics if the lock of the default provider is poisoned.
+#[must_use]
+pub fn default_provider() -> Arc<dyn Provider> {
+    get_in(&DEFAULT)
+}
+
+/// Sets the [`Provider`] held by the given [`Slot`].
+fn set_in(slot: &Slot, provider: Arc<dyn Provider>) {
+    *slot.write().expect("Locale lock should not be poisoned") = Some(provider);
+}
+
+/// Gets the [`Provider`] held by the given [`Slot`], [`EnUs`] if none is set.
+fn get_in(slot: &Slot) -> Arc<dyn Provider> {
+    slot.read()
+        .expect("Locale lock should not be poisoned")
+        .clone()
+        .unwrap_or_else(|| Arc::new(EnUs))
+}
+
+/// English (United States).
+///
+/// Weeks start on Monday, matching the previous behavior of the
+/// [`DatePicker`](crate::native::DatePicker).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EnUs;
+
+impl Provider for EnUs {
+    fn month_name(&self, month: u32) -> String {
+        [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ][(month as usize + 11) % 12]
+            .to_owned()
+    }
+
+    fn weekday_name(&self, weekday: u32) -> String {
+        [
+            "Monday",
+            "Tuesday",
+            "Wednesday",
+            "Thursday",
+            "Friday",
+            "Saturday",
+            "Sunday",
+        ][weekday as usize % 7]
+            .to_owned()
+    }
+
+    fn grouping_separator(&self) -> Option<char> {
+        Some(',')
+    }
+}
+
+/// German (Germany).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DeDe;
+
+impl Provider for DeDe {
+    fn month_name(&self, month: u32) -> String {
+        [
+            "Januar",
+            "Februar",
+            "März",
+            "April",
+            "Mai",
+            "Juni",
+            "Juli",
+            "August",
+            "September",
+            "Oktober",
+            "November",
+            "Dezember",
+        ][(month as usize + 11) % 12]
+            .to_owned()
+    }
+
+    fn weekday_name(&self, weekday: u32) -> String {
+        [
+            "Montag",
+            "Dienstag",
+            "Mittwoch",
+            "Donnerstag",
+            "Freitag",
+            "Samstag",
+            "Sonntag",
+        ][weekday as usize % 7]
+            .to_owned()
+    }
+
+    fn decimal_separator(&self) -> char {
+        ','
+    }
+
+    fn grouping_separator(&self) -> Option<char> {
+        Some('.')
+    }
+
+    fn use_24h(&self) -> bool {
+        true
+    }
+
+    fn now_label(&self) -> String {
+        "Jetzt".to_owned()
+    }
+
+    fn today_label(&self) -> String {
+        "Heute".to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{get_in, set_in, DeDe, EnUs, Provider, Slot};
+    use std::sync::{Arc, RwLock};
+
+    #[test]
+    fn en_us_test() {
+        assert_eq!(EnUs.month_name(1), "January");
+        assert_eq!(EnUs.month_name(12), "December");
+        assert_eq!(EnUs.weekday_short_name(0), "Mo");
+        assert_eq!(EnUs.weekday_short_name(6), "Su");
+        assert_eq!(EnUs.format_number("1234.5"), "1234.5");
+        assert_eq!(EnUs.parse_number("1,234.5"), "1234.5");
+        assert!(!EnUs.use_24h());
+        assert_eq!(EnUs.now_label(), "Now");
+    }
+
+    #[test]
+    fn de_de_test() {
+        assert_eq!(DeDe.month_name(3), "März");
+        assert_eq!(DeDe.weekday_short_name(2), "Mi");
+        assert_eq!(DeDe.format_number("1234.5"), "1234,5");
+        assert_eq!(DeDe.parse_number("1.234,5"), "1234.5");
+        assert!(DeDe.use_24h());
+        assert_eq!(DeDe.now_label(), "Jetzt");
+        assert_eq!(DeDe.today_label(), "Heute");
+    }
+
+    #[test]
+    fn set_default_test() {
+        // A slot of its own, the global default is read by the tests of the
+        // widgets running at the same time
+        let slot: Slot = RwLock::new(None);
+        assert_eq!(get_in(&slot).month_name(10), "October");
+        assert_eq!(get_in(&slot).parse_number("2,5"), "25");
+
+        set_in(&slot, Arc::new(DeDe));
+        assert_eq!(get_in(&slot).month_name(10), "Oktober");
+        assert_eq!(get_in(&slot).parse_number("2,5"), "2.5");
+
+        set_in(&slot, Arc::new(EnUs));
+        assert_eq!(get_in(&slot).month_name(10), "October");
+    }
+}
@@ -1,15 +1,28 @@
 //! A module fitting `iced_core`.
 
+#[cfg(feature = "animations")]
+pub mod animation;
+
 #[cfg(feature = "date_picker")]
 //#[cfg(all(feature = "date_picker", not(target_arch = "wasm32")))]
 pub mod date;
 
+#[cfg(any(feature = "badge", feature = "date_picker"))]
+pub mod ellipsis;
+
 #[cfg(feature = "time_picker")]
 pub mod clock;
 
 #[cfg(feature = "color_picker")]
 pub mod color;
 
+#[cfg(any(
+    feature = "date_picker",
+    feature = "time_picker",
+    feature = "number_input"
+))]
+pub mod locale;
+
 pub mod overlay;
 
 pub mod renderer;
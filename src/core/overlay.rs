@@ -1,5 +1,11 @@
 //! Helper functions for overlays
 
+#[cfg(any(
+    feature = "color_picker",
+    feature = "date_picker",
+    feature = "time_picker"
+))]
+use iced_widget::core::{event, mouse::Cursor, Event, Rectangle};
 use iced_widget::core::{layout, Point, Size};
 
 /// Trait containing functions for positioning of nodes.
@@ -9,6 +15,22 @@ pub trait Position {
     fn center_and_bounce(&mut self, position: Point, bounds: Size);
 }
 
+/// The status of an event for an overlay with the given bounds.
+///
+/// Mouse and touch events over the overlay are captured, so they can't reach
+/// the widgets underneath even if no element of the overlay handled them.
+#[cfg(any(
+    feature = "color_picker",
+    feature = "date_picker",
+    feature = "time_picker"
+))]
+pub(crate) fn pointer_status(event: &Event, bounds: Rectangle, cursor: Cursor) -> event::Status {
+    match event {
+        Event::Mouse(_) | Event::Touch(_) if cursor.is_over(bounds) => event::Status::Captured,
+        _ => event::Status::Ignored,
+    }
+}
+
 impl Position for layout::Node {
     fn center_and_bounce(&mut self, position: Point, bounds: Size) {
         let size = self.size();
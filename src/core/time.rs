@@ -2,7 +2,7 @@
 //!
 //! *This API requires the following crate features to be activated: `time_picker`*
 
-use chrono::{Local, Timelike};
+use chrono::{Duration, Local, NaiveTime, Timelike};
 use std::fmt::Display;
 
 /// The time value
@@ -180,12 +180,192 @@ impl From<chrono::NaiveTime> for Time {
     }
 }
 
+/// The [`Time`] picked with the given time, in the format of the 24 hour or
+/// the AM/PM clock.
+///
+/// It's a [`Time::Hms`] if the seconds are shown, otherwise a [`Time::Hm`]
+/// without the seconds of the given time.
+#[must_use]
+pub fn picked_time(time: NaiveTime, use_24h: bool, show_seconds: bool) -> Time {
+    let (hour, period) = if use_24h {
+        (time.hour(), Period::H24)
+    } else {
+        let (period, hour) = time.hour12();
+        (hour, if period { Period::Pm } else { Period::Am })
+    };
+
+    if show_seconds {
+        Time::Hms {
+            hour,
+            minute: time.minute(),
+            second: time.second(),
+            period,
+        }
+    } else {
+        Time::Hm {
+            hour,
+            minute: time.minute(),
+            period,
+        }
+    }
+}
+
+/// Rounds the minute of the given time down to a multiple of the given step,
+/// keeping the hour and the second.
+#[must_use]
+pub fn floor_minute(time: NaiveTime, step: u32) -> NaiveTime {
+    let step = step.max(1);
+
+    time.with_minute(time.minute() / step * step)
+        .unwrap_or(time)
+}
+
+/// Calculates the time with the next minute that is a multiple of the given
+/// step, in the next hour after the last one.
+#[must_use]
+pub fn succ_minute_step(time: NaiveTime, step: u32) -> NaiveTime {
+    let step = step.max(1);
+    let minute = (time.minute() / step + 1) * step;
+
+    if minute < 60 {
+        time.with_minute(minute)
+    } else {
+        (time + Duration::hours(1)).with_minute(0)
+    }
+    .unwrap_or(time)
+}
+
+/// Calculates the time with the previous minute that is a multiple of the
+/// given step, in the previous hour before the first one.
+#[must_use]
+pub fn pred_minute_step(time: NaiveTime, step: u32) -> NaiveTime {
+    let step = step.max(1);
+
+    if time.minute() > 0 {
+        time.with_minute((time.minute() - 1) / step * step)
+    } else {
+        (time - Duration::hours(1)).with_minute(59 / step * step)
+    }
+    .unwrap_or(time)
+}
+
+/// Checks if any time from `start` to `end` lies in the range from `min` to
+/// `max`, all included.
+///
+/// The range wraps past midnight if `min` is after `max`, e.g. from 22:00 to
+/// 02:00.
+#[must_use]
+pub fn overlaps_range(start: NaiveTime, end: NaiveTime, min: NaiveTime, max: NaiveTime) -> bool {
+    if min <= max {
+        start <= max && end >= min
+    } else {
+        end >= min || start <= max
+    }
+}
+
+/// Moves the given time to the nearest bound of the range from `min` to `max`
+/// if it lies outside of it, see [`overlaps_range`].
+#[must_use]
+pub fn clamp_to_range(time: NaiveTime, min: NaiveTime, max: NaiveTime) -> NaiveTime {
+    if overlaps_range(time, time, min, max) {
+        return time;
+    }
+
+    // Outside of the range, the time lies after `max` and before `min` on the clock
+    let after_max = (time - max).num_seconds().rem_euclid(SECONDS_PER_DAY);
+    let before_min = (min - time).num_seconds().rem_euclid(SECONDS_PER_DAY);
+
+    if after_max <= before_min {
+        max
+    } else {
+        min
+    }
+}
+
+/// The number of seconds of a day.
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// Parses a time typed as `HH:MM`, or as `HH:MM:SS` with seconds.
+///
+/// Without the 24 hour clock, the hour goes from 1 to 12 and may be followed
+/// by `AM` or `PM`; without them, the time is in the afternoon if `pm` is set.
+/// The minutes and the seconds need two digits, so a partly typed time isn't
+/// parsed.
+#[must_use]
+pub fn parse(text: &str, use_24h: bool, show_seconds: bool, pm: bool) -> Option<NaiveTime> {
+    let text = text.trim().to_ascii_lowercase();
+    let (text, pm) = match (text.strip_suffix("am"), text.strip_suffix("pm")) {
+        (Some(text), _) if !use_24h => (text.trim_end(), false),
+        (_, Some(text)) if !use_24h => (text.trim_end(), true),
+        _ => (text.as_str(), pm),
+    };
+
+    let number = |text: &str, digits: std::ops::RangeInclusive<usize>| {
+        (digits.contains(&text.len()) && text.bytes().all(|b| b.is_ascii_digit()))
+            .then(|| text.parse::<u32>().ok())
+            .flatten()
+    };
+
+    let mut parts = text.split(':');
+    let hour = number(parts.next()?, 1..=2)?;
+    let minute = number(parts.next()?, 2..=2)?;
+    let second = if show_seconds {
+        number(parts.next()?, 2..=2)?
+    } else {
+        0
+    };
+    if parts.next().is_some() {
+        return None;
+    }
+
+    let hour = if use_24h {
+        hour
+    } else if (1..=12).contains(&hour) {
+        hour % 12 + if pm { 12 } else { 0 }
+    } else {
+        return None;
+    };
+
+    NaiveTime::from_hms_opt(hour, minute, second)
+}
+
+/// Parses a duration typed as `H:MM`, or as `H:MM:SS` with seconds, into
+/// its number of seconds.
+///
+/// The hours may have any number of digits, the minutes and the seconds need
+/// two digits like for [`parse`].
+#[must_use]
+pub fn parse_duration(text: &str, show_seconds: bool) -> Option<u32> {
+    let number = |text: &str, digits: std::ops::RangeInclusive<usize>| {
+        (digits.contains(&text.len()) && text.bytes().all(|b| b.is_ascii_digit()))
+            .then(|| text.parse::<u32>().ok())
+            .flatten()
+    };
+
+    let mut parts = text.trim().split(':');
+    let hours = number(parts.next()?, 1..=5)?;
+    let minutes = number(parts.next()?, 2..=2).filter(|&minutes| minutes < 60)?;
+    let seconds = if show_seconds {
+        number(parts.next()?, 2..=2).filter(|&seconds| seconds < 60)?
+    } else {
+        0
+    };
+    if parts.next().is_some() {
+        return None;
+    }
+
+    Some(hours * 3600 + minutes * 60 + seconds)
+}
+
 #[cfg(test)]
 
 mod tests {
     use chrono::NaiveTime;
 
-    use super::{Period, Time};
+    use super::{
+        clamp_to_range, floor_minute, overlaps_range, parse, parse_duration, picked_time,
+        pred_minute_step, succ_minute_step, Period, Time,
+    };
 
     #[test]
     fn time_to_naive() {
@@ -279,4 +459,151 @@ mod tests {
             NaiveTime::from_hms_opt(17, 52, 0).expect("Time Conversion failed")
         );
     }
+
+    #[test]
+    fn minute_step_test() {
+        let time = |h, m, s| NaiveTime::from_hms_opt(h, m, s).expect("Time Conversion failed");
+
+        assert_eq!(floor_minute(time(10, 29, 5), 15), time(10, 15, 5));
+        assert_eq!(floor_minute(time(10, 29, 5), 1), time(10, 29, 5));
+        assert_eq!(floor_minute(time(10, 29, 5), 0), time(10, 29, 5));
+
+        assert_eq!(succ_minute_step(time(10, 15, 0), 15), time(10, 30, 0));
+        assert_eq!(succ_minute_step(time(10, 20, 0), 15), time(10, 30, 0));
+        assert_eq!(succ_minute_step(time(10, 45, 0), 15), time(11, 0, 0));
+        assert_eq!(succ_minute_step(time(23, 50, 0), 25), time(0, 0, 0));
+        assert_eq!(succ_minute_step(time(10, 59, 0), 1), time(11, 0, 0));
+
+        assert_eq!(pred_minute_step(time(10, 30, 0), 15), time(10, 15, 0));
+        assert_eq!(pred_minute_step(time(10, 20, 0), 15), time(10, 15, 0));
+        assert_eq!(pred_minute_step(time(10, 0, 0), 15), time(9, 45, 0));
+        assert_eq!(pred_minute_step(time(0, 0, 0), 25), time(23, 50, 0));
+    }
+
+    #[test]
+    fn parse_test() {
+        let time = |h, m, s| NaiveTime::from_hms_opt(h, m, s);
+
+        assert_eq!(parse("08:05", true, false, false), time(8, 5, 0));
+        assert_eq!(parse(" 8:05 ", true, false, false), time(8, 5, 0));
+        assert_eq!(parse("23:59:30", true, true, false), time(23, 59, 30));
+        assert_eq!(parse("24:00", true, false, false), None);
+        assert_eq!(parse("8:5", true, false, false), None);
+        assert_eq!(parse("8:", true, false, false), None);
+        assert_eq!(parse("08:05:00", true, false, false), None);
+        assert_eq!(parse("08:05", true, true, false), None);
+        assert_eq!(parse("+8:05", true, false, false), None);
+        assert_eq!(parse("08:05 PM", true, false, false), None);
+
+        assert_eq!(parse("12:15 AM", false, false, true), time(0, 15, 0));
+        assert_eq!(parse("12:15pm", false, false, false), time(12, 15, 0));
+        assert_eq!(parse("3:15 pm", false, false, false), time(15, 15, 0));
+        assert_eq!(parse("3:15", false, false, true), time(15, 15, 0));
+        assert_eq!(parse("3:15", false, false, false), time(3, 15, 0));
+        assert_eq!(parse("13:15", false, false, false), None);
+        assert_eq!(parse("0:15 AM", false, false, false), None);
+    }
+
+    #[test]
+    fn range_test() {
+        let time = |h, m| NaiveTime::from_hms_opt(h, m, 0).expect("Time Conversion failed");
+
+        // Business hours
+        let (min, max) = (time(8, 0), time(18, 0));
+        assert!(overlaps_range(time(8, 0), time(8, 0), min, max));
+        assert!(overlaps_range(time(7, 0), time(8, 0), min, max));
+        assert!(!overlaps_range(time(7, 0), time(7, 59), min, max));
+        assert!(!overlaps_range(time(18, 1), time(18, 1), min, max));
+        assert_eq!(clamp_to_range(time(12, 0), min, max), time(12, 0));
+        assert_eq!(clamp_to_range(time(3, 0), min, max), time(8, 0));
+        assert_eq!(clamp_to_range(time(23, 0), min, max), time(18, 0));
+
+        // Past midnight
+        let (min, max) = (time(22, 0), time(2, 0));
+        assert!(overlaps_range(time(23, 0), time(23, 0), min, max));
+        assert!(overlaps_range(time(1, 0), time(1, 0), min, max));
+        assert!(overlaps_range(time(21, 0), time(22, 0), min, max));
+        assert!(!overlaps_range(time(12, 0), time(12, 0), min, max));
+        assert_eq!(clamp_to_range(time(0, 30), min, max), time(0, 30));
+        assert_eq!(clamp_to_range(time(3, 0), min, max), time(2, 0));
+        assert_eq!(clamp_to_range(time(21, 0), min, max), time(22, 0));
+    }
+
+    #[test]
+    fn parse_duration_test() {
+        assert_eq!(parse_duration("36:30", false), Some(36 * 3600 + 30 * 60));
+        assert_eq!(parse_duration(" 0:05 ", false), Some(5 * 60));
+        assert_eq!(parse_duration("120:00:15", true), Some(120 * 3600 + 15));
+
+        // Partly typed or out of range
+        assert_eq!(parse_duration("36:3", false), None);
+        assert_eq!(parse_duration("36:30", true), None);
+        assert_eq!(parse_duration("36:30:15", false), None);
+        assert_eq!(parse_duration("1:60", false), None);
+        assert_eq!(parse_duration("-1:00", false), None);
+    }
+
+    #[test]
+    fn picked_time_test() {
+        // Hiding the seconds drops them
+        let time = Time::Hms {
+            hour: 8,
+            minute: 52,
+            second: 17,
+            period: Period::H24,
+        };
+        assert!(matches!(
+            picked_time(time.into(), true, false),
+            Time::Hm {
+                hour: 8,
+                minute: 52,
+                period: Period::H24,
+            }
+        ));
+
+        // Showing the seconds zeroes them
+        let time = Time::Hm {
+            hour: 8,
+            minute: 52,
+            period: Period::Pm,
+        };
+        assert!(matches!(
+            picked_time(time.into(), false, true),
+            Time::Hms {
+                hour: 8,
+                minute: 52,
+                second: 0,
+                period: Period::Pm,
+            }
+        ));
+        assert!(matches!(
+            picked_time(time.into(), true, true),
+            Time::Hms {
+                hour: 20,
+                minute: 52,
+                second: 0,
+                period: Period::H24,
+            }
+        ));
+
+        // Midnight is 12 AM
+        let time = NaiveTime::from_hms_opt(0, 5, 9).expect("Time should be valid");
+        assert!(matches!(
+            picked_time(time, false, false),
+            Time::Hm {
+                hour: 12,
+                minute: 5,
+                period: Period::Am,
+            }
+        ));
+        assert!(matches!(
+            picked_time(time, false, true),
+            Time::Hms {
+                hour: 12,
+                minute: 5,
+                second: 9,
+                period: Period::Am,
+            }
+        ));
+    }
 }
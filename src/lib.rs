@@ -64,9 +64,17 @@ mod platform {
         crate::graphics::icons::{BOOTSTRAP_FONT, NERD_FONT},
     };
 
+    #[doc(no_inline)]
+    #[cfg(feature = "animations")]
+    pub use crate::core::animation;
+
     #[doc(no_inline)]
     #[cfg(feature = "badge")]
-    pub use {crate::native::badge, crate::style::BadgeStyles, badge::Badge};
+    pub use {
+        crate::native::badge,
+        crate::style::BadgeStyles,
+        badge::{Badge, BadgeOverflow, BadgeSize},
+    };
 
     #[doc(no_inline)]
     #[cfg(feature = "card")]
@@ -80,6 +88,14 @@ mod platform {
     #[cfg(feature = "date_picker")]
     pub use {crate::native::date_picker, date_picker::DatePicker};
 
+    #[doc(no_inline)]
+    #[cfg(any(
+        feature = "date_picker",
+        feature = "time_picker",
+        feature = "number_input"
+    ))]
+    pub use crate::core::locale;
+
     #[doc(no_inline)]
     #[cfg(feature = "floating_element")]
     pub use {crate::native::floating_element, floating_element::FloatingElement};
@@ -137,7 +153,7 @@ mod platform {
     pub use {
         crate::native::menu,
         crate::native::menu::{
-            CloseCondition, ItemHeight, ItemWidth, MenuBar, MenuTree, PathHighlight,
+            CloseCondition, ItemHeight, ItemWidth, MenuBar, MenuTree, PathHighlight, Shortcuts,
         },
     };
 
@@ -1,20 +1,64 @@
 //! Use a badge for color highlighting important information.
 //!
 //! *This API requires the following crate features to be activated: badge*
+use crate::core::animation::{self, Animation};
+
 use iced_widget::core::{
     self, event,
     layout::{Limits, Node},
     mouse::{self, Cursor},
-    renderer,
-    widget::tree::Tree,
-    Alignment, Border, Clipboard, Color, Element, Event, Layout, Length, Padding, Point, Rectangle,
-    Shadow, Shell, Size, Widget,
+    renderer, text,
+    time::Duration,
+    touch,
+    widget::{
+        self,
+        tree::{self, Tag, Tree},
+    },
+    window, Alignment, Background, Border, Clipboard, Color, Element, Event, Layout, Length,
+    Padding, Pixels, Point, Rectangle, Shadow, Shell, Size, Widget,
 };
 
 pub use crate::style::badge::{Appearance, StyleSheet};
 
+pub mod anchored;
+pub use anchored::{anchored, Anchored, Corner};
+mod label;
+use label::Label;
+
 /// The ratio of the border radius.
 const BORDER_RADIUS_RATIO: f32 = 34.0 / 15.0;
+/// The default spacing between the icon and the content of a [`Badge`].
+const DEFAULT_ICON_SPACING: f32 = 5.0;
+/// The default diameter of a [`Badge`] of [`BadgeSize::Dot`].
+const DEFAULT_DOT_DIAMETER: f32 = 8.0;
+/// The default maximum count shown by a [`Badge`] of a count.
+const DEFAULT_MAX_COUNT: u32 = 99;
+/// How much of its opacity a pulsing [`Badge`] loses at the low of a pulse.
+const PULSE_FADE: f32 = 0.5;
+/// The default size of the text of a [`Badge`] of [`BadgeSize::Small`].
+const DEFAULT_SMALL_TEXT_SIZE: f32 = 12.0;
+
+/// The size preset of a [`Badge`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BadgeSize {
+    /// A pill around the content.
+    #[default]
+    Normal,
+    /// A pill with half the padding around a smaller text.
+    Small,
+    /// A circle without the content, e.g. to notify that there is something new.
+    Dot,
+}
+
+/// How the text of a [`Badge`] wider than its maximum width is truncated.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BadgeOverflow {
+    /// The end of the text is replaced with an ellipsis.
+    #[default]
+    Ellipsis,
+    /// The end of the text is clipped.
+    Clip,
+}
 
 /// A badge for color highlighting small information.
 ///
@@ -49,6 +93,32 @@ where
     style: <Theme as StyleSheet>::Style,
     /// The content [`Element`] of the [`Badge`].
     content: Element<'a, Message, Theme, Renderer>,
+    /// The optional icon [`Element`] before the content of the [`Badge`].
+    icon: Option<Element<'a, Message, Theme, Renderer>>,
+    /// The spacing between the icon and the content of the [`Badge`].
+    icon_spacing: f32,
+    /// The size preset of the [`Badge`].
+    size: BadgeSize,
+    /// The diameter of the [`Badge`] of [`BadgeSize::Dot`].
+    dot_diameter: f32,
+    /// The count shown by the [`Badge`], if it is a [`Badge`] of a count.
+    count: Option<u32>,
+    /// The maximum count shown by the [`Badge`], a higher count is shown as `max+`.
+    max_count: u32,
+    /// Whether the [`Badge`] of a count is shown when the count is 0.
+    show_zero: bool,
+    /// The message produced when the [`Badge`] is pressed.
+    on_press: Option<Message>,
+    /// The maximum width of the [`Badge`].
+    max_width: Option<f32>,
+    /// The text of the [`Badge`], if it is a [`Badge`] of a text.
+    label: Option<String>,
+    /// How the text of a [`Badge`] of a text is truncated.
+    overflow: BadgeOverflow,
+    /// The size of the text of a [`Badge`] of a count or a text, if set.
+    text_size: Option<Pixels>,
+    /// The period of the pulse of the [`Badge`], if it pulses.
+    pulse: Option<Duration>,
 }
 
 impl<'a, Message, Theme, Renderer> Badge<'a, Message, Theme, Renderer>
@@ -72,6 +142,19 @@ where
             vertical_alignment: Alignment::Center,
             style: <Theme as StyleSheet>::Style::default(),
             content: content.into(),
+            icon: None,
+            icon_spacing: DEFAULT_ICON_SPACING,
+            size: BadgeSize::default(),
+            dot_diameter: DEFAULT_DOT_DIAMETER,
+            count: None,
+            max_count: DEFAULT_MAX_COUNT,
+            show_zero: false,
+            on_press: None,
+            max_width: None,
+            label: None,
+            overflow: BadgeOverflow::default(),
+            text_size: None,
+            pulse: None,
         }
     }
 
@@ -96,6 +179,38 @@ where
         self
     }
 
+    /// Sets the icon [`Element`] shown before the content of the [`Badge`], e.g. a
+    /// [`Text`](iced_widget::Text) with an icon font.
+    ///
+    /// The icon and the content are centered vertically on each other. The icon is drawn
+    /// with the icon color of the [`Appearance`].
+    #[must_use]
+    pub fn icon<I>(mut self, icon: I) -> Self
+    where
+        I: Into<Element<'a, Message, Theme, Renderer>>,
+    {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Sets the spacing between the icon and the content of the [`Badge`].
+    #[must_use]
+    pub fn icon_spacing(mut self, spacing: f32) -> Self {
+        self.icon_spacing = spacing;
+        self
+    }
+
+    /// Sets the message that will be produced when the [`Badge`] is pressed, e.g. to use
+    /// it as a filter chip.
+    ///
+    /// The [`Badge`] is drawn with the pressed appearance of the [`StyleSheet`] while it is
+    /// pressed once this is set.
+    #[must_use]
+    pub fn on_press(mut self, msg: Message) -> Self {
+        self.on_press = Some(msg);
+        self
+    }
+
     /// Sets the padding of the [`Badge`].
     #[must_use]
     pub fn padding(mut self, units: u16) -> Self {
@@ -103,6 +218,42 @@ where
         self
     }
 
+    /// Sets the maximum width of the [`Badge`].
+    ///
+    /// A wider content is clipped, the text of a [`Badge`] created with
+    /// [`text`](Self::text) is truncated as set by [`overflow`](Self::overflow). The
+    /// [`Badge`] never gets narrower than high.
+    #[must_use]
+    pub fn max_width(mut self, max_width: f32) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// Makes the [`Badge`] pulse with the given period to draw the eye, e.g. to show that
+    /// something is new, or stops it with `None` (the default).
+    ///
+    /// The opacity of the [`Badge`] rises and falls smoothly. It only pulses while it is
+    /// visible in the viewport.
+    #[must_use]
+    pub fn pulse(mut self, period: Option<Duration>) -> Self {
+        self.pulse = period;
+        self
+    }
+
+    /// Sets the diameter of the [`Badge`] of [`BadgeSize::Dot`] (8 by default).
+    #[must_use]
+    pub fn dot_diameter(mut self, diameter: f32) -> Self {
+        self.dot_diameter = diameter;
+        self
+    }
+
+    /// Shows the [`Badge`] of a count when the count is 0 (hidden by default).
+    #[must_use]
+    pub fn show_zero(mut self, show_zero: bool) -> Self {
+        self.show_zero = show_zero;
+        self
+    }
+
     /// Sets the style of the [`Badge`].
     #[must_use]
     pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
@@ -116,6 +267,132 @@ where
         self.width = width;
         self
     }
+
+    /// The padding of the [`Badge`] for its [`BadgeSize`].
+    const fn effective_padding(&self) -> u16 {
+        match self.size {
+            BadgeSize::Small => self.padding / 2,
+            BadgeSize::Normal | BadgeSize::Dot => self.padding,
+        }
+    }
+
+    /// The size of the text of a [`Badge`] of a count or a text for its [`BadgeSize`],
+    /// `None` for the default text size.
+    fn effective_text_size(&self) -> Option<Pixels> {
+        self.text_size
+            .or_else(|| (self.size == BadgeSize::Small).then_some(Pixels(DEFAULT_SMALL_TEXT_SIZE)))
+    }
+
+    /// Returns true if the [`Badge`] is a [`Badge`] of a zero count that is hidden.
+    fn is_hidden(&self) -> bool {
+        self.count == Some(0) && !self.show_zero
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Badge<'a, Message, Theme, Renderer>
+where
+    Renderer: 'a + text::Renderer,
+    Theme: 'a + StyleSheet + widget::text::StyleSheet,
+{
+    /// Creates a new [`Badge`] showing the given count.
+    ///
+    /// A count higher than the [`max_count`](Self::max_count) is shown as `max+`, a
+    /// count of 0 hides the [`Badge`] unless [`show_zero`](Self::show_zero) is set. The
+    /// [`Badge`] is at least as wide as high, so one and two digit counts have the same
+    /// size.
+    #[must_use]
+    pub fn count(count: u32) -> Self {
+        let mut badge = Self::new(count_text(count, DEFAULT_MAX_COUNT, None));
+        badge.count = Some(count);
+        badge
+    }
+
+    /// Creates a new [`Badge`] showing the given text on a single line.
+    ///
+    /// The text is truncated with an ellipsis when it is wider than the
+    /// [`max_width`](Self::max_width), see [`overflow`](Self::overflow).
+    #[must_use]
+    pub fn text(text: impl Into<String>) -> Self {
+        let text = text.into();
+        let mut badge = Self::new(Element::new(Label::new(
+            text.clone(),
+            BadgeOverflow::default(),
+            None,
+        )));
+        badge.label = Some(text);
+        badge
+    }
+
+    /// Sets the [`BadgeSize`] preset of the [`Badge`].
+    ///
+    /// [`BadgeSize::Small`] halves the padding and draws the text of a [`Badge`] of a
+    /// count or a text at 12 pixels, unless a [`text_size`](Self::text_size) is set.
+    /// The content of a [`Badge`] created with [`new`](Self::new) keeps its size.
+    /// [`BadgeSize::Dot`] ignores the content and the icon and is drawn as a circle of
+    /// the [`dot_diameter`](Self::dot_diameter).
+    #[must_use]
+    pub fn size(mut self, size: BadgeSize) -> Self {
+        self.size = size;
+        self.with_text_content()
+    }
+
+    /// Sets the size of the text of a [`Badge`] of a count or a text, the default text
+    /// size of the renderer by default.
+    #[must_use]
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self.with_text_content()
+    }
+
+    /// Sets how the text of a [`Badge`] created with [`text`](Self::text) is truncated
+    /// when it is wider than the [`max_width`](Self::max_width)
+    /// ([`BadgeOverflow::Ellipsis`] by default).
+    #[must_use]
+    pub fn overflow(mut self, overflow: BadgeOverflow) -> Self {
+        self.overflow = overflow;
+        self.with_text_content()
+    }
+
+    /// Sets the maximum count shown by the [`Badge`] of a count (99 by default).
+    #[must_use]
+    pub fn max_count(mut self, max_count: u32) -> Self {
+        self.max_count = max_count;
+        self.with_text_content()
+    }
+
+    /// Rebuilds the content of a [`Badge`] of a count or a text from its settings.
+    fn with_text_content(mut self) -> Self {
+        let size = self.effective_text_size();
+        if let Some(count) = self.count {
+            self.content = count_text(count, self.max_count, size).into();
+        } else if let Some(text) = &self.label {
+            self.content = Element::new(Label::new(text.clone(), self.overflow, size));
+        }
+        self
+    }
+}
+
+/// Creates the [`Text`](widget::Text) of a count, `max+` if the count is higher than the
+/// maximum.
+fn count_text<'a, Theme, Renderer>(
+    count: u32,
+    max_count: u32,
+    size: Option<Pixels>,
+) -> widget::Text<'a, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: widget::text::StyleSheet,
+{
+    let text = if count > max_count {
+        widget::Text::new(format!("{max_count}+"))
+    } else {
+        widget::Text::new(count.to_string())
+    };
+
+    match size {
+        Some(size) => text.size(size),
+        None => text,
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -125,12 +402,27 @@ where
     Renderer: 'a + core::Renderer,
     Theme: StyleSheet,
 {
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
     fn children(&self) -> Vec<Tree> {
-        vec![Tree::new(&self.content)]
+        std::iter::once(&self.content)
+            .chain(&self.icon)
+            .map(Tree::new)
+            .collect()
     }
 
     fn diff(&self, tree: &mut Tree) {
-        tree.diff_children(std::slice::from_ref(&self.content));
+        if let Some(icon) = &self.icon {
+            tree.diff_children(&[&self.content, icon]);
+        } else {
+            tree.diff_children(std::slice::from_ref(&self.content));
+        }
     }
 
     fn size(&self) -> Size<Length> {
@@ -141,24 +433,74 @@ where
     }
 
     fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
-        let padding: Padding = self.padding.into();
-        let limits = limits
-            .loose()
-            .width(self.width)
-            .height(self.height)
-            .shrink(padding);
-
-        let mut content =
-            self.content
-                .as_widget()
-                .layout(&mut tree.children[0], renderer, &limits.loose());
-        let size = limits.resolve(self.width, self.height, content.size());
-
-        content = content
+        if self.is_hidden() {
+            return Node::new(Size::ZERO);
+        }
+
+        if self.size == BadgeSize::Dot {
+            return Node::new(Size::new(self.dot_diameter, self.dot_diameter));
+        }
+
+        let padding: Padding = self.effective_padding().into();
+        let mut limits = limits.loose().width(self.width).height(self.height);
+        if let Some(max_width) = self.max_width {
+            limits = limits.max_width(max_width);
+        }
+        let limits = limits.shrink(padding);
+
+        // The icon keeps its size, the content gets the remaining width
+        let icon = self.icon.as_ref().map(|icon| {
+            icon.as_widget()
+                .layout(&mut tree.children[1], renderer, &limits.loose())
+        });
+        let icon_width = icon
+            .as_ref()
+            .map_or(0.0, |icon| icon.size().width + self.icon_spacing);
+
+        let mut content = self.content.as_widget().layout(
+            &mut tree.children[0],
+            renderer,
+            &limits.loose().shrink(Size::new(icon_width, 0.0)),
+        );
+        let row_size = Size::new(
+            icon_width + content.size().width,
+            icon.as_ref()
+                .map_or(0.0, |icon| icon.size().height)
+                .max(content.size().height),
+        );
+        let mut size = limits.resolve(self.width, self.height, row_size);
+        // A count keeps its width while it gets another digit, and a clamped pill is never
+        // narrower than high so its rounded ends don't invert
+        if (self.count.is_some() && self.width == Length::Shrink) || self.max_width.is_some() {
+            size.width = size.width.max(size.height);
+        }
+        // A clipped content is shown from its start
+        let horizontal_alignment = if row_size.width > size.width {
+            Alignment::Start
+        } else {
+            self.horizontal_alignment
+        };
+
+        // The icon and the content are aligned as a row and centered vertically in it
+        let row = Node::new(row_size)
             .move_to(Point::new(padding.left, padding.top))
-            .align(self.horizontal_alignment, self.vertical_alignment, size);
+            .align(horizontal_alignment, self.vertical_alignment, size)
+            .bounds();
+
+        let content_height = content.size().height;
+        content = content.move_to(Point::new(
+            row.x + icon_width,
+            row.y + (row.height - content_height) / 2.0,
+        ));
+        let icon = icon.map(|icon| {
+            let icon_height = icon.size().height;
+            icon.move_to(Point::new(row.x, row.y + (row.height - icon_height) / 2.0))
+        });
 
-        Node::with_children(size.expand(padding), vec![content])
+        Node::with_children(
+            size.expand(padding),
+            std::iter::once(content).chain(icon).collect(),
+        )
     }
 
     fn on_event(
@@ -172,19 +514,83 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) -> event::Status {
-        self.content.as_widget_mut().on_event(
-            &mut state.children[0],
-            event,
-            layout
-                .children()
-                .next()
-                .expect("Native: Layout should have a children layout for a badge."),
-            cursor,
-            renderer,
-            clipboard,
-            shell,
-            viewport,
-        )
+        if self.is_hidden() {
+            return event::Status::Ignored;
+        }
+
+        if let Event::Window(_id, window::Event::RedrawRequested(now)) = event {
+            let state = state.state.downcast_mut::<State>();
+
+            match self.pulse {
+                Some(period) if layout.bounds().intersects(viewport) => {
+                    let pulse = state
+                        .pulse
+                        .filter(|pulse| pulse.duration() == period)
+                        .unwrap_or_else(|| Animation::new(now, period).repeat());
+                    state.pulse = Some(pulse);
+                    state.pulse_progress = pulse.progress(now);
+
+                    animation::request_next_frame(shell, now);
+                }
+                // The pulse is paused while the badge is out of view
+                Some(_) => {}
+                None => {
+                    state.pulse = None;
+                    state.pulse_progress = 0.0;
+                }
+            }
+        }
+
+        let status = if self.size == BadgeSize::Dot {
+            event::Status::Ignored
+        } else {
+            let mut children = layout.children();
+
+            let content_status = self.content.as_widget_mut().on_event(
+                &mut state.children[0],
+                event.clone(),
+                children
+                    .next()
+                    .expect("Native: Layout should have a children layout for a badge."),
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+
+            self.icon
+                .as_mut()
+                .zip(children.next())
+                .map_or(content_status, |(icon, icon_layout)| {
+                    content_status.merge(icon.as_widget_mut().on_event(
+                        &mut state.children[1],
+                        event.clone(),
+                        icon_layout,
+                        cursor,
+                        renderer,
+                        clipboard,
+                        shell,
+                        viewport,
+                    ))
+                })
+        };
+
+        let press_status = self
+            .on_press
+            .clone()
+            .map_or(event::Status::Ignored, |on_press| {
+                on_event_press(
+                    state.state.downcast_mut::<State>(),
+                    &event,
+                    status,
+                    layout.bounds(),
+                    cursor,
+                    || shell.publish(on_press),
+                )
+            });
+
+        status.merge(press_status)
     }
 
     fn mouse_interaction(
@@ -195,6 +601,16 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
+        if self.is_hidden() {
+            return mouse::Interaction::default();
+        }
+        if self.on_press.is_some() && cursor.is_over(layout.bounds()) {
+            return mouse::Interaction::Pointer;
+        }
+        if self.size == BadgeSize::Dot {
+            return mouse::Interaction::default();
+        }
+
         self.content.as_widget().mouse_interaction(
             &state.children[0],
             layout,
@@ -214,21 +630,41 @@ where
         cursor: Cursor,
         viewport: &Rectangle,
     ) {
+        if self.is_hidden() {
+            return;
+        }
+
         let bounds = layout.bounds();
         let mut children = layout.children();
         let is_mouse_over = bounds.contains(cursor.position().unwrap_or_default());
-        let style_sheet = if is_mouse_over {
+        let is_pressed = self.on_press.is_some() && tree.state.downcast_ref::<State>().is_pressed;
+        let style_sheet = if is_pressed {
+            theme.pressed(&self.style)
+        } else if is_mouse_over {
             theme.hovered(&self.style)
         } else {
             theme.active(&self.style)
         };
+        let style_sheet = if self.pulse.is_some() {
+            let progress = tree.state.downcast_ref::<State>().pulse_progress;
+            // A sine wave going from the full opacity to the faded one and back
+            let fade = 0.5 - 0.5 * (progress * std::f32::consts::TAU).cos();
+            faded(&style_sheet, 1.0 - PULSE_FADE * fade)
+        } else {
+            style_sheet
+        };
 
         //println!("height: {}", bounds.height);
         // 34 15
         //  x
-        let border_radius = style_sheet
-            .border_radius
-            .unwrap_or(bounds.height / BORDER_RADIUS_RATIO);
+        let border_radius = if self.size == BadgeSize::Dot {
+            bounds.width.min(bounds.height) / 2.0
+        } else {
+            style_sheet
+                .border_radius
+                .unwrap_or(bounds.height / BORDER_RADIUS_RATIO)
+                .min(bounds.width.min(bounds.height) / 2.0)
+        };
 
         renderer.fill_quad(
             renderer::Quad {
@@ -243,19 +679,118 @@ where
             style_sheet.background,
         );
 
-        self.content.as_widget().draw(
-            &tree.children[0],
-            renderer,
-            theme,
-            &renderer::Style {
-                text_color: style_sheet.text_color,
-            },
-            children
-                .next()
-                .expect("Graphics: Layout should have a children layout for Badge"),
-            cursor,
-            viewport,
-        );
+        if self.size == BadgeSize::Dot {
+            return;
+        }
+
+        let mut draw_content = |renderer: &mut Renderer| {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                &renderer::Style {
+                    text_color: style_sheet.text_color,
+                },
+                children
+                    .next()
+                    .expect("Graphics: Layout should have a children layout for Badge"),
+                cursor,
+                viewport,
+            );
+
+            if let Some((icon, icon_layout)) = self.icon.as_ref().zip(children.next()) {
+                icon.as_widget().draw(
+                    &tree.children[1],
+                    renderer,
+                    theme,
+                    &renderer::Style {
+                        text_color: style_sheet.icon_color.unwrap_or(style_sheet.text_color),
+                    },
+                    icon_layout,
+                    cursor,
+                    viewport,
+                );
+            }
+        };
+
+        // A content wider than the maximum width is clipped inside of the padding
+        if self.max_width.is_some() {
+            let padding = f32::from(self.effective_padding());
+            let inner_bounds = Rectangle {
+                x: bounds.x + padding,
+                y: bounds.y + padding,
+                width: (bounds.width - 2.0 * padding).max(0.0),
+                height: (bounds.height - 2.0 * padding).max(0.0),
+            };
+            renderer.with_layer(inner_bounds, draw_content);
+        } else {
+            draw_content(renderer);
+        }
+    }
+}
+
+/// The state of a [`Badge`].
+#[derive(Debug, Default)]
+struct State {
+    /// Whether the [`Badge`] is pressed.
+    is_pressed: bool,
+    /// The pulse of the [`Badge`], once started.
+    pulse: Option<Animation>,
+    /// The progress of the current pulse.
+    pulse_progress: f32,
+}
+
+/// Multiplies the opacity of the colors of the [`Appearance`] by the given alpha.
+fn faded(appearance: &Appearance, alpha: f32) -> Appearance {
+    let fade = |color: Color| Color {
+        a: color.a * alpha,
+        ..color
+    };
+
+    Appearance {
+        background: match appearance.background {
+            Background::Color(color) => Background::Color(fade(color)),
+            Background::Gradient(gradient) => Background::Gradient(gradient.mul_alpha(alpha)),
+        },
+        border_color: appearance.border_color.map(fade),
+        text_color: fade(appearance.text_color),
+        icon_color: appearance.icon_color.map(fade),
+        ..*appearance
+    }
+}
+
+/// Handles the presses of a pressable [`Badge`] that aren't handled by its content.
+fn on_event_press(
+    state: &mut State,
+    event: &Event,
+    status: event::Status,
+    bounds: Rectangle,
+    cursor: Cursor,
+    on_press: impl FnOnce(),
+) -> event::Status {
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. })
+            if status == event::Status::Ignored && cursor.is_over(bounds) =>
+        {
+            state.is_pressed = true;
+            event::Status::Captured
+        }
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerLifted { .. })
+            if state.is_pressed =>
+        {
+            state.is_pressed = false;
+            if cursor.is_over(bounds) {
+                on_press();
+            }
+            event::Status::Captured
+        }
+        Event::Touch(touch::Event::FingerLost { .. }) => {
+            state.is_pressed = false;
+            event::Status::Ignored
+        }
+        _ => event::Status::Ignored,
     }
 }
 
@@ -270,3 +805,48 @@ where
         Self::new(badge)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Badge, BadgeSize};
+    use crate::native::measure::Measure;
+    use iced_widget::core::{layout::Limits, widget::Tree, Size, Widget};
+
+    type TestBadge<'a> = Badge<'a, (), iced_widget::Theme, Measure>;
+
+    fn height(badge: &TestBadge<'_>) -> f32 {
+        badge
+            .layout(
+                &mut Tree::new(badge as &dyn Widget<(), iced_widget::Theme, Measure>),
+                &Measure,
+                &Limits::new(Size::ZERO, Size::new(400.0, 100.0)),
+            )
+            .size()
+            .height
+    }
+
+    #[test]
+    fn small_size_test() {
+        for (normal, small) in [
+            (
+                TestBadge::count(3),
+                TestBadge::count(3).size(BadgeSize::Small),
+            ),
+            (
+                TestBadge::text("New"),
+                TestBadge::text("New").size(BadgeSize::Small),
+            ),
+        ] {
+            // 16 pixels of text at 1.3 line height and a padding of 7 against 12
+            // pixels of text and a padding of 3
+            assert!((height(&normal) - 34.8).abs() < 0.01);
+            assert!((height(&small) - 21.6).abs() < 0.01);
+        }
+
+        // A set text size is kept
+        let badge = TestBadge::text("New")
+            .text_size(20.0)
+            .size(BadgeSize::Small);
+        assert!((height(&badge) - 32.0).abs() < 0.01);
+    }
+}
@@ -0,0 +1,271 @@
+//! Use a badge anchored on a corner of another widget, e.g. a counter on an icon.
+//!
+//! *This API requires the following crate features to be activated: badge*
+
+use crate::native::floating_element::{Anchor, FloatingElement, Offset};
+
+use iced_widget::core::{
+    self, event,
+    layout::{Limits, Node},
+    mouse::Cursor,
+    renderer,
+    widget::Tree,
+    Clipboard, Element, Event, Layout, Length, Rectangle, Shell, Size, Vector, Widget,
+};
+
+/// The corner of the underlay an [`Anchored`] badge is placed on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Corner {
+    /// The top left corner.
+    TopLeft,
+    /// The top right corner.
+    #[default]
+    TopRight,
+    /// The bottom left corner.
+    BottomLeft,
+    /// The bottom right corner.
+    BottomRight,
+}
+
+impl Corner {
+    /// The signs of the direction pointing out of the underlay at this [`Corner`].
+    const fn direction(self) -> Vector {
+        match self {
+            Self::TopLeft => Vector::new(-1.0, -1.0),
+            Self::TopRight => Vector::new(1.0, -1.0),
+            Self::BottomLeft => Vector::new(-1.0, 1.0),
+            Self::BottomRight => Vector::new(1.0, 1.0),
+        }
+    }
+}
+
+impl From<Corner> for Anchor {
+    fn from(corner: Corner) -> Self {
+        match corner {
+            Corner::TopLeft => Self::NorthWest,
+            Corner::TopRight => Self::NorthEast,
+            Corner::BottomLeft => Self::SouthWest,
+            Corner::BottomRight => Self::SouthEast,
+        }
+    }
+}
+
+/// Creates an [`Anchored`] badge overlapping the given [`Corner`] of the underlay.
+///
+/// It expects:
+///     * the underlay [`Element`] the badge is anchored on.
+///     * the badge [`Element`], usually a [`Badge`](super::Badge).
+///     * the [`Corner`] of the underlay.
+pub fn anchored<'a, Message, Theme, Renderer>(
+    underlay: impl Into<Element<'a, Message, Theme, Renderer>>,
+    badge: impl Into<Element<'a, Message, Theme, Renderer>>,
+    corner: Corner,
+) -> Anchored<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    Anchored::new(underlay, badge, corner)
+}
+
+/// A badge anchored on a corner of an underlay.
+///
+/// The center of the badge is placed on the [`Corner`] of the underlay, so the badge
+/// overlaps it by half its size. The badge is not interactive: the clicks on it reach
+/// the underlay.
+///
+/// # Example
+/// ```ignore
+/// # use iced::widget::{button, Text};
+/// # use iced_aw::badge::{self, Badge, Corner};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Inbox,
+/// }
+///
+/// let inbox = badge::anchored(
+///     button(Text::new("Inbox")).on_press(Message::Inbox),
+///     Badge::new(Text::new("3")),
+///     Corner::TopRight,
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Anchored<'a, Message, Theme = iced_widget::Theme, Renderer = iced_widget::Renderer>
+where
+    Renderer: core::Renderer,
+{
+    /// The underlay [`Element`] the badge is anchored on.
+    underlay: Element<'a, Message, Theme, Renderer>,
+    /// The badge [`Element`].
+    badge: Element<'a, Message, Theme, Renderer>,
+    /// The [`Corner`] of the underlay the badge is placed on.
+    corner: Corner,
+    /// The offset of the badge from its position on the [`Corner`].
+    offset: Vector,
+}
+
+impl<'a, Message, Theme, Renderer> Anchored<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    /// Creates a new [`Anchored`] badge overlapping the given [`Corner`] of the underlay.
+    pub fn new<U, B>(underlay: U, badge: B, corner: Corner) -> Self
+    where
+        U: Into<Element<'a, Message, Theme, Renderer>>,
+        B: Into<Element<'a, Message, Theme, Renderer>>,
+    {
+        Anchored {
+            underlay: underlay.into(),
+            badge: badge.into(),
+            corner,
+            offset: Vector::new(0.0, 0.0),
+        }
+    }
+
+    /// Moves the badge of the [`Anchored`] badge from its position on the [`Corner`],
+    /// e.g. `Vector::new(-4.0, 4.0)` to move it towards the inside of a top right corner.
+    #[must_use]
+    pub fn offset(mut self, offset: Vector) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Anchored<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + core::Renderer,
+    Theme: 'a,
+{
+    fn from(anchored: Anchored<'a, Message, Theme, Renderer>) -> Self {
+        // The floating element offsets towards the inside of the underlay
+        let direction = anchored.corner.direction();
+        let offset = Offset {
+            x: anchored.offset.x * -direction.x,
+            y: anchored.offset.y * -direction.y,
+        };
+
+        FloatingElement::new(
+            anchored.underlay,
+            Overhang {
+                content: anchored.badge,
+                direction,
+            },
+        )
+        .anchor(anchored.corner.into())
+        .offset(offset)
+        .pass_through(true)
+        .into()
+    }
+}
+
+/// Shifts its content out of its bounds by half the size of the content, so the
+/// [`FloatingElement`] placing it in a corner centers the content on the corner.
+///
+/// It ignores the events but the ones of the window, the badge only decorates the underlay.
+struct Overhang<'a, Message, Theme, Renderer> {
+    /// The content [`Element`].
+    content: Element<'a, Message, Theme, Renderer>,
+    /// The signs of the direction the content is shifted to.
+    direction: Vector,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Overhang<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let content =
+            self.content
+                .as_widget()
+                .layout(&mut tree.children[0], renderer, &limits.loose());
+        let size = content.size();
+
+        Node::with_children(
+            size,
+            vec![content.translate(Vector::new(
+                self.direction.x * size.width / 2.0,
+                self.direction.y * size.height / 2.0,
+            ))],
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        // The badge may be animated
+        let (Event::Window(..), Some(content_layout)) = (&event, layout.children().next()) else {
+            return event::Status::Ignored;
+        };
+
+        self.content.as_widget_mut().on_event(
+            &mut state.children[0],
+            event,
+            content_layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let Some(content_layout) = layout.children().next() else {
+            return;
+        };
+
+        self.content.as_widget().draw(
+            &state.children[0],
+            renderer,
+            theme,
+            style,
+            content_layout,
+            cursor,
+            &content_layout.bounds(),
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Overhang<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + core::Renderer,
+    Theme: 'a,
+{
+    fn from(overhang: Overhang<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(overhang)
+    }
+}
@@ -0,0 +1,124 @@
+//! The text of a badge, truncated when it is too wide.
+//!
+//! *This API requires the following crate features to be activated: badge*
+
+use super::BadgeOverflow;
+use crate::core::ellipsis::Ellipsis;
+
+use iced_widget::core::{
+    alignment::{Horizontal, Vertical},
+    layout::{Limits, Node},
+    mouse::Cursor,
+    renderer,
+    text::{self, LineHeight, Paragraph, Shaping},
+    widget::{tree, Tree},
+    Layout, Length, Pixels, Point, Rectangle, Size, Widget,
+};
+
+/// A single line of text that is truncated with an ellipsis or clipped when it is wider
+/// than its limits.
+pub(super) struct Label {
+    /// The text of the [`Label`].
+    content: String,
+    /// How the text is truncated.
+    overflow: BadgeOverflow,
+    /// The size of the text, the default size of the renderer if `None`.
+    size: Option<Pixels>,
+}
+
+impl Label {
+    /// Creates a new [`Label`] showing the given text.
+    pub(super) const fn new(
+        content: String,
+        overflow: BadgeOverflow,
+        size: Option<Pixels>,
+    ) -> Self {
+        Self {
+            content,
+            overflow,
+            size,
+        }
+    }
+
+    /// The text of the [`Label`] to draw or measure.
+    fn text<'b, Renderer>(
+        &self,
+        content: &'b str,
+        bounds: Size,
+        renderer: &Renderer,
+    ) -> text::Text<'b, Renderer::Font>
+    where
+        Renderer: text::Renderer,
+    {
+        text::Text {
+            content,
+            bounds,
+            size: self.size.unwrap_or_else(|| renderer.default_size()),
+            line_height: LineHeight::default(),
+            font: renderer.default_font(),
+            horizontal_alignment: Horizontal::Left,
+            vertical_alignment: Vertical::Top,
+            shaping: Shaping::Advanced,
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Label
+where
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<Ellipsis>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(Ellipsis::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    fn layout(&self, _tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let size =
+            Renderer::Paragraph::with_text(self.text(&self.content, Size::INFINITY, renderer))
+                .min_bounds();
+
+        Node::new(limits.resolve(Length::Shrink, Length::Shrink, size))
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        let ellipsized;
+        let content = if self.overflow == BadgeOverflow::Ellipsis {
+            ellipsized = state.state.downcast_ref::<Ellipsis>().shorten(
+                &self.content,
+                bounds.width,
+                |content| {
+                    Renderer::Paragraph::with_text(self.text(content, Size::INFINITY, renderer))
+                        .min_width()
+                },
+            );
+            &ellipsized
+        } else {
+            &self.content
+        };
+
+        renderer.fill_text(
+            self.text(content, Size::new(f32::INFINITY, bounds.height), renderer),
+            Point::new(bounds.x, bounds.y),
+            style.text_color,
+            bounds,
+        );
+    }
+}
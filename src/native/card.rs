@@ -12,17 +12,34 @@ use iced_widget::{
         layout::{Limits, Node},
         mouse::{self, Cursor},
         renderer, touch,
-        widget::{Operation, Tree},
-        Alignment, Border, Clipboard, Color, Element, Event, Layout, Length, Padding, Pixels,
-        Point, Rectangle, Shadow, Shell, Size, Widget,
+        widget::{
+            tree::{self, Tag},
+            Operation, Tree,
+        },
+        Alignment, Border, Clipboard, Color, Element, Event, Font, Layout, Length, Padding, Pixels,
+        Point, Rectangle, Shadow, Shell, Size, Vector, Widget,
     },
     text::LineHeight,
+    Row, Space,
 };
 
 pub use crate::style::card::{Appearance, StyleSheet};
 
+pub mod deck;
+pub use deck::{Deck, Direction};
+
 /// The default padding of a [`Card`].
 const DEFAULT_PADDING: f32 = 10.0;
+/// The spacing between the head actions of a [`Card`] and around them.
+const HEAD_ACTIONS_SPACING: f32 = 5.0;
+/// The minimal width and height of the area of the close icon reacting to the cursor.
+const MIN_CLOSE_HIT_SIZE: f32 = 24.0;
+/// The width of the scrollbar of the body of a [`Card`].
+const SCROLLBAR_WIDTH: f32 = 6.0;
+/// The minimal height of the scroller of the scrollbar of the body of a [`Card`].
+const MIN_SCROLLER_HEIGHT: f32 = 20.0;
+/// The distance scrolled per line of the mouse wheel.
+const SCROLL_LINE_HEIGHT: f32 = 60.0;
 
 /// A card consisting of a head, body and optional foot.
 ///
@@ -66,14 +83,34 @@ where
     padding_foot: Padding,
     /// The optional size of the close icon of the [`Card`].
     close_size: Option<f32>,
+    /// The optional custom close icon of the [`Card`].
+    close_icon: Option<char>,
+    /// The optional font of the custom close icon of the [`Card`].
+    close_icon_font: Option<Font>,
+    /// The position of the close icon of the [`Card`].
+    close_position: ClosePosition,
     /// The optional message that is send if the close icon of the [`Card`] is pressed.
     on_close: Option<Message>,
+    /// The optional message that is send if the [`Card`] is pressed.
+    on_press: Option<Message>,
+    /// The optional banner [`Element`] above the head of the [`Card`].
+    banner: Option<Element<'a, Message, Theme, Renderer>>,
     /// The head [`Element`] of the [`Card`].
     head: Element<'a, Message, Theme, Renderer>,
+    /// Whether the [`Card`] has no head.
+    headless: bool,
+    /// The optional actions at the trailing end of the head of the [`Card`].
+    head_actions: Option<Element<'a, Message, Theme, Renderer>>,
     /// The body [`Element`] of the [`Card`].
     body: Element<'a, Message, Theme, Renderer>,
     /// The optional foot [`Element`] of the [`Card`].
     foot: Option<Element<'a, Message, Theme, Renderer>>,
+    /// The horizontal alignment of the foot [`Element`] of the [`Card`].
+    foot_alignment: Horizontal,
+    /// Whether the [`Card`] is loading, covering its body and foot.
+    loading: bool,
+    /// The optional indicator shown while the [`Card`] is loading.
+    loading_indicator: Option<Element<'a, Message, Theme, Renderer>>,
     /// The style of the [`Card`].
     style: <Theme as StyleSheet>::Style,
 }
@@ -102,14 +139,52 @@ where
             padding_body: DEFAULT_PADDING.into(),
             padding_foot: DEFAULT_PADDING.into(),
             close_size: None,
+            close_icon: None,
+            close_icon_font: None,
+            close_position: ClosePosition::default(),
             on_close: None,
+            on_press: None,
+            banner: None,
             head: head.into(),
+            headless: false,
+            head_actions: None,
             body: body.into(),
             foot: None,
+            foot_alignment: Horizontal::Left,
+            loading: false,
+            loading_indicator: None,
             style: <Theme as StyleSheet>::Style::default(),
         }
     }
 
+    /// Creates a new [`Card`] containing the given body but no head.
+    ///
+    /// No head background is drawn, the close icon and the head actions are shown at the
+    /// top right of the body.
+    pub fn headless<B>(body: B) -> Self
+    where
+        Message: 'a,
+        B: Into<Element<'a, Message, Theme, Renderer>>,
+    {
+        Self {
+            headless: true,
+            ..Self::new(Space::new(Length::Shrink, Length::Shrink), body)
+        }
+    }
+
+    /// Sets the banner [`Element`] of the [`Card`], e.g. an image, shown above the head
+    /// at the full width of the [`Card`].
+    ///
+    /// The banner is clipped to the bounds of the [`Card`] and its rounded top corners.
+    #[must_use]
+    pub fn banner<B>(mut self, banner: B) -> Self
+    where
+        B: Into<Element<'a, Message, Theme, Renderer>>,
+    {
+        self.banner = Some(banner.into());
+        self
+    }
+
     /// Sets the [`Element`] of the foot of the [`Card`].
     #[must_use]
     pub fn foot<F>(mut self, foot: F) -> Self
@@ -120,13 +195,73 @@ where
         self
     }
 
+    /// Sets the horizontal alignment of the foot [`Element`] of the [`Card`] (left by
+    /// default), e.g. right for the buttons of a dialog.
+    ///
+    /// The foot is aligned as a whole: to spread several buttons, put them in a `Row` of
+    /// [`Length::Fill`] width with spaces of [`Length::Fill`] width between them.
+    #[must_use]
+    pub fn foot_alignment(mut self, alignment: Horizontal) -> Self {
+        self.foot_alignment = alignment;
+        self
+    }
+
     /// Sets the size of the close icon of the [`Card`].
+    ///
+    /// The close icon reacts to the cursor in an area of at least 24 by 24 pixels.
     #[must_use]
     pub fn close_size(mut self, size: f32) -> Self {
         self.close_size = Some(size);
         self
     }
 
+    /// Sets the close icon of the [`Card`], replacing the default cross.
+    ///
+    /// It is drawn with the font of [`close_icon_font`](Self::close_icon_font) and the
+    /// close color of the style.
+    #[must_use]
+    pub fn close_icon(mut self, icon: char) -> Self {
+        self.close_icon = Some(icon);
+        self
+    }
+
+    /// Sets the font of the custom [`close_icon`](Self::close_icon), e.g. an icon font.
+    ///
+    /// Defaults to the font of the text.
+    #[must_use]
+    pub fn close_icon_font(mut self, font: Font) -> Self {
+        self.close_icon_font = Some(font);
+        self
+    }
+
+    /// Sets the [`ClosePosition`] of the close icon of the [`Card`] (in the head by
+    /// default).
+    #[must_use]
+    pub fn close_position(mut self, position: ClosePosition) -> Self {
+        self.close_position = position;
+        self
+    }
+
+    /// Sets the actions of the head of the [`Card`], e.g. small buttons.
+    ///
+    /// They are placed in a row at the trailing end of the head, before the close icon.
+    /// The head [`Element`] gets the remaining width and is cut off if it doesn't fit.
+    #[must_use]
+    pub fn head_actions(mut self, actions: Vec<Element<'a, Message, Theme, Renderer>>) -> Self
+    where
+        Message: 'a,
+        Theme: 'a,
+        Renderer: 'a,
+    {
+        self.head_actions = (!actions.is_empty()).then(|| {
+            Row::with_children(actions)
+                .spacing(HEAD_ACTIONS_SPACING)
+                .align_items(Alignment::Center)
+                .into()
+        });
+        self
+    }
+
     /// Sets the height of the [`Card`].
     #[must_use]
     pub fn height(mut self, height: Length) -> Self {
@@ -134,7 +269,56 @@ where
         self
     }
 
+    /// Sets whether the [`Card`] is loading.
+    ///
+    /// While loading, the body and the foot are covered with the loading scrim of the
+    /// [`Appearance`] and don't get any input, the head stays usable. A [`Spinner`] is
+    /// shown on top of them unless another [`loading_indicator`](Self::loading_indicator)
+    /// is set.
+    ///
+    /// [`Spinner`]: crate::Spinner
+    #[cfg(feature = "spinner")]
+    #[must_use]
+    pub fn loading(mut self, loading: bool) -> Self
+    where
+        Message: 'a,
+        Theme: 'a + crate::style::spinner::StyleSheet,
+        Renderer: 'a,
+    {
+        self.loading = loading;
+        if loading && self.loading_indicator.is_none() {
+            self.loading_indicator = Some(crate::native::Spinner::new().into());
+        }
+        self
+    }
+
+    /// Sets whether the [`Card`] is loading.
+    ///
+    /// While loading, the body and the foot are covered with the loading scrim of the
+    /// [`Appearance`] and don't get any input, the head stays usable. The
+    /// [`loading_indicator`](Self::loading_indicator) is shown on top of them.
+    #[cfg(not(feature = "spinner"))]
+    #[must_use]
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    /// Sets the [`Element`] shown in the middle of the body and the foot while the
+    /// [`Card`] is [`loading`](Self::loading).
+    #[must_use]
+    pub fn loading_indicator<I>(mut self, indicator: I) -> Self
+    where
+        I: Into<Element<'a, Message, Theme, Renderer>>,
+    {
+        self.loading_indicator = Some(indicator.into());
+        self
+    }
+
     /// Sets the maximum height of the [`Card`].
+    ///
+    /// If the body doesn't fit between the head and the foot, it becomes scrollable while
+    /// the foot stays at the bottom.
     #[must_use]
     pub fn max_height(mut self, height: f32) -> Self {
         self.max_height = height;
@@ -158,6 +342,18 @@ where
         self
     }
 
+    /// Sets the message that will be produced when the [`Card`] is pressed, e.g. to select
+    /// it.
+    ///
+    /// Presses handled by the elements inside of the [`Card`], like buttons, don't produce
+    /// it. The [`Card`] is drawn with the hovered and pressed appearances of the
+    /// [`StyleSheet`] once this is set.
+    #[must_use]
+    pub fn on_press(mut self, msg: Message) -> Self {
+        self.on_press = Some(msg);
+        self
+    }
+
     /// Sets the padding of the [`Card`].
     ///
     /// This will set the padding of the head, body and foot to the
@@ -204,6 +400,71 @@ where
         self.width = width;
         self
     }
+
+    /// The child [`Element`]s of the [`Card`] in the order of their states.
+    fn elements(&self) -> Vec<&Element<'a, Message, Theme, Renderer>> {
+        [
+            Some(&self.head),
+            Some(&self.body),
+            self.foot.as_ref(),
+            self.head_actions.as_ref(),
+            self.banner.as_ref(),
+            self.shown_loading_indicator(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    /// The index of the state of the head actions, after the foot if there is one.
+    fn head_actions_index(&self) -> usize {
+        if self.foot.is_some() {
+            3
+        } else {
+            2
+        }
+    }
+
+    /// The close icon to draw.
+    fn resolved_close_icon(&self, renderer: &Renderer) -> CloseIcon
+    where
+        Renderer: core::text::Renderer<Font = Font>,
+    {
+        CloseIcon {
+            content: self
+                .close_icon
+                .map_or_else(|| icon_to_string(BootstrapIcon::X), String::from),
+            font: match (self.close_icon, self.close_icon_font) {
+                (_, Some(font)) => font,
+                (Some(_), None) => renderer.default_font(),
+                (None, None) => BOOTSTRAP_FONT,
+            },
+            size: self.resolved_close_size(renderer),
+        }
+    }
+
+    /// The size of the close icon.
+    fn resolved_close_size(&self, renderer: &Renderer) -> f32
+    where
+        Renderer: core::text::Renderer,
+    {
+        self.close_size.unwrap_or_else(|| renderer.default_size().0)
+    }
+
+    /// The index of the state of the banner, after the head actions if there are any.
+    fn banner_index(&self) -> usize {
+        self.head_actions_index() + usize::from(self.head_actions.is_some())
+    }
+
+    /// The loading indicator, if the [`Card`] is loading.
+    fn shown_loading_indicator(&self) -> Option<&Element<'a, Message, Theme, Renderer>> {
+        self.loading_indicator.as_ref().filter(|_| self.loading)
+    }
+
+    /// The index of the state of the loading indicator, the last one.
+    fn loading_indicator_index(&self) -> usize {
+        self.banner_index() + usize::from(self.banner.is_some())
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -213,25 +474,20 @@ where
     Renderer: 'a + core::Renderer + core::text::Renderer<Font = iced_widget::core::Font>,
     Theme: StyleSheet,
 {
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
     fn children(&self) -> Vec<Tree> {
-        self.foot.as_ref().map_or_else(
-            || vec![Tree::new(&self.head), Tree::new(&self.body)],
-            |foot| {
-                vec![
-                    Tree::new(&self.head),
-                    Tree::new(&self.body),
-                    Tree::new(foot),
-                ]
-            },
-        )
+        self.elements().into_iter().map(Tree::new).collect()
     }
 
     fn diff(&self, tree: &mut Tree) {
-        if let Some(foot) = self.foot.as_ref() {
-            tree.diff_children(&[&self.head, &self.body, foot]);
-        } else {
-            tree.diff_children(&[&self.head, &self.body]);
-        }
+        tree.diff_children(&self.elements());
     }
 
     fn size(&self) -> Size<Length> {
@@ -244,49 +500,133 @@ where
     fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
         let limits = limits.max_width(self.max_width).max_height(self.max_height);
 
-        let head_node = head_node(
+        let banner_node = self.banner.as_ref().map_or_else(Node::default, |banner| {
+            banner_node(
+                renderer,
+                &limits,
+                banner,
+                self.width,
+                &mut tree.children[self.banner_index()],
+            )
+        });
+
+        let mut head_node = head_node(
             renderer,
             &limits,
             &self.head,
+            self.head_actions
+                .as_ref()
+                .map(|actions| (actions, self.head_actions_index())),
             self.padding_head,
             self.width,
-            self.on_close.is_some(),
-            self.close_size,
+            self.on_close.is_some() && self.close_position == ClosePosition::Head,
+            self.resolved_close_size(renderer),
             tree,
         );
 
+        if self.headless {
+            // The close icon and the head actions are kept on top of the body
+            head_node = Node::with_children(
+                Size::new(head_node.size().width, 0.0),
+                head_node.children().to_vec(),
+            );
+        }
+
+        if self.on_close.is_some() && self.close_position == ClosePosition::Body {
+            // The close icon is placed at the top right of the body, but kept in the head
+            // layout where it is looked for
+            let close_size = self.resolved_close_size(renderer) + 1.0;
+            let head_size = head_node.size();
+            let mut children = head_node.children().to_vec();
+            children.push(
+                Node::new(Size::new(close_size, close_size)).move_to(Point::new(
+                    head_size.width - self.padding_body.right - close_size,
+                    head_size.height + self.padding_body.top,
+                )),
+            );
+            head_node = Node::with_children(head_size, children);
+        }
+
+        let mut foot_node = self.foot.as_ref().map_or_else(Node::default, |foot| {
+            foot_node(
+                renderer,
+                &limits,
+                foot,
+                self.padding_foot,
+                self.width,
+                self.foot_alignment,
+                tree,
+            )
+        });
+
+        // The body gets the height left by the banner, the head and the foot
         let mut body_node = body_node(
             renderer,
-            &limits,
+            &limits.shrink(Size::new(
+                0.0,
+                banner_node.size().height + head_node.size().height + foot_node.size().height,
+            )),
             &self.body,
             self.padding_body,
             self.width,
             tree,
         );
 
+        let head_bounds = head_node.bounds();
+        head_node = head_node.move_to(Point::new(
+            head_bounds.x,
+            head_bounds.y + banner_node.bounds().height,
+        ));
+
         let body_bounds = body_node.bounds();
         body_node = body_node.move_to(Point::new(
             body_bounds.x,
-            body_bounds.y + head_node.bounds().height,
+            body_bounds.y + banner_node.bounds().height + head_node.bounds().height,
         ));
 
-        let mut foot_node = self.foot.as_ref().map_or_else(Node::default, |foot| {
-            foot_node(renderer, &limits, foot, self.padding_foot, self.width, tree)
-        });
-
         let foot_bounds = foot_node.bounds();
 
         foot_node = foot_node.move_to(Point::new(
             foot_bounds.x,
-            foot_bounds.y + head_node.bounds().height + body_node.bounds().height,
+            foot_bounds.y
+                + banner_node.bounds().height
+                + head_node.bounds().height
+                + body_node.bounds().height,
         ));
 
+        let loading_indicator_node =
+            self.shown_loading_indicator()
+                .map_or_else(Node::default, |indicator| {
+                    // Centered in the body and the foot
+                    let covered = body_node.bounds().union(&foot_node.bounds());
+                    let node = indicator.as_widget().layout(
+                        &mut tree.children[self.loading_indicator_index()],
+                        renderer,
+                        &Limits::new(Size::ZERO, covered.size()),
+                    );
+                    let node_size = node.size();
+
+                    node.move_to(Point::new(
+                        covered.x + (covered.width - node_size.width) / 2.0,
+                        covered.y + (covered.height - node_size.height) / 2.0,
+                    ))
+                });
+
         Node::with_children(
             Size::new(
                 body_node.size().width,
-                head_node.size().height + body_node.size().height + foot_node.size().height,
+                banner_node.size().height
+                    + head_node.size().height
+                    + body_node.size().height
+                    + foot_node.size().height,
             ),
-            vec![head_node, body_node, foot_node],
+            vec![
+                head_node,
+                body_node,
+                foot_node,
+                banner_node,
+                loading_indicator_node,
+            ],
         )
     }
 
@@ -320,6 +660,24 @@ where
             viewport,
         );
 
+        let actions_index = self.head_actions_index();
+        let actions_status = self
+            .head_actions
+            .as_mut()
+            .and_then(|actions| Some((actions, head_children.next()?)))
+            .map_or(event::Status::Ignored, |(actions, actions_layout)| {
+                actions.as_widget_mut().on_event(
+                    &mut state.children[actions_index],
+                    event.clone(),
+                    actions_layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            });
+
         let close_status = head_children
             .next()
             .map_or(event::Status::Ignored, |close_layout| {
@@ -331,8 +689,7 @@ where
                         // TODO: `let` expressions in this position are experimental
                         // see issue #53667 <https://github.com/rust-lang/rust/issues/53667> for more information
                         .filter(|_| {
-                            close_layout
-                                .bounds()
+                            close_hit_bounds(close_layout.bounds())
                                 .contains(cursor.position().unwrap_or_default())
                         })
                         .map_or(event::Status::Ignored, |on_close| {
@@ -347,42 +704,136 @@ where
             .next()
             .expect("Native: Layout should have a body layout");
         let mut body_children = body_layout.children();
-        let body_status = self.body.as_widget_mut().on_event(
-            &mut state.children[1],
-            event.clone(),
-            body_children
-                .next()
-                .expect("Native: Layout should have a body content layout"),
-            cursor,
-            renderer,
-            clipboard,
-            shell,
-            viewport,
-        );
+        let body_content_layout = body_children
+            .next()
+            .expect("Native: Layout should have a body content layout");
+        let scroll = BodyScroll::new(body_layout, body_content_layout, self.padding_body);
+        let card_state = state.state.downcast_mut::<State>();
+        card_state.scroll_offset = scroll.clamp(card_state.scroll_offset);
 
         let foot_layout = children
             .next()
             .expect("Native: Layout should have a foot layout");
-        let mut foot_children = foot_layout.children();
-        let foot_status = self.foot.as_mut().map_or(event::Status::Ignored, |foot| {
-            foot.as_widget_mut().on_event(
-                &mut state.children[2],
-                event,
-                foot_children
-                    .next()
-                    .expect("Native: Layout should have a foot content layout"),
-                cursor,
-                renderer,
-                clipboard,
-                shell,
-                viewport,
-            )
-        });
+        let (body_status, foot_status) = if self.loading && is_input_event(&event) {
+            // The body and the foot are covered while loading
+            let covered = body_layout.bounds().union(&foot_layout.bounds());
+            let status = if !matches!(event, Event::Keyboard(_)) && cursor.is_over(covered) {
+                event::Status::Captured
+            } else {
+                event::Status::Ignored
+            };
+
+            (status, event::Status::Ignored)
+        } else {
+            let body_status = if on_event_scrollbar(card_state, &event, scroll, cursor)
+                == event::Status::Captured
+            {
+                event::Status::Captured
+            } else {
+                let offset = card_state.scroll_offset;
+                let status = self.body.as_widget_mut().on_event(
+                    &mut state.children[1],
+                    event.clone(),
+                    body_content_layout,
+                    scroll.cursor(cursor, offset),
+                    renderer,
+                    clipboard,
+                    shell,
+                    &scroll.viewport(viewport, offset),
+                );
+
+                if status == event::Status::Ignored {
+                    on_event_scroll(state.state.downcast_mut::<State>(), &event, scroll, cursor)
+                } else {
+                    status
+                }
+            };
+
+            let mut foot_children = foot_layout.children();
+            let foot_status = self.foot.as_mut().map_or(event::Status::Ignored, |foot| {
+                foot.as_widget_mut().on_event(
+                    &mut state.children[2],
+                    event.clone(),
+                    foot_children
+                        .next()
+                        .expect("Native: Layout should have a foot content layout"),
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            });
+
+            (body_status, foot_status)
+        };
+
+        let banner_layout = children
+            .next()
+            .expect("Native: Layout should have a banner layout");
+        let banner_index = self.banner_index();
+        let banner_status = self
+            .banner
+            .as_mut()
+            .map_or(event::Status::Ignored, |banner| {
+                banner.as_widget_mut().on_event(
+                    &mut state.children[banner_index],
+                    event.clone(),
+                    banner_layout
+                        .children()
+                        .next()
+                        .expect("Native: Layout should have a banner content layout"),
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            });
+
+        let indicator_index = self.loading_indicator_index();
+        let loading = self.loading;
+        let indicator_status = self
+            .loading_indicator
+            .as_mut()
+            .filter(|_| loading)
+            .zip(children.next())
+            .map_or(event::Status::Ignored, |(indicator, indicator_layout)| {
+                indicator.as_widget_mut().on_event(
+                    &mut state.children[indicator_index],
+                    event.clone(),
+                    indicator_layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            });
 
-        head_status
+        let status = banner_status
+            .merge(indicator_status)
+            .merge(head_status)
+            .merge(actions_status)
             .merge(close_status)
             .merge(body_status)
-            .merge(foot_status)
+            .merge(foot_status);
+
+        let press_status = self
+            .on_press
+            .clone()
+            .map_or(event::Status::Ignored, |on_press| {
+                on_event_press(
+                    state.state.downcast_mut::<State>(),
+                    &event,
+                    status,
+                    layout.bounds(),
+                    cursor,
+                    || shell.publish(on_press),
+                )
+            });
+
+        status.merge(press_status)
     }
 
     fn mouse_interaction(
@@ -403,14 +854,20 @@ where
         let head = head_children
             .next()
             .expect("Native: Layout should have a head layout");
+        let actions_layout = self
+            .head_actions
+            .as_ref()
+            .and_then(|_| head_children.next());
         let close_layout = head_children.next();
 
         let is_mouse_over_close = close_layout.map_or(false, |layout| {
-            let bounds = layout.bounds();
+            let bounds = close_hit_bounds(layout.bounds());
             bounds.contains(cursor.position().unwrap_or_default())
         });
 
-        let mouse_interaction = if is_mouse_over_close {
+        let mouse_interaction = if is_mouse_over_close
+            || (self.on_press.is_some() && cursor.is_over(layout.bounds()))
+        {
             mouse::Interaction::Pointer
         } else {
             mouse::Interaction::default()
@@ -419,13 +876,25 @@ where
         let body_layout = children
             .next()
             .expect("Native: Layout should have a body layout");
-        let mut body_children = body_layout.children();
+        let body_content_layout = body_layout
+            .children()
+            .next()
+            .expect("Native: Layout should have a body content layout");
+        let scroll = BodyScroll::new(body_layout, body_content_layout, self.padding_body);
+        let offset = scroll.clamp(state.state.downcast_ref::<State>().scroll_offset);
 
         let foot_layout = children
             .next()
             .expect("Native: Layout should have a foot layout");
         let mut foot_children = foot_layout.children();
 
+        // The body and the foot are covered while loading
+        let covered_cursor = if self.loading {
+            Cursor::Unavailable
+        } else {
+            cursor
+        };
+
         mouse_interaction
             .max(self.head.as_widget().mouse_interaction(
                 &state.children[0],
@@ -434,17 +903,25 @@ where
                 viewport,
                 renderer,
             ))
-            .max(
-                self.body.as_widget().mouse_interaction(
-                    &state.children[1],
-                    body_children
-                        .next()
-                        .expect("Native: Layout should have a body content layout"),
-                    cursor,
-                    viewport,
-                    renderer,
-                ),
-            )
+            .max(self.head_actions.as_ref().zip(actions_layout).map_or_else(
+                mouse::Interaction::default,
+                |(actions, actions_layout)| {
+                    actions.as_widget().mouse_interaction(
+                        &state.children[self.head_actions_index()],
+                        actions_layout,
+                        cursor,
+                        viewport,
+                        renderer,
+                    )
+                },
+            ))
+            .max(self.body.as_widget().mouse_interaction(
+                &state.children[1],
+                body_content_layout,
+                scroll.cursor(covered_cursor, offset),
+                &scroll.viewport(viewport, offset),
+                renderer,
+            ))
             .max(
                 self.foot
                     .as_ref()
@@ -454,6 +931,22 @@ where
                             foot_children
                                 .next()
                                 .expect("Native: Layout should have a foot content layout"),
+                            covered_cursor,
+                            viewport,
+                            renderer,
+                        )
+                    }),
+            )
+            .max(
+                self.banner
+                    .as_ref()
+                    .map_or_else(mouse::Interaction::default, |banner| {
+                        banner.as_widget().mouse_interaction(
+                            &state.children[self.banner_index()],
+                            children
+                                .next()
+                                .and_then(|layout| layout.children().next())
+                                .expect("Native: Layout should have a banner content layout"),
                             cursor,
                             viewport,
                             renderer,
@@ -473,6 +966,16 @@ where
         let head_layout = children.next().expect("Missing Head Layout");
         let body_layout = children.next().expect("Missing Body Layout");
         let foot_layout = children.next().expect("Missing Footer Layout");
+        let banner_layout = children.next().expect("Missing Banner Layout");
+
+        if let Some(banner) = &self.banner {
+            banner.as_widget().operate(
+                &mut state.children[self.banner_index()],
+                banner_layout,
+                renderer,
+                operation,
+            );
+        }
 
         self.head
             .as_widget()
@@ -486,6 +989,19 @@ where
                 .as_widget()
                 .operate(&mut state.children[2], foot_layout, renderer, operation);
         };
+
+        if let Some((actions, actions_layout)) = self
+            .head_actions
+            .as_ref()
+            .zip(head_layout.children().nth(1))
+        {
+            actions.as_widget().operate(
+                &mut state.children[self.head_actions_index()],
+                actions_layout,
+                renderer,
+                operation,
+            );
+        }
     }
 
     fn draw(
@@ -500,18 +1016,27 @@ where
     ) {
         let bounds = layout.bounds();
         let mut children = layout.children();
-        let style_sheet = theme.active(&self.style);
+        let card_state = state.state.downcast_ref::<State>();
+        let style_sheet = if self.on_press.is_none() {
+            theme.active(&self.style)
+        } else if card_state.is_pressed {
+            theme.pressed(&self.style)
+        } else if cursor.is_over(bounds) {
+            theme.hovered(&self.style)
+        } else {
+            theme.active(&self.style)
+        };
 
         // Background
         renderer.fill_quad(
             renderer::Quad {
                 bounds,
                 border: Border {
-                    radius: style_sheet.border_radius.into(),
+                    radius: style_sheet.border_radius,
                     width: style_sheet.border_width,
                     color: style_sheet.border_color,
                 },
-                shadow: Shadow::default(),
+                shadow: style_sheet.shadow,
             },
             style_sheet.background,
         );
@@ -522,7 +1047,7 @@ where
             renderer::Quad {
                 bounds,
                 border: Border {
-                    radius: style_sheet.border_radius.into(),
+                    radius: style_sheet.border_radius,
                     width: style_sheet.border_width,
                     color: style_sheet.border_color,
                 },
@@ -531,6 +1056,49 @@ where
             Color::TRANSPARENT,
         );
 
+        // ----------- Banner --------------------
+        if let Some(banner) = &self.banner {
+            let banner_layout = layout
+                .children()
+                .nth(3)
+                .expect("Graphics: Layout should have a banner layout");
+            let [top_left, top_right, _, _]: [f32; 4] = style_sheet.border_radius.into();
+
+            for clip in banner_clips(bounds, top_left, top_right) {
+                let Some(clip) = clip.intersection(&banner_layout.bounds()) else {
+                    continue;
+                };
+
+                renderer.with_layer(clip, |renderer| {
+                    banner.as_widget().draw(
+                        &state.children[self.banner_index()],
+                        renderer,
+                        theme,
+                        &renderer::Style {
+                            text_color: style_sheet.head_text_color,
+                        },
+                        banner_layout
+                            .children()
+                            .next()
+                            .expect("Graphics: Layout should have a banner content layout"),
+                        cursor,
+                        viewport,
+                    );
+                });
+            }
+        }
+
+        // The banner takes the rounded top corners
+        let [_, _, bottom_right, bottom_left]: [f32; 4] = style_sheet.border_radius.into();
+        let head_style_sheet = if self.banner.is_some() {
+            Appearance {
+                border_radius: [0.0, 0.0, bottom_right, bottom_left].into(),
+                ..style_sheet
+            }
+        } else {
+            style_sheet
+        };
+
         // ----------- Head ----------------------
         let head_layout = children
             .next()
@@ -539,12 +1107,15 @@ where
             &state.children[0],
             renderer,
             &self.head,
+            self.head_actions
+                .as_ref()
+                .map(|actions| (actions, &state.children[self.head_actions_index()])),
             head_layout,
             cursor,
             viewport,
             theme,
-            &self.style,
-            self.close_size,
+            &head_style_sheet,
+            &self.resolved_close_icon(renderer),
         );
 
         // ----------- Body ----------------------
@@ -553,13 +1124,15 @@ where
             .expect("Graphics: Layout should have a body layout");
         draw_body(
             &state.children[1],
+            card_state.scroll_offset,
             renderer,
             &self.body,
+            self.padding_body,
             body_layout,
             cursor,
             viewport,
             theme,
-            &self.style,
+            &style_sheet,
         );
 
         // ----------- Foot ----------------------
@@ -574,8 +1147,43 @@ where
             cursor,
             viewport,
             theme,
-            &self.style,
+            &style_sheet,
         );
+
+        // ----------- Loading -------------------
+        if self.loading {
+            let [_, _, bottom_right, bottom_left]: [f32; 4] = style_sheet.border_radius.into();
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: body_layout.bounds().union(&foot_layout.bounds()),
+                    border: Border {
+                        radius: [0.0, 0.0, bottom_right, bottom_left].into(),
+                        width: 0.0,
+                        color: Color::TRANSPARENT,
+                    },
+                    shadow: Shadow::default(),
+                },
+                style_sheet.loading_scrim,
+            );
+
+            if let Some(indicator) = self.shown_loading_indicator() {
+                indicator.as_widget().draw(
+                    &state.children[self.loading_indicator_index()],
+                    renderer,
+                    theme,
+                    &renderer::Style {
+                        text_color: style_sheet.body_text_color,
+                    },
+                    layout
+                        .children()
+                        .nth(4)
+                        .expect("Graphics: Layout should have a loading indicator layout"),
+                    cursor,
+                    viewport,
+                );
+            }
+        }
     }
 
     fn overlay<'b>(
@@ -584,35 +1192,104 @@ where
         layout: Layout<'_>,
         renderer: &Renderer,
     ) -> Option<core::overlay::Element<'b, Message, Theme, Renderer>> {
+        let actions_layout = self
+            .head_actions
+            .as_ref()
+            .and_then(|_| layout.children().next()?.children().nth(1));
+        let banner_layout = layout
+            .children()
+            .nth(3)
+            .and_then(|layout| layout.children().next());
+        let body_layout = layout.children().nth(1)?;
+        let scroll = BodyScroll::new(
+            body_layout,
+            body_layout.children().next()?,
+            self.padding_body,
+        );
+        // The overlays of the body are moved with its content
+        let body_translation = Vector::new(
+            0.0,
+            -scroll.clamp(tree.state.downcast_ref::<State>().scroll_offset),
+        );
+
         let mut children = vec![&mut self.head, &mut self.body];
         if let Some(foot) = &mut self.foot {
             children.push(foot);
         }
-        let children = children
+        let mut states = tree.children.iter_mut();
+        let mut children = children
             .into_iter()
-            .zip(&mut tree.children)
+            .zip(states.by_ref())
             .zip(layout.children())
-            .filter_map(|((child, state), layout)| {
+            .zip([Vector::ZERO, body_translation, Vector::ZERO])
+            .filter_map(|(((child, state), layout), translation)| {
                 layout.children().next().and_then(|child_layout| {
-                    child.as_widget_mut().overlay(state, child_layout, renderer)
+                    child
+                        .as_widget_mut()
+                        .overlay(state, child_layout, renderer)
+                        .map(|overlay| overlay.translate(translation))
                 })
             })
             .collect::<Vec<_>>();
+        children.extend(
+            self.head_actions
+                .as_mut()
+                .and_then(|actions| Some((actions, states.next()?)))
+                .zip(actions_layout)
+                .and_then(|((actions, state), actions_layout)| {
+                    actions
+                        .as_widget_mut()
+                        .overlay(state, actions_layout, renderer)
+                }),
+        );
+        children.extend(
+            self.banner
+                .as_mut()
+                .and_then(|banner| Some((banner, states.next()?)))
+                .zip(banner_layout)
+                .and_then(|((banner, state), banner_layout)| {
+                    banner
+                        .as_widget_mut()
+                        .overlay(state, banner_layout, renderer)
+                }),
+        );
 
         (!children.is_empty()).then(|| core::overlay::Group::with_children(children).overlay())
     }
 }
 
+/// Calculates the layout of the banner.
+fn banner_node<Message, Theme, Renderer>(
+    renderer: &Renderer,
+    limits: &Limits,
+    banner: &Element<'_, Message, Theme, Renderer>,
+    width: Length,
+    tree: &mut Tree,
+) -> Node
+where
+    Renderer: core::Renderer,
+{
+    let banner_size = banner.as_widget().size();
+
+    let limits = limits.loose().width(width).height(banner_size.height);
+
+    let banner = banner.as_widget().layout(tree, renderer, &limits);
+    let size = limits.resolve(width, banner_size.height, banner.size());
+
+    Node::with_children(size, vec![banner])
+}
+
 /// Calculates the layout of the head.
 #[allow(clippy::too_many_arguments)]
 fn head_node<Message, Theme, Renderer>(
     renderer: &Renderer,
     limits: &Limits,
     head: &Element<'_, Message, Theme, Renderer>,
+    actions: Option<(&Element<'_, Message, Theme, Renderer>, usize)>,
     padding: Padding,
     width: Length,
     on_close: bool,
-    close_size: Option<f32>,
+    close_size: f32,
     tree: &mut Tree,
 ) -> Node
 where
@@ -626,21 +1303,36 @@ where
         .height(header_size.height)
         .shrink(padding);
 
-    let close_size = close_size.unwrap_or_else(|| renderer.default_size().0);
-
     if on_close {
         limits = limits.shrink(Size::new(close_size, 0.0));
     }
 
+    // The actions keep their size, the head gets the remaining width
+    let actions = actions.map(|(actions, index)| {
+        let node = actions
+            .as_widget()
+            .layout(&mut tree.children[index], renderer, &limits);
+        limits = limits.shrink(Size::new(node.size().width + HEAD_ACTIONS_SPACING, 0.0));
+        node
+    });
+
     let mut head = head
         .as_widget()
         .layout(&mut tree.children[0], renderer, &limits);
     let mut size = limits.resolve(width, header_size.height, head.size());
+    if let Some(actions) = &actions {
+        size.height = size.height.max(actions.size().height);
+    }
 
     head = head.move_to(Point::new(padding.left, padding.top));
     let head_size = head.size();
     head = head.align(Alignment::Start, Alignment::Center, head_size);
 
+    let content_width = size.width;
+    if let Some(actions) = &actions {
+        size.width += actions.size().width + HEAD_ACTIONS_SPACING;
+    }
+
     let close = if on_close {
         let node = Node::new(Size::new(close_size + 1.0, close_size + 1.0));
         let node_size = node.size();
@@ -655,12 +1347,17 @@ where
         None
     };
 
+    let actions = actions.map(|actions| {
+        let actions_size = actions.size();
+        actions.move_to(Point::new(
+            padding.left + content_width + HEAD_ACTIONS_SPACING,
+            padding.top + (size.height - actions_size.height) / 2.0,
+        ))
+    });
+
     Node::with_children(
         size.expand(padding),
-        match close {
-            Some(node) => vec![head, node],
-            None => vec![head],
-        },
+        [Some(head), actions, close].into_iter().flatten().collect(),
     )
 }
 
@@ -684,9 +1381,17 @@ where
         .height(body_size.height)
         .shrink(padding);
 
+    // The body is measured at its full height and scrolled if it doesn't fit, unless it
+    // fills the space it gets
+    let content_limits = if body_size.height.fill_factor() == 0 {
+        Limits::new(Size::ZERO, Size::new(limits.max().width, f32::INFINITY))
+    } else {
+        limits
+    };
+
     let mut body = body
         .as_widget()
-        .layout(&mut tree.children[1], renderer, &limits);
+        .layout(&mut tree.children[1], renderer, &content_limits);
     let size = limits.resolve(width, body_size.height, body.size());
 
     body = body.move_to(Point::new(padding.left, padding.top)).align(
@@ -705,6 +1410,7 @@ fn foot_node<Message, Theme, Renderer>(
     foot: &Element<'_, Message, Theme, Renderer>,
     padding: Padding,
     width: Length,
+    alignment: Horizontal,
     tree: &mut Tree,
 ) -> Node
 where
@@ -723,8 +1429,8 @@ where
         .layout(&mut tree.children[2], renderer, &limits);
     let size = limits.resolve(width, foot_size.height, foot.size());
 
-    foot = foot.move_to(Point::new(padding.left, padding.right)).align(
-        Alignment::Start,
+    foot = foot.move_to(Point::new(padding.left, padding.top)).align(
+        alignment.into(),
         Alignment::Center,
         size,
     );
@@ -732,33 +1438,223 @@ where
     Node::with_children(size.expand(padding), vec![foot])
 }
 
+/// Whether the event is an input of the user.
+fn is_input_event(event: &Event) -> bool {
+    matches!(
+        event,
+        Event::Mouse(_) | Event::Touch(_) | Event::Keyboard(_)
+    )
+}
+
+/// The area of the close icon reacting to the cursor, at least
+/// [`MIN_CLOSE_HIT_SIZE`] wide and high.
+fn close_hit_bounds(bounds: Rectangle) -> Rectangle {
+    let width = bounds.width.max(MIN_CLOSE_HIT_SIZE);
+    let height = bounds.height.max(MIN_CLOSE_HIT_SIZE);
+
+    Rectangle {
+        x: bounds.center_x() - width / 2.0,
+        y: bounds.center_y() - height / 2.0,
+        width,
+        height,
+    }
+}
+
+/// The areas of the [`Card`] with the given bounds and top corner radii the banner
+/// is drawn in: a row per pixel along the rounded corners, narrowed to the
+/// corners, then the rest of the [`Card`].
+fn banner_clips(bounds: Rectangle, top_left: f32, top_right: f32) -> Vec<Rectangle> {
+    let max_radius = bounds.width.min(bounds.height) / 2.0;
+    let top_left = top_left.clamp(0.0, max_radius);
+    let top_right = top_right.clamp(0.0, max_radius);
+    // How far the corner of the given radius is inset at the given height
+    let inset = |radius: f32, y: f32| {
+        if y < radius {
+            radius - (y * (2.0 * radius - y)).sqrt()
+        } else {
+            0.0
+        }
+    };
+
+    let rows = top_left.max(top_right).ceil();
+    let mut clips: Vec<Rectangle> = (0..rows as u16)
+        .map(|row| {
+            let y = f32::from(row) + 0.5;
+            let left = inset(top_left, y);
+            let right = inset(top_right, y);
+
+            Rectangle {
+                x: bounds.x + left,
+                y: bounds.y + f32::from(row),
+                width: (bounds.width - left - right).max(0.0),
+                height: 1.0,
+            }
+        })
+        .collect();
+    clips.push(Rectangle {
+        y: bounds.y + rows,
+        height: (bounds.height - rows).max(0.0),
+        ..bounds
+    });
+
+    clips
+}
+
+/// Handles the presses of the [`Card`] that weren't handled by its elements.
+fn on_event_press(
+    state: &mut State,
+    event: &Event,
+    status: event::Status,
+    bounds: Rectangle,
+    cursor: Cursor,
+    on_press: impl FnOnce(),
+) -> event::Status {
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. })
+            if status == event::Status::Ignored && cursor.is_over(bounds) =>
+        {
+            state.is_pressed = true;
+            event::Status::Captured
+        }
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerLifted { .. })
+            if state.is_pressed =>
+        {
+            state.is_pressed = false;
+            if cursor.is_over(bounds) {
+                on_press();
+            }
+            event::Status::Captured
+        }
+        Event::Touch(touch::Event::FingerLost { .. }) => {
+            state.is_pressed = false;
+            event::Status::Ignored
+        }
+        _ => event::Status::Ignored,
+    }
+}
+
+/// Handles the events of the scrollbar of the body and of dragging it.
+fn on_event_scrollbar(
+    state: &mut State,
+    event: &Event,
+    scroll: BodyScroll,
+    cursor: Cursor,
+) -> event::Status {
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+            let Some(((scrollbar, scroller), position)) =
+                scroll.scrollbar(state.scroll_offset).zip(cursor.position())
+            else {
+                return event::Status::Ignored;
+            };
+            if !scrollbar.contains(position) {
+                return event::Status::Ignored;
+            }
+
+            let factor = scroll.max_offset / (scrollbar.height - scroller.height).max(1.0);
+            if !scroller.contains(position) {
+                // Jump so that the scroller is centered on the cursor
+                state.scroll_offset =
+                    scroll.clamp((position.y - scrollbar.y - scroller.height / 2.0) * factor);
+            }
+            state.drag = Some(Drag {
+                start: position.y,
+                offset: state.scroll_offset,
+                factor,
+            });
+
+            event::Status::Captured
+        }
+        Event::Mouse(mouse::Event::CursorMoved { position })
+        | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+            state.drag.map_or(event::Status::Ignored, |drag| {
+                state.scroll_offset =
+                    scroll.clamp(drag.offset + (position.y - drag.start) * drag.factor);
+                event::Status::Captured
+            })
+        }
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+            if state.drag.take().is_some() {
+                event::Status::Captured
+            } else {
+                event::Status::Ignored
+            }
+        }
+        _ => event::Status::Ignored,
+    }
+}
+
+/// Handles the events scrolling the body that its content ignored.
+fn on_event_scroll(
+    state: &mut State,
+    event: &Event,
+    scroll: BodyScroll,
+    cursor: Cursor,
+) -> event::Status {
+    if scroll.max_offset <= 0.0 || !cursor.is_over(scroll.bounds) {
+        return event::Status::Ignored;
+    }
+
+    match event {
+        Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+            let delta = match delta {
+                mouse::ScrollDelta::Lines { y, .. } => y * SCROLL_LINE_HEIGHT,
+                mouse::ScrollDelta::Pixels { y, .. } => *y,
+            };
+            let offset = scroll.clamp(state.scroll_offset - delta);
+
+            // Let the parent scroll once the body is scrolled to its end
+            if (offset - state.scroll_offset).abs() < f32::EPSILON {
+                return event::Status::Ignored;
+            }
+            state.scroll_offset = offset;
+
+            event::Status::Captured
+        }
+        Event::Touch(touch::Event::FingerPressed { position, .. }) => {
+            // The content follows the finger
+            state.drag = Some(Drag {
+                start: position.y,
+                offset: state.scroll_offset,
+                factor: -1.0,
+            });
+
+            event::Status::Captured
+        }
+        _ => event::Status::Ignored,
+    }
+}
+
 /// Draws the head of the card.
 #[allow(clippy::too_many_arguments)]
 fn draw_head<Message, Theme, Renderer>(
     state: &Tree,
     renderer: &mut Renderer,
     head: &Element<'_, Message, Theme, Renderer>,
+    actions: Option<(&Element<'_, Message, Theme, Renderer>, &Tree)>,
     layout: Layout<'_>,
     cursor: Cursor,
     viewport: &Rectangle,
     theme: &Theme,
-    style: &<Theme as StyleSheet>::Style,
-    close_size: Option<f32>,
+    style_sheet: &Appearance,
+    close_icon: &CloseIcon,
 ) where
     Renderer: core::Renderer + core::text::Renderer<Font = iced_widget::core::Font>,
     Theme: StyleSheet,
 {
     let mut head_children = layout.children();
-    let style_sheet = theme.active(style);
     let bounds = layout.bounds();
-    let border_radius = style_sheet.border_radius;
+    let [top_left, top_right, _, _]: [f32; 4] = style_sheet.border_radius.into();
 
-    // Head background
+    // Head background, only rounded at the top corners of the card
     renderer.fill_quad(
         renderer::Quad {
             bounds,
             border: Border {
-                radius: border_radius.into(),
+                radius: [top_left, top_right, 0.0, 0.0].into(),
                 width: 0.0,
                 color: Color::TRANSPARENT,
             },
@@ -767,52 +1663,66 @@ fn draw_head<Message, Theme, Renderer>(
         style_sheet.head_background,
     );
 
-    // cover rounded button of header
-    renderer.fill_quad(
-        renderer::Quad {
-            bounds: Rectangle {
-                x: bounds.x,
-                y: bounds.y + bounds.height - border_radius,
-                width: bounds.width,
-                height: border_radius,
+    let head_layout = head_children
+        .next()
+        .expect("Graphics: Layout should have a head content layout");
+    let actions_layout = actions.and_then(|_| head_children.next());
+
+    // The head is cut off before the actions if it doesn't fit
+    let head_bounds = head_layout.bounds();
+    let head_end = actions_layout.map_or(bounds.x + bounds.width, |layout| {
+        layout.bounds().x - HEAD_ACTIONS_SPACING
+    });
+    let draw_head_content = |renderer: &mut Renderer| {
+        head.as_widget().draw(
+            state,
+            renderer,
+            theme,
+            &renderer::Style {
+                text_color: style_sheet.head_text_color,
             },
-            border: Border {
-                radius: (0.0).into(),
-                width: 0.0,
-                color: Color::TRANSPARENT,
+            head_layout,
+            cursor,
+            viewport,
+        );
+    };
+    if head_bounds.x + head_bounds.width > head_end {
+        renderer.with_layer(
+            Rectangle {
+                width: (head_end - head_bounds.x).max(0.0),
+                ..head_bounds
             },
-            shadow: Shadow::default(),
-        },
-        style_sheet.head_background,
-    );
+            draw_head_content,
+        );
+    } else {
+        draw_head_content(renderer);
+    }
 
-    head.as_widget().draw(
-        state,
-        renderer,
-        theme,
-        &renderer::Style {
-            text_color: style_sheet.head_text_color,
-        },
-        head_children
-            .next()
-            .expect("Graphics: Layout should have a head content layout"),
-        cursor,
-        viewport,
-    );
+    if let Some(((actions, state), actions_layout)) = actions.zip(actions_layout) {
+        actions.as_widget().draw(
+            state,
+            renderer,
+            theme,
+            &renderer::Style {
+                text_color: style_sheet.head_text_color,
+            },
+            actions_layout,
+            cursor,
+            viewport,
+        );
+    }
 
     if let Some(close_layout) = head_children.next() {
         let close_bounds = close_layout.bounds();
-        let is_mouse_over_close = close_bounds.contains(cursor.position().unwrap_or_default());
+        let is_mouse_over_close =
+            close_hit_bounds(close_bounds).contains(cursor.position().unwrap_or_default());
 
         renderer.fill_text(
             core::text::Text {
-                content: &icon_to_string(BootstrapIcon::X),
+                content: &close_icon.content,
                 bounds: Size::new(close_bounds.width, close_bounds.height),
-                size: Pixels(
-                    close_size.unwrap_or_else(|| renderer.default_size().0)
-                        + if is_mouse_over_close { 1.0 } else { 0.0 },
-                ),
-                font: BOOTSTRAP_FONT,
+                size: Pixels(close_icon.size + if is_mouse_over_close { 1.0 } else { 0.0 }),
+                font: close_icon.font,
                 horizontal_alignment: Horizontal::Center,
                 vertical_alignment: Vertical::Center,
                 line_height: LineHeight::Relative(1.3),
@@ -829,19 +1739,20 @@ fn draw_head<Message, Theme, Renderer>(
 #[allow(clippy::too_many_arguments)]
 fn draw_body<Message, Theme, Renderer>(
     state: &Tree,
+    scroll_offset: f32,
     renderer: &mut Renderer,
     body: &Element<'_, Message, Theme, Renderer>,
+    padding: Padding,
     layout: Layout<'_>,
     cursor: Cursor,
     viewport: &Rectangle,
     theme: &Theme,
-    style: &<Theme as StyleSheet>::Style,
+    style_sheet: &Appearance,
 ) where
     Renderer: core::Renderer + core::text::Renderer<Font = iced_widget::core::Font>,
     Theme: StyleSheet,
 {
     let mut body_children = layout.children();
-    let style_sheet = theme.active(style);
 
     // Body background
     renderer.fill_quad(
@@ -857,18 +1768,54 @@ fn draw_body<Message, Theme, Renderer>(
         style_sheet.body_background,
     );
 
-    body.as_widget().draw(
-        state,
-        renderer,
-        theme,
-        &renderer::Style {
-            text_color: style_sheet.body_text_color,
+    let content_layout = body_children
+        .next()
+        .expect("Graphics: Layout should have a body content layout");
+    let scroll = BodyScroll::new(layout, content_layout, padding);
+    let offset = scroll.clamp(scroll_offset);
+    let draw_content = |renderer: &mut Renderer, cursor: Cursor, viewport: &Rectangle| {
+        body.as_widget().draw(
+            state,
+            renderer,
+            theme,
+            &renderer::Style {
+                text_color: style_sheet.body_text_color,
+            },
+            content_layout,
+            cursor,
+            viewport,
+        );
+    };
+
+    let Some((_, scroller)) = scroll.scrollbar(offset) else {
+        draw_content(renderer, cursor, viewport);
+        return;
+    };
+
+    renderer.with_layer(scroll.bounds, |renderer| {
+        renderer.with_translation(Vector::new(0.0, -offset), |renderer| {
+            draw_content(
+                renderer,
+                scroll.cursor(cursor, offset),
+                &scroll.viewport(viewport, offset),
+            );
+        });
+    });
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: scroller,
+            border: Border {
+                radius: (SCROLLBAR_WIDTH / 2.0).into(),
+                width: 0.0,
+                color: Color::TRANSPARENT,
+            },
+            shadow: Shadow::default(),
+        },
+        Color {
+            a: style_sheet.body_text_color.a * 0.5,
+            ..style_sheet.body_text_color
         },
-        body_children
-            .next()
-            .expect("Graphics: Layout should have a body content layout"),
-        cursor,
-        viewport,
     );
 }
 
@@ -882,20 +1829,21 @@ fn draw_foot<Message, Theme, Renderer>(
     cursor: Cursor,
     viewport: &Rectangle,
     theme: &Theme,
-    style: &<Theme as StyleSheet>::Style,
+    style_sheet: &Appearance,
 ) where
     Renderer: core::Renderer + core::text::Renderer<Font = iced_widget::core::Font>,
     Theme: StyleSheet,
 {
     let mut foot_children = layout.children();
-    let style_sheet = theme.active(style);
 
-    // Foot background
+    let [_, _, bottom_right, bottom_left]: [f32; 4] = style_sheet.border_radius.into();
+
+    // Foot background, only rounded at the bottom corners of the card
     renderer.fill_quad(
         renderer::Quad {
             bounds: layout.bounds(),
             border: Border {
-                radius: style_sheet.border_radius.into(),
+                radius: [0.0, 0.0, bottom_right, bottom_left].into(),
                 width: 0.0,
                 color: Color::TRANSPARENT,
             },
@@ -921,6 +1869,130 @@ fn draw_foot<Message, Theme, Renderer>(
     }
 }
 
+/// The position of the close icon of a [`Card`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ClosePosition {
+    /// At the end of the head.
+    #[default]
+    Head,
+    /// At the top right of the body, e.g. for a [`Card`] without head.
+    Body,
+    /// Not shown.
+    Hidden,
+}
+
+/// The close icon of a [`Card`].
+struct CloseIcon {
+    /// The icon.
+    content: String,
+    /// The font of the icon.
+    font: Font,
+    /// The size of the icon.
+    size: f32,
+}
+
+/// The state of a [`Card`].
+#[derive(Debug, Default)]
+struct State {
+    /// The distance the body is scrolled down.
+    scroll_offset: f32,
+    /// The drag scrolling the body, if any.
+    drag: Option<Drag>,
+    /// Whether the [`Card`] is pressed.
+    is_pressed: bool,
+}
+
+/// A drag scrolling the body of a [`Card`].
+#[derive(Clone, Copy, Debug)]
+struct Drag {
+    /// The vertical position of the cursor when the drag started.
+    start: f32,
+    /// The scroll offset when the drag started.
+    offset: f32,
+    /// The change of the scroll offset per moved pixel.
+    factor: f32,
+}
+
+/// The visible area of the body of a [`Card`] and how far it can be scrolled.
+#[derive(Clone, Copy, Debug)]
+struct BodyScroll {
+    /// The bounds of the visible area of the body.
+    bounds: Rectangle,
+    /// The maximal scroll offset, zero if the body fits.
+    max_offset: f32,
+}
+
+impl BodyScroll {
+    /// Gets the scrollable area of the body from its layout and the layout of its content.
+    fn new(layout: Layout<'_>, content_layout: Layout<'_>, padding: Padding) -> Self {
+        let bounds = layout.bounds();
+        let content_bounds = content_layout.bounds();
+
+        Self {
+            bounds,
+            max_offset: (content_bounds.y + content_bounds.height + padding.bottom
+                - (bounds.y + bounds.height))
+                .max(0.0),
+        }
+    }
+
+    /// Restricts the scroll offset to the scrollable distance.
+    fn clamp(self, offset: f32) -> f32 {
+        offset.clamp(0.0, self.max_offset)
+    }
+
+    /// The bounds of the scrollbar and of its scroller, if the body is scrollable.
+    fn scrollbar(self, offset: f32) -> Option<(Rectangle, Rectangle)> {
+        if self.max_offset <= 0.0 {
+            return None;
+        }
+
+        let scrollbar = Rectangle {
+            x: self.bounds.x + self.bounds.width - SCROLLBAR_WIDTH,
+            width: SCROLLBAR_WIDTH,
+            ..self.bounds
+        };
+        let height = (scrollbar.height * scrollbar.height / (scrollbar.height + self.max_offset))
+            .max(MIN_SCROLLER_HEIGHT)
+            .min(scrollbar.height);
+
+        Some((
+            scrollbar,
+            Rectangle {
+                y: scrollbar.y + (scrollbar.height - height) * offset / self.max_offset,
+                height,
+                ..scrollbar
+            },
+        ))
+    }
+
+    /// The cursor over the scrolled content, unavailable outside of the visible area.
+    fn cursor(self, cursor: Cursor, offset: f32) -> Cursor {
+        if self.max_offset <= 0.0 {
+            return cursor;
+        }
+
+        match cursor.position() {
+            Some(position) if self.bounds.contains(position) => {
+                Cursor::Available(position + Vector::new(0.0, offset))
+            }
+            _ => Cursor::Unavailable,
+        }
+    }
+
+    /// The visible area of the scrolled content, the given viewport if the body fits.
+    fn viewport(self, viewport: &Rectangle, offset: f32) -> Rectangle {
+        if self.max_offset <= 0.0 {
+            return *viewport;
+        }
+
+        Rectangle {
+            y: self.bounds.y + offset,
+            ..self.bounds
+        }
+    }
+}
+
 impl<'a, Message, Theme, Renderer> From<Card<'a, Message, Theme, Renderer>>
     for Element<'a, Message, Theme, Renderer>
 where
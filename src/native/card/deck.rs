@@ -0,0 +1,518 @@
+//! Displays a [`Deck`] of swipeable [`Card`]s.
+//!
+//! *This API requires the following crate features to be activated: card*
+
+use super::{Card, StyleSheet};
+
+use iced_widget::core::{
+    self, event, keyboard,
+    layout::{Limits, Node},
+    mouse::{self, Cursor},
+    overlay, renderer,
+    time::{Duration, Instant},
+    touch,
+    widget::{
+        tree::{self, Tag},
+        Operation, Tree,
+    },
+    window, Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Size, Vector,
+    Widget,
+};
+
+/// The offset between the stacked cards of a [`Deck`].
+const STACK_OFFSET: f32 = 8.0;
+/// The duration of the swipe and spring back animations.
+const ANIMATION_DURATION: Duration = Duration::from_millis(250);
+
+/// The direction a [`Card`] of a [`Deck`] was swiped to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// The card was swiped to the left.
+    Left,
+    /// The card was swiped to the right.
+    Right,
+}
+
+/// A stack of [`Card`]s where the top card can be swiped away to the left or
+/// right to show the next one.
+///
+/// The top card follows the mouse or finger while dragged horizontally. If it
+/// is released past the threshold, it flies out and the deck moves on to the
+/// next card, otherwise it springs back. The arrow keys swipe the top card
+/// while the deck is hovered.
+///
+/// The deck keeps track of the swiped cards itself. If the number of cards
+/// changes, e.g. because the swiped card was removed, it starts again with
+/// the first card on top.
+///
+/// # Example
+/// ```ignore
+/// # use iced::widget::Text;
+/// # use iced_aw::card::{Card, Deck, Direction};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Swiped(usize, Direction),
+/// }
+///
+/// let deck = Deck::new(vec![
+///     Card::new(Text::new("First"), Text::new("Body")),
+///     Card::new(Text::new("Second"), Text::new("Body")),
+/// ])
+/// .on_swipe(Message::Swiped);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Deck<'a, Message, Theme = iced_widget::Theme, Renderer = iced_widget::Renderer>
+where
+    Renderer: core::Renderer,
+{
+    /// The cards of the [`Deck`], the first one on top.
+    cards: Vec<Element<'a, Message, Theme, Renderer>>,
+    /// The function that produces a message when a card is swiped away.
+    on_swipe: Option<Box<dyn Fn(usize, Direction) -> Message + 'a>>,
+    /// The distance a card has to be dragged to be swiped away.
+    threshold: f32,
+    /// The number of cards shown stacked behind each other.
+    stacked: usize,
+    /// The width of the [`Deck`].
+    width: Length,
+    /// The height of the [`Deck`].
+    height: Length,
+}
+
+impl<'a, Message, Theme, Renderer> Deck<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + core::Renderer + core::text::Renderer<Font = core::Font>,
+    Theme: 'a + StyleSheet,
+{
+    /// Creates a new [`Deck`] of the given [`Card`]s, the first one on top.
+    pub fn new(cards: impl IntoIterator<Item = Card<'a, Message, Theme, Renderer>>) -> Self {
+        Deck {
+            cards: cards.into_iter().map(Element::from).collect(),
+            on_swipe: None,
+            threshold: 100.0,
+            stacked: 3,
+            width: Length::Fill,
+            height: Length::Shrink,
+        }
+    }
+
+    /// Sets the function that produces a message with the index and
+    /// [`Direction`] of a [`Card`] that was swiped away.
+    #[must_use]
+    pub fn on_swipe<F>(mut self, on_swipe: F) -> Self
+    where
+        F: 'a + Fn(usize, Direction) -> Message,
+    {
+        self.on_swipe = Some(Box::new(on_swipe));
+        self
+    }
+
+    /// Sets the distance a [`Card`] has to be dragged to be swiped away.
+    #[must_use]
+    pub fn threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Sets the number of [`Card`]s shown stacked behind each other.
+    #[must_use]
+    pub fn stacked(mut self, stacked: usize) -> Self {
+        self.stacked = stacked.max(1);
+        self
+    }
+
+    /// Sets the width of the [`Deck`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Deck`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Deck<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + core::Renderer,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(self.cards.len()))
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.cards.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state: &mut State = tree.state.downcast_mut();
+        if state.cards != self.cards.len() {
+            *state = State::new(self.cards.len());
+        }
+
+        tree.diff_children(&self.cards);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let top = tree.state.downcast_ref::<State>().top;
+        let limits = limits.width(self.width).height(self.height);
+
+        let children: Vec<Node> = self
+            .cards
+            .iter()
+            .zip(&mut tree.children)
+            .enumerate()
+            .map(|(index, (card, tree))| {
+                // The cards behind the top one are moved down and narrowed
+                let depth = index.saturating_sub(top).min(self.stacked - 1) as f32;
+                let inset = STACK_OFFSET * depth;
+                let limits = limits.shrink(Size::new(
+                    2.0 * inset,
+                    STACK_OFFSET * (self.stacked - 1) as f32,
+                ));
+
+                card.as_widget()
+                    .layout(tree, renderer, &limits)
+                    .move_to(Point::new(inset, inset))
+            })
+            .collect();
+
+        let intrinsic =
+            children
+                .iter()
+                .skip(top)
+                .take(self.stacked)
+                .fold(Size::ZERO, |size, node| {
+                    let bounds = node.bounds();
+                    Size::new(
+                        size.width.max(bounds.x + bounds.width),
+                        size.height.max(bounds.y + bounds.height),
+                    )
+                });
+
+        Node::with_children(limits.resolve(self.width, self.height, intrinsic), children)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state: &mut State = tree.state.downcast_mut();
+        let top = state.top;
+
+        let Some(card_layout) = layout.children().nth(top) else {
+            return event::Status::Ignored;
+        };
+        let card_bounds = card_layout.bounds();
+
+        if let Event::Window(_, window::Event::RedrawRequested(now)) = event {
+            if let Some(direction) = state.animate(now) {
+                if let Some(on_swipe) = &self.on_swipe {
+                    shell.publish(on_swipe(top, direction));
+                }
+                shell.invalidate_layout();
+                return event::Status::Ignored;
+            }
+            if state.animation.is_some() {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+        }
+
+        if state.animation.is_some() {
+            return event::Status::Ignored;
+        }
+
+        if let Some(origin) = state.drag_origin {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { position })
+                | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                    state.offset = position.x - origin;
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                    return event::Status::Captured;
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(
+                    touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. },
+                ) => {
+                    state.drag_origin = None;
+                    let direction = if state.offset > self.threshold {
+                        Some(Direction::Right)
+                    } else if state.offset < -self.threshold {
+                        Some(Direction::Left)
+                    } else {
+                        None
+                    };
+                    state.start_animation(direction, card_bounds.width);
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                    return event::Status::Captured;
+                }
+                _ => {}
+            }
+        }
+
+        let status = self.cards[top].as_widget_mut().on_event(
+            &mut tree.children[top],
+            event.clone(),
+            card_layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        let state: &mut State = tree.state.downcast_mut();
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(position) = cursor.position_over(card_bounds) {
+                    state.drag_origin = Some(position.x);
+                    state.offset = 0.0;
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                ..
+            }) if cursor.is_over(layout.bounds()) => {
+                let direction = match key {
+                    keyboard::key::Named::ArrowLeft => Direction::Left,
+                    keyboard::key::Named::ArrowRight => Direction::Right,
+                    _ => return event::Status::Ignored,
+                };
+                state.start_animation(Some(direction), card_bounds.width);
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state: &State = tree.state.downcast_ref();
+
+        if state.drag_origin.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let Some(card_layout) = layout.children().nth(state.top) else {
+            return mouse::Interaction::default();
+        };
+
+        let interaction = self.cards[state.top].as_widget().mouse_interaction(
+            &tree.children[state.top],
+            card_layout,
+            cursor,
+            viewport,
+            renderer,
+        );
+
+        if interaction == mouse::Interaction::default() && cursor.is_over(card_layout.bounds()) {
+            mouse::Interaction::Grab
+        } else {
+            interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state: &State = tree.state.downcast_ref();
+
+        let count = self.cards.len().saturating_sub(state.top).min(self.stacked);
+
+        // Draw from the bottom of the stack to the top card
+        for depth in (0..count).rev() {
+            let index = state.top + depth;
+            let Some(layout) = layout.children().nth(index) else {
+                continue;
+            };
+            let draw = |renderer: &mut Renderer| {
+                self.cards[index].as_widget().draw(
+                    &tree.children[index],
+                    renderer,
+                    theme,
+                    style,
+                    layout,
+                    cursor,
+                    viewport,
+                );
+            };
+
+            if depth == 0 {
+                renderer.with_translation(Vector::new(state.offset, 0.0), draw);
+            } else {
+                draw(renderer);
+            }
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let top = tree.state.downcast_ref::<State>().top;
+
+        if let (Some(card), Some(layout)) = (self.cards.get(top), layout.children().nth(top)) {
+            card.as_widget()
+                .operate(&mut tree.children[top], layout, renderer, operation);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let top = tree.state.downcast_ref::<State>().top;
+
+        self.cards
+            .get_mut(top)
+            .zip(layout.children().nth(top))
+            .and_then(|(card, layout)| {
+                card.as_widget_mut()
+                    .overlay(&mut tree.children[top], layout, renderer)
+            })
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Deck<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + core::Renderer,
+    Theme: 'a,
+{
+    fn from(deck: Deck<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(deck)
+    }
+}
+
+/// The state of a [`Deck`].
+#[derive(Debug)]
+struct State {
+    /// The number of cards the state was made for.
+    cards: usize,
+    /// The index of the card on top.
+    top: usize,
+    /// The horizontal position where the drag of the top card started.
+    drag_origin: Option<f32>,
+    /// The horizontal offset of the top card.
+    offset: f32,
+    /// The running swipe or spring back animation.
+    animation: Option<Animation>,
+}
+
+/// The animation of the top card of a [`Deck`] flying out or springing back.
+#[derive(Debug)]
+struct Animation {
+    /// When the animation started.
+    start: Instant,
+    /// The offset the animation started from.
+    from: f32,
+    /// The offset the animation ends at.
+    to: f32,
+    /// The direction the card flies out to, or `None` to spring back.
+    direction: Option<Direction>,
+}
+
+impl State {
+    /// Creates the state of a [`Deck`] with the given number of cards.
+    const fn new(cards: usize) -> Self {
+        Self {
+            cards,
+            top: 0,
+            drag_origin: None,
+            offset: 0.0,
+            animation: None,
+        }
+    }
+
+    /// Starts moving the top card out to the given direction, or back to its
+    /// place.
+    fn start_animation(&mut self, direction: Option<Direction>, width: f32) {
+        let to = match direction {
+            Some(Direction::Left) => -1.5 * width,
+            Some(Direction::Right) => 1.5 * width,
+            None => 0.0,
+        };
+
+        self.animation = Some(Animation {
+            start: Instant::now(),
+            from: self.offset,
+            to,
+            direction,
+        });
+    }
+
+    /// Advances the animation to the given instant and returns the direction
+    /// of the card that was swiped away once it is done.
+    fn animate(&mut self, now: Instant) -> Option<Direction> {
+        let animation = self.animation.as_ref()?;
+        let progress = (now.saturating_duration_since(animation.start).as_secs_f32()
+            / ANIMATION_DURATION.as_secs_f32())
+        .min(1.0);
+        // Ease out cubic
+        let eased = 1.0 - (1.0 - progress).powi(3);
+        self.offset = animation.from + (animation.to - animation.from) * eased;
+
+        if progress < 1.0 {
+            return None;
+        }
+
+        let direction = animation.direction;
+        self.animation = None;
+        self.offset = 0.0;
+        if direction.is_some() {
+            self.top += 1;
+        }
+        direction
+    }
+}
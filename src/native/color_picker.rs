@@ -3,7 +3,7 @@
 //! *This API requires the following crate features to be activated: `color_picker`*
 
 use super::overlay::color_picker::{
-    self, ColorBarDragged, ColorPickerOverlay, ColorPickerOverlayButtons,
+    self, ColorBarDragged, ColorPickerOverlay, ColorPickerOverlayButtons, DEFAULT_SIZE,
 };
 
 use iced_widget::{
@@ -13,6 +13,7 @@ use iced_widget::{
         layout::{Limits, Node},
         mouse::{self, Cursor},
         overlay, renderer,
+        text::Renderer as _,
         widget::{
             self,
             tree::{self, Tag, Tree},
@@ -20,13 +21,29 @@ use iced_widget::{
         Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shell, Widget,
     },
     renderer::Renderer,
+    text_input,
 };
 
 pub use crate::style::color_picker::{Appearance, StyleSheet};
 
+pub mod convert;
+pub use convert::ColorModel;
+
+pub mod inline;
+pub use inline::Inline;
+
+/// The default maximum number of recently used colors shown.
+const DEFAULT_MAX_RECENT: usize = 8;
+/// The size of the overlay of the [`ColorPicker::compact`] preset.
+const COMPACT_SIZE: f32 = 300.0;
+
 //TODO: Remove ignore when Null is updated. Temp fix for Test runs
 /// An input element for picking colors.
 ///
+/// For a picker always shown in the layout, e.g. docked in an inspector panel, use
+/// [`Inline`] instead: it has the same controls without the underlay and the submit
+/// and cancel buttons.
+///
 /// # Example
 /// ```ignore
 /// # use iced_aw::ColorPicker;
@@ -64,8 +81,22 @@ where
     on_cancel: Message,
     /// The function that produces a message when the submit button of the [`ColorPickerOverlay`] is pressed.
     on_submit: Box<dyn Fn(Color) -> Message>,
+    /// The function that produces a message when the color picked in the [`ColorPickerOverlay`] changes.
+    on_change: Option<Box<dyn Fn(Color) -> Message>>,
     /// The style of the [`ColorPickerOverlay`].
     style: <Theme as StyleSheet>::Style,
+    /// Toggle the alpha bar of the [`ColorPickerOverlay`].
+    show_alpha: bool,
+    /// The color model of the gradient area and the hue bar of the [`ColorPickerOverlay`].
+    model: ColorModel,
+    /// The preset colors shown as swatches in the [`ColorPickerOverlay`].
+    palette: Vec<Color>,
+    /// The recently used colors shown as swatches in the [`ColorPickerOverlay`].
+    recent: Vec<Color>,
+    /// The maximum number of recently used colors shown.
+    max_recent: usize,
+    /// The length of the longer side of the [`ColorPickerOverlay`].
+    size: f32,
     /// The buttons of the overlay.
     overlay_state: Element<'a, Message, Theme, Renderer>,
 }
@@ -73,7 +104,7 @@ where
 impl<'a, Message, Theme> ColorPicker<'a, Message, Theme>
 where
     Message: 'a + Clone,
-    Theme: 'a + StyleSheet + button::StyleSheet + widget::text::StyleSheet,
+    Theme: 'a + StyleSheet + button::StyleSheet + widget::text::StyleSheet + text_input::StyleSheet,
 {
     /// Creates a new [`ColorPicker`] wrapping around the given underlay.
     ///
@@ -103,17 +134,107 @@ where
             underlay: underlay.into(),
             on_cancel,
             on_submit: Box::new(on_submit),
+            on_change: None,
             style: <Theme as StyleSheet>::Style::default(),
+            show_alpha: true,
+            model: ColorModel::default(),
+            palette: Vec::new(),
+            recent: Vec::new(),
+            max_recent: DEFAULT_MAX_RECENT,
+            size: DEFAULT_SIZE,
             overlay_state: ColorPickerOverlayButtons::default().into(),
         }
     }
 
+    /// Sets the function that will be called whenever the color picked in the overlay
+    /// changes, before it's submitted or cancelled, e.g. to preview it.
+    ///
+    /// It's called at most once per frame with the latest color, while dragging in the
+    /// saturation/value area, moving a bar, typing or picking a swatch.
+    #[must_use]
+    pub fn on_change<F>(mut self, on_change: F) -> Self
+    where
+        F: 'static + Fn(Color) -> Message,
+    {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
     /// Sets the style of the [`ColorPicker`].
     #[must_use]
     pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
         self.style = style;
         self
     }
+
+    /// Shows or hides the alpha bar of the [`ColorPicker`] (shown by default).
+    ///
+    /// Without the alpha bar only opaque colors are picked: the submitted [`Color`]
+    /// always has an alpha of `1.0`.
+    #[must_use]
+    pub fn show_alpha(mut self, show_alpha: bool) -> Self {
+        self.show_alpha = show_alpha;
+        self
+    }
+
+    /// Sets the color model of the gradient area and the hue bar of the [`ColorPicker`]
+    /// ([`ColorModel::Hsv`] by default).
+    ///
+    /// The picked [`Color`] is an sRGB color whatever the model.
+    #[must_use]
+    pub fn model(mut self, model: ColorModel) -> Self {
+        self.model = model;
+        self
+    }
+
+    /// Sets the preset colors of the [`ColorPicker`], shown as swatches below the hue bar.
+    ///
+    /// Clicking a swatch, or picking it with the arrow keys once the palette is focused,
+    /// picks its color.
+    #[must_use]
+    pub fn palette(mut self, palette: Vec<Color>) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    /// Sets the recently used colors of the [`ColorPicker`], most recent first, shown as
+    /// swatches after the palette.
+    ///
+    /// The [`ColorPicker`] doesn't keep them itself: push the color given to `on_submit`
+    /// into the list of the application. Colors looking the same are shown once.
+    #[must_use]
+    pub fn recent(mut self, recent: Vec<Color>) -> Self {
+        self.recent = recent;
+        self
+    }
+
+    /// Sets the maximum number of recently used colors shown by the [`ColorPicker`]
+    /// (8 by default), the older ones are hidden.
+    #[must_use]
+    pub fn max_recent(mut self, max_recent: usize) -> Self {
+        self.max_recent = max_recent;
+        self
+    }
+
+    /// Sets the length of the longer side of the overlay of the [`ColorPicker`] (600 by
+    /// default), the shorter side being half of it.
+    ///
+    /// The areas, the bars, the spacing and the text are scaled along with it. Below
+    /// 360 the overlay has no text fields, showing the hex code as text instead.
+    #[must_use]
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Makes the overlay of the [`ColorPicker`] half its default size, e.g. for a
+    /// popover in a toolbar.
+    ///
+    /// See [`ColorPicker::size`].
+    #[must_use]
+    pub fn compact(self) -> Self {
+        self.size(COMPACT_SIZE)
+    }
 }
 
 /// The state of the [`ColorPicker`].
@@ -121,6 +242,8 @@ where
 pub struct State {
     /// The state of the overlay.
     pub(crate) overlay_state: color_picker::State,
+    /// The color last given to the [`ColorPicker`].
+    pub(crate) color: Color,
 }
 
 impl State {
@@ -129,6 +252,7 @@ impl State {
     pub fn new(color: Color) -> Self {
         Self {
             overlay_state: color_picker::State::new(color),
+            color,
         }
     }
 
@@ -142,7 +266,7 @@ impl State {
 impl<'a, Message, Theme> Widget<Message, Theme, Renderer> for ColorPicker<'a, Message, Theme>
 where
     Message: 'static + Clone,
-    Theme: 'a + StyleSheet + button::StyleSheet + widget::text::StyleSheet,
+    Theme: 'a + StyleSheet + button::StyleSheet + widget::text::StyleSheet + text_input::StyleSheet,
 {
     fn tag(&self) -> Tag {
         Tag::of::<State>()
@@ -159,8 +283,24 @@ where
     fn diff(&self, tree: &mut Tree) {
         let color_picker_state = tree.state.downcast_mut::<State>();
 
-        if color_picker_state.overlay_state.color != self.color {
-            color_picker_state.overlay_state.color = self.color;
+        color_picker_state
+            .overlay_state
+            .set_show_alpha(self.show_alpha);
+        color_picker_state.overlay_state.set_model(self.model);
+        color_picker_state.overlay_state.scale = (self.size / DEFAULT_SIZE).max(0.0);
+        color_picker_state.overlay_state.set_palette(&self.palette);
+        color_picker_state
+            .overlay_state
+            .set_recent(&self.recent, self.max_recent);
+        // The picked color is kept until the given one changes or the picker is closed
+        if !self.show_picker || color_picker_state.color != self.color {
+            color_picker_state.color = self.color;
+            color_picker_state.overlay_state.set_color(self.color);
+            color_picker_state.overlay_state.reset_change();
+        }
+        // The color to compare with is the one given when the picker was opened
+        if !self.show_picker {
+            color_picker_state.overlay_state.initial_color = self.color;
         }
 
         tree.diff_children(&[&self.underlay, &self.overlay_state]);
@@ -260,8 +400,10 @@ where
                 picker_state,
                 self.on_cancel.clone(),
                 &self.on_submit,
+                self.on_change.as_deref(),
                 position,
                 self.style.clone(),
+                renderer.default_size(),
                 &mut state.children[1],
             )
             .overlay(),
@@ -273,7 +415,7 @@ impl<'a, Message, Theme> From<ColorPicker<'a, Message, Theme>>
     for Element<'a, Message, Theme, Renderer>
 where
     Message: 'static + Clone,
-    Theme: 'a + StyleSheet + button::StyleSheet + widget::text::StyleSheet,
+    Theme: 'a + StyleSheet + button::StyleSheet + widget::text::StyleSheet + text_input::StyleSheet,
 {
     fn from(color_picker: ColorPicker<'a, Message, Theme>) -> Self {
         Element::new(color_picker)
@@ -0,0 +1,406 @@
+//! Conversions between the sRGB [`Color`] and the color models of the
+//! [`ColorPicker`](super::ColorPicker).
+//!
+//! *This API requires the following crate features to be activated: `color_picker`*
+
+use iced_widget::core::Color;
+
+/// The largest chroma of the gradient area of the [`ColorModel::Oklch`] model.
+///
+/// All sRGB colors have a smaller chroma.
+pub const MAX_OKLCH_CHROMA: f32 = 0.37;
+
+/// The color model represented by the gradient area and the hue bar of the
+/// [`ColorPicker`](super::ColorPicker).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ColorModel {
+    /// Hue, saturation and value.
+    #[default]
+    Hsv,
+    /// Hue, saturation and lightness.
+    Hsl,
+    /// The perceptual lightness, chroma and hue of Oklab.
+    Oklch,
+}
+
+/// The place of a color in a [`ColorModel`]: its hue and its position in the
+/// gradient area.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Coordinates {
+    /// The hue in degrees (0.0 - 360.0).
+    pub hue: f32,
+    /// The horizontal position in the gradient area (0.0 - 1.0): the saturation, or the
+    /// chroma.
+    pub x: f32,
+    /// The vertical position in the gradient area (0.0 - 1.0): the value, or the lightness.
+    pub y: f32,
+}
+
+impl ColorModel {
+    /// The [`Coordinates`] of the given color in this model.
+    #[must_use]
+    pub fn coordinates(self, color: Color) -> Coordinates {
+        match self {
+            // https://en.wikipedia.org/wiki/HSL_and_HSV#From_RGB
+            Self::Hsv => {
+                let max = color.r.max(color.g.max(color.b));
+                let min = color.r.min(color.g.min(color.b));
+                let chroma = max - min;
+
+                Coordinates {
+                    hue: hue(color, max, chroma),
+                    x: if max <= 0.0 { 0.0 } else { chroma / max },
+                    y: max,
+                }
+            }
+            Self::Hsl => {
+                let hsl = Hsl::from(color);
+                Coordinates {
+                    hue: hsl.hue,
+                    x: hsl.saturation,
+                    y: hsl.lightness,
+                }
+            }
+            Self::Oklch => {
+                let oklch = Oklch::from(color);
+                Coordinates {
+                    hue: oklch.hue,
+                    x: (oklch.chroma / MAX_OKLCH_CHROMA).min(1.0),
+                    y: oklch.lightness,
+                }
+            }
+        }
+    }
+
+    /// The opaque color with the given [`Coordinates`] in this model.
+    ///
+    /// Colors outside of sRGB are brought into it.
+    #[must_use]
+    pub fn color(self, coordinates: Coordinates) -> Color {
+        let Coordinates { hue, x, y } = coordinates;
+        let hue = hue.rem_euclid(360.0);
+        let x = x.clamp(0.0, 1.0);
+        let y = y.clamp(0.0, 1.0);
+
+        match self {
+            // https://en.wikipedia.org/wiki/HSL_and_HSV#HSV_to_RGB_alternative
+            Self::Hsv => {
+                let f = |n: f32| {
+                    let k = (n + hue / 60.0) % 6.0;
+                    y - y * x * k.min(4.0 - k).clamp(0.0, 1.0)
+                };
+
+                Color::from_rgb(f(5.0), f(3.0), f(1.0))
+            }
+            Self::Hsl => Hsl {
+                hue,
+                saturation: x,
+                lightness: y,
+            }
+            .into(),
+            Self::Oklch => Oklch {
+                lightness: y,
+                chroma: x * MAX_OKLCH_CHROMA,
+                hue,
+            }
+            .into(),
+        }
+    }
+
+    /// The color shown for the given hue on the hue bar.
+    #[must_use]
+    pub fn hue_color(self, hue: f32) -> Color {
+        let y = match self {
+            Self::Hsv => 1.0,
+            Self::Hsl => 0.5,
+            Self::Oklch => 0.75,
+        };
+        let x = match self {
+            Self::Hsv | Self::Hsl => 1.0,
+            Self::Oklch => 0.125 / MAX_OKLCH_CHROMA,
+        };
+
+        self.color(Coordinates { hue, x, y })
+    }
+}
+
+/// A color in the HSL model.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hsl {
+    /// The hue in degrees (0.0 - 360.0).
+    pub hue: f32,
+    /// The saturation (0.0 - 1.0).
+    pub saturation: f32,
+    /// The lightness (0.0 - 1.0).
+    pub lightness: f32,
+}
+
+impl From<Color> for Hsl {
+    // https://en.wikipedia.org/wiki/HSL_and_HSV#From_RGB
+    fn from(color: Color) -> Self {
+        let max = color.r.max(color.g.max(color.b));
+        let min = color.r.min(color.g.min(color.b));
+        let chroma = max - min;
+
+        let lightness = f32::midpoint(max, min);
+        let saturation = if lightness <= 0.0 || lightness >= 1.0 {
+            0.0
+        } else {
+            chroma / (1.0 - (2.0 * lightness - 1.0).abs())
+        };
+
+        Self {
+            hue: hue(color, max, chroma),
+            saturation: saturation.clamp(0.0, 1.0),
+            lightness,
+        }
+    }
+}
+
+impl From<Hsl> for Color {
+    // https://en.wikipedia.org/wiki/HSL_and_HSV#HSL_to_RGB_alternative
+    fn from(hsl: Hsl) -> Self {
+        let a = hsl.saturation * hsl.lightness.min(1.0 - hsl.lightness);
+        let f = |n: f32| {
+            let k = (n + hsl.hue / 30.0) % 12.0;
+            hsl.lightness - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+        };
+
+        Self::from_rgb(f(0.0), f(8.0), f(4.0))
+    }
+}
+
+/// A color in the Oklch model, the polar form of Oklab.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Oklch {
+    /// The perceptual lightness (0.0 - 1.0).
+    pub lightness: f32,
+    /// The chroma (0.0 - about 0.37 for sRGB colors).
+    pub chroma: f32,
+    /// The hue in degrees (0.0 - 360.0).
+    pub hue: f32,
+}
+
+impl Oklch {
+    /// Checks if this color is an sRGB color.
+    #[must_use]
+    pub fn is_in_gamut(self) -> bool {
+        self.linear_rgb()
+            .iter()
+            .all(|channel| (-1e-4..=1.0 + 1e-4).contains(channel))
+    }
+
+    /// The linear sRGB channels of this color, outside of 0.0 - 1.0 out of sRGB.
+    // https://bottosson.github.io/posts/oklab/
+    fn linear_rgb(self) -> [f64; 3] {
+        let lightness = f64::from(self.lightness);
+        let hue = f64::from(self.hue).to_radians();
+        let a = f64::from(self.chroma) * hue.cos();
+        let b = f64::from(self.chroma) * hue.sin();
+
+        let long = (lightness + 0.396_337_777_4 * a + 0.215_803_757_3 * b).powi(3);
+        let medium = (lightness - 0.105_561_345_8 * a - 0.063_854_172_8 * b).powi(3);
+        let short = (lightness - 0.089_484_177_5 * a - 1.291_485_548 * b).powi(3);
+
+        [
+            4.076_741_662_1 * long - 3.307_711_591_3 * medium + 0.230_969_929_2 * short,
+            -1.268_438_004_6 * long + 2.609_757_401_1 * medium - 0.341_319_396_5 * short,
+            -0.004_196_086_3 * long - 0.703_418_614_7 * medium + 1.707_614_701 * short,
+        ]
+    }
+}
+
+impl From<Color> for Oklch {
+    // https://bottosson.github.io/posts/oklab/
+    fn from(color: Color) -> Self {
+        let [red, green, blue] = [color.r, color.g, color.b].map(to_linear);
+
+        let long =
+            (0.412_221_470_8 * red + 0.536_332_536_3 * green + 0.051_445_992_9 * blue).cbrt();
+        let medium =
+            (0.211_903_498_2 * red + 0.680_699_545_1 * green + 0.107_396_956_6 * blue).cbrt();
+        let short =
+            (0.088_302_461_9 * red + 0.281_718_837_6 * green + 0.629_978_700_5 * blue).cbrt();
+
+        let lightness = 0.210_454_255_3 * long + 0.793_617_785 * medium - 0.004_072_046_8 * short;
+        let a = 1.977_998_495_1 * long - 2.428_592_205 * medium + 0.450_593_709_9 * short;
+        let b = 0.025_904_037_1 * long + 0.782_771_766_2 * medium - 0.808_675_766 * short;
+
+        let chroma = a.hypot(b);
+        // The hue of grays is meaningless, it's kept at 0 like for the other models
+        let hue = if chroma < 1e-4 {
+            0.0
+        } else {
+            b.atan2(a).to_degrees().rem_euclid(360.0)
+        };
+
+        Self {
+            lightness: lightness as f32,
+            chroma: chroma as f32,
+            hue: hue as f32,
+        }
+    }
+}
+
+impl From<Oklch> for Color {
+    /// Colors outside of sRGB keep their lightness and hue with the largest chroma in
+    /// sRGB.
+    fn from(oklch: Oklch) -> Self {
+        let mut oklch = Oklch {
+            lightness: oklch.lightness.clamp(0.0, 1.0),
+            chroma: oklch.chroma.max(0.0),
+            ..oklch
+        };
+
+        if !oklch.is_in_gamut() {
+            let (mut low, mut high) = (0.0, oklch.chroma);
+            for _ in 0..16 {
+                let chroma = f32::midpoint(low, high);
+                let candidate = Oklch { chroma, ..oklch };
+                if candidate.is_in_gamut() {
+                    low = chroma;
+                } else {
+                    high = chroma;
+                }
+            }
+            oklch.chroma = low;
+        }
+
+        let [red, green, blue] = oklch.linear_rgb().map(from_linear);
+        Self::from_rgb(red, green, blue)
+    }
+}
+
+/// The hue in degrees of the given color with the given largest channel and chroma.
+fn hue(color: Color, max: f32, chroma: f32) -> f32 {
+    let hue = if chroma <= 0.0 {
+        0.0
+    } else if (max - color.r).abs() < f32::EPSILON {
+        60.0 * ((color.g - color.b) / chroma)
+    } else if (max - color.g).abs() < f32::EPSILON {
+        60.0 * (2.0 + (color.b - color.r) / chroma)
+    } else {
+        60.0 * (4.0 + (color.r - color.g) / chroma)
+    };
+
+    hue.rem_euclid(360.0)
+}
+
+/// Converts a sRGB channel into a linear one.
+fn to_linear(channel: f32) -> f64 {
+    let channel = f64::from(channel);
+    if channel <= 0.040_45 {
+        channel / 12.92
+    } else {
+        ((channel + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Converts a linear channel into a sRGB one.
+fn from_linear(channel: f64) -> f32 {
+    let channel = channel.clamp(0.0, 1.0);
+    let channel = if channel <= 0.003_130_8 {
+        channel * 12.92
+    } else {
+        1.055 * channel.powf(1.0 / 2.4) - 0.055
+    };
+
+    channel as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_widget::core::Color;
+
+    use super::{ColorModel, Coordinates, Hsl, Oklch};
+
+    /// Some colors to convert back and forth.
+    const COLORS: [Color; 8] = [
+        Color::BLACK,
+        Color::WHITE,
+        Color::from_rgb(1.0, 0.0, 0.0),
+        Color::from_rgb(0.0, 1.0, 0.0),
+        Color::from_rgb(0.0, 0.0, 1.0),
+        Color::from_rgb(0.5, 0.5, 0.5),
+        Color::from_rgb(0.2, 0.4, 0.8),
+        Color::from_rgb(0.9, 0.6, 0.1),
+    ];
+
+    fn assert_close(left: Color, right: Color) {
+        let close = [
+            (left.r, right.r),
+            (left.g, right.g),
+            (left.b, right.b),
+            (left.a, right.a),
+        ]
+        .iter()
+        .all(|(left, right)| (left - right).abs() < 1e-3);
+
+        assert!(close, "{left:?} != {right:?}");
+    }
+
+    #[test]
+    fn hsl_round_trip_test() {
+        for color in COLORS {
+            assert_close(Color::from(Hsl::from(color)), color);
+        }
+
+        let hsl = Hsl::from(Color::from_rgb(0.2, 0.4, 0.8));
+        assert!((hsl.hue - 220.0).abs() < 1e-3);
+        assert!((hsl.saturation - 0.6).abs() < 1e-3);
+        assert!((hsl.lightness - 0.5).abs() < 1e-3);
+    }
+
+    #[test]
+    fn oklch_round_trip_test() {
+        for color in COLORS {
+            assert_close(Color::from(Oklch::from(color)), color);
+        }
+
+        let white = Oklch::from(Color::WHITE);
+        assert!((white.lightness - 1.0).abs() < 1e-3);
+        assert!(white.chroma < 1e-3);
+
+        // The red of sRGB, https://bottosson.github.io/posts/oklab/
+        let red = Oklch::from(Color::from_rgb(1.0, 0.0, 0.0));
+        assert!((red.lightness - 0.628).abs() < 1e-3);
+        assert!((red.chroma - 0.258).abs() < 1e-3);
+        assert!((red.hue - 29.23).abs() < 1e-1);
+    }
+
+    #[test]
+    fn oklch_gamut_test() {
+        let oklch = Oklch {
+            lightness: 0.7,
+            chroma: 0.37,
+            hue: 150.0,
+        };
+        assert!(!oklch.is_in_gamut());
+
+        let color = Color::from(oklch);
+        let mapped = Oklch::from(color);
+        assert!((mapped.lightness - 0.7).abs() < 1e-2);
+        assert!((mapped.hue - 150.0).abs() < 1.0);
+        assert!(mapped.chroma < 0.37);
+    }
+
+    #[test]
+    fn model_round_trip_test() {
+        for model in [ColorModel::Hsv, ColorModel::Hsl, ColorModel::Oklch] {
+            for color in COLORS {
+                let coordinates = model.coordinates(color);
+                assert_close(model.color(coordinates), color);
+            }
+        }
+
+        let coordinates = Coordinates {
+            hue: 120.0,
+            x: 1.0,
+            y: 0.5,
+        };
+        assert_close(
+            ColorModel::Hsl.color(coordinates),
+            Color::from_rgb(0.0, 1.0, 0.0),
+        );
+    }
+}
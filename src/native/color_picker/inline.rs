@@ -0,0 +1,471 @@
+//! Use a color picker laid out in place instead of as an overlay.
+//!
+//! *This API requires the following crate features to be activated: `color_picker`*
+
+use super::{ColorModel, DEFAULT_MAX_RECENT};
+use crate::native::overlay::color_picker::{style_sheet, Focus, Metrics, Panel, State};
+
+use iced_widget::{
+    button,
+    core::{
+        event,
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        renderer, touch,
+        widget::{
+            self,
+            tree::{self, Tag, Tree},
+        },
+        Clipboard, Color, Element, Event, Layout, Length, Pixels, Rectangle, Shell, Size, Widget,
+    },
+    renderer::Renderer,
+    text_input,
+};
+
+pub use crate::style::color_picker::{Appearance, StyleSheet};
+
+/// The default height of the [`Inline`] color picker.
+const DEFAULT_HEIGHT: f32 = 300.0;
+
+/// A color picker shown directly in the layout, e.g. as a section of a settings panel.
+///
+/// It has the controls of the overlay of the [`ColorPicker`](super::ColorPicker): the
+/// saturation/value area, the hue and RGBA bars, the hex field next to the comparison of
+/// the initial and the picked color, and the fields of the channels. It has no submit and
+/// cancel buttons: every change of the color is reported right away.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::color_picker;
+/// # use iced::Color;
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     ColorChanged(Color),
+/// }
+///
+/// let picker = color_picker::Inline::new(Color::WHITE, Message::ColorChanged);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Inline<'a, Message, Theme = iced_widget::style::Theme>
+where
+    Theme: StyleSheet + button::StyleSheet + text_input::StyleSheet,
+{
+    /// The color to show.
+    color: Color,
+    /// The function that produces a message when the color is changed.
+    on_change: Box<dyn Fn(Color) -> Message + 'a>,
+    /// The width of the [`Inline`] color picker.
+    width: Length,
+    /// The height of the [`Inline`] color picker.
+    height: Length,
+    /// The style of the [`Inline`] color picker.
+    style: <Theme as StyleSheet>::Style,
+    /// Toggle the alpha bar of the [`Inline`] color picker.
+    show_alpha: bool,
+    /// The color model of the gradient area and the hue bar of the [`Inline`] color picker.
+    model: ColorModel,
+    /// The preset colors shown as swatches in the [`Inline`] color picker.
+    palette: Vec<Color>,
+    /// The recently used colors shown as swatches in the [`Inline`] color picker.
+    recent: Vec<Color>,
+    /// The maximum number of recently used colors shown.
+    max_recent: usize,
+}
+
+impl<'a, Message, Theme> Inline<'a, Message, Theme>
+where
+    Theme: StyleSheet + button::StyleSheet + text_input::StyleSheet,
+{
+    /// Creates a new [`Inline`] color picker showing the given color.
+    ///
+    /// `on_change` is called with the new color each time the user changes it.
+    pub fn new<F>(color: Color, on_change: F) -> Self
+    where
+        F: 'a + Fn(Color) -> Message,
+    {
+        Self {
+            color,
+            on_change: Box::new(on_change),
+            width: Length::Fill,
+            height: Length::Fixed(DEFAULT_HEIGHT),
+            style: <Theme as StyleSheet>::Style::default(),
+            show_alpha: true,
+            model: ColorModel::default(),
+            palette: Vec::new(),
+            recent: Vec::new(),
+            max_recent: DEFAULT_MAX_RECENT,
+        }
+    }
+
+    /// Sets the width of the [`Inline`] color picker.
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Inline`] color picker.
+    ///
+    /// The hue and saturation/value area is placed next to the bars if the picker is wider
+    /// than high, else above them.
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`Inline`] color picker.
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Shows or hides the alpha bar of the [`Inline`] color picker (shown by default).
+    ///
+    /// Without the alpha bar the reported colors are always opaque.
+    #[must_use]
+    pub fn show_alpha(mut self, show_alpha: bool) -> Self {
+        self.show_alpha = show_alpha;
+        self
+    }
+
+    /// Sets the color model of the gradient area and the hue bar of the [`Inline`] color
+    /// picker ([`ColorModel::Hsv`] by default).
+    #[must_use]
+    pub fn model(mut self, model: ColorModel) -> Self {
+        self.model = model;
+        self
+    }
+
+    /// Sets the preset colors of the [`Inline`] color picker, shown as swatches below
+    /// the hue bar.
+    #[must_use]
+    pub fn palette(mut self, palette: Vec<Color>) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    /// Sets the recently used colors of the [`Inline`] color picker, most recent first,
+    /// shown as swatches after the palette.
+    ///
+    /// Colors looking the same are shown once.
+    #[must_use]
+    pub fn recent(mut self, recent: Vec<Color>) -> Self {
+        self.recent = recent;
+        self
+    }
+
+    /// Sets the maximum number of recently used colors shown by the [`Inline`] color
+    /// picker (8 by default), the older ones are hidden.
+    #[must_use]
+    pub fn max_recent(mut self, max_recent: usize) -> Self {
+        self.max_recent = max_recent;
+        self
+    }
+}
+
+impl<'a, Message, Theme> Widget<Message, Theme, Renderer> for Inline<'a, Message, Theme>
+where
+    Theme: StyleSheet + button::StyleSheet + widget::text::StyleSheet + text_input::StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(self.color))
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        // Only the states of the widgets are used, their sizes don't matter
+        Panel::<Theme>::new(&State::new(self.color), Metrics::new(1.0, Pixels(16.0))).children()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        state.set_show_alpha(self.show_alpha);
+        state.set_model(self.model);
+        state.set_palette(&self.palette);
+        state.set_recent(&self.recent, self.max_recent);
+        state.set_color(self.color);
+
+        Panel::<Theme>::new(state, Metrics::new(1.0, Pixels(16.0))).diff(&mut tree.children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let size = limits.width(self.width).height(self.height).resolve(
+            self.width,
+            self.height,
+            Size::ZERO,
+        );
+
+        let state = tree.state.downcast_ref::<State>();
+
+        Panel::<Theme>::new(state, state.metrics(renderer)).layout(
+            &mut tree.children,
+            renderer,
+            Limits::new(Size::ZERO, size)
+                .width(Length::Fill)
+                .height(Length::Fill),
+            size.width > size.height,
+            0.0,
+            state,
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        // The keyboard changes the color only until something else is pressed
+        if matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+        ) && !cursor.is_over(layout.bounds())
+        {
+            state.focus = Focus::None;
+        }
+
+        let color = state.color;
+        let status = Panel::<Theme>::new(state, state.metrics(renderer)).on_event(
+            state,
+            &mut tree.children,
+            &event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            false,
+        );
+
+        if state.color != color {
+            shell.publish((self.on_change)(state.color));
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        Panel::<Theme>::new(state, state.metrics(renderer)).mouse_interaction(
+            state,
+            &tree.children,
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        Panel::<Theme>::new(state, state.metrics(renderer)).draw(
+            state,
+            &tree.children,
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            &style_sheet(theme, &self.style),
+            &StyleSheet::invalid(theme, &self.style),
+        );
+    }
+}
+
+impl<'a, Message, Theme> From<Inline<'a, Message, Theme>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a + StyleSheet + button::StyleSheet + widget::text::StyleSheet + text_input::StyleSheet,
+{
+    fn from(inline: Inline<'a, Message, Theme>) -> Self {
+        Element::new(inline)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Inline, State};
+    use iced_widget::{
+        core::{
+            clipboard, keyboard,
+            layout::{Limits, Node},
+            mouse::{self, Cursor},
+            renderer::Style,
+            widget::Tree,
+            Color, Event, Font, Layout, Pixels, Point, Rectangle, Shell, Size, Widget,
+        },
+        renderer::Renderer,
+        Theme,
+    };
+
+    const SIZE: Size = Size::new(600.0, 300.0);
+
+    fn renderer() -> Renderer {
+        Renderer::TinySkia(iced_tiny_skia::Renderer::new(
+            iced_tiny_skia::Backend::new(),
+            Font::default(),
+            Pixels(16.0),
+        ))
+    }
+
+    fn layout(inline: &Inline<'_, Color, Theme>, tree: &mut Tree, renderer: &Renderer) -> Node {
+        inline.layout(tree, renderer, &Limits::new(Size::ZERO, SIZE))
+    }
+
+    fn event(
+        inline: &mut Inline<'_, Color, Theme>,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        event: Event,
+        position: Point,
+    ) -> Vec<Color> {
+        let node = layout(inline, tree, renderer);
+        let mut messages = Vec::new();
+        let _ = inline.on_event(
+            tree,
+            event,
+            Layout::new(&node),
+            Cursor::Available(position),
+            renderer,
+            &mut clipboard::Null,
+            &mut Shell::new(&mut messages),
+            &Rectangle::with_size(SIZE),
+        );
+        messages
+    }
+
+    fn draw(
+        inline: &Inline<'_, Color, Theme>,
+        tree: &mut Tree,
+        renderer: &mut Renderer,
+        position: Point,
+    ) {
+        let node = layout(inline, tree, renderer);
+        inline.draw(
+            tree,
+            renderer,
+            &Theme::Light,
+            &Style {
+                text_color: Color::BLACK,
+            },
+            Layout::new(&node),
+            Cursor::Available(position),
+            &Rectangle::with_size(SIZE),
+        );
+    }
+
+    fn key(key: keyboard::Key, modifiers: keyboard::Modifiers, text: Option<&str>) -> Event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key,
+            location: keyboard::Location::Standard,
+            modifiers,
+            text: text.map(Into::into),
+        })
+    }
+
+    #[test]
+    fn hex_input_test() {
+        let renderer = renderer();
+        let mut inline = Inline::new(Color::WHITE, |color| color);
+        let mut tree = Tree::new(&inline as &dyn Widget<Color, Theme, Renderer>);
+
+        // The hex field is next to the comparison, below the RGBA bars
+        let node = layout(&inline, &mut tree, &renderer);
+        let hex_input = Layout::new(&node)
+            .children()
+            .nth(1)
+            .and_then(|block2| block2.children().nth(2))
+            .and_then(|fields| fields.children().next())
+            .expect("Inline should have a hex field")
+            .bounds();
+        let position = hex_input.center();
+
+        let pressed = Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left));
+        let _ = event(&mut inline, &mut tree, &renderer, pressed, position);
+
+        // The typed text replaces the selected hex code
+        let command = keyboard::Modifiers::COMMAND;
+        let modifiers = Event::Keyboard(keyboard::Event::ModifiersChanged(command));
+        let _ = event(&mut inline, &mut tree, &renderer, modifiers, position);
+        let select_all = key(keyboard::Key::Character("a".into()), command, Some("a"));
+        let _ = event(&mut inline, &mut tree, &renderer, select_all, position);
+        let modifiers = Event::Keyboard(keyboard::Event::ModifiersChanged(
+            keyboard::Modifiers::empty(),
+        ));
+        let _ = event(&mut inline, &mut tree, &renderer, modifiers, position);
+
+        let mut messages = Vec::new();
+        for c in "#00ff00".chars() {
+            let text = c.to_string();
+            let typed = key(
+                keyboard::Key::Character(text.as_str().into()),
+                keyboard::Modifiers::empty(),
+                Some(&text),
+            );
+            messages.extend(event(&mut inline, &mut tree, &renderer, typed, position));
+        }
+
+        assert_eq!(messages.last(), Some(&Color::from_rgb8(0, 255, 0)));
+    }
+
+    #[test]
+    fn sat_value_cache_test() {
+        let mut renderer = renderer();
+        let mut red = Inline::new(Color::from_rgb(1.0, 0.0, 0.0), |color| color);
+        let mut tree = Tree::new(&red as &dyn Widget<Color, Theme, Renderer>);
+        let regenerations =
+            |tree: &Tree| tree.state.downcast_ref::<State>().sat_value_regenerations();
+
+        draw(&red, &mut tree, &mut renderer, Point::ORIGIN);
+        assert_eq!(regenerations(&tree), 1);
+
+        // Moving the cursor over the gradient only draws the markers again
+        let position = Point::new(50.0, 50.0);
+        let moved = Event::Mouse(mouse::Event::CursorMoved { position });
+        let _ = event(&mut red, &mut tree, &renderer, moved, position);
+        draw(&red, &mut tree, &mut renderer, position);
+        assert_eq!(regenerations(&tree), 1);
+
+        // Another hue generates the gradient again
+        let green = Inline::new(Color::from_rgb(0.0, 1.0, 0.0), |color| color);
+        tree.diff(&green as &dyn Widget<Color, Theme, Renderer>);
+        draw(&green, &mut tree, &mut renderer, position);
+        assert_eq!(regenerations(&tree), 2);
+    }
+}
@@ -1,6 +1,12 @@
 //! Common types for reuse.
 //!
 
+#[cfg(any(feature = "grid", feature = "selection_list", feature = "wrap"))]
+use iced_widget::core::{
+    layout::{Limits, Node},
+    widget::Tree,
+    Alignment, Element, Length, Renderer,
+};
 use iced_widget::core::{Padding, Rectangle};
 
 /// Methods for creating inner bounds
@@ -61,3 +67,27 @@ impl InnerBounds {
         }
     }
 }
+
+/// Lays out the element shown by a collection widget without items,
+/// centered in the bounds the widget takes with the given width and height.
+#[cfg(any(feature = "grid", feature = "selection_list", feature = "wrap"))]
+pub(crate) fn layout_empty<Message, Theme, R>(
+    empty: &Element<'_, Message, Theme, R>,
+    tree: &mut Tree,
+    renderer: &R,
+    limits: &Limits,
+    width: Length,
+    height: Length,
+) -> Node
+where
+    R: Renderer,
+{
+    let limits = limits.width(width).height(height);
+    let content = empty.as_widget().layout(tree, renderer, &limits.loose());
+    let size = limits.resolve(width, height, content.size());
+
+    Node::with_children(
+        size,
+        vec![content.align(Alignment::Center, Alignment::Center, size)],
+    )
+}
@@ -1,17 +1,21 @@
 //! A context menu for showing actions on right click.
 //!
 use iced_widget::core::{
-    self, event,
+    self, event, keyboard,
     layout::{Limits, Node},
     mouse::{self, Button, Cursor},
     overlay, renderer,
-    widget::{tree, Operation, Tree},
-    Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Widget,
+    widget::{operation, tree, Id, Operation, Tree},
+    window, Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Widget,
 };
+use std::time::{Duration, Instant};
 
 use crate::native::overlay::ContextMenuOverlay;
 pub use crate::style::context_menu::StyleSheet;
 
+pub mod item;
+pub use item::{menu_items, Item};
+
 /// A context menu
 ///
 ///
@@ -29,7 +33,7 @@ pub use crate::style::context_menu::StyleSheet;
 ///
 /// let cm = ContextMenu::new(
 ///     underlay,
-///     || Button::new("action1").on_press(Message::Action1).into()
+///     |_| Button::new("action1").on_press(Message::Action1).into()
 /// );
 /// ```
 #[allow(missing_debug_implementations)]
@@ -40,7 +44,7 @@ pub struct ContextMenu<
     Theme = iced_widget::Theme,
     Renderer = iced_widget::Renderer,
 > where
-    Overlay: Fn() -> Element<'a, Message, Theme, Renderer>,
+    Overlay: Fn(ContextInfo) -> Element<'a, Message, Theme, Renderer>,
     Message: Clone,
     Renderer: core::Renderer,
     Theme: StyleSheet,
@@ -51,11 +55,21 @@ pub struct ContextMenu<
     overlay: Overlay,
     /// The style of the [`ContextMenu`].
     style: <Theme as StyleSheet>::Style,
+    /// The keyboard modifiers that must be held to open the [`ContextMenu`].
+    modifiers: keyboard::Modifiers,
+    /// The duration of the open animation of the [`ContextMenu`].
+    animation: Option<Duration>,
+    /// The inactivity after which the overlay closes itself, if any.
+    auto_close: Option<Duration>,
+    /// Whether the overlay is confined to the bounds of the underlay.
+    confine_to_underlay: bool,
+    /// The message produced when the overlay is opened or closed.
+    on_toggle: Option<Box<dyn Fn(bool) -> Message + 'a>>,
 }
 
 impl<'a, Overlay, Message, Theme, Renderer> ContextMenu<'a, Overlay, Message, Theme, Renderer>
 where
-    Overlay: Fn() -> Element<'a, Message, Theme, Renderer>,
+    Overlay: Fn(ContextInfo) -> Element<'a, Message, Theme, Renderer>,
     Message: Clone,
     Renderer: core::Renderer,
     Theme: StyleSheet,
@@ -65,6 +79,7 @@ where
     /// `underlay`: The underlying element.
     ///
     /// `overlay`: The content of [`ContextMenuOverlay`] which will be displayed when `underlay` is clicked.
+    /// It is given the [`ContextInfo`] of the click that opened the menu.
     pub fn new<U>(underlay: U, overlay: Overlay) -> Self
     where
         U: Into<Element<'a, Message, Theme, Renderer>>,
@@ -73,9 +88,67 @@ where
             underlay: underlay.into(),
             overlay,
             style: <Theme as StyleSheet>::Style::default(),
+            modifiers: keyboard::Modifiers::empty(),
+            animation: None,
+            auto_close: None,
+            confine_to_underlay: false,
+            on_toggle: None,
         }
     }
 
+    /// Sets the message produced when the overlay of the [`ContextMenu`] is
+    /// opened (`true`) or closed (`false`), whatever closed it.
+    #[must_use]
+    pub fn on_toggle<F>(mut self, on_toggle: F) -> Self
+    where
+        F: 'a + Fn(bool) -> Message,
+    {
+        self.on_toggle = Some(Box::new(on_toggle));
+        self
+    }
+
+    /// Sets whether the overlay of the [`ContextMenu`] is positioned and clamped
+    /// within the bounds of the underlay instead of the whole window.
+    ///
+    /// A menu taller than the underlay scrolls internally.
+    #[must_use]
+    pub fn confine_to_underlay(mut self, confine: bool) -> Self {
+        self.confine_to_underlay = confine;
+        self
+    }
+
+    /// Sets the duration of the fade and scale animation played when the
+    /// [`ContextMenu`] opens.
+    ///
+    /// The menu accepts input right away, while the animation is running.
+    #[must_use]
+    pub fn animation(mut self, duration: Duration) -> Self {
+        self.animation = Some(duration);
+        self
+    }
+
+    /// Sets the inactivity after which the overlay of the [`ContextMenu`]
+    /// closes itself.
+    ///
+    /// The countdown starts when the menu opens and starts over on any
+    /// cursor movement over the menu or keyboard input. The toggle message
+    /// is produced when the menu closes this way.
+    #[must_use]
+    pub fn auto_close(mut self, timeout: Duration) -> Self {
+        self.auto_close = Some(timeout);
+        self
+    }
+
+    /// Sets the keyboard modifiers that must be held while right clicking
+    /// to open the [`ContextMenu`].
+    ///
+    /// With no modifiers set, a plain right click opens the menu.
+    #[must_use]
+    pub fn modifiers(mut self, modifiers: keyboard::Modifiers) -> Self {
+        self.modifiers = modifiers;
+        self
+    }
+
     /// Sets the style of the [`ContextMenu`].
     #[must_use]
     pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
@@ -87,7 +160,7 @@ where
 impl<'a, Content, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for ContextMenu<'a, Content, Message, Theme, Renderer>
 where
-    Content: 'a + Fn() -> Element<'a, Message, Theme, Renderer>,
+    Content: 'a + Fn(ContextInfo) -> Element<'a, Message, Theme, Renderer>,
     Message: 'a + Clone,
     Renderer: 'a + core::Renderer,
     Theme: StyleSheet,
@@ -132,11 +205,15 @@ where
     }
 
     fn children(&self) -> Vec<Tree> {
-        vec![Tree::new(&self.underlay), Tree::new(&(self.overlay)())]
+        vec![
+            Tree::new(&self.underlay),
+            Tree::new((self.overlay)(ContextInfo::default())),
+        ]
     }
 
     fn diff(&self, tree: &mut Tree) {
-        tree.diff_children(&[&self.underlay, &(self.overlay)()]);
+        let context = tree.state.downcast_ref::<State>().context.clone();
+        tree.diff_children(&[&self.underlay, &(self.overlay)(context)]);
     }
 
     fn operate<'b>(
@@ -149,7 +226,7 @@ where
         let s: &mut State = state.state.downcast_mut();
 
         if s.show {
-            let content = (self.overlay)();
+            let content = (self.overlay)(s.context.clone());
             content.as_widget().diff(&mut state.children[1]);
 
             content
@@ -173,13 +250,67 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) -> event::Status {
+        // The overlay handles its events first and may have closed the menu
+        state
+            .state
+            .downcast_mut::<State>()
+            .notify_toggle(self.on_toggle.as_deref(), shell);
+
+        if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) = event {
+            let s: &mut State = state.state.downcast_mut();
+            s.keyboard_modifiers = modifiers;
+        }
+
         if event == Event::Mouse(mouse::Event::ButtonPressed(Button::Right)) {
             let bounds = layout.bounds();
+            let modifiers = state.state.downcast_ref::<State>().keyboard_modifiers;
+
+            if cursor.is_over(bounds) && modifiers.contains(self.modifiers) {
+                let position = cursor.position().unwrap_or_default();
+
+                // Find the innermost widget with an id under the cursor
+                let mut hit_test = HitTest {
+                    position,
+                    target: None,
+                };
+                self.underlay.as_widget().operate(
+                    &mut state.children[0],
+                    layout,
+                    renderer,
+                    &mut hit_test,
+                );
 
-            if cursor.is_over(bounds) {
                 let s: &mut State = state.state.downcast_mut();
-                s.cursor_position = cursor.position().unwrap_or_default();
+                s.cursor_position = position;
+                s.context = ContextInfo {
+                    position,
+                    target_bounds: hit_test.target.as_ref().map(|(_, bounds)| *bounds),
+                    target_id: hit_test.target.map(|(id, _)| id),
+                };
                 s.show = !s.show;
+                s.scroll_offset = 0.0;
+
+                if s.show && self.animation.is_some() {
+                    s.opened_at = Some(Instant::now());
+                    s.animation_progress = 0.0;
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+
+                if let Some(timeout) = self.auto_close.filter(|_| s.show) {
+                    let now = Instant::now();
+                    s.last_interaction = Some(now);
+                    shell.request_redraw(window::RedrawRequest::At(now + timeout));
+                }
+
+                s.notify_toggle(self.on_toggle.as_deref(), shell);
+                return event::Status::Captured;
+            }
+        }
+
+        if let Event::Mouse(mouse::Event::WheelScrolled { .. }) = event {
+            let s: &State = state.state.downcast_ref();
+
+            if s.show {
                 return event::Status::Captured;
             }
         }
@@ -229,12 +360,21 @@ where
         }
 
         let position = s.cursor_position;
-        let content = (self.overlay)();
+        let content = (self.overlay)(s.context.clone());
         content.as_widget().diff(&mut state.children[1]);
 
         Some(
-            ContextMenuOverlay::new(&mut state.children[1], content, self.style.clone(), s)
-                .overlay(position),
+            ContextMenuOverlay::new(
+                &mut state.children[1],
+                content,
+                self.style.clone(),
+                self.animation,
+                self.auto_close,
+                self.confine_to_underlay.then(|| layout.bounds()),
+                self.on_toggle.as_deref(),
+                s,
+            )
+            .overlay(position),
         )
     }
 }
@@ -242,7 +382,7 @@ where
 impl<'a, Content, Message, Theme, Renderer> From<ContextMenu<'a, Content, Message, Theme, Renderer>>
     for Element<'a, Message, Theme, Renderer>
 where
-    Content: 'a + Fn() -> Element<'a, Message, Theme, Renderer>,
+    Content: 'a + Fn(ContextInfo) -> Element<'a, Message, Theme, Renderer>,
     Message: 'a + Clone,
     Renderer: 'a + core::Renderer,
     Theme: 'a + StyleSheet,
@@ -257,8 +397,22 @@ where
 pub(crate) struct State {
     /// The visibility of the [`ContextMenu`] overlay.
     pub show: bool,
+    /// The visibility last reported through the toggle message.
+    pub reported_show: bool,
     /// Use for showing the overlay where the click was made.
     pub cursor_position: Point,
+    /// The information about the click that opened the overlay.
+    pub context: ContextInfo,
+    /// The state of the keyboard modifiers.
+    pub keyboard_modifiers: keyboard::Modifiers,
+    /// The instant the overlay was opened, if it is animated.
+    pub opened_at: Option<Instant>,
+    /// The progress of the open animation, from 0.0 to 1.0.
+    pub animation_progress: f32,
+    /// The scroll offset of an overlay confined to the underlay.
+    pub scroll_offset: f32,
+    /// The instant of the last interaction with the overlay, if it closes itself.
+    pub last_interaction: Option<Instant>,
 }
 
 impl State {
@@ -266,7 +420,89 @@ impl State {
     pub const fn new() -> Self {
         Self {
             show: false,
+            reported_show: false,
             cursor_position: Point::ORIGIN,
+            context: ContextInfo {
+                position: Point::ORIGIN,
+                target_id: None,
+                target_bounds: None,
+            },
+            keyboard_modifiers: keyboard::Modifiers::empty(),
+            opened_at: None,
+            animation_progress: 1.0,
+            scroll_offset: 0.0,
+            last_interaction: None,
+        }
+    }
+
+    /// Publishes the toggle message if the visibility changed since it was last reported.
+    pub fn notify_toggle<Message>(
+        &mut self,
+        on_toggle: Option<&dyn Fn(bool) -> Message>,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        if self.show != self.reported_show {
+            self.reported_show = self.show;
+
+            if let Some(on_toggle) = on_toggle {
+                shell.publish(on_toggle(self.show));
+            }
+        }
+    }
+}
+
+/// The information about the right click that opened a [`ContextMenu`].
+#[derive(Debug, Clone, Default)]
+pub struct ContextInfo {
+    /// The position of the cursor.
+    pub position: Point,
+    /// The [`Id`] of the innermost widget with an id under the cursor, if any.
+    pub target_id: Option<Id>,
+    /// The bounds of the widget of `target_id`, if any.
+    pub target_bounds: Option<Rectangle>,
+}
+
+/// An [`Operation`] finding the innermost widget with an id containing a position.
+///
+/// Only containers and scrollables report their bounds.
+struct HitTest {
+    /// The position to test.
+    position: Point,
+    /// The id and bounds of the innermost widget found so far.
+    target: Option<(Id, Rectangle)>,
+}
+
+impl HitTest {
+    /// Records the widget if it has an id and contains the position.
+    fn hit(&mut self, id: Option<&Id>, bounds: Rectangle) {
+        if let Some(id) = id {
+            if bounds.contains(self.position) {
+                self.target = Some((id.clone(), bounds));
+            }
         }
     }
 }
+
+impl<T> Operation<T> for HitTest {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        if bounds.contains(self.position) {
+            self.hit(id, bounds);
+            operate_on_children(self);
+        }
+    }
+
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn operation::Scrollable,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        _translation: core::Vector,
+    ) {
+        self.hit(id, bounds);
+    }
+}
@@ -0,0 +1,377 @@
+//! Ready-made rows for the content of a [`ContextMenu`](super::ContextMenu).
+//!
+//! *This API requires the following crate features to be activated: `context_menu`*
+use iced_widget::{
+    core::{
+        self, event,
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        renderer, text, touch,
+        widget::Tree,
+        Alignment, Border, Clipboard, Color, Element, Event, Layout, Length, Padding, Point,
+        Rectangle, Shadow, Shell, Size, Widget,
+    },
+    Column, Row, Text,
+};
+
+pub use crate::style::context_menu::StyleSheet;
+
+/// The height of a separator [`Item`].
+const SEPARATOR_HEIGHT: f32 = 9.0;
+
+/// A row of a [`ContextMenu`](super::ContextMenu) with a hover highlight.
+///
+/// An [`Item`] shows a label with an optional leading icon and an optional
+/// trailing shortcut text. Clicking it publishes its message and closes the
+/// menu. An [`Item`] without a message is drawn as disabled.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::context_menu::Item;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Copy,
+/// }
+///
+/// let item = Item::new("Copy").shortcut("Ctrl+C").on_press(Message::Copy);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Item<'a, Message, Theme = iced_widget::Theme, Renderer = iced_widget::Renderer>
+where
+    Theme: StyleSheet,
+{
+    /// The label of the [`Item`], `None` for a separator.
+    label: Option<String>,
+    /// The optional leading icon of the [`Item`].
+    icon: Option<Element<'a, Message, Theme, Renderer>>,
+    /// The optional trailing shortcut text of the [`Item`].
+    shortcut: Option<String>,
+    /// The message produced when the [`Item`] is clicked.
+    on_press: Option<Message>,
+    /// The padding of the [`Item`].
+    padding: Padding,
+    /// The style of the [`Item`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme, Renderer> Item<'a, Message, Theme, Renderer>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`Item`] with the given label.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: Some(label.into()),
+            icon: None,
+            shortcut: None,
+            on_press: None,
+            padding: Padding::from([4, 8]),
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Creates a new separator [`Item`].
+    #[must_use]
+    pub fn separator() -> Self {
+        Self {
+            label: None,
+            ..Self::new("")
+        }
+    }
+
+    /// Sets the message produced when the [`Item`] is clicked.
+    #[must_use]
+    pub fn on_press(mut self, message: Message) -> Self {
+        self.on_press = Some(message);
+        self
+    }
+
+    /// Sets the message produced when the [`Item`] is clicked, if `Some`.
+    ///
+    /// If `None`, the [`Item`] is disabled.
+    #[must_use]
+    pub fn on_press_maybe(mut self, message: Option<Message>) -> Self {
+        self.on_press = message;
+        self
+    }
+
+    /// Sets the leading icon of the [`Item`].
+    #[must_use]
+    pub fn icon(mut self, icon: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Sets the trailing shortcut text of the [`Item`].
+    #[must_use]
+    pub fn shortcut(mut self, shortcut: impl Into<String>) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+
+    /// Sets the padding of the [`Item`].
+    #[must_use]
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the style of the [`Item`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+/// Creates a column of [`Item`]s from the given labels and messages.
+#[must_use]
+pub fn menu_items<'a, Message, Theme, Renderer>(
+    items: Vec<(String, Message)>,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer,
+    Theme: 'a + StyleSheet + iced_widget::text::StyleSheet,
+{
+    Column::with_children(
+        items
+            .into_iter()
+            .map(|(label, message)| Item::new(label).on_press(message).into()),
+    )
+    .width(Length::Shrink)
+    .into()
+}
+
+/// The widget drawing an [`Item`].
+struct ItemWidget<'a, Message, Theme, Renderer>
+where
+    Theme: StyleSheet,
+{
+    /// The content of the row, `None` for a separator.
+    content: Option<Element<'a, Message, Theme, Renderer>>,
+    /// The message produced when the row is clicked.
+    on_press: Option<Message>,
+    /// The padding of the row.
+    padding: Padding,
+    /// The style of the row.
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ItemWidget<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: core::Renderer,
+    Theme: StyleSheet,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fill, Length::Shrink)
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.content.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.content.iter().collect::<Vec<_>>());
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(Length::Fill).height(Length::Shrink);
+
+        let Some(content) = &self.content else {
+            let size = limits.resolve(
+                Length::Fill,
+                Length::Shrink,
+                Size::new(0.0, SEPARATOR_HEIGHT),
+            );
+            return Node::new(size);
+        };
+
+        let content = content
+            .as_widget()
+            .layout(
+                &mut tree.children[0],
+                renderer,
+                &limits.shrink(self.padding),
+            )
+            .move_to(Point::new(self.padding.left, self.padding.top));
+
+        let size = limits.resolve(
+            Length::Fill,
+            Length::Shrink,
+            Size::new(
+                content.size().width + self.padding.horizontal(),
+                content.size().height + self.padding.vertical(),
+            ),
+        );
+
+        Node::with_children(size, vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let Some(content) = &mut self.content else {
+            return event::Status::Ignored;
+        };
+
+        let status = content.as_widget_mut().on_event(
+            &mut state.children[0],
+            event.clone(),
+            layout
+                .children()
+                .next()
+                .expect("Native: Layout should have a content layout."),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                if let Some(message) = self.on_press.clone() {
+                    if cursor.is_over(layout.bounds()) {
+                        shell.publish(message);
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+
+        let Some(content) = &self.content else {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        y: bounds.center_y().floor(),
+                        height: 1.0,
+                        ..bounds
+                    },
+                    border: Border {
+                        radius: (0.0).into(),
+                        width: 0.0,
+                        color: Color::TRANSPARENT,
+                    },
+                    shadow: Shadow::default(),
+                },
+                appearance.separator_color,
+            );
+            return;
+        };
+
+        let text_color = if self.on_press.is_none() {
+            appearance.item_disabled_text_color
+        } else if cursor.is_over(bounds) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border {
+                        radius: (0.0).into(),
+                        width: 0.0,
+                        color: Color::TRANSPARENT,
+                    },
+                    shadow: Shadow::default(),
+                },
+                appearance.item_hovered_background,
+            );
+            appearance.item_hovered_text_color
+        } else {
+            appearance.item_text_color
+        };
+
+        content.as_widget().draw(
+            &state.children[0],
+            renderer,
+            theme,
+            &renderer::Style { text_color },
+            layout
+                .children()
+                .next()
+                .expect("Native: Layout should have a content layout."),
+            cursor,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.on_press.is_some() && cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Item<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer,
+    Theme: 'a + StyleSheet + iced_widget::text::StyleSheet,
+{
+    fn from(item: Item<'a, Message, Theme, Renderer>) -> Self {
+        let content = item.label.map(|label| {
+            let mut row = Row::new().spacing(8).align_items(Alignment::Center);
+
+            if let Some(icon) = item.icon {
+                row = row.push(icon);
+            }
+
+            row = row.push(Text::new(label).width(Length::Fill));
+
+            if let Some(shortcut) = item.shortcut {
+                row = row.push(Text::new(shortcut));
+            }
+
+            row.into()
+        });
+
+        Element::new(ItemWidget {
+            content,
+            on_press: item.on_press,
+            padding: item.padding,
+            style: item.style,
+        })
+    }
+}
@@ -2,9 +2,15 @@
 //!
 //! *This API requires the following crate features to be activated: `date_picker`*
 
-use super::overlay::date_picker::{self, DatePickerOverlay, DatePickerOverlayButtons};
+pub mod calendar;
+pub use calendar::Calendar;
 
-use chrono::Local;
+use super::overlay::date_picker::{
+    self, Body, DatePickerOverlay, DatePickerOverlayButtons, OnSubmit,
+};
+
+pub use chrono::Weekday;
+use chrono::{Datelike, Local, NaiveDate};
 use iced_widget::{
     button, container,
     core::{
@@ -16,14 +22,16 @@ use iced_widget::{
             self,
             tree::{Tag, Tree},
         },
-        Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Widget,
+        Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shell, Widget,
     },
     renderer::Renderer,
     text,
 };
+use std::{collections::BTreeSet, sync::Arc};
 
+use crate::core::locale::{self, Provider};
 pub use crate::{
-    core::date::Date,
+    core::date::{Date, InvalidDate},
     style::date_picker::{Appearance, StyleSheet},
 };
 
@@ -66,11 +74,33 @@ where
     /// The message that is send if the cancel button of the [`DatePickerOverlay`] is pressed.
     on_cancel: Message,
     /// The function that produces a message when the submit button of the [`DatePickerOverlay`] is pressed.
-    on_submit: Box<dyn Fn(Date) -> Message>,
+    on_submit: OnSubmit<Message>,
+    /// The picked days to show when picking multiple days.
+    selected: BTreeSet<NaiveDate>,
+    /// The maximum number of days picked at once when picking multiple days.
+    max_selected: Option<usize>,
     /// The style of the [`DatePickerOverlay`].
     style: <Theme as StyleSheet>::Style,
     /// The buttons of the overlay.
     overlay_state: Element<'a, Message, Theme, Renderer>,
+    /// The locale of the [`DatePickerOverlay`].
+    locale: Arc<dyn Provider>,
+    /// The function checking if a day can't be picked.
+    disabled: Option<Box<dyn Fn(Date) -> bool>>,
+    /// The function giving the color of the dot marking a day, if any.
+    highlights: Option<Box<dyn Fn(Date) -> Option<Color>>>,
+    /// The weekday the weeks start with, the one of the locale if `None`.
+    first_day_of_week: Option<Weekday>,
+    /// The function formatting the month and the year of the header.
+    header_format: Option<Box<HeaderFormat>>,
+    /// The function formatting the picked day shown in the header, if shown.
+    format: Option<Box<Format>>,
+    /// Whether the overlay shows the button going back to the current day.
+    show_today_button: bool,
+    /// The label of the today button, the one of the locale if `None`.
+    today_label: Option<String>,
+    /// Whether a click outside of the overlay cancels the picker.
+    close_on_outside_click: bool,
     //button_style: <Renderer as button::Renderer>::Style, // clone not satisfied
 }
 
@@ -83,7 +113,7 @@ where
     ///
     /// It expects:
     ///     * if the overlay of the date picker is visible.
-    ///     * the initial date to show.
+    ///     * the initial date to show, which has to exist.
     ///     * the underlay [`Element`] on which this [`DatePicker`]
     ///         will be wrapped around.
     ///     * a message that will be send when the cancel button of the [`DatePicker`]
@@ -101,18 +131,107 @@ where
         U: Into<Element<'a, Message, Theme, Renderer>>,
         F: 'static + Fn(Date) -> Message,
     {
+        Self::with_on_submit(
+            show_picker,
+            date.into(),
+            underlay.into(),
+            on_cancel,
+            OnSubmit::Single(Box::new(on_submit)),
+        )
+    }
+
+    /// Creates a new [`DatePicker`] submitting the picked days with the
+    /// given function.
+    fn with_on_submit(
+        show_picker: bool,
+        date: Date,
+        underlay: Element<'a, Message, Theme, Renderer>,
+        on_cancel: Message,
+        on_submit: OnSubmit<Message>,
+    ) -> Self {
         Self {
             show_picker,
-            date: date.into(),
-            underlay: underlay.into(),
+            date,
+            underlay,
             on_cancel,
-            on_submit: Box::new(on_submit),
+            on_submit,
+            selected: BTreeSet::new(),
+            max_selected: None,
             style: <Theme as StyleSheet>::Style::default(),
             overlay_state: DatePickerOverlayButtons::default().into(),
+            locale: locale::default_provider(),
+            disabled: None,
+            highlights: None,
+            first_day_of_week: None,
+            header_format: None,
+            format: None,
+            show_today_button: false,
+            today_label: None,
+            close_on_outside_click: true,
             //button_style: <Renderer as button::Renderer>::Style::default(),
         }
     }
 
+    /// Creates a new [`DatePicker`] picking multiple days, wrapping around
+    /// the given underlay.
+    ///
+    /// Clicking a day adds it to the picked days or removes it again, and
+    /// the picked days are kept while moving to other months. The overlay
+    /// opens on the month of the first picked day, or on the current month.
+    ///
+    /// It expects:
+    ///     * if the overlay of the date picker is visible.
+    ///     * the initially picked dates.
+    ///     * the underlay [`Element`] on which this [`DatePicker`]
+    ///         will be wrapped around.
+    ///     * a message that will be send when the cancel button of the [`DatePicker`]
+    ///         is pressed.
+    ///     * a function that will be called when the submit button of the [`DatePicker`]
+    ///         is pressed, which takes the picked [`Date`](crate::date_picker::Date) values in order.
+    ///
+    /// Dates that don't exist are left out.
+    pub fn new_multiple<U, F>(
+        show_picker: bool,
+        dates: impl IntoIterator<Item = Date>,
+        underlay: U,
+        on_cancel: Message,
+        on_submit: F,
+    ) -> Self
+    where
+        U: Into<Element<'a, Message, Theme, Renderer>>,
+        F: 'static + Fn(Vec<Date>) -> Message,
+    {
+        let selected: BTreeSet<NaiveDate> = dates
+            .into_iter()
+            .filter_map(|date| NaiveDate::try_from(date).ok())
+            .collect();
+        let date = selected
+            .first()
+            .map_or_else(Date::today, |&date| date.into());
+
+        Self {
+            selected,
+            ..Self::with_on_submit(
+                show_picker,
+                date,
+                underlay.into(),
+                on_cancel,
+                OnSubmit::Multiple(Box::new(on_submit)),
+            )
+        }
+    }
+
+    /// Sets the maximum number of days picked at once by a [`DatePicker`]
+    /// picking multiple days, see [`DatePicker::new_multiple`].
+    ///
+    /// Once reached, clicking other days doesn't add them until a picked day
+    /// is removed again.
+    #[must_use]
+    pub fn max_selected(mut self, max_selected: usize) -> Self {
+        self.max_selected = Some(max_selected);
+        self
+    }
+
     /// Sets the style of the [`DatePicker`].
     #[must_use]
     pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
@@ -120,8 +239,123 @@ where
         //self.button_style = style.into();
         self
     }
+
+    /// Sets the function checking if a day can't be picked, e.g. weekends,
+    /// public holidays or booked days.
+    ///
+    /// Disabled days are drawn with the disabled style and can't be clicked,
+    /// the arrow keys skip them and they can't be submitted. Moving to a
+    /// disabled day, e.g. with the month and year arrows, lands on the nearest
+    /// enabled day instead. The function is called for the shown days and,
+    /// when moving, for up to a year of days around the reached one.
+    #[must_use]
+    pub fn disabled<F>(mut self, disabled: F) -> Self
+    where
+        F: 'static + Fn(Date) -> bool,
+    {
+        self.disabled = Some(Box::new(disabled));
+        self
+    }
+
+    /// Sets the function giving the color of the dot drawn below the number
+    /// of a day, e.g. to mark the days with calendar events. Days without a
+    /// color get no dot.
+    ///
+    /// The function is only called for the days shown by the overlay.
+    #[must_use]
+    pub fn highlights<F>(mut self, highlights: F) -> Self
+    where
+        F: 'static + Fn(Date) -> Option<Color>,
+    {
+        self.highlights = Some(Box::new(highlights));
+        self
+    }
+
+    /// Sets the weekday the weeks of the [`DatePicker`] start with, e.g.
+    /// [`Weekday::Sun`] for US calendars, instead of the one of the locale.
+    #[must_use]
+    pub fn first_day_of_week(mut self, weekday: Weekday) -> Self {
+        self.first_day_of_week = Some(weekday);
+        self
+    }
+
+    /// Sets the function formatting the whole header, e.g. `2024年3月`, shown
+    /// instead of the month and the year controls.
+    ///
+    /// The header then has a single control: its arrows step the months, or
+    /// the years in the month grid, and clicking it shows the month grid,
+    /// then the year grid.
+    #[must_use]
+    pub fn header_format<F>(mut self, header_format: F) -> Self
+    where
+        F: Fn(Date) -> String + 'static,
+    {
+        self.header_format = Some(Box::new(header_format));
+        self
+    }
+
+    /// Sets the function formatting the picked day shown in a header above
+    /// the month and the year controls, e.g. `"Tue, 5 Mar 2024"`.
+    ///
+    /// Without it, no such header is shown. A text too wide for the overlay
+    /// is shortened with an ellipsis.
+    #[must_use]
+    pub fn format<F>(mut self, format: F) -> Self
+    where
+        F: Fn(&Date) -> String + 'static,
+    {
+        self.format = Some(Box::new(format));
+        self
+    }
+
+    /// Sets whether the overlay of the [`DatePicker`] shows a button going
+    /// back to the current day, between the cancel and the submit buttons.
+    ///
+    /// The button picks today, or the nearest enabled day if today is disabled.
+    #[must_use]
+    pub fn show_today_button(mut self, show: bool) -> Self {
+        self.show_today_button = show;
+        self
+    }
+
+    /// Sets the label of the today button of the [`DatePicker`] instead of
+    /// the one of the locale, see [`DatePicker::show_today_button`].
+    #[must_use]
+    pub fn today_label(mut self, label: impl Into<String>) -> Self {
+        self.today_label = Some(label.into());
+        self
+    }
+
+    /// Sets whether pressing outside of the overlay of the [`DatePicker`]
+    /// cancels it like the cancel button and the escape key. Enabled by
+    /// default.
+    ///
+    /// The press that cancels the picker doesn't reach the widgets below the
+    /// overlay.
+    #[must_use]
+    pub fn close_on_outside_click(mut self, close: bool) -> Self {
+        self.close_on_outside_click = close;
+        self
+    }
+
+    /// Sets the locale [`Provider`] of the [`DatePicker`] instead of the
+    /// default one.
+    ///
+    /// Next to the month and weekday names, the locale provides the texts of
+    /// the submit and cancel buttons shown instead of their icons.
+    #[must_use]
+    pub fn locale(mut self, locale: impl Provider + 'static) -> Self {
+        self.locale = Arc::new(locale);
+        self
+    }
 }
 
+/// The function formatting the month and the year of the header of a [`DatePicker`].
+type HeaderFormat = dyn Fn(Date) -> String;
+
+/// The function formatting the picked day shown in the header of a [`DatePicker`].
+type Format = dyn Fn(&Date) -> String;
+
 /// The state of the [`DatePicker`] / [`DatePickerOverlay`].
 #[derive(Debug)]
 pub struct State {
@@ -139,16 +373,22 @@ impl State {
     }
 
     /// Creates a new [`State`] with the given date.
+    ///
+    /// # Panics
+    /// Panics if the date doesn't exist.
     #[must_use]
     pub fn new(date: Date) -> Self {
         Self {
-            overlay_state: date_picker::State::new(date.into()),
+            overlay_state: date_picker::State::new(
+                date.try_into().expect("Year, Month or Day doesnt Exist"),
+            ),
         }
     }
 
     /// Resets the date of the state to the current date.
     pub fn reset(&mut self) {
         self.overlay_state.date = Local::now().naive_local().date();
+        self.overlay_state.day = self.overlay_state.date.day();
     }
 }
 
@@ -162,7 +402,10 @@ where
     }
 
     fn state(&self) -> widget::tree::State {
-        widget::tree::State::new(State::new(self.date))
+        let mut state = State::new(self.date);
+        state.overlay_state.selected.clone_from(&self.selected);
+
+        widget::tree::State::new(state)
     }
 
     fn children(&self) -> Vec<Tree> {
@@ -170,6 +413,19 @@ where
     }
 
     fn diff(&self, tree: &mut Tree) {
+        // The picker opens on the given date with the keyboard on its day
+        if !self.show_picker {
+            let overlay_state = &mut tree.state.downcast_mut::<State>().overlay_state;
+            overlay_state.date = self
+                .date
+                .try_into()
+                .expect("Year, Month or Day doesnt Exist");
+            overlay_state.day = overlay_state.date.day();
+            overlay_state.focus = date_picker::Focus::Day;
+            overlay_state.view = date_picker::View::Days;
+            overlay_state.selected.clone_from(&self.selected);
+        }
+
         tree.diff_children(&[&self.underlay, &self.overlay_state]);
     }
 
@@ -269,6 +525,25 @@ where
                 &self.on_submit,
                 position,
                 self.style.clone(),
+                Body {
+                    locale: self.locale.as_ref(),
+                    first_weekday: self.first_day_of_week.map_or_else(
+                        || self.locale.first_weekday(),
+                        |weekday| weekday.num_days_from_monday(),
+                    ),
+                    disabled: self.disabled.as_deref(),
+                    highlights: self.highlights.as_deref(),
+                    header_format: self.header_format.as_deref(),
+                    format: self.format.as_deref(),
+                    multiple: matches!(self.on_submit, OnSubmit::Multiple(_)),
+                    max_selected: self.max_selected,
+                },
+                self.show_today_button.then(|| {
+                    self.today_label
+                        .clone()
+                        .unwrap_or_else(|| self.locale.today_label())
+                }),
+                self.close_on_outside_click,
                 &mut state.children[1],
             )
             .overlay(),
@@ -0,0 +1,427 @@
+//! Use a calendar laid out in place instead of as an overlay.
+//!
+//! *This API requires the following crate features to be activated: `date_picker`*
+
+use crate::{
+    core::locale::{self, Provider},
+    native::overlay::date_picker::{body_layout, style_sheet, Body, Focus, State, PADDING},
+    style::style_state::StyleState,
+};
+
+use chrono::{Datelike, NaiveDate};
+use iced_widget::{
+    container,
+    core::{
+        event, keyboard,
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        renderer, touch,
+        widget::tree::{self, Tag, Tree},
+        Border, Clipboard, Color, Element, Event, Layout, Length, Padding, Point, Rectangle,
+        Renderer as _, Shadow, Shell, Size, Widget,
+    },
+    renderer::Renderer,
+    text,
+};
+use std::sync::Arc;
+
+use super::{Date, Weekday};
+pub use crate::style::date_picker::{Appearance, StyleSheet};
+
+/// The default height of the [`Calendar`].
+const DEFAULT_HEIGHT: f32 = 300.0;
+
+/// A calendar shown directly in the layout, e.g. in a sidebar.
+///
+/// It has the month / year bar and the days of the overlay of the
+/// [`DatePicker`](super::DatePicker), but no submit and cancel buttons: every
+/// picked day is reported right away.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::date_picker::{self, Date};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     DateSelected(Date),
+/// }
+///
+/// let calendar = date_picker::Calendar::new(Date::today(), Message::DateSelected);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Calendar<'a, Message, Theme = iced_widget::style::Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The picked date.
+    date: Date,
+    /// The function that produces a message when a day is picked.
+    on_select: Box<dyn Fn(Date) -> Message + 'a>,
+    /// The width of the [`Calendar`].
+    width: Length,
+    /// The height of the [`Calendar`].
+    height: Length,
+    /// The style of the [`Calendar`].
+    style: <Theme as StyleSheet>::Style,
+    /// The locale of the [`Calendar`].
+    locale: Arc<dyn Provider>,
+    /// The function checking if a day can't be picked.
+    disabled: Option<Box<dyn Fn(Date) -> bool + 'a>>,
+    /// The function giving the color of the dot marking a day, if any.
+    highlights: Option<Box<dyn Fn(Date) -> Option<Color> + 'a>>,
+    /// The weekday the weeks start with, the one of the locale if `None`.
+    first_day_of_week: Option<Weekday>,
+    /// The function formatting the month and the year of the header.
+    header_format: Option<Box<HeaderFormat<'a>>>,
+    /// The function formatting the picked day shown in the header, if shown.
+    format: Option<Box<Format<'a>>>,
+}
+
+impl<'a, Message, Theme> Calendar<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`Calendar`] showing the month of the given date, which
+    /// has to exist.
+    ///
+    /// `on_select` is called with the day each time the user picks one.
+    pub fn new<F>(date: impl Into<Date>, on_select: F) -> Self
+    where
+        F: 'a + Fn(Date) -> Message,
+    {
+        Self {
+            date: date.into(),
+            on_select: Box::new(on_select),
+            width: Length::Fill,
+            height: Length::Fixed(DEFAULT_HEIGHT),
+            style: <Theme as StyleSheet>::Style::default(),
+            locale: locale::default_provider(),
+            disabled: None,
+            highlights: None,
+            first_day_of_week: None,
+            header_format: None,
+            format: None,
+        }
+    }
+
+    /// Sets the width of the [`Calendar`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Calendar`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`Calendar`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the function checking if a day can't be picked, see
+    /// [`DatePicker::disabled`](super::DatePicker::disabled).
+    #[must_use]
+    pub fn disabled<F>(mut self, disabled: F) -> Self
+    where
+        F: 'a + Fn(Date) -> bool,
+    {
+        self.disabled = Some(Box::new(disabled));
+        self
+    }
+
+    /// Sets the function giving the color of the dot marking a day, see
+    /// [`DatePicker::highlights`](super::DatePicker::highlights).
+    #[must_use]
+    pub fn highlights<F>(mut self, highlights: F) -> Self
+    where
+        F: 'a + Fn(Date) -> Option<Color>,
+    {
+        self.highlights = Some(Box::new(highlights));
+        self
+    }
+
+    /// Sets the weekday the weeks of the [`Calendar`] start with instead of
+    /// the one of the locale.
+    #[must_use]
+    pub fn first_day_of_week(mut self, weekday: Weekday) -> Self {
+        self.first_day_of_week = Some(weekday);
+        self
+    }
+
+    /// Sets the function formatting the header shown instead of the month and
+    /// the year controls, see
+    /// [`DatePicker::header_format`](super::DatePicker::header_format).
+    #[must_use]
+    pub fn header_format<F>(mut self, header_format: F) -> Self
+    where
+        F: 'a + Fn(Date) -> String,
+    {
+        self.header_format = Some(Box::new(header_format));
+        self
+    }
+
+    /// Sets the function formatting the picked day shown in a header above
+    /// the month and the year controls, see
+    /// [`DatePicker::format`](super::DatePicker::format).
+    #[must_use]
+    pub fn format<F>(mut self, format: F) -> Self
+    where
+        F: 'a + Fn(&Date) -> String,
+    {
+        self.format = Some(Box::new(format));
+        self
+    }
+
+    /// Sets the locale [`Provider`] of the [`Calendar`] instead of the
+    /// default one.
+    #[must_use]
+    pub fn locale(mut self, locale: impl Provider + 'static) -> Self {
+        self.locale = Arc::new(locale);
+        self
+    }
+
+    /// The calendar shared with the overlay of the [`DatePicker`](super::DatePicker).
+    fn body(&self) -> Body<'_> {
+        Body {
+            locale: self.locale.as_ref(),
+            first_weekday: self.first_day_of_week.map_or_else(
+                || self.locale.first_weekday(),
+                |weekday| weekday.num_days_from_monday(),
+            ),
+            disabled: self.disabled.as_deref(),
+            highlights: self.highlights.as_deref(),
+            header_format: self.header_format.as_deref(),
+            format: self.format.as_deref(),
+            multiple: false,
+            max_selected: None,
+        }
+    }
+}
+
+/// The function formatting the month and the year of the header of a [`Calendar`].
+type HeaderFormat<'a> = dyn Fn(Date) -> String + 'a;
+
+/// The function formatting the picked day shown in the header of a [`Calendar`].
+type Format<'a> = dyn Fn(&Date) -> String + 'a;
+
+/// The state of a [`Calendar`].
+#[derive(Debug)]
+struct CalendarState {
+    /// The state of the shown calendar.
+    body: State,
+    /// The date last given to the [`Calendar`].
+    date: NaiveDate,
+}
+
+impl<'a, Message, Theme> Widget<Message, Theme, Renderer> for Calendar<'a, Message, Theme>
+where
+    Theme: StyleSheet + text::StyleSheet + container::StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<CalendarState>()
+    }
+
+    fn state(&self) -> tree::State {
+        let date = self
+            .date
+            .try_into()
+            .expect("Year, Month or Day doesnt Exist");
+
+        tree::State::new(CalendarState {
+            body: State::new(date),
+            date,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::empty()]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<CalendarState>();
+        let date: NaiveDate = self
+            .date
+            .try_into()
+            .expect("Year, Month or Day doesnt Exist");
+
+        // The shown month only follows the given date when it changes
+        if state.date != date {
+            state.date = date;
+            state.body.date = date;
+            state.body.day = date.day();
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let size = limits.width(self.width).height(self.height).resolve(
+            self.width,
+            self.height,
+            Size::ZERO,
+        );
+
+        let body = body_layout::<Message, Theme>(
+            &mut tree.children[0],
+            renderer,
+            &Limits::new(Size::ZERO, size)
+                .shrink(Padding::from(PADDING))
+                .width(Length::Fill)
+                .height(Length::Fill),
+            self.format.is_some(),
+            self.header_format.is_some(),
+        )
+        .move_to(Point::new(PADDING, PADDING));
+
+        Node::with_children(size, vec![body])
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = &mut state.state.downcast_mut::<CalendarState>().body;
+        let body = self.body();
+        let body_layout = layout
+            .children()
+            .next()
+            .expect("Native: Layout should have a calendar layout");
+
+        // The keyboard moves through the calendar only until something else is pressed
+        if matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+        ) && !cursor.is_over(layout.bounds())
+        {
+            state.focus = Focus::None;
+        }
+
+        if let Some(date) = body.picked_day(state, &event, body_layout, cursor) {
+            shell.publish((self.on_select)(date.into()));
+        }
+
+        let status = body.on_event(state, &event, body_layout, cursor);
+        if status == event::Status::Captured
+            || body.on_event_keyboard(state, &event) == event::Status::Captured
+        {
+            return event::Status::Captured;
+        }
+
+        let Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(key),
+            ..
+        }) = event
+        else {
+            return event::Status::Ignored;
+        };
+
+        match (key, state.focus) {
+            // Tab only moves between the month, the year and the days
+            (keyboard::key::Named::Tab, Focus::Month | Focus::Year | Focus::Day) => {
+                state.focus = match (state.focus, state.keyboard_modifiers.shift()) {
+                    (Focus::Month, false) | (Focus::Day, true) => Focus::Year,
+                    (Focus::Year, false) | (Focus::Month, true) => Focus::Day,
+                    _ => Focus::Month,
+                };
+                event::Status::Captured
+            }
+            (keyboard::key::Named::Enter, Focus::Day) => {
+                if !body.is_disabled(state.date) {
+                    shell.publish((self.on_select)(state.date.into()));
+                }
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.body().mouse_interaction(
+            &state.state.downcast_ref::<CalendarState>().body,
+            layout
+                .children()
+                .next()
+                .expect("Graphics: Layout should have a calendar layout"),
+            cursor,
+        )
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let style_sheet = style_sheet(theme, &self.style);
+        let style_state = if cursor.is_over(bounds) {
+            StyleState::Hovered
+        } else {
+            StyleState::Active
+        };
+
+        // Background
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: Border {
+                    radius: style_sheet[&style_state].border_radius.into(),
+                    width: style_sheet[&style_state].border_width,
+                    color: style_sheet[&style_state].border_color,
+                },
+                shadow: Shadow::default(),
+            },
+            style_sheet[&style_state].background,
+        );
+
+        self.body().draw(
+            &state.state.downcast_ref::<CalendarState>().body,
+            renderer,
+            layout
+                .children()
+                .next()
+                .expect("Graphics: Layout should have a calendar layout"),
+            cursor,
+            &style_sheet,
+        );
+    }
+}
+
+impl<'a, Message, Theme> From<Calendar<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a + StyleSheet + text::StyleSheet + container::StyleSheet,
+{
+    fn from(calendar: Calendar<'a, Message, Theme>) -> Self {
+        Element::new(calendar)
+    }
+}
@@ -53,6 +53,8 @@ pub struct FloatingElement<
     offset: Offset,
     /// The visibility of the element.
     hidden: bool,
+    /// Whether the cursor is passed through the element to the underlay.
+    pass_through: bool,
     /// The underlying element.
     underlay: Element<'a, Message, Theme, Renderer>,
     /// The floating element of the [`FloatingElementOverlay`].
@@ -79,6 +81,7 @@ where
             anchor: Anchor::SouthEast,
             offset: 5.0.into(),
             hidden: false,
+            pass_through: false,
             underlay: underlay.into(),
             element: element.into(),
         }
@@ -107,6 +110,16 @@ where
         self.offset = offset.into();
         self
     }
+
+    /// Passes the cursor through the [`Element`] of the [`FloatingElement`] to the
+    /// underlay, so the underlay stays clickable below it.
+    ///
+    /// The [`Element`] still receives the events, it should ignore them.
+    #[must_use]
+    pub fn pass_through(mut self, pass_through: bool) -> Self {
+        self.pass_through = pass_through;
+        self
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -224,13 +237,16 @@ where
 
             Some(overlay::Element::new(
                 bounds.position(),
-                Box::new(FloatingElementOverlay::new(
-                    &mut state.children[1],
-                    &mut self.element,
-                    &self.anchor,
-                    &self.offset,
-                    bounds,
-                )),
+                Box::new(
+                    FloatingElementOverlay::new(
+                        &mut state.children[1],
+                        &mut self.element,
+                        &self.anchor,
+                        &self.offset,
+                        bounds,
+                    )
+                    .pass_through(self.pass_through),
+                ),
             ))
         } else {
             None
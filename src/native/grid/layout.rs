@@ -4,11 +4,14 @@ use iced_widget::core::{
     alignment::{Horizontal, Vertical},
     layout::{Limits, Node},
     widget::Tree,
-    Length, Padding, Pixels, Point, Size,
+    Element, Length, Padding, Pixels, Point, Size,
 };
 use itertools::{Itertools, Position};
 
-use super::types::GridRow;
+use super::{
+    reorder::{header_columns, State},
+    types::GridRow,
+};
 
 #[allow(clippy::too_many_arguments)]
 pub(super) fn layout<Message, Theme, Renderer>(
@@ -19,6 +22,7 @@ pub(super) fn layout<Message, Theme, Renderer>(
     row_count: usize,
     element_count: usize,
     rows: &[GridRow<'_, Message, Theme, Renderer>],
+    details: &[Option<Element<'_, Message, Theme, Renderer>>],
     column_spacing: Pixels,
     row_spacing: Pixels,
     padding: Padding,
@@ -34,42 +38,61 @@ where
 {
     let mut column_widths = Vec::<f32>::with_capacity(column_count);
     let mut row_heights = Vec::<f32>::with_capacity(row_count);
+    let (element_trees, detail_trees) = tree.children.split_at_mut(element_count);
 
     // Measure the minimum row and column size to fit the contents
-    minimum_row_column_sizes(tree, renderer, &mut column_widths, &mut row_heights, rows);
+    minimum_row_column_sizes(
+        element_trees,
+        renderer,
+        &mut column_widths,
+        &mut row_heights,
+        rows,
+    );
 
     // Adjust for fixed row and column sizes
     adjust_size_for_fixed_length(&mut column_widths, column_lengths);
     adjust_size_for_fixed_length(&mut row_heights, row_lengths);
 
     // Calculate grid limits
-    let min_size = Size::new(
-        total_length(&column_widths, column_spacing),
-        total_length(&row_heights, row_spacing),
-    );
-
     let grid_limits = limits
         .shrink(padding)
-        .min_width(min_size.width)
-        .min_height(min_size.height)
+        .min_width(total_length(&column_widths, column_spacing))
         .width(width)
         .height(height);
 
+    // Allocate the available width
+    let grid_width = grid_limits.resolve(width, height, grid_limits.min()).width;
+    let available_width = grid_width - total_spacing(column_count, column_spacing);
+    allocate_space(&mut column_widths, column_lengths, available_width);
+
+    // Measure the space taken by the expanded detail rows, as wide as the columns
+    let detail_width = total_length(&column_widths, column_spacing);
+    let details_height =
+        minimum_details_height(detail_trees, renderer, details, detail_width, row_spacing);
+
+    let grid_limits =
+        grid_limits.min_height(total_length(&row_heights, row_spacing) + details_height);
+
     //use to be grid_limits.fill();
     let grid_size = grid_limits.min();
     let grid_size = grid_limits.resolve(width, height, grid_size);
 
-    // Allocate the available space
-    let available_width = grid_size.width - total_spacing(column_count, column_spacing);
-    let available_height = grid_size.height - total_spacing(row_count, row_spacing);
-    allocate_space(&mut column_widths, column_lengths, available_width);
+    // Allocate the available height
+    let available_height =
+        grid_size.height - total_spacing(row_count, row_spacing) - details_height;
     allocate_space(&mut row_heights, row_lengths, available_height);
 
+    // Remember the header cells for reordering the columns
+    tree.state.downcast_mut::<State>().columns =
+        header_columns(rows, &column_widths, &row_heights, column_spacing, padding);
+
     // Lay out the widgets
     create_grid_layout(
-        tree,
+        element_trees,
+        detail_trees,
         element_count,
         rows,
+        details,
         &row_heights,
         &column_widths,
         renderer,
@@ -83,7 +106,7 @@ where
 }
 
 fn minimum_row_column_sizes<Message, Theme, Renderer>(
-    tree: &mut Tree,
+    trees: &mut [Tree],
     renderer: &Renderer,
     column_widths: &mut Vec<f32>,
     row_heights: &mut Vec<f32>,
@@ -91,7 +114,7 @@ fn minimum_row_column_sizes<Message, Theme, Renderer>(
 ) where
     Renderer: iced_widget::core::Renderer,
 {
-    let mut children = tree.children.iter_mut();
+    let mut children = trees.iter_mut();
     for row in rows {
         let mut row_height = 0.0f32;
 
@@ -118,6 +141,45 @@ fn minimum_row_column_sizes<Message, Theme, Renderer>(
     }
 }
 
+fn minimum_details_height<Message, Theme, Renderer>(
+    trees: &mut [Tree],
+    renderer: &Renderer,
+    details: &[Option<Element<'_, Message, Theme, Renderer>>],
+    detail_width: f32,
+    row_spacing: Pixels,
+) -> f32
+where
+    Renderer: iced_widget::core::Renderer,
+{
+    details
+        .iter()
+        .flatten()
+        .zip(trees.iter_mut())
+        .map(|(detail, tree)| {
+            detail
+                .as_widget()
+                .layout(tree, renderer, &detail_limits(detail, detail_width))
+                .size()
+                .height
+                + row_spacing.0
+        })
+        .sum()
+}
+
+/// The limits of an expanded detail row spanning all the columns.
+fn detail_limits<Message, Theme, Renderer>(
+    detail: &Element<'_, Message, Theme, Renderer>,
+    detail_width: f32,
+) -> Limits
+where
+    Renderer: iced_widget::core::Renderer,
+{
+    Limits::NONE
+        .width(Length::Fill)
+        .height(detail.as_widget().size().height)
+        .max_width(detail_width)
+}
+
 fn adjust_size_for_fixed_length(sizes: &mut [f32], length_settings: &[Length]) {
     for (size, lenght) in sizes.iter_mut().zip(length_settings.iter().cycle()) {
         if let Length::Fixed(value) = *lenght {
@@ -175,9 +237,11 @@ fn allocate_space(current_sizes: &mut [f32], length_settings: &[Length], availab
 
 #[allow(clippy::too_many_arguments)]
 fn create_grid_layout<Message, Theme, Renderer>(
-    tree: &mut Tree,
+    element_trees: &mut [Tree],
+    detail_trees: &mut [Tree],
     element_count: usize,
     rows: &[GridRow<'_, Message, Theme, Renderer>],
+    details: &[Option<Element<'_, Message, Theme, Renderer>>],
     row_heights: &[f32],
     column_widths: &[f32],
     renderer: &Renderer,
@@ -192,10 +256,15 @@ where
     Renderer: iced_widget::core::Renderer,
 {
     let mut y = padding.top;
-    let mut nodes = Vec::with_capacity(element_count);
-    let mut children = tree.children.iter_mut();
+    let mut nodes = Vec::with_capacity(element_count + detail_trees.len());
+    let mut detail_nodes = Vec::with_capacity(detail_trees.len());
+    let mut children = element_trees.iter_mut();
+    let mut detail_children = detail_trees.iter_mut();
+    let detail_width = total_length(column_widths, column_spacing);
 
-    for (row_position, (row, &row_height)) in rows.iter().zip(row_heights).with_position() {
+    for (row_position, (row_index, (row, &row_height))) in
+        rows.iter().zip(row_heights).enumerate().with_position()
+    {
         let mut x = padding.left;
         for (col_position, (element, &column_width)) in
             row.elements.iter().zip(column_widths).with_position()
@@ -228,11 +297,28 @@ where
             }
         }
         y += row_height;
+
+        if let Some(Some(detail)) = details.get(row_index) {
+            y += row_spacing.0;
+
+            let node = detail
+                .as_widget()
+                .layout(
+                    detail_children.next().expect("Grid missing detail child"),
+                    renderer,
+                    &detail_limits(detail, detail_width),
+                )
+                .move_to(Point::new(padding.left, y));
+            y += node.size().height;
+            detail_nodes.push(node);
+        }
+
         if not_last(row_position) {
             y += row_spacing.0;
         }
     }
 
+    nodes.append(&mut detail_nodes);
     Node::with_children(grid_size, nodes)
 }
 
@@ -1,6 +1,7 @@
 //! A container to layout widgets in a grid.
 
 mod layout;
+mod reorder;
 mod types;
 mod widget;
 
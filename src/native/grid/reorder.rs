@@ -0,0 +1,188 @@
+use iced_widget::core::{mouse, touch, Event, Padding, Pixels, Point, Rectangle, Shell, Vector};
+
+use super::types::GridRow;
+
+/// The distance the cursor has to move before a pressed header cell is dragged.
+const DRAG_THRESHOLD: f32 = 4.0;
+
+/// The state of a [`Grid`](super::Grid).
+#[derive(Debug, Default)]
+pub(super) struct State {
+    /// The bounds of the columns of the header row, relative to the grid.
+    pub(super) columns: Vec<Rectangle>,
+    /// The header cell being pressed or dragged.
+    pub(super) drag: Option<Drag>,
+}
+
+/// A header cell pressed to reorder its column.
+#[derive(Clone, Copy, Debug)]
+pub(super) struct Drag {
+    /// The index of the column.
+    pub(super) column: usize,
+    /// Where the cell was pressed.
+    pub(super) origin: Point,
+    /// Where the cursor is now.
+    pub(super) cursor: Point,
+    /// Whether the cursor moved past the threshold.
+    pub(super) dragging: bool,
+}
+
+impl Drag {
+    /// The horizontal distance the cell was dragged.
+    pub(super) fn offset(&self) -> f32 {
+        self.cursor.x - self.origin.x
+    }
+}
+
+/// Tracks the dragging of the header cells after the `leading` columns and reports dropped
+/// columns.
+///
+/// Returns whether a drag ended with this event, in which case the event should not reach the
+/// cells as a click.
+pub(super) fn on_event<Message>(
+    state: &mut State,
+    event: &Event,
+    bounds: Rectangle,
+    cursor: mouse::Cursor,
+    shell: &mut Shell<'_, Message>,
+    leading: usize,
+    on_column_reorder: &dyn Fn(usize, usize) -> Message,
+) -> bool {
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) => {
+            state.drag = cursor.position_over(bounds).and_then(|position| {
+                let column = (leading..state.columns.len()).find(|&column| {
+                    (state.columns[column] + Vector::new(bounds.x, bounds.y)).contains(position)
+                })?;
+
+                Some(Drag {
+                    column,
+                    origin: position,
+                    cursor: position,
+                    dragging: false,
+                })
+            });
+            false
+        }
+        Event::Mouse(mouse::Event::CursorMoved { .. })
+        | Event::Touch(touch::Event::FingerMoved { .. }) => {
+            if let (Some(drag), Some(position)) = (&mut state.drag, cursor.position()) {
+                drag.cursor = position;
+                drag.dragging |= drag.origin.distance(position) > DRAG_THRESHOLD;
+            }
+            false
+        }
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+            match state.drag.take() {
+                Some(drag) if drag.dragging => {
+                    let from = drag.column - leading;
+                    let slot = insertion_slot(&state.columns[leading..], drag.cursor.x - bounds.x);
+
+                    if let Some(to) = target(from, slot) {
+                        shell.publish(on_column_reorder(from, to));
+                    }
+                    true
+                }
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+/// The x coordinate, relative to the grid, of the gap the dragged column would be dropped
+/// into, if dropping it there moves it.
+pub(super) fn insertion_x(
+    state: &State,
+    drag: &Drag,
+    bounds: Rectangle,
+    leading: usize,
+    column_spacing: Pixels,
+) -> Option<f32> {
+    let columns = &state.columns[leading..];
+    let slot = insertion_slot(columns, drag.cursor.x - bounds.x);
+    let _ = target(drag.column - leading, slot)?;
+    let half_spacing = column_spacing.0 / 2.0;
+
+    Some(slot.checked_sub(1).map_or_else(
+        || columns[0].x - half_spacing,
+        |previous| columns[previous].x + columns[previous].width + half_spacing,
+    ))
+}
+
+/// The bounds of the columns of the header row, relative to the grid.
+pub(super) fn header_columns<Message, Theme, Renderer>(
+    rows: &[GridRow<'_, Message, Theme, Renderer>],
+    column_widths: &[f32],
+    row_heights: &[f32],
+    column_spacing: Pixels,
+    padding: Padding,
+) -> Vec<Rectangle> {
+    let (Some(header), Some(&height)) = (rows.first(), row_heights.first()) else {
+        return Vec::new();
+    };
+
+    let mut x = padding.left;
+    column_widths
+        .iter()
+        .take(header.elements.len())
+        .map(|&width| {
+            let column = Rectangle {
+                x,
+                y: padding.top,
+                width,
+                height,
+            };
+            x += width + column_spacing.0;
+            column
+        })
+        .collect()
+}
+
+/// The gap a column dropped at `x` goes into, from 0 before the first column to the number of
+/// columns after the last one.
+fn insertion_slot(columns: &[Rectangle], x: f32) -> usize {
+    columns
+        .iter()
+        .take_while(|column| column.center_x() < x)
+        .count()
+}
+
+/// The index a column ends up at when moved from `from` into the gap `slot`, `None` if it
+/// stays where it is.
+fn target(from: usize, slot: usize) -> Option<usize> {
+    let to = if slot > from { slot - 1 } else { slot };
+    (to != from).then_some(to)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{insertion_slot, target};
+    use iced_widget::core::Rectangle;
+
+    #[test]
+    fn target_test() {
+        let columns: Vec<_> = (0..3)
+            .map(|i| Rectangle {
+                x: i as f32 * 10.0,
+                y: 0.0,
+                width: 9.0,
+                height: 5.0,
+            })
+            .collect();
+
+        assert_eq!(insertion_slot(&columns, -3.0), 0);
+        assert_eq!(insertion_slot(&columns, 12.0), 1);
+        assert_eq!(insertion_slot(&columns, 16.0), 2);
+        assert_eq!(insertion_slot(&columns, 40.0), 3);
+
+        assert_eq!(target(0, 0), None);
+        assert_eq!(target(0, 1), None);
+        assert_eq!(target(0, 2), Some(1));
+        assert_eq!(target(0, 3), Some(2));
+        assert_eq!(target(2, 0), Some(0));
+        assert_eq!(target(2, 3), None);
+    }
+}
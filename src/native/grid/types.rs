@@ -1,6 +1,10 @@
-use iced_widget::core::{
-    alignment::{Horizontal, Vertical},
-    Element, Length, Padding, Pixels,
+use iced_widget::{
+    button,
+    core::{
+        alignment::{Horizontal, Vertical},
+        text, Element, Length, Padding, Pixels,
+    },
+    Button, Text,
 };
 
 /// A container that distributes its contents in a grid of rows and columns.
@@ -18,6 +22,10 @@ pub struct Grid<'a, Message, Theme = iced_widget::Theme, Renderer = iced_widget:
     pub(super) height: Length,
     pub(super) column_widths: Vec<Length>,
     pub(super) row_heights: Vec<Length>,
+    pub(super) details: Vec<Option<Element<'a, Message, Theme, Renderer>>>,
+    pub(super) empty: Option<Element<'a, Message, Theme, Renderer>>,
+    pub(super) on_column_reorder: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+    pub(super) leading_columns: usize,
 }
 
 impl<'a, Message, Theme, Renderer> Default for Grid<'a, Message, Theme, Renderer>
@@ -36,6 +44,10 @@ where
             height: Length::Shrink,
             column_widths: vec![Length::Fill],
             row_heights: vec![Length::Fill],
+            details: Vec::new(),
+            empty: None,
+            on_column_reorder: None,
+            leading_columns: 0,
         }
     }
 }
@@ -171,16 +183,129 @@ where
         self
     }
 
+    /// Sets the element shown centered in the [`Grid`] while it has no cells.
+    ///
+    /// The element receives no events and is removed as soon as a cell is pushed.
+    #[must_use]
+    pub fn empty(mut self, empty: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.empty = Some(empty.into());
+        self
+    }
+
+    /// Makes the rows of the [`Grid`] expandable.
+    ///
+    /// `expanded` holds the expansion state of each row and is controlled by the application,
+    /// rows past its end are collapsed. Only for the expanded rows, `detail` is asked for an
+    /// element that is laid out spanning all columns directly below that row, pushing the
+    /// subsequent rows down. An expanded row for which `detail` returns `None` shows no detail.
+    ///
+    /// The details are taken for the rows already pushed to the [`Grid`], so this should be
+    /// called after the rows have been set.
+    #[must_use]
+    pub fn expandable_rows<F>(mut self, expanded: &[bool], detail: F) -> Self
+    where
+        F: Fn(usize) -> Option<Element<'a, Message, Theme, Renderer>>,
+    {
+        self.details = (0..self.rows.len())
+            .map(|index| {
+                expanded
+                    .get(index)
+                    .copied()
+                    .unwrap_or(false)
+                    .then(|| detail(index))
+                    .flatten()
+            })
+            .collect();
+        self
+    }
+
+    /// Adds a leading column with a chevron button to every row, producing the message
+    /// returned by `on_toggle` with the index of the row when pressed.
+    ///
+    /// The chevrons are added to the rows already pushed to the [`Grid`], so this should be
+    /// called after the rows and [`Self::expandable_rows()`] have been set. The chevron column
+    /// shrinks to fit the chevrons and isn't counted by [`Self::column_widths()`].
+    #[must_use]
+    pub fn on_toggle<F>(mut self, on_toggle: F) -> Self
+    where
+        F: Fn(usize) -> Message,
+        Message: Clone + 'a,
+        Theme: button::StyleSheet + iced_widget::text::StyleSheet + 'a,
+        Renderer: text::Renderer + 'a,
+    {
+        for (index, row) in self.rows.iter_mut().enumerate() {
+            let is_expanded = self.details.get(index).is_some_and(Option::is_some);
+            let chevron = Button::new(Text::new(if is_expanded { "▾" } else { "▸" }))
+                .on_press(on_toggle(index));
+
+            row.elements.insert(0, chevron.into());
+        }
+        self.leading_columns += 1;
+        self
+    }
+
+    /// Lets the user reorder the columns by dragging the cells of the first row, the header,
+    /// producing the message returned by `on_column_reorder` with the index the dropped column
+    /// is moved from and the index it is moved to.
+    ///
+    /// The [`Grid`] only reports the gesture, the application is expected to permute its data.
+    /// The column added by [`Self::on_toggle()`] can't be dragged and isn't counted.
+    #[must_use]
+    pub fn on_column_reorder<F>(mut self, on_column_reorder: F) -> Self
+    where
+        F: Fn(usize, usize) -> Message + 'a,
+    {
+        self.on_column_reorder = Some(Box::new(on_column_reorder));
+        self
+    }
+
     pub(super) fn elements_iter(
         &self,
     ) -> impl Iterator<Item = &Element<'a, Message, Theme, Renderer>> {
         self.rows.iter().flat_map(|row| row.elements.iter())
     }
 
-    pub(super) fn elements_iter_mut(
+    /// Iterates over the cell elements followed by the expanded detail elements.
+    pub(super) fn children_iter(
+        &self,
+    ) -> impl Iterator<Item = &Element<'a, Message, Theme, Renderer>> {
+        let is_empty = self.element_count() == 0;
+
+        self.elements_iter()
+            .chain(self.details.iter().flatten().filter(move |_| !is_empty))
+    }
+
+    /// Iterates mutably over the cell elements followed by the expanded detail elements.
+    pub(super) fn children_iter_mut(
         &mut self,
     ) -> impl Iterator<Item = &mut Element<'a, Message, Theme, Renderer>> {
-        self.rows.iter_mut().flat_map(|row| row.elements.iter_mut())
+        let is_empty = self.element_count() == 0;
+
+        self.rows
+            .iter_mut()
+            .flat_map(|row| row.elements.iter_mut())
+            .chain(self.details.iter_mut().flatten().filter(move |_| !is_empty))
+    }
+
+    /// The empty element, if there are no cells.
+    pub(super) fn placeholder(&self) -> Option<&Element<'a, Message, Theme, Renderer>> {
+        self.empty.as_ref().filter(|_| self.element_count() == 0)
+    }
+
+    /// The width of each column: the leading columns shrink, the others take the column
+    /// widths set by the user.
+    pub(super) fn column_lengths(&self) -> Vec<Length> {
+        let user_columns = self.column_count().saturating_sub(self.leading_columns);
+
+        std::iter::repeat_n(Length::Shrink, self.leading_columns)
+            .chain(
+                self.column_widths
+                    .iter()
+                    .copied()
+                    .cycle()
+                    .take(user_columns),
+            )
+            .collect()
     }
 
     pub(super) fn column_count(&self) -> usize {
@@ -245,3 +370,55 @@ where
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Grid, GridRow};
+    use iced_widget::{
+        core::{renderer::Null, Length},
+        Text,
+    };
+    use std::cell::RefCell;
+
+    fn row<'a>() -> GridRow<'a, usize, iced_widget::Theme, Null> {
+        GridRow::new().push(Text::new("a")).push(Text::new("b"))
+    }
+
+    #[test]
+    fn column_lengths_test() {
+        let grid = Grid::with_rows(vec![row(), row()])
+            .column_widths(&[Length::Fixed(50.0), Length::Fill])
+            .expandable_rows(&[true], |index| {
+                (index == 0).then(|| Text::new("detail").into())
+            });
+        assert_eq!(
+            grid.column_lengths(),
+            vec![Length::Fixed(50.0), Length::Fill]
+        );
+
+        let grid = grid.on_toggle(|index| index);
+        assert_eq!(
+            grid.column_lengths(),
+            vec![Length::Shrink, Length::Fixed(50.0), Length::Fill]
+        );
+    }
+
+    #[test]
+    fn expandable_rows_test() {
+        let asked = RefCell::new(Vec::new());
+        let grid = Grid::with_rows(vec![row(), row(), row(), row()]).expandable_rows(
+            &[false, true],
+            |index| {
+                asked.borrow_mut().push(index);
+                Some(Text::new("detail").into())
+            },
+        );
+
+        // Only the expanded row is asked for its detail, the rows past the expansion
+        // states are collapsed
+        assert_eq!(asked.into_inner(), vec![1]);
+        assert_eq!(grid.details.len(), 4);
+        assert_eq!(grid.details.iter().flatten().count(), 1);
+        assert!(grid.details[1].is_some());
+    }
+}
@@ -3,12 +3,21 @@ use iced_widget::core::{
     layout::{Limits, Node},
     mouse, overlay,
     overlay::Group,
-    renderer::Style,
-    widget::{Operation, Tree},
-    Clipboard, Element, Event, Layout, Length, Rectangle, Shell, Size, Widget,
+    renderer::{Quad, Style},
+    widget::{
+        tree::{self, Tag},
+        Operation, Tree,
+    },
+    Border, Clipboard, Color, Element, Event, Layout, Length, Rectangle, Shadow, Shell, Size,
+    Vector, Widget,
 };
 
-use super::{layout::layout, types::Grid};
+use super::{
+    layout::layout,
+    reorder::{self, State},
+    types::Grid,
+};
+use crate::native::common::layout_empty;
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for Grid<'a, Message, Theme, Renderer>
@@ -22,7 +31,28 @@ where
         }
     }
 
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
     fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        tree.state.downcast_mut::<State>().columns.clear();
+
+        if let Some(empty) = self.placeholder() {
+            return layout_empty(
+                empty,
+                &mut tree.children[0],
+                renderer,
+                limits,
+                self.width,
+                self.height,
+            );
+        }
+
         if self.element_count() == 0 {
             return Node::new(Size::ZERO);
         }
@@ -44,6 +74,7 @@ where
             self.row_count(),
             self.element_count(),
             &self.rows,
+            &self.details,
             self.column_spacing,
             self.row_spacing,
             self.padding,
@@ -51,7 +82,7 @@ where
             self.vertical_alignment,
             self.width,
             self.height,
-            &self.column_widths,
+            &self.column_lengths(),
             &self.row_heights,
         )
     }
@@ -67,7 +98,8 @@ where
         viewport: &Rectangle,
     ) {
         for ((element, state), layout) in self
-            .elements_iter()
+            .children_iter()
+            .chain(self.placeholder())
             .zip(&state.children)
             .zip(layout.children())
         {
@@ -75,14 +107,90 @@ where
                 .as_widget()
                 .draw(state, renderer, theme, style, layout, cursor, viewport);
         }
+
+        let grid_state = state.state.downcast_ref::<State>();
+        let Some((drag, column)) = grid_state
+            .drag
+            .filter(|drag| drag.dragging)
+            .and_then(|drag| Some((drag, *grid_state.columns.get(drag.column)?)))
+        else {
+            return;
+        };
+        let bounds = layout.bounds();
+        let column = column + Vector::new(bounds.x, bounds.y);
+
+        renderer.with_layer(*viewport, |renderer| {
+            // Ghost of the dragged header cell following the cursor
+            renderer.with_translation(Vector::new(drag.offset(), 0.0), |renderer| {
+                renderer.fill_quad(
+                    Quad {
+                        bounds: column,
+                        border: Border {
+                            color: Color {
+                                a: 0.5,
+                                ..style.text_color
+                            },
+                            width: 1.0,
+                            radius: 0.0.into(),
+                        },
+                        shadow: Shadow::default(),
+                    },
+                    Color {
+                        a: 0.1,
+                        ..style.text_color
+                    },
+                );
+
+                if let (Some(element), Some(state), Some(layout)) = (
+                    self.elements_iter().nth(drag.column),
+                    state.children.get(drag.column),
+                    layout.children().nth(drag.column),
+                ) {
+                    element
+                        .as_widget()
+                        .draw(state, renderer, theme, style, layout, cursor, viewport);
+                }
+            });
+
+            // Indicator of the gap the column is dropped into
+            if let Some(x) = reorder::insertion_x(
+                grid_state,
+                &drag,
+                bounds,
+                self.leading_columns,
+                self.column_spacing,
+            ) {
+                renderer.fill_quad(
+                    Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + x - 1.0,
+                            y: column.y,
+                            width: 2.0,
+                            height: column.height,
+                        },
+                        border: Border::default(),
+                        shadow: Shadow::default(),
+                    },
+                    style.text_color,
+                );
+            }
+        });
     }
 
     fn children(&self) -> Vec<Tree> {
-        self.elements_iter().map(Tree::new).collect()
+        self.children_iter()
+            .chain(self.placeholder())
+            .map(Tree::new)
+            .collect()
     }
 
     fn diff(&self, tree: &mut Tree) {
-        tree.diff_children(&self.elements_iter().collect::<Vec<_>>());
+        tree.diff_children(
+            &self
+                .children_iter()
+                .chain(self.placeholder())
+                .collect::<Vec<_>>(),
+        );
     }
 
     fn operate(
@@ -93,7 +201,7 @@ where
         operation: &mut dyn Operation<Message>,
     ) {
         for ((element, state), layout) in self
-            .elements_iter()
+            .children_iter()
             .zip(&mut state.children)
             .zip(layout.children())
         {
@@ -114,8 +222,29 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) -> event::Status {
+        let dropped = self
+            .on_column_reorder
+            .as_deref()
+            .is_some_and(|on_column_reorder| {
+                reorder::on_event(
+                    state.state.downcast_mut::<State>(),
+                    &event,
+                    layout.bounds(),
+                    cursor,
+                    shell,
+                    self.leading_columns,
+                    on_column_reorder,
+                )
+            });
+        // The release ending a drag is no click on the cells
+        let cursor = if dropped {
+            mouse::Cursor::Unavailable
+        } else {
+            cursor
+        };
+
         let children_status = self
-            .elements_iter_mut()
+            .children_iter_mut()
             .zip(&mut state.children)
             .zip(layout.children())
             .map(|((child, state), layout)| {
@@ -131,7 +260,13 @@ where
                 )
             });
 
-        children_status.fold(event::Status::Ignored, event::Status::merge)
+        let status = children_status.fold(event::Status::Ignored, event::Status::merge);
+
+        if dropped {
+            event::Status::Captured
+        } else {
+            status
+        }
     }
 
     fn mouse_interaction(
@@ -142,7 +277,16 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
-        self.elements_iter()
+        if state
+            .state
+            .downcast_ref::<State>()
+            .drag
+            .is_some_and(|drag| drag.dragging)
+        {
+            return mouse::Interaction::Grabbing;
+        }
+
+        self.children_iter()
             .zip(&state.children)
             .zip(layout.children())
             .map(|((e, state), layout)| {
@@ -161,7 +305,7 @@ where
         renderer: &Renderer,
     ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
         let children = self
-            .elements_iter_mut()
+            .children_iter_mut()
             .zip(&mut tree.children)
             .zip(layout.children())
             .filter_map(|((child, state), layout)| {
@@ -167,7 +167,8 @@ where
     Theme: 'a
         + crate::style::color_picker::StyleSheet
         + iced_widget::button::StyleSheet
-        + iced_widget::text::StyleSheet,
+        + iced_widget::text::StyleSheet
+        + iced_widget::text_input::StyleSheet,
     F: 'static + Fn(Color) -> Message,
 {
     crate::ColorPicker::new(show_picker, color, underlay, on_cancel, on_submit)
@@ -212,7 +213,8 @@ where
     Theme: 'a
         + crate::style::time_picker::StyleSheet
         + iced_widget::button::StyleSheet
-        + iced_widget::text::StyleSheet,
+        + iced_widget::text::StyleSheet
+        + iced_widget::text_input::StyleSheet,
     U: Into<Element<'a, Message, Theme, iced_widget::renderer::Renderer>>,
     F: 'static + Fn(crate::core::time::Time) -> Message,
 {
@@ -319,7 +321,7 @@ pub fn number_input<'a, T, Message, Theme, Renderer, F>(
     on_changed: F,
 ) -> crate::NumberInput<'a, T, Message, Theme, Renderer>
 where
-    Message: Clone,
+    Message: 'a + Clone,
     Renderer: core::text::Renderer<Font = core::Font>,
     Theme: crate::style::number_input::StyleSheet
         + iced_widget::text_input::StyleSheet
@@ -332,7 +334,8 @@ where
         + PartialOrd
         + std::fmt::Display
         + std::str::FromStr
-        + Copy,
+        + Copy
+        + num_traits::Bounded,
 {
     crate::NumberInput::new(value, max, on_changed)
 }
@@ -0,0 +1,153 @@
+//! A renderer measuring text for the layout tests.
+
+use iced_widget::core::{
+    alignment,
+    renderer::{self, Quad},
+    text::{self, Difference, Hit, Text},
+    Background, Color, Font, Pixels, Point, Rectangle, Size, Transformation,
+};
+use std::borrow::Cow;
+
+/// A renderer measuring every character half as wide as the size of its text, so
+/// the layout follows the labels.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Measure;
+
+/// A paragraph measured by [`Measure`].
+#[derive(Clone, Debug)]
+pub struct Paragraph {
+    content: String,
+    size: Pixels,
+    bounds: Size,
+    min_bounds: Size,
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
+}
+
+impl Default for Paragraph {
+    fn default() -> Self {
+        Self {
+            content: String::new(),
+            size: Pixels(0.0),
+            bounds: Size::ZERO,
+            min_bounds: Size::ZERO,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+        }
+    }
+}
+
+/// The width of the given text measured by [`Measure`].
+pub fn text_width(content: &str, size: f32) -> f32 {
+    content.chars().count() as f32 * size / 2.0
+}
+
+impl text::Paragraph for Paragraph {
+    type Font = Font;
+
+    fn with_text(text: Text<'_, Self::Font>) -> Self {
+        Self {
+            content: text.content.to_owned(),
+            size: text.size,
+            bounds: text.bounds,
+            min_bounds: Size::new(
+                text_width(text.content, text.size.0),
+                text.line_height.to_absolute(text.size).0,
+            ),
+            horizontal_alignment: text.horizontal_alignment,
+            vertical_alignment: text.vertical_alignment,
+        }
+    }
+
+    fn resize(&mut self, new_bounds: Size) {
+        self.bounds = new_bounds;
+    }
+
+    fn compare(&self, text: Text<'_, Self::Font>) -> Difference {
+        if self.content != text.content || self.size != text.size {
+            Difference::Shape
+        } else if self.bounds == text.bounds {
+            Difference::None
+        } else {
+            Difference::Bounds
+        }
+    }
+
+    fn horizontal_alignment(&self) -> alignment::Horizontal {
+        self.horizontal_alignment
+    }
+
+    fn vertical_alignment(&self) -> alignment::Vertical {
+        self.vertical_alignment
+    }
+
+    fn min_bounds(&self) -> Size {
+        self.min_bounds
+    }
+
+    fn hit_test(&self, _point: Point) -> Option<Hit> {
+        None
+    }
+
+    fn grapheme_position(&self, _line: usize, _index: usize) -> Option<Point> {
+        None
+    }
+}
+
+impl renderer::Renderer for Measure {
+    fn with_layer(&mut self, _bounds: Rectangle, _f: impl FnOnce(&mut Self)) {}
+
+    fn with_transformation(&mut self, _transformation: Transformation, _f: impl FnOnce(&mut Self)) {
+    }
+
+    fn clear(&mut self) {}
+
+    fn fill_quad(&mut self, _quad: Quad, _background: impl Into<Background>) {}
+}
+
+impl text::Renderer for Measure {
+    type Font = Font;
+    type Paragraph = Paragraph;
+    type Editor = ();
+
+    const ICON_FONT: Font = Font::DEFAULT;
+    const CHECKMARK_ICON: char = '0';
+    const ARROW_DOWN_ICON: char = '0';
+
+    fn default_font(&self) -> Self::Font {
+        Font::default()
+    }
+
+    fn default_size(&self) -> Pixels {
+        Pixels(16.0)
+    }
+
+    fn load_font(&mut self, _font: Cow<'static, [u8]>) {}
+
+    fn fill_paragraph(
+        &mut self,
+        _paragraph: &Self::Paragraph,
+        _position: Point,
+        _color: Color,
+        _clip_bounds: Rectangle,
+    ) {
+    }
+
+    fn fill_editor(
+        &mut self,
+        _editor: &Self::Editor,
+        _position: Point,
+        _color: Color,
+        _clip_bounds: Rectangle,
+    ) {
+    }
+
+    fn fill_text(
+        &mut self,
+        _text: Text<'_, Self::Font>,
+        _position: Point,
+        _color: Color,
+        _clip_bounds: Rectangle,
+    ) {
+    }
+}
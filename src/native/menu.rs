@@ -54,12 +54,17 @@
 
 mod flex;
 pub mod menu_bar;
+pub mod menu_def;
 mod menu_inner;
 pub mod menu_tree;
+pub mod shortcuts;
 
 pub use crate::style::menu_bar::{Appearance, StyleSheet};
 /// A `MenuBar` collects `MenuTree`s and handles
 pub type MenuBar<'a, Message, Renderer> = menu_bar::MenuBar<'a, Message, Renderer>;
+pub use menu_def::MenuDef;
 pub use menu_inner::{CloseCondition, ItemHeight, ItemWidth, PathHighlight};
 /// Nested menu is essentially a tree of items, a menu is a collection of items
 pub type MenuTree<'a, Message, Renderer> = menu_tree::MenuTree<'a, Message, Renderer>;
+pub use menu_tree::Shortcut;
+pub use shortcuts::Shortcuts;
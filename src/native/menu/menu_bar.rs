@@ -3,7 +3,7 @@ use super::{
     menu_inner::{
         CloseCondition, Direction, ItemHeight, ItemWidth, Menu, MenuState, PathHighlight,
     },
-    menu_tree::MenuTree,
+    menu_tree::{MenuTree, Shortcut},
 };
 use crate::style::menu_bar::StyleSheet;
 
@@ -11,7 +11,7 @@ use iced_widget::core::{
     event,
     layout::{Limits, Node},
     mouse::{self, Cursor},
-    overlay, renderer, touch,
+    overlay, renderer, text, touch,
     widget::{tree, Tree},
     Alignment, Clipboard, Color, Element, Layout, Length, Padding, Rectangle, Shell, Widget,
 };
@@ -71,6 +71,7 @@ where
     close_condition: CloseCondition,
     item_width: ItemWidth,
     item_height: ItemHeight,
+    max_width: Option<f32>,
     path_highlight: Option<PathHighlight>,
     menu_roots: Vec<MenuTree<'a, Message, Renderer>>,
     style: <Renderer::Theme as StyleSheet>::Style,
@@ -102,12 +103,30 @@ where
             },
             item_width: ItemWidth::Uniform(150),
             item_height: ItemHeight::Uniform(30),
+            max_width: None,
             path_highlight: Some(PathHighlight::MenuActive),
             menu_roots,
             style: <Renderer::Theme as StyleSheet>::Style::default(),
         }
     }
 
+    /// The keyboard shortcuts bound to the menu trees, to be handled by
+    /// [`Shortcuts`] wrapping the content of the window
+    ///
+    /// See [`MenuTree::shortcut`]
+    ///
+    /// [`Shortcuts`]: `super::Shortcuts`
+    #[must_use]
+    pub fn shortcuts(&self) -> Vec<Shortcut<Message>>
+    where
+        Message: Clone,
+    {
+        self.menu_roots
+            .iter()
+            .flat_map(MenuTree::shortcuts)
+            .collect()
+    }
+
     /// Sets the expand value for each menu's check bounds
     ///
     /// When the cursor goes outside of a menu's check bounds,
@@ -154,6 +173,17 @@ where
         self
     }
 
+    /// Caps the width of each menu, whatever its [`ItemWidth`]
+    ///
+    /// Items are laid out with the capped width, so items of a
+    /// [`ItemHeight::Dynamic`] menu with a `Shrink` height, like wrapping
+    /// text, grow taller instead of widening the menu.
+    #[must_use]
+    pub fn max_width(mut self, max_width: f32) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
     /// Moves all the menus in the vertical open direction
     #[must_use]
     pub fn main_offset(mut self, value: i32) -> Self {
@@ -198,7 +228,7 @@ where
 }
 impl<'a, Message, Renderer> Widget<Message, Renderer> for MenuBar<'a, Message, Renderer>
 where
-    Renderer: renderer::Renderer,
+    Renderer: text::Renderer,
     Renderer::Theme: StyleSheet,
 {
     fn width(&self) -> Length {
@@ -410,6 +440,7 @@ where
                 close_condition: self.close_condition,
                 item_width: self.item_width,
                 item_height: self.item_height,
+                max_width: self.max_width,
                 bar_bounds: layout.bounds(),
                 main_offset: self.main_offset,
                 cross_offset: self.cross_offset,
@@ -424,7 +455,7 @@ where
 impl<'a, Message, Renderer> From<MenuBar<'a, Message, Renderer>> for Element<'a, Message, Renderer>
 where
     Message: 'a,
-    Renderer: 'a + renderer::Renderer,
+    Renderer: 'a + text::Renderer,
     Renderer::Theme: StyleSheet,
 {
     fn from(value: MenuBar<'a, Message, Renderer>) -> Self {
@@ -0,0 +1,161 @@
+//! An owned, data-oriented description of a menu
+//!
+//! A [`MenuDef`] holds no widgets, so menus can be assembled in loops, stored,
+//! or, with the `serde` feature, loaded from configuration files. It is turned
+//! into a [`MenuTree`] each view with [`MenuDef::to_tree`].
+//!
+//! ```ignore
+//! use iced_aw::menu::{MenuBar, MenuDef};
+//!
+//! let defs = vec![MenuDef::menu(
+//!     "File",
+//!     vec![
+//!         MenuDef::item("Open", Action::Open),
+//!         MenuDef::separator(),
+//!         MenuDef::item("Quit", Action::Quit).enabled(can_quit),
+//!     ],
+//! )];
+//!
+//! let menu_bar = MenuBar::new(defs.iter().map(|def| def.to_tree(&Message::Menu)).collect());
+//! ```
+
+use super::menu_tree::MenuTree;
+
+use iced_widget::{
+    button, container,
+    core::{alignment, text, Element, Length},
+    horizontal_rule, rule, text as text_widget,
+};
+
+/// The description of an item or a menu of a [`MenuTree`]
+///
+/// An entry with children is a menu, otherwise it's an item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct MenuDef<Id> {
+    /// The text shown for the entry
+    pub label: String,
+    /// The entries of the menu, empty for an item
+    pub children: Vec<MenuDef<Id>>,
+    /// The identifier mapped to the message produced when the entry is pressed
+    pub action: Option<Id>,
+    /// Whether the entry is a separator line instead of a labeled entry
+    pub separator: bool,
+    /// Whether the entry can be pressed
+    pub enabled: bool,
+    /// The width of the menu of the entry, see [`MenuTree::width`]
+    pub width: Option<u16>,
+    /// The height of the entry, see [`MenuTree::height`]
+    pub height: Option<u16>,
+}
+
+impl<Id> Default for MenuDef<Id> {
+    fn default() -> Self {
+        Self {
+            label: String::new(),
+            children: Vec::new(),
+            action: None,
+            separator: false,
+            enabled: true,
+            width: None,
+            height: None,
+        }
+    }
+}
+
+impl<Id> MenuDef<Id> {
+    /// Creates an item producing the message of `action` when pressed
+    pub fn item(label: impl Into<String>, action: Id) -> Self {
+        Self {
+            label: label.into(),
+            action: Some(action),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a menu with the given entries
+    pub fn menu(label: impl Into<String>, children: Vec<MenuDef<Id>>) -> Self {
+        Self {
+            label: label.into(),
+            children,
+            ..Self::default()
+        }
+    }
+
+    /// Creates a separator line
+    #[must_use]
+    pub fn separator() -> Self {
+        Self {
+            separator: true,
+            ..Self::default()
+        }
+    }
+
+    /// Sets whether the entry can be pressed
+    #[must_use]
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Builds the [`MenuTree`] described by the entry, producing messages
+    /// with the given mapper
+    pub fn to_tree<'a, Message, Renderer>(
+        &self,
+        mapper: &impl Fn(Id) -> Message,
+    ) -> MenuTree<'a, Message, Renderer>
+    where
+        Id: Clone,
+        Message: 'a + Clone,
+        Renderer: 'a + text::Renderer,
+        Renderer::Theme:
+            button::StyleSheet + container::StyleSheet + rule::StyleSheet + text_widget::StyleSheet,
+    {
+        let item: Element<'a, Message, Renderer> = if self.separator {
+            horizontal_rule(1).into()
+        } else {
+            let label = text_widget(&self.label)
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .vertical_alignment(alignment::Vertical::Center);
+            let message = self.action.clone().filter(|_| self.enabled).map(mapper);
+
+            match message {
+                Some(message) => button(label)
+                    .padding([4, 8])
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .on_press(message)
+                    .into(),
+                // Disabled items keep the look of a disabled button, menus
+                // without action are plain labels
+                None if self.children.is_empty() => button(label)
+                    .padding([4, 8])
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .into(),
+                None => container(label)
+                    .padding([4, 8])
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .into(),
+            }
+        };
+
+        let mut tree = MenuTree::with_children(
+            item,
+            self.children
+                .iter()
+                .map(|child| child.to_tree(mapper))
+                .collect::<Vec<_>>(),
+        );
+        if let Some(width) = self.width {
+            tree = tree.width(width);
+        }
+        if let Some(height) = self.height {
+            tree = tree.height(height);
+        }
+        tree
+    }
+}
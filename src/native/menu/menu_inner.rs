@@ -3,14 +3,17 @@ use super::{menu_bar::MenuBarState, menu_tree::MenuTree};
 use crate::style::menu_bar::StyleSheet;
 
 use iced_widget::core::{
-    event,
+    alignment, event,
     layout::{Limits, Node},
     mouse::{self, Cursor},
-    overlay, renderer, touch,
+    overlay, renderer, text, touch,
     widget::Tree,
     Clipboard, Color, Layout, Length, Padding, Point, Rectangle, Shell, Size, Vector,
 };
 
+/// The space between the shortcut label and the right edge of its item
+const SHORTCUT_PADDING: f32 = 8.0;
+
 /// The condition of when to close a menu
 #[derive(Debug, Clone, Copy)]
 pub struct CloseCondition {
@@ -241,6 +244,7 @@ impl MenuBounds {
         renderer: &Renderer,
         item_width: ItemWidth,
         item_height: ItemHeight,
+        max_width: Option<f32>,
         viewport_size: Size,
         overlay_offset: Vector,
         aod: &Aod,
@@ -251,7 +255,7 @@ impl MenuBounds {
         Renderer: renderer::Renderer,
     {
         let (children_size, child_positions, child_sizes) =
-            get_children_layout(menu_tree, renderer, item_width, item_height);
+            get_children_layout(menu_tree, renderer, item_width, item_height, max_width);
 
         // viewport space parent bounds
         let view_parent_bounds = parent_bounds + overlay_offset;
@@ -434,6 +438,7 @@ where
     pub(super) close_condition: CloseCondition,
     pub(super) item_width: ItemWidth,
     pub(super) item_height: ItemHeight,
+    pub(super) max_width: Option<f32>,
     pub(super) bar_bounds: Rectangle,
     pub(super) main_offset: i32,
     pub(super) cross_offset: i32,
@@ -443,7 +448,7 @@ where
 }
 impl<'a, 'b, Message, Renderer> Menu<'a, 'b, Message, Renderer>
 where
-    Renderer: renderer::Renderer,
+    Renderer: text::Renderer,
     Renderer::Theme: StyleSheet,
 {
     pub(super) fn overlay(self) -> overlay::Element<'b, Message, Renderer> {
@@ -453,7 +458,7 @@ where
 impl<'a, 'b, Message, Renderer> overlay::Overlay<Message, Renderer>
     for Menu<'a, 'b, Message, Renderer>
 where
-    Renderer: renderer::Renderer,
+    Renderer: text::Renderer,
     Renderer::Theme: StyleSheet,
 {
     fn layout(&self, _renderer: &Renderer, bounds: Size, position: Point) -> Node {
@@ -679,6 +684,10 @@ where
                                 view_cursor,
                                 &children_layout.bounds(),
                             );
+
+                            if let Some(shortcut) = &mt.shortcut {
+                                draw_shortcut(r, style, clo.bounds(), &shortcut.label());
+                            }
                         });
                 };
 
@@ -691,6 +700,32 @@ where
     }
 }
 
+/// Draws the label of a shortcut right-aligned in the bounds of its item
+fn draw_shortcut<Renderer>(
+    renderer: &mut Renderer,
+    style: &renderer::Style,
+    bounds: Rectangle,
+    label: &str,
+) where
+    Renderer: text::Renderer,
+{
+    renderer.fill_text(text::Text {
+        content: label,
+        bounds: Rectangle {
+            x: bounds.x + bounds.width - SHORTCUT_PADDING,
+            y: bounds.center_y(),
+            ..bounds
+        },
+        size: renderer.default_size(),
+        line_height: text::LineHeight::default(),
+        color: style.text_color,
+        font: renderer.default_font(),
+        horizontal_alignment: alignment::Horizontal::Right,
+        vertical_alignment: alignment::Vertical::Center,
+        shaping: text::Shaping::Basic,
+    });
+}
+
 fn pad_rectangle(rect: Rectangle, padding: Padding) -> Rectangle {
     Rectangle {
         x: rect.x - padding.left,
@@ -753,6 +788,7 @@ fn init_root_menu<Message, Renderer>(
                 renderer,
                 menu.item_width,
                 menu.item_height,
+                menu.max_width,
                 viewport_size,
                 overlay_offset,
                 &aod,
@@ -1010,6 +1046,7 @@ where
                 renderer,
                 menu.item_width,
                 menu.item_height,
+                menu.max_width,
                 viewport_size,
                 overlay_offset,
                 &aod,
@@ -1106,6 +1143,7 @@ fn get_children_layout<Message, Renderer>(
     renderer: &Renderer,
     item_width: ItemWidth,
     item_height: ItemHeight,
+    max_width: Option<f32>,
 ) -> (Size, Vec<f32>, Vec<Size>)
 where
     Renderer: renderer::Renderer,
@@ -1114,6 +1152,7 @@ where
         ItemWidth::Uniform(u) => f32::from(u),
         ItemWidth::Static(s) => f32::from(menu_tree.width.unwrap_or(s)),
     };
+    let width = max_width.map_or(width, |max_width| width.min(max_width));
 
     let child_sizes: Vec<Size> = match item_height {
         ItemHeight::Uniform(u) => {
@@ -1,6 +1,58 @@
 //! A tree structure for constructing a hierarchical menu
 
-use iced_widget::core::{renderer, Element};
+use iced_widget::core::{keyboard, renderer, Element};
+
+/// A keyboard shortcut bound to an item of a [`MenuTree`]
+#[derive(Debug, Clone)]
+pub struct Shortcut<Message> {
+    /// The key that triggers the shortcut
+    pub key: keyboard::Key,
+    /// The modifiers that must be held with the key
+    pub modifiers: keyboard::Modifiers,
+    /// The message produced by the shortcut, `None` if the item is disabled
+    pub message: Option<Message>,
+}
+impl<Message> Shortcut<Message> {
+    /// Checks if the shortcut is triggered by the given key and modifiers
+    ///
+    /// Characters are compared ignoring their case, Shift turns `s` into `S`.
+    #[must_use]
+    pub fn matches(&self, key: &keyboard::Key, modifiers: keyboard::Modifiers) -> bool {
+        let same_key = match (&self.key, key) {
+            (keyboard::Key::Character(a), keyboard::Key::Character(b)) => {
+                a.to_lowercase() == b.to_lowercase()
+            }
+            (a, b) => a == b,
+        };
+
+        same_key && self.modifiers == modifiers
+    }
+
+    /// The text of the shortcut shown in its item, e.g. `Ctrl+S`
+    #[must_use]
+    pub fn label(&self) -> String {
+        let modifiers = [
+            (self.modifiers.control(), "Ctrl"),
+            (self.modifiers.alt(), "Alt"),
+            (self.modifiers.shift(), "Shift"),
+            (self.modifiers.logo(), "Super"),
+        ];
+        let key = match &self.key {
+            keyboard::Key::Named(named) => format!("{named:?}"),
+            keyboard::Key::Character(c) => c.to_uppercase(),
+            keyboard::Key::Unidentified => String::new(),
+        };
+
+        modifiers
+            .into_iter()
+            .filter(|(held, _)| *held)
+            .map(|(_, name)| name.to_owned())
+            .chain(std::iter::once(key))
+            .collect::<Vec<_>>()
+            .join("+")
+    }
+}
+
 /// Nested menu is essentially a tree of items, a menu is a collection of items
 /// a menu itself can also be an item of another menu.
 ///
@@ -23,6 +75,8 @@ pub struct MenuTree<'a, Message, Renderer = iced_widget::Renderer> {
     pub(super) width: Option<u16>,
     /// The height of the menu tree
     pub(super) height: Option<u16>,
+    /// The keyboard shortcut of the menu tree
+    pub(super) shortcut: Option<Shortcut<Message>>,
 }
 impl<'a, Message, Renderer> MenuTree<'a, Message, Renderer>
 where
@@ -36,6 +90,7 @@ where
             children: Vec::new(),
             width: None,
             height: None,
+            shortcut: None,
         }
     }
 
@@ -50,6 +105,7 @@ where
             children: children.into_iter().map(Into::into).collect(),
             width: None,
             height: None,
+            shortcut: None,
         }
     }
 
@@ -73,6 +129,44 @@ where
         self
     }
 
+    /// Binds a keyboard shortcut to the menu tree.
+    ///
+    /// Pressing `key` with `modifiers` produces `message`, even when no menu is
+    /// open, once the shortcuts of the [`MenuBar`] are handled by [`Shortcuts`].
+    /// Passing `None` as message disables the shortcut, like a disabled item.
+    ///
+    /// The shortcut is shown right-aligned in the item, e.g. `Ctrl+S`, see
+    /// [`Shortcut::label`]. The item should leave room for it.
+    ///
+    /// [`MenuBar`]: `super::MenuBar`
+    /// [`Shortcuts`]: `super::Shortcuts`
+    #[must_use]
+    pub fn shortcut(
+        mut self,
+        key: keyboard::Key,
+        modifiers: keyboard::Modifiers,
+        message: Option<Message>,
+    ) -> Self {
+        self.shortcut = Some(Shortcut {
+            key,
+            modifiers,
+            message,
+        });
+        self
+    }
+
+    /// The shortcuts of the menu tree and of its children
+    pub(super) fn shortcuts(&self) -> Vec<Shortcut<Message>>
+    where
+        Message: Clone,
+    {
+        self.shortcut
+            .iter()
+            .cloned()
+            .chain(self.children.iter().flat_map(Self::shortcuts))
+            .collect()
+    }
+
     /* Keep `set_index()` and `flattern()` recurse in the same order */
 
     /// Set the index of each item
@@ -0,0 +1,193 @@
+//! A widget publishing the keyboard shortcuts of a [`MenuBar`]
+//!
+//! [`MenuBar`]: `super::MenuBar`
+
+use super::menu_tree::Shortcut;
+
+use iced_widget::core::{
+    event, keyboard,
+    layout::{Limits, Node},
+    mouse::{self, Cursor},
+    overlay, renderer,
+    widget::{Operation, Tree},
+    Clipboard, Element, Layout, Length, Rectangle, Shell, Widget,
+};
+
+/// Wraps the content of a window, usually holding the [`MenuBar`], and
+/// publishes the message of the shortcut matching a key press that no
+/// widget of the content captured, e.g. a focused text input.
+///
+/// ```ignore
+/// let menu_bar = MenuBar::new(vec![root_1, root_2]);
+/// let shortcuts = menu_bar.shortcuts();
+///
+/// Shortcuts::new(column![menu_bar, content], shortcuts)
+/// ```
+///
+/// [`MenuBar`]: `super::MenuBar`
+#[allow(missing_debug_implementations)]
+pub struct Shortcuts<'a, Message, Renderer = iced_widget::Renderer> {
+    /// The content the key presses go to first
+    content: Element<'a, Message, Renderer>,
+    /// The shortcuts published when the content ignores a key press
+    shortcuts: Vec<Shortcut<Message>>,
+}
+impl<'a, Message, Renderer> Shortcuts<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    /// Creates new [`Shortcuts`] wrapping the given content
+    ///
+    /// See [`MenuBar::shortcuts`](super::MenuBar::shortcuts)
+    pub fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        shortcuts: Vec<Shortcut<Message>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            shortcuts,
+        }
+    }
+}
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Shortcuts<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content.as_widget().layout(renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: event::Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        // The content, like a focused text input, takes precedence
+        let event::Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. }) = event
+        else {
+            return status;
+        };
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        match self
+            .shortcuts
+            .iter()
+            .find(|shortcut| shortcut.matches(&key, modifiers))
+        {
+            Some(shortcut) => {
+                if let Some(message) = &shortcut.message {
+                    shell.publish(message.clone());
+                }
+                event::Status::Captured
+            }
+            None => status,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &<Renderer as renderer::Renderer>::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer)
+    }
+}
+impl<'a, Message, Renderer> From<Shortcuts<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(value: Shortcuts<'a, Message, Renderer>) -> Self {
+        Self::new(value)
+    }
+}
@@ -10,6 +10,9 @@ pub mod common;
 
 pub use common::InnerBounds;
 
+#[cfg(all(test, any(feature = "badge", feature = "tab_bar")))]
+mod measure;
+
 #[cfg(feature = "badge")]
 pub mod badge;
 #[cfg(feature = "badge")]
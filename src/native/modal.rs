@@ -9,7 +9,7 @@ use iced_widget::core::{
     layout::{Limits, Node},
     mouse::{self, Cursor},
     overlay, renderer,
-    widget::{Operation, Tree},
+    widget::{tree, Operation, Tree},
     Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Widget,
 };
 
@@ -55,6 +55,8 @@ where
     style: <Theme as StyleSheet>::Style,
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
+    /// Whether mouse wheel events reach the underlay while the overlay is shown.
+    scroll_passthrough: bool,
 }
 
 impl<'a, Message, Theme, Renderer> Modal<'a, Message, Theme, Renderer>
@@ -83,6 +85,7 @@ where
             style: <Theme as StyleSheet>::Style::default(),
             horizontal_alignment: alignment::Horizontal::Center,
             vertical_alignment: alignment::Vertical::Center,
+            scroll_passthrough: false,
         }
     }
 
@@ -118,6 +121,16 @@ where
         self
     }
 
+    /// Sets whether mouse wheel events reach the underlay while the overlay
+    /// of the [`Modal`] is shown, letting the background scroll.
+    ///
+    /// All other events, except window events, are kept from the underlay.
+    #[must_use]
+    pub fn scroll_passthrough(mut self, passthrough: bool) -> Self {
+        self.scroll_passthrough = passthrough;
+        self
+    }
+
     /// Sets the style of the [`Modal`].
     #[must_use]
     pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
@@ -133,6 +146,14 @@ where
     Renderer: core::Renderer,
     Theme: StyleSheet,
 {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<Cursor>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(Cursor::Unavailable)
+    }
+
     fn children(&self) -> Vec<Tree> {
         self.overlay.as_ref().map_or_else(
             || vec![Tree::new(&self.underlay)],
@@ -169,20 +190,26 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) -> event::Status {
-        if self.overlay.is_none() {
-            return self.underlay.as_widget_mut().on_event(
-                &mut state.children[0],
-                event,
-                layout,
-                cursor,
-                renderer,
-                clipboard,
-                shell,
-                viewport,
-            );
-        }
+        // Only window events and passed through scrolling reach the underlay of a shown overlay
+        let cursor = match &event {
+            _ if self.overlay.is_none() => cursor,
+            Event::Window(..) => Cursor::Unavailable,
+            Event::Mouse(mouse::Event::WheelScrolled { .. }) if self.scroll_passthrough => {
+                *state.state.downcast_ref::<Cursor>()
+            }
+            _ => return event::Status::Ignored,
+        };
 
-        event::Status::Ignored
+        self.underlay.as_widget_mut().on_event(
+            &mut state.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
     }
 
     fn mouse_interaction(
@@ -234,20 +261,27 @@ where
         renderer: &Renderer,
     ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
         if let Some(overlay) = &mut self.overlay {
+            let Tree {
+                state: cursor,
+                children,
+                ..
+            } = state;
             let bounds = layout.bounds();
             let position = Point::new(bounds.x, bounds.y);
-            overlay.as_widget().diff(&mut state.children[1]);
+            overlay.as_widget().diff(&mut children[1]);
 
             Some(overlay::Element::new(
                 position,
                 Box::new(ModalOverlay::new(
-                    &mut state.children[1],
+                    &mut children[1],
                     overlay,
                     self.backdrop.clone(),
                     self.esc.clone(),
                     self.style.clone(),
                     self.horizontal_alignment,
                     self.vertical_alignment,
+                    self.scroll_passthrough,
+                    cursor.downcast_mut(),
                 )),
             ))
         } else {
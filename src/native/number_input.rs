@@ -10,6 +10,8 @@ use iced_widget::{
         layout::{Limits, Node},
         mouse::{self, Cursor},
         renderer,
+        text::Paragraph as _,
+        touch,
         widget::{
             tree::{State, Tag},
             Operation, Tree,
@@ -22,9 +24,10 @@ use iced_widget::{
     text_input::{self, cursor, Value},
     Column, Container, Row, Text, TextInput,
 };
-use num_traits::{Num, NumAssignOps};
-use std::{fmt::Display, str::FromStr};
+use num_traits::{Bounded, Num, NumAssignOps};
+use std::{cell::RefCell, fmt::Display, rc::Rc, str::FromStr, sync::Arc};
 
+use crate::core::locale::{self, Provider};
 use crate::style;
 pub use crate::{
     graphics::icons::{icon_to_string, BootstrapIcon, BOOTSTRAP_FONT},
@@ -33,6 +36,16 @@ pub use crate::{
 
 /// The default padding
 const DEFAULT_PADDING: f32 = 5.0;
+/// The distance the cursor has to move before a press starts scrubbing.
+const SCRUB_THRESHOLD: f32 = 3.0;
+/// The distance the cursor moves per step while scrubbing.
+const SCRUB_STEP_WIDTH: f32 = 5.0;
+
+/// The function converting the text of a [`NumberInput`] to a value.
+type Parser<T> = Rc<dyn Fn(&str) -> Option<T>>;
+
+/// The function converting the value of a [`NumberInput`] to a text.
+type Formatter<T> = Box<dyn Fn(&T) -> String>;
 
 /// A field that can only be filled with numeric type.
 ///
@@ -55,7 +68,7 @@ const DEFAULT_PADDING: f32 = 5.0;
 /// )
 /// .step(2);
 /// ```
-#[allow(missing_debug_implementations)]
+#[allow(missing_debug_implementations, clippy::struct_excessive_bools)]
 pub struct NumberInput<'a, T, Message, Theme = iced_widget::Theme, Renderer = iced_widget::Renderer>
 where
     Renderer: core::text::Renderer<Font = core::Font>,
@@ -68,27 +81,75 @@ where
     value: T,
     /// The step for each modify of the [`NumberInput`].
     step: T,
-    /// The min and max value of the [`NumberInput`].
-    bounds: (T, T),
+    /// The step used while Ctrl is held, `step / 10` if `None`.
+    fine_step: Option<T>,
+    /// The step used while Shift is held, `step * 10` if `None`.
+    coarse_step: Option<T>,
+    /// The step of the [`NumberInput`] used by Page Up and Page Down.
+    page_step: Option<T>,
+    /// The min value of the [`NumberInput`], if any.
+    min: Option<T>,
+    /// The max value of the [`NumberInput`], if any.
+    max: Option<T>,
     /// The content padding of the [`NumberInput`].
     padding: f32,
     /// The text size of the [`NumberInput`].
     size: Option<f32>,
     /// The underlying element of the [`NumberInput`].
     content: TextInput<'a, Message, Theme, Renderer>,
+    /// The width of the underlying element of the [`NumberInput`].
+    content_width: Length,
+    /// The function producing the message when the [`NumberInput`] is submitted.
+    on_submit: Option<Rc<dyn Fn(T) -> Message>>,
+    /// The function producing the message when the typed text is invalid.
+    on_invalid: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    /// The function producing the message when a text fails to parse.
+    on_error: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    /// The text last typed into the underlying element, while submitting.
+    edited: Rc<RefCell<Option<String>>>,
+    /// The text that failed to parse in the underlying element, while not submitting.
+    failed: Rc<RefCell<Option<String>>>,
     /// The on_change event of the [`NumberInput`].
-    on_change: Box<dyn Fn(T) -> Message>,
+    on_change: Rc<dyn Fn(T) -> Message>,
     /// The style of the [`NumberInput`].
     style: <Theme as number_input::StyleSheet>::Style,
     /// The font text of the [`NumberInput`].
-    font: Renderer::Font,
+    font: Option<Renderer::Font>,
     /// The Width to use for the NumberBox Default is Length::Fill
     width: Length,
+    /// The locale used to format and parse the value of the [`NumberInput`].
+    locale: Arc<dyn Provider>,
+    /// The number of decimal places the value of the [`NumberInput`] is rounded to.
+    precision: Option<usize>,
+    /// The function converting the text of the [`NumberInput`] to a value.
+    parser: Parser<T>,
+    /// Whether the parser was set by the user, it then gets the pasted text as is.
+    custom_parser: bool,
+    /// The function converting the value of the [`NumberInput`] to a text, if any.
+    formatter: Option<Formatter<T>>,
+    /// Whether the mouse wheel leaves the value of the [`NumberInput`] unchanged.
+    ignore_scroll_events: bool,
+    /// The text shown in front of the value of the [`NumberInput`].
+    prefix: Option<String>,
+    /// The text shown after the value of the [`NumberInput`], e.g. a unit.
+    suffix: Option<String>,
+    /// Whether the value can be changed by dragging over the text.
+    scrubbable: bool,
+    /// Whether arithmetic expressions are evaluated when the [`NumberInput`] is submitted.
+    evaluate_expressions: bool,
+    /// The horizontal alignment of the text of the [`NumberInput`].
+    text_alignment: Horizontal,
+    /// The size of the icons of the buttons, 5/8 of the text size if `None`.
+    icon_size: Option<f32>,
+    /// The decrease and increase icons of the buttons, replacing the defaults.
+    icons: Option<(char, char)>,
+    /// The font of the custom icons, the font of the text if `None`.
+    icon_font: Option<core::Font>,
 }
 
 impl<'a, T, Message, Theme, Renderer> NumberInput<'a, T, Message, Theme, Renderer>
 where
-    T: Num + NumAssignOps + PartialOrd + Display + FromStr + Copy,
+    T: Num + NumAssignOps + PartialOrd + Display + FromStr + Copy + Bounded,
     Message: Clone,
     Renderer: core::text::Renderer<Font = core::Font>,
     Theme: number_input::StyleSheet
@@ -107,45 +168,279 @@ where
     where
         F: 'static + Fn(T) -> Message + Copy,
         T: 'static,
+        Message: 'a,
     {
-        let padding = DEFAULT_PADDING;
-        let convert_to_num = move |s: String| {
-            on_changed(T::from_str(&s).unwrap_or(if s.is_empty() { T::zero() } else { value }))
-        };
+        Self::with_locale(value, max, on_changed, locale::default_provider())
+    }
 
-        Self {
+    /// Creates a new [`NumberInput`] formatting and parsing its value with the
+    /// given locale [`Provider`] instead of the default one.
+    ///
+    /// It expects:
+    /// - the current value
+    /// - the max value
+    /// - a function that produces a message when the [`NumberInput`] changes
+    /// - the locale [`Provider`]
+    pub fn with_locale<F>(value: T, max: T, on_changed: F, locale: Arc<dyn Provider>) -> Self
+    where
+        F: 'static + Fn(T) -> Message + Copy,
+        T: 'static,
+        Message: 'a,
+    {
+        let parser = Arc::clone(&locale);
+        let mut number_input = Self {
             value,
             step: T::one(),
-            bounds: (T::zero(), max),
-            padding,
+            fine_step: None,
+            coarse_step: None,
+            page_step: None,
+            min: Some(T::zero()),
+            max: Some(max),
+            padding: DEFAULT_PADDING,
             size: None,
-            content: TextInput::new("", format!("{value}").as_str())
-                .on_input(convert_to_num)
-                .padding(padding)
-                .width(Length::Fixed(127.0)),
-            on_change: Box::new(on_changed),
+            content: TextInput::new("", ""),
+            content_width: Length::Fixed(127.0),
+            on_submit: None,
+            on_invalid: None,
+            on_error: None,
+            edited: Rc::new(RefCell::new(None)),
+            failed: Rc::new(RefCell::new(None)),
+            on_change: Rc::new(on_changed),
             style: <Theme as number_input::StyleSheet>::Style::default(),
-            font: Renderer::Font::default(),
+            font: None,
             width: Length::Shrink,
+            locale,
+            precision: None,
+            parser: Rc::new(move |text| T::from_str(&parser.parse_number(text)).ok()),
+            custom_parser: false,
+            formatter: None,
+            ignore_scroll_events: false,
+            prefix: None,
+            suffix: None,
+            scrubbable: false,
+            evaluate_expressions: false,
+            text_alignment: Horizontal::Left,
+            icon_size: None,
+            icons: None,
+            icon_font: None,
+        };
+        number_input.content = number_input.text_input();
+        number_input
+    }
+
+    /// Builds the underlying text input of the [`NumberInput`] from its settings.
+    fn text_input(&self) -> TextInput<'a, Message, Theme, Renderer>
+    where
+        T: 'static,
+        Message: 'a,
+    {
+        self.text_input_with(&self.display_value())
+    }
+
+    /// Builds the underlying text input of the [`NumberInput`] showing the given text.
+    fn text_input_with(&self, text: &str) -> TextInput<'a, Message, Theme, Renderer>
+    where
+        T: 'static,
+        Message: 'a,
+    {
+        let on_change = Rc::clone(&self.on_change);
+        let parser = Rc::clone(&self.parser);
+        let edited = Rc::clone(&self.edited);
+        let failed = self.on_submit.is_none().then(|| Rc::clone(&self.failed));
+        let value = self.value;
+        let grouping = self.locale.grouping_separator();
+        let (prefix, suffix) = (self.prefix.clone(), self.suffix.clone());
+        let custom_parser = self.custom_parser;
+        let convert_to_num = Rc::new(move |s: String| {
+            let value = match parser(&s) {
+                Some(value) => value,
+                None if s.is_empty() => T::zero(),
+                None => {
+                    if let Some(failed) = &failed {
+                        *failed.borrow_mut() = Some(s.clone());
+                    }
+                    value
+                }
+            };
+            *edited.borrow_mut() = Some(s);
+            on_change(value)
+        });
+        let convert_pasted = Rc::clone(&convert_to_num);
+
+        let mut content = TextInput::new("", text)
+            .on_input(move |s| convert_to_num(s))
+            // Pasted text may still carry separators and units, typed text and
+            // the text of a custom parser are left as they are
+            .on_paste(move |s| {
+                if custom_parser {
+                    convert_pasted(s)
+                } else {
+                    convert_pasted(sanitize(&s, grouping, prefix.as_deref(), suffix.as_deref()))
+                }
+            })
+            .padding(self.padding)
+            .width(self.content_width);
+
+        if let Some(size) = self.size {
+            content = content.size(size);
+        }
+        if let Some(font) = self.font {
+            content = content.font(font);
         }
+        content
+    }
+
+    /// The text showing the value of the [`NumberInput`], formatted with its
+    /// precision.
+    fn display_value(&self) -> String {
+        if let Some(formatter) = &self.formatter {
+            return formatter(&self.value);
+        }
+
+        let text = self.precision.map_or_else(
+            || self.value.to_string(),
+            |precision| format!("{:.precision$}", self.value),
+        );
+
+        self.locale.format_number(&text)
+    }
+
+    /// The text shown by the [`NumberInput`]: the text being edited, e.g. with
+    /// more decimal places than the precision, or else the formatted value.
+    fn shown_text(&self, state: &ModifierState) -> String {
+        state.draft.clone().unwrap_or_else(|| self.display_value())
+    }
+
+    /// Rounds the given value to the precision of the [`NumberInput`].
+    fn round(&self, value: T) -> T {
+        self.precision.map_or(value, |precision| {
+            T::from_str(&format!("{value:.precision$}")).unwrap_or(value)
+        })
     }
 
     /// Sets the minimum & maximum value (bound) of the [`NumberInput`].
     #[must_use]
     pub fn bounds(mut self, bounds: (T, T)) -> Self {
         if bounds.0 <= bounds.1 {
-            self.bounds = bounds;
+            self.min = Some(bounds.0);
+            self.max = Some(bounds.1);
         }
         self
     }
 
+    /// Checks if the given value is within the bounds of the [`NumberInput`].
+    fn in_bounds(&self, value: T) -> bool {
+        self.min.is_none_or(|min| min <= value) && self.max.is_none_or(|max| value <= max)
+    }
+
+    /// Checks if the bounds of the [`NumberInput`] allow a single value.
+    fn is_fixed(&self) -> bool {
+        self.min.is_some() && self.min == self.max
+    }
+
     /// Sets the content width of the [`NumberInput`].
     #[must_use]
     pub fn content_width(mut self, width: Length) -> Self {
+        self.content_width = width;
         self.content = self.content.width(width);
         self
     }
 
+    /// Sets the horizontal alignment of the text of the [`NumberInput`].
+    ///
+    /// Numeric columns usually align their text to the right.
+    #[must_use]
+    pub fn text_alignment(mut self, alignment: Horizontal) -> Self {
+        self.text_alignment = alignment;
+        self
+    }
+
+    /// Sets the size of the icons of the increase and decrease buttons.
+    #[must_use]
+    pub fn icon_size(mut self, size: f32) -> Self {
+        self.icon_size = Some(size);
+        self
+    }
+
+    /// Sets the icons of the decrease and increase buttons, replacing the
+    /// default carets.
+    ///
+    /// They are drawn with the font of [`icon_font`](Self::icon_font) and
+    /// use the colors of the style like the defaults.
+    #[must_use]
+    pub fn icons(mut self, decrease: char, increase: char) -> Self {
+        self.icons = Some((decrease, increase));
+        self
+    }
+
+    /// Sets the font of the custom [`icons`](Self::icons), e.g. an icon font.
+    ///
+    /// Defaults to the font of the text.
+    #[must_use]
+    pub fn icon_font(mut self, font: core::Font) -> Self {
+        self.icon_font = Some(font);
+        self
+    }
+
+    /// The size of the icons of the buttons.
+    fn resolved_icon_size(&self, renderer: &Renderer) -> f32 {
+        self.icon_size
+            .unwrap_or_else(|| self.size.unwrap_or_else(|| renderer.default_size().0) * 2.5 / 4.0)
+    }
+
+    /// The text and font of the given decrease or increase icon.
+    fn icon(&self, renderer: &Renderer, increase: bool) -> (String, core::Font) {
+        match self.icons {
+            Some((decrease_icon, increase_icon)) => (
+                if increase {
+                    increase_icon
+                } else {
+                    decrease_icon
+                }
+                .to_string(),
+                self.icon_font
+                    .or(self.font)
+                    .unwrap_or_else(|| renderer.default_font()),
+            ),
+            None => (
+                icon_to_string(if increase {
+                    BootstrapIcon::CaretUpFill
+                } else {
+                    BootstrapIcon::CaretDownFill
+                }),
+                BOOTSTRAP_FONT,
+            ),
+        }
+    }
+
+    /// Narrows the text area of the content layout to the shown text and
+    /// moves it to the text alignment.
+    fn align_text(&self, content: Node, tree: &Tree, renderer: &Renderer) -> Node {
+        if self.text_alignment == Horizontal::Left {
+            return content;
+        }
+
+        let shown = self.shown_text(tree.state.downcast_ref::<ModifierState>());
+        let text = self.affix_text(renderer, &shown, Size::INFINITY, Horizontal::Left);
+        // Leave room for the caret at the end of the text
+        let text_width = Renderer::Paragraph::with_text(text).min_width() + 1.0;
+
+        let mut children = content.children().to_vec();
+        if let Some(text) = children.first_mut() {
+            let bounds = text.bounds();
+            let width = text_width.min(bounds.width);
+            let x = match self.text_alignment {
+                Horizontal::Left => bounds.x,
+                Horizontal::Center => bounds.x + (bounds.width - width) / 2.0,
+                Horizontal::Right => bounds.x + bounds.width - width,
+            };
+
+            *text = Node::new(Size::new(width, bounds.height)).move_to(Point::new(x, bounds.y));
+        }
+
+        Node::with_children(content.size(), children)
+    }
+
     /// Sets the [`Font`] of the [`Text`].
     ///
     /// [`Font`]: core::Font
@@ -153,37 +448,289 @@ where
     #[allow(clippy::needless_pass_by_value)]
     #[must_use]
     pub fn font(mut self, font: Renderer::Font) -> Self {
-        self.font = font;
+        self.font = Some(font);
         self.content = self.content.font(font);
         self
     }
 
     /// Sets the minimum value of the [`NumberInput`].
+    ///
+    /// Passing `None` removes the lower bound.
     #[must_use]
-    pub fn min(mut self, min: T) -> Self {
-        if min <= self.bounds.1 {
-            self.bounds.0 = min;
+    pub fn min(mut self, min: impl Into<Option<T>>) -> Self {
+        let min = min.into();
+        if min.is_none_or(|min| self.max.is_none_or(|max| min <= max)) {
+            self.min = min;
         }
         self
     }
 
     /// Sets the maximum value of the [`NumberInput`].
+    ///
+    /// Passing `None` removes the upper bound.
     #[must_use]
-    pub fn max(mut self, max: T) -> Self {
-        if max >= self.bounds.0 {
-            self.bounds.1 = max;
+    pub fn max(mut self, max: impl Into<Option<T>>) -> Self {
+        let max = max.into();
+        if max.is_none_or(|max| self.min.is_none_or(|min| max >= min)) {
+            self.max = max;
         }
         self
     }
 
-    /// Sets the message that should be produced when the [`NumberInput`] is
-    /// focused and the enter key is pressed.
+    /// Sets the function producing the message when the [`NumberInput`] is
+    /// focused and the enter key is pressed, or when it loses focus after
+    /// being edited.
+    ///
+    /// Once set, edits only change the text shown until they are submitted and
+    /// no longer produce the `on_change` message. The submitted text is parsed,
+    /// rounded to the precision and clamped to the bounds. A text that cannot
+    /// be parsed is reverted to the last value without producing a message.
+    /// Steps made with the buttons or the mouse wheel while the
+    /// [`NumberInput`] is not focused are submitted right away.
+    #[must_use]
+    pub fn on_submit<F>(mut self, on_submit: F) -> Self
+    where
+        F: 'static + Fn(T) -> Message,
+        T: 'static,
+        Message: 'a,
+    {
+        self.on_submit = Some(Rc::new(on_submit));
+        self.content = self.text_input();
+        self
+    }
+
+    /// Sets the function producing a message with the typed text when it
+    /// can't be parsed or is out of bounds, e.g. to show an error label.
+    ///
+    /// The [`NumberInput`] uses the invalid appearance of its style until
+    /// the text is valid again or editing ends.
     #[must_use]
-    pub fn on_submit(mut self, message: Message) -> Self {
-        self.content = self.content.on_submit(message);
+    pub fn on_invalid<F>(mut self, on_invalid: F) -> Self
+    where
+        F: 'a + Fn(String) -> Message,
+    {
+        self.on_invalid = Some(Box::new(on_invalid));
         self
     }
 
+    /// Sets whether simple arithmetic expressions like `12*4+1` are evaluated
+    /// when the [`NumberInput`] is submitted.
+    ///
+    /// Expressions may use `+`, `-`, `*`, `/` and parentheses. An expression
+    /// that cannot be evaluated reverts to the previous value. Only applies
+    /// with [`on_submit`](Self::on_submit), otherwise each edit must be a
+    /// number.
+    #[must_use]
+    pub fn evaluate_expressions(mut self, evaluate: bool) -> Self {
+        self.evaluate_expressions = evaluate;
+        self
+    }
+
+    /// Checks if the typed text is left as it is until submitted.
+    fn is_free_text(&self) -> bool {
+        self.evaluate_expressions && self.on_submit.is_some()
+    }
+
+    /// Parses the given text, evaluating it as an expression if enabled.
+    fn parse(&self, text: &str) -> Option<T> {
+        (self.parser)(text).or_else(|| {
+            self.evaluate_expressions
+                .then(|| evaluate(&self.locale.parse_number(text)))
+                .flatten()
+                .and_then(|value| T::from_str(&value.to_string()).ok())
+        })
+    }
+
+    /// Sets the function producing a message with the offending text when it
+    /// fails to parse, e.g. to log it or show a toast.
+    ///
+    /// It is produced when a pasted text is rejected and, with
+    /// [`on_submit`](Self::on_submit), when a text is submitted, but not for
+    /// each keystroke. An empty text is not an error.
+    ///
+    /// Without `on_submit`, the text shown reverts to the last value right
+    /// away. With `on_submit`, the text is kept with the invalid appearance
+    /// of the style until it is submitted, then reverts to the last
+    /// submitted value.
+    #[must_use]
+    pub fn on_error<F>(mut self, on_error: F) -> Self
+    where
+        F: 'a + Fn(String) -> Message,
+    {
+        self.on_error = Some(Box::new(on_error));
+        self
+    }
+
+    /// Publishes the `on_error` message for the given text.
+    fn report_error(&self, text: String, shell: &mut Shell<Message>) {
+        if let Some(on_error) = &self.on_error {
+            shell.publish(on_error(text));
+        }
+    }
+
+    /// Checks if the given text parses to a value within the bounds of the [`NumberInput`].
+    fn is_valid(&self, text: &str) -> bool {
+        self.parse(text).is_some_and(|value| self.in_bounds(value))
+    }
+
+    /// Publishes the `on_invalid` message if the typed text became invalid.
+    fn report_invalid(&self, state: &Tree, previous: Option<&String>, shell: &mut Shell<Message>) {
+        let invalid = state.state.downcast_ref::<ModifierState>().invalid.as_ref();
+        if let (Some(on_invalid), Some(text)) = (&self.on_invalid, invalid) {
+            if invalid != previous {
+                shell.publish(on_invalid(text.clone()));
+            }
+        }
+    }
+
+    /// Sets whether the mouse wheel leaves the value of the [`NumberInput`]
+    /// unchanged.
+    ///
+    /// If `true`, wheel events pass through to the parent, like a
+    /// [`Scrollable`](iced_widget::Scrollable) the [`NumberInput`] is in.
+    #[must_use]
+    pub fn ignore_scroll_events(mut self, ignore: bool) -> Self {
+        self.ignore_scroll_events = ignore;
+        self
+    }
+
+    /// Sets whether the value of the [`NumberInput`] can be changed by
+    /// dragging horizontally over the text.
+    ///
+    /// Each few pixels of the drag change the value by a step. A click
+    /// without dragging focuses the text for typing as usual.
+    #[must_use]
+    pub fn scrubbable(mut self, scrubbable: bool) -> Self {
+        self.scrubbable = scrubbable;
+        self
+    }
+
+    /// Sets the text shown in front of the value of the [`NumberInput`].
+    ///
+    /// It is not part of the editable text.
+    #[must_use]
+    pub fn prefix(mut self, prefix: &str) -> Self
+    where
+        T: 'static,
+        Message: 'a,
+    {
+        self.prefix = Some(prefix.to_owned());
+        self.content = self.text_input();
+        self
+    }
+
+    /// Sets the text shown after the value of the [`NumberInput`], e.g. a unit
+    /// like `px`.
+    ///
+    /// It is not part of the editable text and is stripped from pasted text.
+    #[must_use]
+    pub fn suffix(mut self, suffix: &str) -> Self
+    where
+        T: 'static,
+        Message: 'a,
+    {
+        self.suffix = Some(suffix.to_owned());
+        self.content = self.text_input();
+        self
+    }
+
+    /// The text of the given prefix or suffix to measure or draw.
+    fn affix_text<'b>(
+        &self,
+        renderer: &Renderer,
+        content: &'b str,
+        bounds: Size,
+        horizontal_alignment: Horizontal,
+    ) -> core::text::Text<'b, core::Font> {
+        core::text::Text {
+            content,
+            bounds,
+            size: self
+                .size
+                .map_or_else(|| renderer.default_size(), Pixels::from),
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            horizontal_alignment,
+            vertical_alignment: Vertical::Center,
+            line_height: LineHeight::default(),
+            shaping: iced_widget::text::Shaping::Advanced,
+        }
+    }
+
+    /// The width taken by the given prefix or suffix, including the space
+    /// between it and the value.
+    fn affix_width(&self, renderer: &Renderer, affix: Option<&String>) -> f32 {
+        affix.map_or(0.0, |affix| {
+            let text = self.affix_text(renderer, affix, Size::INFINITY, Horizontal::Left);
+            Renderer::Paragraph::with_text(text).min_width() + self.padding
+        })
+    }
+
+    /// Shrinks the text area of the content layout to leave room for the
+    /// prefix and the suffix, keeping the suffix left of the modifiers.
+    fn layout_affixes(&self, content: Node, renderer: &Renderer, modifier_x: f32) -> Node {
+        if self.prefix.is_none() && self.suffix.is_none() {
+            return content;
+        }
+
+        let prefix_width = self.affix_width(renderer, self.prefix.as_ref());
+        let suffix_width = self.affix_width(renderer, self.suffix.as_ref());
+
+        let mut children = content.children().to_vec();
+        if let Some(text) = children.first_mut() {
+            let bounds = text.bounds();
+            let right = if self.suffix.is_some() {
+                bounds.x + bounds.width.min(modifier_x - self.padding - bounds.x)
+            } else {
+                bounds.x + bounds.width
+            };
+            let x = bounds.x + prefix_width;
+
+            *text = Node::new(Size::new(
+                (right - suffix_width - x).max(0.0),
+                bounds.height,
+            ))
+            .move_to(Point::new(x, bounds.y));
+        }
+
+        Node::with_children(content.size(), children)
+    }
+
+    /// Draws the prefix and the suffix around the text area of the content.
+    fn draw_affixes(&self, renderer: &mut Renderer, theme: &Theme, content_layout: Layout<'_>) {
+        let Some(text_bounds) = content_layout.children().next().map(|text| text.bounds()) else {
+            return;
+        };
+        let color = if self.is_fixed() {
+            style::number_input::StyleSheet::disabled(theme, &self.style)
+        } else {
+            style::number_input::StyleSheet::active(theme, &self.style)
+        }
+        .affix_color;
+        let clip_bounds = content_layout.bounds();
+        let bounds = Size::new(clip_bounds.width, text_bounds.height);
+
+        if let Some(prefix) = &self.prefix {
+            renderer.fill_text(
+                self.affix_text(renderer, prefix, bounds, Horizontal::Right),
+                Point::new(text_bounds.x - self.padding, text_bounds.center_y()),
+                color,
+                clip_bounds,
+            );
+        }
+
+        if let Some(suffix) = &self.suffix {
+            renderer.fill_text(
+                self.affix_text(renderer, suffix, bounds, Horizontal::Left),
+                Point::new(
+                    text_bounds.x + text_bounds.width + self.padding,
+                    text_bounds.center_y(),
+                ),
+                color,
+                clip_bounds,
+            );
+        }
+    }
+
     /// Sets the padding of the [`NumberInput`].
     #[must_use]
     pub fn padding(mut self, units: f32) -> Self {
@@ -200,6 +747,56 @@ where
         self
     }
 
+    /// Sets the number of decimal places the value of the [`NumberInput`]
+    /// is shown with and rounded to when stepping.
+    ///
+    /// More decimal places can be typed, the value is rounded when the
+    /// [`NumberInput`] is submitted or loses focus. A value given with more
+    /// decimal places, e.g. the result of a computation, is shown rounded.
+    #[must_use]
+    pub fn precision(mut self, precision: usize) -> Self
+    where
+        T: 'static,
+        Message: 'a,
+    {
+        self.precision = Some(precision);
+        self.content = self.text_input();
+        self
+    }
+
+    /// Sets the function converting the text of the [`NumberInput`] to a value.
+    ///
+    /// Keystrokes producing a text the parser rejects are ignored. By default,
+    /// the text is parsed with [`FromStr`] after applying the separators of the
+    /// locale. The parser gets the typed and pasted text as it is, with its
+    /// separators, whitespace and units.
+    #[must_use]
+    pub fn parser<F>(mut self, parser: F) -> Self
+    where
+        F: 'static + Fn(&str) -> Option<T>,
+        T: 'static,
+        Message: 'a,
+    {
+        self.parser = Rc::new(parser);
+        self.custom_parser = true;
+        self.content = self.text_input();
+        self
+    }
+
+    /// Sets the function converting the value of the [`NumberInput`] to the
+    /// text shown, replacing the formatting of the precision and the locale.
+    #[must_use]
+    pub fn formatter<F>(mut self, formatter: F) -> Self
+    where
+        F: 'static + Fn(&T) -> String,
+        T: 'static,
+        Message: 'a,
+    {
+        self.formatter = Some(Box::new(formatter));
+        self.content = self.text_input();
+        self
+    }
+
     /// Sets the step of the [`NumberInput`].
     #[must_use]
     pub fn step(mut self, step: T) -> Self {
@@ -207,6 +804,53 @@ where
         self
     }
 
+    /// Sets the step of the [`NumberInput`] used while Ctrl is held.
+    ///
+    /// Defaults to a tenth of the step.
+    #[must_use]
+    pub fn fine_step(mut self, step: T) -> Self {
+        self.fine_step = Some(step);
+        self
+    }
+
+    /// Sets the step of the [`NumberInput`] used while Shift is held.
+    ///
+    /// Defaults to ten times the step.
+    #[must_use]
+    pub fn coarse_step(mut self, step: T) -> Self {
+        self.coarse_step = Some(step);
+        self
+    }
+
+    /// Sets the step of the [`NumberInput`] used by Page Up and Page Down.
+    ///
+    /// Defaults to the coarse step.
+    #[must_use]
+    pub fn page_step(mut self, step: T) -> Self {
+        self.page_step = Some(step);
+        self
+    }
+
+    /// The step of the [`NumberInput`] for the held keyboard modifiers.
+    fn step_for(&self, modifiers: keyboard::Modifiers) -> T {
+        let ten = T::from_str_radix("10", 10).unwrap_or_else(|_| T::one());
+
+        let step = if modifiers.shift() {
+            self.coarse_step.unwrap_or(self.step * ten)
+        } else if modifiers.control() {
+            self.fine_step.unwrap_or(self.step / ten)
+        } else {
+            self.step
+        };
+
+        // A tenth of an integer step may be zero
+        if step == T::zero() {
+            self.step
+        } else {
+            step
+        }
+    }
+
     /// Sets the style of the [`NumberInput`].
     #[must_use]
     pub fn style(mut self, style: impl Into<<Theme as number_input::StyleSheet>::Style>) -> Self {
@@ -221,28 +865,448 @@ where
         self
     }
 
+    /// Handles an event, publishing the `on_change` messages to the given [`Shell`].
+    #[allow(
+        clippy::too_many_arguments,
+        clippy::too_many_lines,
+        clippy::cognitive_complexity
+    )]
+    fn update(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let mut children = layout.children();
+        let content = children.next().expect("fail to get content layout");
+        let mut mod_children = children
+            .next()
+            .expect("fail to get modifiers layout")
+            .children();
+        let inc_bounds = mod_children
+            .next()
+            .expect("fail to get increase mod layout")
+            .bounds();
+        let dec_bounds = mod_children
+            .next()
+            .expect("fail to get decreate mod layout")
+            .bounds();
+        let mouse_over_inc = inc_bounds.contains(cursor.position().unwrap_or_default());
+        let mouse_over_dec = dec_bounds.contains(cursor.position().unwrap_or_default());
+
+        // Round values typed with extra decimal places once editing ends
+        if state.children[0]
+            .state
+            .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+            .is_focused()
+        {
+            match &event {
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key: keyboard::Key::Named(keyboard::key::Named::Enter),
+                    ..
+                }) => {
+                    state.state.downcast_mut::<ModifierState>().invalid = None;
+                    self.normalize_val(shell);
+                }
+                Event::Mouse(mouse::Event::ButtonPressed(_))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+                    if !cursor.is_over(content.bounds()) =>
+                {
+                    state.state.downcast_mut::<ModifierState>().invalid = None;
+                    self.normalize_val(shell);
+                }
+                _ => {}
+            }
+        }
+
+        let is_scrubbing = state
+            .state
+            .downcast_ref::<ModifierState>()
+            .scrub_origin
+            .is_some();
+        if self.scrubbable
+            && !self.is_fixed()
+            && (is_scrubbing || !(mouse_over_inc || mouse_over_dec))
+        {
+            if let Some(status) = self.scrub(
+                state, &event, content, cursor, renderer, clipboard, shell, viewport,
+            ) {
+                return status;
+            }
+        }
+
+        let modifiers = state.state.downcast_mut::<ModifierState>();
+        let child = &mut state.children[0];
+
+        if let Event::Keyboard(keyboard::Event::ModifiersChanged(keyboard_modifiers)) = event {
+            modifiers.keyboard_modifiers = keyboard_modifiers;
+        }
+        let keyboard_modifiers = modifiers.keyboard_modifiers;
+
+        if self.is_fixed() {
+            return event::Status::Ignored;
+        }
+
+        // Step with the arrow and page keys wherever the cursor is
+        if let Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(key),
+            ..
+        }) = &event
+        {
+            let is_focused = child
+                .state
+                .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+                .is_focused();
+            let step = match key {
+                keyboard::key::Named::ArrowUp | keyboard::key::Named::ArrowDown => {
+                    Some(self.step_for(keyboard_modifiers))
+                }
+                keyboard::key::Named::PageUp | keyboard::key::Named::PageDown => Some(
+                    self.page_step
+                        .unwrap_or_else(|| self.step_for(keyboard::Modifiers::SHIFT)),
+                ),
+                _ => None,
+            };
+
+            if let Some(step) = step.filter(|_| is_focused) {
+                if matches!(
+                    key,
+                    keyboard::key::Named::ArrowUp | keyboard::key::Named::PageUp
+                ) {
+                    self.increase_by(step, shell);
+                } else {
+                    self.decrease_by(step, shell);
+                }
+                return event::Status::Captured;
+            }
+        }
+
+        if layout
+            .bounds()
+            .contains(cursor.position().unwrap_or_default())
+        {
+            if mouse_over_inc || mouse_over_dec {
+                let mut event_status = event::Status::Captured;
+                match event {
+                    Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                        if mouse_over_dec {
+                            modifiers.decrease_pressed = true;
+                            self.decrease_val(keyboard_modifiers, shell);
+                        } else if mouse_over_inc {
+                            modifiers.increase_pressed = true;
+                            self.increase_val(keyboard_modifiers, shell);
+                        } else {
+                            event_status = event::Status::Ignored;
+                        }
+                    }
+                    Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                        if mouse_over_dec {
+                            modifiers.decrease_pressed = false;
+                        } else if mouse_over_inc {
+                            modifiers.increase_pressed = false;
+                        } else {
+                            event_status = event::Status::Ignored;
+                        }
+                    }
+                    _ => event_status = event::Status::Ignored,
+                }
+                event_status
+            } else {
+                match event.clone() {
+                    Event::Keyboard(keyboard::Event::KeyPressed { key, .. })
+                        if child
+                            .state
+                            .downcast_mut::<text_input::State<Renderer::Paragraph>>()
+                            .is_focused() =>
+                    {
+                        match key.as_ref() {
+                            // Shortcuts like paste and the text of expressions are
+                            // checked once they change the text
+                            keyboard::Key::Character(_)
+                            | keyboard::Key::Named(keyboard::key::Named::Backspace)
+                                if keyboard_modifiers.command() || self.is_free_text() =>
+                            {
+                                self.content.on_event(
+                                    child, event, content, cursor, renderer, clipboard, shell,
+                                    viewport,
+                                )
+                            }
+                            keyboard::Key::Character(c) => {
+                                let mut new_val = self.shown_text(modifiers);
+                                match child
+                                    .state
+                                    .downcast_mut::<text_input::State<Renderer::Paragraph>>()
+                                    .cursor()
+                                    .state(&Value::new(&new_val))
+                                {
+                                    cursor::State::Index(mut idx) => {
+                                        if T::zero().eq(&self.value)
+                                            && c.trim().parse::<i64>().is_ok()
+                                        {
+                                            new_val = c.to_owned();
+                                        } else {
+                                            for char in c.chars() {
+                                                new_val.insert(idx, char);
+                                                idx += 1;
+                                            }
+                                        }
+                                    }
+                                    cursor::State::Selection { start, end } => {
+                                        if (0..new_val.len()).contains(&start)
+                                            && (0..new_val.len()).contains(&end)
+                                        {
+                                            new_val.replace_range(
+                                                if start > end { end..start } else { start..end },
+                                                c,
+                                            );
+                                        }
+                                    }
+                                }
+
+                                match (self.parser)(&new_val) {
+                                    Some(val) if self.in_bounds(val) => {
+                                        modifiers.invalid = None;
+                                        self.value = val;
+                                        shell.publish((self.on_change)(self.value));
+                                        self.content.on_event(
+                                            child, event, content, cursor, renderer, clipboard,
+                                            shell, viewport,
+                                        )
+                                    }
+                                    _ => {
+                                        modifiers.invalid = Some(new_val);
+                                        event::Status::Ignored
+                                    }
+                                }
+                            }
+                            keyboard::Key::Named(k) => match k {
+                                keyboard::key::Named::Backspace => {
+                                    if T::zero().eq(&self.value) {
+                                        event::Status::Ignored
+                                    } else {
+                                        let mut new_val = self.shown_text(modifiers);
+                                        match child
+                                            .state
+                                            .downcast_mut::<text_input::State<Renderer::Paragraph>>(
+                                            )
+                                            .cursor()
+                                            .state(&Value::new(&new_val))
+                                        {
+                                            cursor::State::Index(idx) => {
+                                                if idx >= 1 && idx <= new_val.len() {
+                                                    if new_val.len() == 1 {
+                                                        new_val = self
+                                                            .min
+                                                            .filter(|min| *min > T::zero())
+                                                            .unwrap_or_else(T::zero)
+                                                            .to_string();
+                                                    } else {
+                                                        let _ = new_val.remove(idx - 1);
+                                                    }
+                                                }
+                                            }
+                                            cursor::State::Selection { start, end } => {
+                                                if (0..new_val.len()).contains(&start)
+                                                    && (0..new_val.len()).contains(&end)
+                                                {
+                                                    new_val.replace_range(
+                                                        if start > end {
+                                                            end..start
+                                                        } else {
+                                                            start..end
+                                                        },
+                                                        "",
+                                                    );
+                                                }
+                                            }
+                                        }
+
+                                        match (self.parser)(&new_val) {
+                                            Some(val) if self.in_bounds(val) => {
+                                                modifiers.invalid = None;
+                                                self.value = val;
+                                                shell.publish((self.on_change)(self.value));
+                                                self.content.on_event(
+                                                    child, event, content, cursor, renderer,
+                                                    clipboard, shell, viewport,
+                                                )
+                                            }
+                                            _ => {
+                                                modifiers.invalid = Some(new_val);
+                                                event::Status::Ignored
+                                            }
+                                        }
+                                    }
+                                }
+                                _ => self.content.on_event(
+                                    child, event, content, cursor, renderer, clipboard, shell,
+                                    viewport,
+                                ),
+                            },
+                            keyboard::Key::Unidentified => event::Status::Ignored,
+                        }
+                    }
+                    Event::Mouse(mouse::Event::WheelScrolled { .. })
+                        if self.ignore_scroll_events =>
+                    {
+                        event::Status::Ignored
+                    }
+                    Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                        let positive = match delta {
+                            mouse::ScrollDelta::Lines { y, .. }
+                            | mouse::ScrollDelta::Pixels { y, .. } => y.is_sign_positive(),
+                        };
+                        if positive {
+                            self.increase_val(keyboard_modifiers, shell);
+                        } else {
+                            self.decrease_val(keyboard_modifiers, shell);
+                        }
+                        event::Status::Captured
+                    }
+                    _ => self.content.on_event(
+                        child, event, content, cursor, renderer, clipboard, shell, viewport,
+                    ),
+                }
+            }
+        } else {
+            match event {
+                Event::Keyboard(_) => event::Status::Ignored,
+                _ => self.content.on_event(
+                    child, event, content, cursor, renderer, clipboard, shell, viewport,
+                ),
+            }
+        }
+    }
+
+    /// Handles dragging over the text to scrub the value.
+    ///
+    /// Returns `None` for the events that are not part of a scrub.
+    #[allow(clippy::too_many_arguments)]
+    fn scrub(
+        &mut self,
+        state: &mut Tree,
+        event: &Event,
+        content: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+        viewport: &Rectangle,
+    ) -> Option<event::Status> {
+        let is_focused = state.children[0]
+            .state
+            .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+            .is_focused();
+        let modifiers = state.state.downcast_mut::<ModifierState>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) if !is_focused => {
+                modifiers.scrub_origin = Some(cursor.position_over(content.bounds())?.x);
+                modifiers.scrub_steps = 0;
+                modifiers.scrubbing = false;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                let distance = position.x - modifiers.scrub_origin?;
+
+                if modifiers.scrubbing || distance.abs() >= SCRUB_THRESHOLD {
+                    modifiers.scrubbing = true;
+
+                    #[allow(clippy::cast_possible_truncation)]
+                    let steps = (distance / SCRUB_STEP_WIDTH).trunc() as i64;
+                    let delta = steps - modifiers.scrub_steps;
+                    let keyboard_modifiers = modifiers.keyboard_modifiers;
+                    modifiers.scrub_steps = steps;
+
+                    for _ in 0..delta.unsigned_abs() {
+                        if delta > 0 {
+                            self.increase_val(keyboard_modifiers, shell);
+                        } else {
+                            self.decrease_val(keyboard_modifiers, shell);
+                        }
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                let _ = modifiers.scrub_origin.take()?;
+
+                // A click without dragging focuses the text for typing
+                if !std::mem::take(&mut modifiers.scrubbing) {
+                    let press = Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left));
+                    for event in [press, event.clone()] {
+                        let _ = self.content.on_event(
+                            &mut state.children[0],
+                            event,
+                            content,
+                            cursor,
+                            renderer,
+                            clipboard,
+                            shell,
+                            viewport,
+                        );
+                    }
+                }
+            }
+            _ => return None,
+        }
+
+        Some(event::Status::Captured)
+    }
+
     /// Decrease current value by step of the [`NumberInput`].
-    fn decrease_val(&mut self, shell: &mut Shell<Message>) {
-        if self.value > self.bounds.0 {
-            let new_val = self.value - self.step;
-            self.value = if new_val > self.bounds.0 {
-                new_val
+    ///
+    /// Without a minimum, the value saturates at the minimum of `T`.
+    fn decrease_val(&mut self, modifiers: keyboard::Modifiers, shell: &mut Shell<Message>) {
+        self.decrease_by(self.step_for(modifiers), shell);
+    }
+
+    /// Decrease current value by the given step.
+    fn decrease_by(&mut self, step: T, shell: &mut Shell<Message>) {
+        let min = self.min.unwrap_or_else(T::min_value);
+
+        if self.value > min {
+            let new_val = if self.value < min + step {
+                min
             } else {
-                self.bounds.0
+                self.round(self.value - step)
             };
+            self.value = if new_val > min { new_val } else { min };
+            shell.publish((self.on_change)(self.value));
+        }
+    }
+
+    /// Rounds a value typed with more decimal places than the precision of the [`NumberInput`].
+    fn normalize_val(&mut self, shell: &mut Shell<Message>) {
+        let value = self.round(self.value);
+
+        if value != self.value {
+            self.value = value;
             shell.publish((self.on_change)(self.value));
         }
     }
 
     /// Increase current value by step of the [`NumberInput`].
-    fn increase_val(&mut self, shell: &mut Shell<Message>) {
-        if self.value < self.bounds.1 {
-            let new_val = self.value + self.step;
-            self.value = if new_val < self.bounds.1 {
-                new_val
+    ///
+    /// Without a maximum, the value saturates at the maximum of `T`.
+    fn increase_val(&mut self, modifiers: keyboard::Modifiers, shell: &mut Shell<Message>) {
+        self.increase_by(self.step_for(modifiers), shell);
+    }
+
+    /// Increase current value by the given step.
+    fn increase_by(&mut self, step: T, shell: &mut Shell<Message>) {
+        let max = self.max.unwrap_or_else(T::max_value);
+
+        if self.value < max {
+            let new_val = if self.value > max - step {
+                max
             } else {
-                self.bounds.1
+                self.round(self.value + step)
             };
+            self.value = if new_val < max { new_val } else { max };
             shell.publish((self.on_change)(self.value));
         }
     }
@@ -251,7 +1315,7 @@ where
 impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for NumberInput<'a, T, Message, Theme, Renderer>
 where
-    T: Num + NumAssignOps + PartialOrd + Display + FromStr + ToString + Copy,
+    T: 'static + Num + NumAssignOps + PartialOrd + Display + FromStr + ToString + Copy + Bounded,
     Message: 'a + Clone,
     Renderer: 'a + core::text::Renderer<Font = core::Font>,
     Theme: number_input::StyleSheet
@@ -297,34 +1361,48 @@ where
             .width(num_size.width)
             .height(Length::Shrink)
             .shrink(padding);
-        let content = self
-            .content
-            .layout(&mut tree.children[0], renderer, &limits, None);
+        // Lay out the text typed since the last submit
+        let draft = tree
+            .state
+            .downcast_ref::<ModifierState>()
+            .draft
+            .as_ref()
+            .map(|draft| self.text_input_with(draft));
+        let content = draft.as_ref().unwrap_or(&self.content).layout(
+            &mut tree.children[0],
+            renderer,
+            &limits,
+            None,
+        );
         let limits2 = Limits::new(Size::new(0.0, 0.0), content.size());
-        let txt_size = self.size.unwrap_or_else(|| renderer.default_size().0);
-
-        let icon_size = txt_size * 2.5 / 4.0;
+        let icon_size = self.resolved_icon_size(renderer);
         let btn_mod = |c| {
-            Container::<Message, Theme, Renderer>::new(Text::new(format!(" {c} ")).size(icon_size))
+            let mut icon = Text::new(format!(" {c} ")).size(icon_size);
+            if self.icons.is_some() {
+                icon = icon.font(self.icon(renderer, false).1);
+            }
+            Container::<Message, Theme, Renderer>::new(icon)
                 .center_y()
                 .center_x()
         };
 
         let element = if self.padding < DEFAULT_PADDING {
+            let (decrease, increase) = self.icons.unwrap_or(('-', '+'));
             Element::new(
                 Row::<Message, Theme, Renderer>::new()
                     .spacing(1)
                     .width(Length::Shrink)
-                    .push(btn_mod('+'))
-                    .push(btn_mod('-')),
+                    .push(btn_mod(increase))
+                    .push(btn_mod(decrease)),
             )
         } else {
+            let (decrease, increase) = self.icons.unwrap_or(('▼', '▲'));
             Element::new(
                 Column::<Message, Theme, Renderer>::new()
                     .spacing(1)
                     .width(Length::Shrink)
-                    .push(btn_mod('▲'))
-                    .push(btn_mod('▼')),
+                    .push(btn_mod(increase))
+                    .push(btn_mod(decrease)),
             )
         };
 
@@ -346,6 +1424,9 @@ where
         );
         modifier = modifier.align(Alignment::End, Alignment::Center, intrinsic);
 
+        let content = self.layout_affixes(content, renderer, modifier.bounds().x);
+        let content = self.align_text(content, tree, renderer);
+
         let size = limits.resolve(num_size.width, Length::Shrink, intrinsic);
         Node::with_children(size, vec![content, modifier])
     }
@@ -370,232 +1451,166 @@ where
         });
     }
 
-    #[allow(clippy::too_many_lines, clippy::cognitive_complexity)]
-    fn on_event(
-        &mut self,
-        state: &mut Tree,
-        event: Event,
-        layout: Layout<'_>,
-        cursor: Cursor,
-        renderer: &Renderer,
-        clipboard: &mut dyn Clipboard,
-        shell: &mut Shell<Message>,
-        viewport: &Rectangle,
-    ) -> event::Status {
-        let mut children = layout.children();
-        let content = children.next().expect("fail to get content layout");
-        let mut mod_children = children
-            .next()
-            .expect("fail to get modifiers layout")
-            .children();
-        let inc_bounds = mod_children
-            .next()
-            .expect("fail to get increase mod layout")
-            .bounds();
-        let dec_bounds = mod_children
-            .next()
-            .expect("fail to get decreate mod layout")
-            .bounds();
-        let mouse_over_inc = inc_bounds.contains(cursor.position().unwrap_or_default());
-        let mouse_over_dec = dec_bounds.contains(cursor.position().unwrap_or_default());
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let invalid = state.state.downcast_ref::<ModifierState>().invalid.clone();
+        let Some(on_submit) = self.on_submit.clone() else {
+            // Continue from the text typed while the input is focused
+            if let Some(draft) = &state.state.downcast_ref::<ModifierState>().draft {
+                self.content = self.text_input_with(draft);
+            }
+            let value = self.value;
+            let is_enter = matches!(
+                event,
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key: keyboard::Key::Named(keyboard::key::Named::Enter),
+                    ..
+                })
+            );
+
+            let status = self.update(
+                state, event, layout, cursor, renderer, clipboard, shell, viewport,
+            );
+
+            // The typed text is kept until editing ends or the value is stepped
+            let focused = state.children[0]
+                .state
+                .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+                .is_focused();
+            let edited = self.edited.borrow_mut().take();
+            let modifiers = state.state.downcast_mut::<ModifierState>();
+            if !focused || is_enter || (edited.is_none() && self.value != value) {
+                modifiers.draft = None;
+            } else if edited.is_some() {
+                modifiers.draft = edited;
+            }
+            self.content = self.text_input_with(&self.shown_text(modifiers));
+
+            self.report_invalid(state, invalid.as_ref(), shell);
+            if let Some(text) = self.failed.borrow_mut().take() {
+                self.report_error(text, shell);
+            }
+            return status;
+        };
+
+        let is_focused = |state: &Tree| {
+            state.children[0]
+                .state
+                .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+                .is_focused()
+        };
+        let was_focused = is_focused(state);
+        let is_enter = matches!(
+            event,
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Enter),
+                ..
+            })
+        );
+        let committed = self.value;
+
+        // Continue from the text typed since the last submit
+        if let Some(draft) = &state.state.downcast_ref::<ModifierState>().draft {
+            if let Some(value) = self.parse(draft) {
+                self.value = value;
+            }
+            self.content = self.text_input_with(draft);
+        }
+        let edited_value = self.value;
+
+        // The messages of the edits are dropped until the value is submitted
+        let mut fake_messages: Vec<Message> = Vec::new();
+        let mut fake_shell = Shell::new(&mut fake_messages);
+        let status = self.update(
+            state,
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            &mut fake_shell,
+            viewport,
+        );
+
+        if fake_shell.is_layout_invalid() {
+            shell.invalidate_layout();
+        }
+        if fake_shell.are_widgets_invalid() {
+            shell.invalidate_widgets();
+        }
+        if let Some(request) = fake_shell.redraw_request() {
+            shell.request_redraw(request);
+        }
+
+        let focused = is_focused(state);
         let modifiers = state.state.downcast_mut::<ModifierState>();
-        let child = &mut state.children[0];
+        let edited = self.edited.borrow_mut().take();
+        let mut changed = edited.is_some() || self.value != edited_value;
 
-        if self.bounds.0 == self.bounds.1 {
-            return event::Status::Ignored;
+        // Drafts are reverted or clamped once submitted, until then mark them
+        if let Some(text) = &edited {
+            modifiers.invalid = (!self.is_valid(text)).then(|| text.clone());
         }
-
-        if layout
-            .bounds()
-            .contains(cursor.position().unwrap_or_default())
+        if let Some(text) =
+            edited.or_else(|| (self.value != edited_value).then(|| self.display_value()))
         {
-            if mouse_over_inc || mouse_over_dec {
-                let mut event_status = event::Status::Captured;
-                match event {
-                    Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
-                        if mouse_over_dec {
-                            modifiers.decrease_pressed = true;
-                            self.decrease_val(shell);
-                        } else if mouse_over_inc {
-                            modifiers.increase_pressed = true;
-                            self.increase_val(shell);
-                        } else {
-                            event_status = event::Status::Ignored;
-                        }
-                    }
-                    Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
-                        if mouse_over_dec {
-                            modifiers.decrease_pressed = false;
-                        } else if mouse_over_inc {
-                            modifiers.increase_pressed = false;
-                        } else {
-                            event_status = event::Status::Ignored;
-                        }
-                    }
-                    _ => event_status = event::Status::Ignored,
-                }
-                event_status
-            } else {
-                match event.clone() {
-                    Event::Keyboard(keyboard::Event::KeyPressed { key, .. })
-                        if child
-                            .state
-                            .downcast_mut::<text_input::State<Renderer::Paragraph>>()
-                            .is_focused() =>
-                    {
-                        match key.as_ref() {
-                            keyboard::Key::Character(c) if c.trim().parse::<i64>().is_ok() => {
-                                let mut new_val = self.value.to_string();
-                                match child
-                                    .state
-                                    .downcast_mut::<text_input::State<Renderer::Paragraph>>()
-                                    .cursor()
-                                    .state(&Value::new(&new_val))
-                                {
-                                    cursor::State::Index(mut idx) => {
-                                        if T::zero().eq(&self.value) {
-                                            new_val = c.to_owned();
-                                        } else {
-                                            for char in c.chars() {
-                                                new_val.insert(idx, char);
-                                                idx += 1;
-                                            }
-                                        }
-                                    }
-                                    cursor::State::Selection { start, end } => {
-                                        if (0..new_val.len()).contains(&start)
-                                            && (0..new_val.len()).contains(&end)
-                                        {
-                                            new_val.replace_range(
-                                                if start > end { end..start } else { start..end },
-                                                c,
-                                            );
-                                        }
-                                    }
-                                }
+            modifiers.draft = Some(text);
+        }
 
-                                match T::from_str(&new_val) {
-                                    Ok(val) => {
-                                        if (self.bounds.0..=self.bounds.1).contains(&val) {
-                                            self.value = val;
-                                            shell.publish((self.on_change)(self.value));
-                                            self.content.on_event(
-                                                child, event, content, cursor, renderer, clipboard,
-                                                shell, viewport,
-                                            )
-                                        } else {
-                                            event::Status::Ignored
-                                        }
-                                    }
-                                    Err(_) => event::Status::Ignored,
-                                }
-                            }
-                            keyboard::Key::Named(k) => match k {
-                                keyboard::key::Named::ArrowUp => {
-                                    self.increase_val(shell);
-                                    event::Status::Captured
-                                }
-                                keyboard::key::Named::ArrowDown => {
-                                    self.decrease_val(shell);
-                                    event::Status::Captured
-                                }
-                                keyboard::key::Named::Backspace => {
-                                    if T::zero().eq(&self.value) {
-                                        event::Status::Ignored
-                                    } else {
-                                        let mut new_val = self.value.to_string();
-                                        match child
-                                            .state
-                                            .downcast_mut::<text_input::State<Renderer::Paragraph>>(
-                                            )
-                                            .cursor()
-                                            .state(&Value::new(&new_val))
-                                        {
-                                            cursor::State::Index(idx) => {
-                                                if idx >= 1 && idx <= new_val.len() {
-                                                    if new_val.len() == 1 {
-                                                        new_val = if self.bounds.0 > T::zero() {
-                                                            self.bounds.0
-                                                        } else {
-                                                            T::zero()
-                                                        }
-                                                        .to_string();
-                                                    } else {
-                                                        let _ = new_val.remove(idx - 1);
-                                                    }
-                                                }
-                                            }
-                                            cursor::State::Selection { start, end } => {
-                                                if (0..new_val.len()).contains(&start)
-                                                    && (0..new_val.len()).contains(&end)
-                                                {
-                                                    new_val.replace_range(
-                                                        if start > end {
-                                                            end..start
-                                                        } else {
-                                                            start..end
-                                                        },
-                                                        "",
-                                                    );
-                                                }
-                                            }
-                                        }
+        // Scrubbed values are submitted once the drag ends
+        if (was_focused && is_enter)
+            || (modifiers.draft.is_some() && !focused && modifiers.scrub_origin.is_none())
+        {
+            let draft = modifiers.draft.take();
+            let value = draft
+                .as_ref()
+                .map_or(Some(self.value), |draft| self.parse(draft));
 
-                                        match T::from_str(&new_val) {
-                                            Ok(val) => {
-                                                if (self.bounds.0..=self.bounds.1).contains(&val) {
-                                                    self.value = val;
-                                                    shell.publish((self.on_change)(self.value));
-                                                    self.content.on_event(
-                                                        child, event, content, cursor, renderer,
-                                                        clipboard, shell, viewport,
-                                                    )
-                                                } else {
-                                                    event::Status::Ignored
-                                                }
-                                            }
-                                            Err(_) => event::Status::Ignored,
-                                        }
-                                    }
-                                }
-                                _ => self.content.on_event(
-                                    child, event, content, cursor, renderer, clipboard, shell,
-                                    viewport,
-                                ),
-                            },
-                            _ => event::Status::Ignored,
-                        }
-                    }
-                    Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
-                        let positive = match delta {
-                            mouse::ScrollDelta::Lines { y, .. }
-                            | mouse::ScrollDelta::Pixels { y, .. } => y.is_sign_positive(),
-                        };
-                        if positive {
-                            self.increase_val(shell);
-                        } else {
-                            self.decrease_val(shell);
-                        }
-                        event::Status::Captured
-                    }
-                    _ => self.content.on_event(
-                        child, event, content, cursor, renderer, clipboard, shell, viewport,
-                    ),
+            if let Some(value) = value {
+                let value = self.round(value);
+                self.value = match (self.min, self.max) {
+                    (Some(min), _) if value < min => min,
+                    (_, Some(max)) if value > max => max,
+                    _ => value,
+                };
+                shell.publish(on_submit(self.value));
+            } else {
+                self.value = committed;
+                if let Some(draft) = draft.filter(|draft| !draft.is_empty()) {
+                    self.report_error(draft, shell);
                 }
             }
-        } else {
-            match event {
-                Event::Keyboard(_) => event::Status::Ignored,
-                _ => self.content.on_event(
-                    child, event, content, cursor, renderer, clipboard, shell, viewport,
-                ),
+            modifiers.invalid = None;
+            changed = true;
+        }
+
+        if changed {
+            // Aligned text moves with its width, which edits change without a new view
+            if self.text_alignment != Horizontal::Left {
+                shell.invalidate_layout();
             }
+            self.content = modifiers
+                .draft
+                .as_ref()
+                .map_or_else(|| self.text_input(), |draft| self.text_input_with(draft));
         }
+
+        self.report_invalid(state, invalid.as_ref(), shell);
+        status
     }
 
     fn mouse_interaction(
         &self,
-        _state: &Tree,
+        state: &Tree,
         layout: Layout<'_>,
         cursor: Cursor,
         _viewport: &Rectangle,
@@ -603,7 +1618,7 @@ where
     ) -> mouse::Interaction {
         let bounds = layout.bounds();
         let mut children = layout.children();
-        let _content_layout = children.next().expect("fail to get content layout");
+        let content_layout = children.next().expect("fail to get content layout");
         let mut mod_children = children
             .next()
             .expect("fail to get modifiers layout")
@@ -617,8 +1632,8 @@ where
             .expect("fail to get decreate mod layout")
             .bounds();
         let is_mouse_over = bounds.contains(cursor.position().unwrap_or_default());
-        let is_decrease_disabled = self.value <= self.bounds.0 || self.bounds.0 == self.bounds.1;
-        let is_increase_disabled = self.value >= self.bounds.1 || self.bounds.0 == self.bounds.1;
+        let is_decrease_disabled = self.min.is_some_and(|min| self.value <= min) || self.is_fixed();
+        let is_increase_disabled = self.max.is_some_and(|max| self.value >= max) || self.is_fixed();
         let mouse_over_decrease = dec_bounds.contains(cursor.position().unwrap_or_default());
         let mouse_over_increase = inc_bounds.contains(cursor.position().unwrap_or_default());
 
@@ -626,6 +1641,18 @@ where
             || (mouse_over_increase && !is_increase_disabled)
         {
             mouse::Interaction::Pointer
+        } else if self.scrubbable
+            && !self.is_fixed()
+            && !mouse_over_decrease
+            && !mouse_over_increase
+            && (state.state.downcast_ref::<ModifierState>().scrubbing
+                || (cursor.is_over(content_layout.bounds())
+                    && !state.children[0]
+                        .state
+                        .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+                        .is_focused()))
+        {
+            mouse::Interaction::ResizingHorizontally
         } else if is_mouse_over {
             mouse::Interaction::Text
         } else {
@@ -657,7 +1684,14 @@ where
             .next()
             .expect("fail to get decreate mod layout")
             .bounds();
-        self.content.draw(
+        // Show the text typed since the last submit
+        let draft = state
+            .state
+            .downcast_ref::<ModifierState>()
+            .draft
+            .as_ref()
+            .map(|draft| self.text_input_with(draft));
+        draft.as_ref().unwrap_or(&self.content).draw(
             &state.children[0],
             renderer,
             theme,
@@ -666,8 +1700,31 @@ where
             None,
             viewport,
         );
-        let is_decrease_disabled = self.value <= self.bounds.0 || self.bounds.0 == self.bounds.1;
-        let is_increase_disabled = self.value >= self.bounds.1 || self.bounds.0 == self.bounds.1;
+        if state
+            .state
+            .downcast_ref::<ModifierState>()
+            .invalid
+            .is_some()
+        {
+            let appearance = style::number_input::StyleSheet::invalid(theme, &self.style);
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: content_layout.bounds(),
+                    border: Border {
+                        radius: (2.0).into(),
+                        width: 1.0,
+                        color: appearance.border_color.unwrap_or(Color::TRANSPARENT),
+                    },
+                    shadow: Shadow::default(),
+                },
+                appearance
+                    .background
+                    .unwrap_or(Background::Color(Color::TRANSPARENT)),
+            );
+        }
+        self.draw_affixes(renderer, theme, content_layout);
+        let is_decrease_disabled = self.min.is_some_and(|min| self.value <= min) || self.is_fixed();
+        let is_increase_disabled = self.max.is_some_and(|max| self.value >= max) || self.is_fixed();
 
         let decrease_btn_style = if is_decrease_disabled {
             style::number_input::StyleSheet::disabled(theme, &self.style)
@@ -686,9 +1743,9 @@ where
             style::number_input::StyleSheet::active(theme, &self.style)
         };
 
-        let txt_size = self.size.unwrap_or_else(|| renderer.default_size().0);
-
-        let icon_size = Pixels(txt_size * 2.5 / 4.0);
+        let icon_size = Pixels(self.resolved_icon_size(renderer));
+        let (decrease_icon, decrease_font) = self.icon(renderer, false);
+        let (increase_icon, increase_font) = self.icon(renderer, true);
 
         // decrease button section
         renderer.fill_quad(
@@ -708,10 +1765,10 @@ where
 
         renderer.fill_text(
             core::text::Text {
-                content: &icon_to_string(BootstrapIcon::CaretDownFill),
+                content: &decrease_icon,
                 bounds: Size::new(dec_bounds.width, dec_bounds.height),
                 size: icon_size,
-                font: BOOTSTRAP_FONT,
+                font: decrease_font,
                 horizontal_alignment: Horizontal::Center,
                 vertical_alignment: Vertical::Center,
                 line_height: LineHeight::Relative(1.3),
@@ -740,10 +1797,10 @@ where
 
         renderer.fill_text(
             core::text::Text {
-                content: &icon_to_string(BootstrapIcon::CaretUpFill),
+                content: &increase_icon,
                 bounds: Size::new(inc_bounds.width, inc_bounds.height),
                 size: icon_size,
-                font: BOOTSTRAP_FONT,
+                font: increase_font,
                 horizontal_alignment: Horizontal::Center,
                 vertical_alignment: Vertical::Center,
                 line_height: LineHeight::Relative(1.3),
@@ -763,12 +1820,25 @@ pub struct ModifierState {
     pub decrease_pressed: bool,
     /// The state of increase button on a [`NumberInput`].
     pub increase_pressed: bool,
+    /// The keyboard modifiers changing the step of a [`NumberInput`].
+    pub keyboard_modifiers: keyboard::Modifiers,
+    /// The text typed into a [`NumberInput`] with `on_submit` that is not submitted yet,
+    /// or the text typed into a [`NumberInput`] without it that is still being edited.
+    pub draft: Option<String>,
+    /// The horizontal position of a press that may start scrubbing the value.
+    pub scrub_origin: Option<f32>,
+    /// The number of steps the value was changed by the current scrub.
+    pub scrub_steps: i64,
+    /// Whether the value of a [`NumberInput`] is being scrubbed.
+    pub scrubbing: bool,
+    /// The text typed into a [`NumberInput`] that can't be parsed or is out of bounds.
+    pub invalid: Option<String>,
 }
 
 impl<'a, T, Message, Theme, Renderer> From<NumberInput<'a, T, Message, Theme, Renderer>>
     for Element<'a, Message, Theme, Renderer>
 where
-    T: 'a + Num + NumAssignOps + PartialOrd + Display + FromStr + Copy,
+    T: 'static + Num + NumAssignOps + PartialOrd + Display + FromStr + Copy + Bounded,
     Message: 'a + Clone,
     Renderer: 'a + core::text::Renderer<Font = core::Font>,
     Theme: 'a
@@ -781,3 +1851,287 @@ where
         Element::new(num_input)
     }
 }
+
+/// Strips whitespace, grouping separators and the given affixes from a
+/// pasted text.
+fn sanitize(
+    text: &str,
+    grouping: Option<char>,
+    prefix: Option<&str>,
+    suffix: Option<&str>,
+) -> String {
+    let text = text.trim();
+    let text = suffix
+        .and_then(|suffix| text.strip_suffix(suffix.trim()))
+        .unwrap_or(text);
+    let text = prefix
+        .and_then(|prefix| text.strip_prefix(prefix.trim()))
+        .unwrap_or(text);
+
+    text.chars()
+        .filter(|c| !c.is_whitespace() && Some(*c) != grouping)
+        .collect()
+}
+
+/// Evaluates an arithmetic expression with `+`, `-`, `*`, `/` and parentheses.
+fn evaluate(text: &str) -> Option<f64> {
+    let tokens: Vec<char> = text.chars().filter(|c| !c.is_whitespace()).collect();
+    let mut pos = 0;
+    let value = evaluate_sum(&tokens, &mut pos)?;
+
+    (pos == tokens.len() && value.is_finite()).then_some(value)
+}
+
+/// Evaluates a sum or difference of products.
+fn evaluate_sum(tokens: &[char], pos: &mut usize) -> Option<f64> {
+    let mut value = evaluate_product(tokens, pos)?;
+
+    while let Some(&op) = tokens.get(*pos).filter(|c| matches!(c, '+' | '-')) {
+        *pos += 1;
+        let rhs = evaluate_product(tokens, pos)?;
+        if op == '+' {
+            value += rhs;
+        } else {
+            value -= rhs;
+        }
+    }
+    Some(value)
+}
+
+/// Evaluates a product or quotient of factors.
+fn evaluate_product(tokens: &[char], pos: &mut usize) -> Option<f64> {
+    let mut value = evaluate_factor(tokens, pos)?;
+
+    while let Some(&op) = tokens.get(*pos).filter(|c| matches!(c, '*' | '/')) {
+        *pos += 1;
+        let rhs = evaluate_factor(tokens, pos)?;
+        if op == '*' {
+            value *= rhs;
+        } else {
+            value /= rhs;
+        }
+    }
+    Some(value)
+}
+
+/// Evaluates a number, a negated factor or an expression in parentheses.
+fn evaluate_factor(tokens: &[char], pos: &mut usize) -> Option<f64> {
+    match tokens.get(*pos)? {
+        '-' => {
+            *pos += 1;
+            evaluate_factor(tokens, pos).map(|value| -value)
+        }
+        '(' => {
+            *pos += 1;
+            let value = evaluate_sum(tokens, pos)?;
+            if tokens.get(*pos) != Some(&')') {
+                return None;
+            }
+            *pos += 1;
+            Some(value)
+        }
+        _ => {
+            let start = *pos;
+            while tokens
+                .get(*pos)
+                .is_some_and(|c| c.is_ascii_digit() || *c == '.')
+            {
+                *pos += 1;
+            }
+            tokens[start..*pos].iter().collect::<String>().parse().ok()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{evaluate, sanitize, ModifierState, NumberInput};
+    use crate::core::locale::DeDe;
+    use iced_widget::core::{
+        clipboard, keyboard,
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        renderer::Null,
+        widget::Tree,
+        Event, Layout, Point, Rectangle, Shell, Size, Widget,
+    };
+    use std::sync::Arc;
+
+    type Input<'a> = NumberInput<'a, f64, f64, iced_widget::Theme, Null>;
+
+    fn number_input<'a>(value: f64) -> Input<'a> {
+        NumberInput::new(value, 10.0, |value| value).precision(2)
+    }
+
+    fn layout(input: &Input<'_>, tree: &mut Tree) -> Node {
+        input.layout(
+            tree,
+            &Null,
+            &Limits::new(Size::ZERO, Size::new(400.0, 100.0)),
+        )
+    }
+
+    fn event(input: &mut Input<'_>, tree: &mut Tree, event: Event, position: Point) -> Vec<f64> {
+        let node = layout(input, tree);
+        let mut messages = Vec::new();
+        let _ = input.on_event(
+            tree,
+            event,
+            Layout::new(&node),
+            Cursor::Available(position),
+            &Null,
+            &mut clipboard::Null,
+            &mut Shell::new(&mut messages),
+            &Rectangle::with_size(Size::new(400.0, 100.0)),
+        );
+        messages
+    }
+
+    fn key(key: keyboard::Key, text: Option<&str>) -> Event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key,
+            location: keyboard::Location::Standard,
+            modifiers: keyboard::Modifiers::empty(),
+            text: text.map(Into::into),
+        })
+    }
+
+    fn shown_text(input: &Input<'_>, tree: &Tree) -> String {
+        input.shown_text(tree.state.downcast_ref::<ModifierState>())
+    }
+
+    #[test]
+    fn sanitize_test() {
+        assert_eq!(sanitize(" 42 ", Some(','), None, None), "42");
+        assert_eq!(
+            sanitize("1,234.5 px", Some(','), None, Some(" px")),
+            "1234.5"
+        );
+        assert_eq!(sanitize("$ 1 000", None, Some("$"), None), "1000");
+    }
+
+    #[test]
+    fn evaluate_test() {
+        assert_eq!(evaluate("12*4+1"), Some(49.0));
+        assert_eq!(evaluate("(1 + 2) * -3"), Some(-9.0));
+        assert_eq!(evaluate("10/4-0.5"), Some(2.0));
+        assert_eq!(evaluate("2*(3"), None);
+        assert_eq!(evaluate("1/0"), None);
+        assert_eq!(evaluate("1+"), None);
+        assert_eq!(evaluate("abc"), None);
+    }
+
+    #[test]
+    fn locale_test() {
+        let input: Input<'_> =
+            NumberInput::with_locale(1234.5, 10_000.0, |value| value, Arc::new(DeDe)).precision(2);
+
+        assert_eq!(input.display_value(), "1234,50");
+        assert_eq!(input.parse("1.234,5"), Some(1234.5));
+        assert_eq!(input.parse("2,5"), Some(2.5));
+    }
+
+    #[test]
+    fn precision_test() {
+        let input = number_input(0.1 + 0.2);
+        let tree = Tree::new(&input as &dyn Widget<f64, iced_widget::Theme, Null>);
+
+        assert_eq!(input.display_value(), "0.30");
+        assert_eq!(shown_text(&input, &tree), "0.30");
+        assert_eq!(number_input(2.0).display_value(), "2.00");
+    }
+
+    #[test]
+    fn precision_edit_test() {
+        let mut input = number_input(1.23);
+        let mut tree = Tree::new(&input as &dyn Widget<f64, iced_widget::Theme, Null>);
+        let text = Layout::new(&layout(&input, &mut tree))
+            .children()
+            .next()
+            .expect("The number input should have a content layout")
+            .bounds()
+            .center();
+        let press = |button| Event::Mouse(mouse::Event::ButtonPressed(button));
+
+        // Typing a decimal place more than the precision
+        let _ = event(&mut input, &mut tree, press(mouse::Button::Left), text);
+        let _ = event(
+            &mut input,
+            &mut tree,
+            key(keyboard::Key::Named(keyboard::key::Named::End), None),
+            text,
+        );
+        let messages = event(
+            &mut input,
+            &mut tree,
+            key(keyboard::Key::Character("4".into()), Some("4")),
+            text,
+        );
+        assert_eq!(messages.last(), Some(&1.234));
+
+        // The typed text is shown while editing
+        let mut input = number_input(1.234);
+        tree.diff(&input as &dyn Widget<f64, iced_widget::Theme, Null>);
+        assert_eq!(shown_text(&input, &tree), "1.234");
+
+        // The rounded value is shown once editing ends
+        let messages = event(
+            &mut input,
+            &mut tree,
+            press(mouse::Button::Left),
+            Point::new(390.0, 90.0),
+        );
+        assert_eq!(messages.last(), Some(&1.23));
+        let input = number_input(1.23);
+        tree.diff(&input as &dyn Widget<f64, iced_widget::Theme, Null>);
+        assert_eq!(shown_text(&input, &tree), "1.23");
+    }
+
+    #[allow(clippy::approx_constant)]
+    #[test]
+    fn custom_parser_test() {
+        let comma_input = |value| -> Input<'_> {
+            NumberInput::new(value, 10.0, |value| value)
+                .parser(|text| text.replace(',', ".").parse().ok())
+        };
+        let mut input = comma_input(3.0);
+        let mut tree = Tree::new(&input as &dyn Widget<f64, iced_widget::Theme, Null>);
+        let text = Layout::new(&layout(&input, &mut tree))
+            .children()
+            .next()
+            .expect("The number input should have a content layout")
+            .bounds()
+            .center();
+
+        // The grouping separator of the locale reaches the parser
+        let _ = event(
+            &mut input,
+            &mut tree,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            text,
+        );
+        let mut value = 3.0;
+        for c in [",", "1", "4"] {
+            let _ = event(
+                &mut input,
+                &mut tree,
+                key(keyboard::Key::Named(keyboard::key::Named::End), None),
+                text,
+            );
+            let messages = event(
+                &mut input,
+                &mut tree,
+                key(keyboard::Key::Character(c.into()), Some(c)),
+                text,
+            );
+            assert!(!messages.contains(&314.0));
+            value = messages.last().copied().unwrap_or(value);
+
+            input = comma_input(value);
+            tree.diff(&input as &dyn Widget<f64, iced_widget::Theme, Null>);
+        }
+
+        assert!((value - 3.14).abs() < f64::EPSILON);
+        assert_eq!(shown_text(&input, &tree), "3,14");
+    }
+}
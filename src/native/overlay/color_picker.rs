@@ -3,9 +3,12 @@
 //! *This API requires the following crate features to be activated: `color_picker`*
 
 use crate::{
-    color_picker,
+    color_picker::{
+        self,
+        convert::{ColorModel, Coordinates},
+    },
     core::{
-        color::{HexString, Hsv},
+        color::{self, ChannelMode, Hsv},
         overlay::Position,
     },
     graphics::icons::bootstrap::{icon_to_string, BootstrapIcon},
@@ -27,14 +30,18 @@ use iced_widget::{
         text::Renderer as _,
         touch,
         widget::{self, tree::Tree},
-        Alignment, Border, Clipboard, Color, Element, Event, Layout, Length, Overlay, Padding,
-        Point, Rectangle, Renderer as _, Shadow, Shell, Size, Text, Vector, Widget,
+        window, Alignment, Border, Clipboard, Color, Element, Event, Layout, Length, Overlay,
+        Padding, Pixels, Point, Rectangle, Renderer as _, Shadow, Shell, Size, Text, Vector,
+        Widget,
     },
     graphics::geometry::Renderer as _,
     renderer::Renderer,
-    Button, Column, Row,
+    text_input, Button, Column, Row, TextInput,
+};
+use std::{
+    cell::Cell,
+    collections::{HashMap, HashSet},
 };
-use std::collections::HashMap;
 
 /// The padding around the elements.
 const PADDING: f32 = 10.0;
@@ -44,31 +51,58 @@ const SPACING: f32 = 15.0;
 const BUTTON_SPACING: f32 = 5.0;
 
 /// The step value of the keyboard change of the sat/value color values.
-const SAT_VALUE_STEP: f32 = 0.005;
-/// The step value of the keyboard change of the hue color value.
-const HUE_STEP: i32 = 1;
-/// The step value of the keyboard change of the RGBA color values.
-const RGBA_STEP: i16 = 1;
+const SAT_VALUE_STEP: f32 = 1.0 / 255.0;
+/// The step value of the keyboard change of the hue color value, in degrees.
+const HUE_STEP: f32 = 1.0;
+/// The step value of the keyboard change of the RGB color values, in bytes.
+const RGB_STEP: i16 = 1;
+/// The step value of the keyboard change of the alpha value, in percent.
+const ALPHA_STEP: i16 = 1;
+/// The factor of the keyboard steps while shift is held.
+const SHIFT_STEP_FACTOR: i16 = 10;
+/// The size of the swatches of the palette.
+const SWATCH_SIZE: f32 = 20.0;
+/// The spacing between the swatches of the palette.
+const SWATCH_SPACING: f32 = 6.0;
+/// The change of the hue generating the gradient of the sat/value area again, smaller
+/// changes aren't visible.
+const SAT_VALUE_CACHE_HUE_STEP: f32 = 0.5;
+/// The size of the squares of the checkerboard behind transparent colors.
+const CHECKER_SIZE: f32 = 6.0;
+/// The padding of the text fields and the buttons, the default of iced.
+const INPUT_PADDING: f32 = 5.0;
+/// The length of the longer side of the overlay at the default size.
+pub(crate) const DEFAULT_SIZE: f32 = 600.0;
+/// The smallest scale of the overlay still having the text fields.
+const MIN_TEXT_FIELDS_SCALE: f32 = 0.6;
 
 /// The overlay of the [`ColorPicker`](crate::native::ColorPicker).
 #[allow(missing_debug_implementations)]
 pub struct ColorPickerOverlay<'a, Message, Theme>
 where
     Message: Clone,
-    Theme: StyleSheet + button::StyleSheet,
+    Theme: StyleSheet + button::StyleSheet + text_input::StyleSheet,
 {
     /// The state of the [`ColorPickerOverlay`].
     state: &'a mut State,
+    /// The panel of the [`ColorPickerOverlay`] with its text fields.
+    panel: Panel<'a, Theme>,
     /// The cancel button of the [`ColorPickerOverlay`].
     cancel_button: Button<'a, Message, Theme, Renderer>,
     /// The submit button of the [`ColorPickerOverlay`].
     submit_button: Button<'a, Message, Theme, Renderer>,
+    /// The message that is send if the [`ColorPickerOverlay`] is cancelled with the keyboard.
+    on_cancel: Message,
     /// The function that produces a message when the submit button of the [`ColorPickerOverlay`].
     on_submit: &'a dyn Fn(Color) -> Message,
+    /// The function that produces a message when the color picked with the [`ColorPickerOverlay`] changes.
+    on_change: Option<&'a dyn Fn(Color) -> Message>,
     /// The position of the [`ColorPickerOverlay`].
     position: Point,
     /// The style of the [`ColorPickerOverlay`].
     style: <Theme as StyleSheet>::Style,
+    /// The spacing and the sizes of the [`ColorPickerOverlay`].
+    metrics: Metrics,
     /// The reference to the tree holding the state of this overlay.
     tree: &'a mut Tree,
 }
@@ -76,41 +110,55 @@ where
 impl<'a, Message, Theme> ColorPickerOverlay<'a, Message, Theme>
 where
     Message: 'static + Clone,
-    Theme: 'a + StyleSheet + button::StyleSheet + widget::text::StyleSheet,
+    Theme: 'a + StyleSheet + button::StyleSheet + widget::text::StyleSheet + text_input::StyleSheet,
 {
     /// Creates a new [`ColorPickerOverlay`] on the given position.
+    ///
+    /// The text is scaled from the given `default_text_size` along with the overlay.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         state: &'a mut color_picker::State,
         on_cancel: Message,
         on_submit: &'a dyn Fn(Color) -> Message,
+        on_change: Option<&'a dyn Fn(Color) -> Message>,
         position: Point,
         style: <Theme as StyleSheet>::Style,
+        default_text_size: Pixels,
         tree: &'a mut Tree,
     ) -> Self {
         //state.color_hex = color_picker::State::color_as_string(state.color);
-        let color_picker::State { overlay_state } = state;
+        let color_picker::State { overlay_state, .. } = state;
+        let metrics = Metrics::new(overlay_state.scale, default_text_size);
 
         ColorPickerOverlay {
+            panel: Panel::new(overlay_state, metrics),
             state: overlay_state,
             cancel_button: Button::new(
                 iced_widget::Text::new(icon_to_string(BootstrapIcon::X))
                     .horizontal_alignment(alignment::Horizontal::Center)
                     .width(Length::Fill)
+                    .size(metrics.text_size)
                     .font(crate::BOOTSTRAP_FONT),
             )
             .width(Length::Fill)
+            .padding(metrics.input_padding)
             .on_press(on_cancel.clone()),
             submit_button: Button::new(
                 iced_widget::Text::new(icon_to_string(BootstrapIcon::Check))
                     .horizontal_alignment(alignment::Horizontal::Center)
                     .width(Length::Fill)
+                    .size(metrics.text_size)
                     .font(crate::BOOTSTRAP_FONT),
             )
             .width(Length::Fill)
-            .on_press(on_cancel), // Sending a fake message
+            .padding(metrics.input_padding)
+            .on_press(on_cancel.clone()), // Sending a fake message
+            on_cancel,
             on_submit,
+            on_change,
             position,
             style,
+            metrics,
             tree,
         }
     }
@@ -121,427 +169,49 @@ where
         overlay::Element::new(self.position, Box::new(self))
     }
 
-    /// The event handling for the HSV color area.
-    fn on_event_hsv_color(
-        &mut self,
-        event: &Event,
-        layout: Layout<'_>,
-        cursor: Cursor,
-        _shell: &mut Shell<Message>,
-        _renderer: &Renderer,
-        _clipboard: &mut dyn Clipboard,
-    ) -> event::Status {
-        let mut hsv_color_children = layout.children();
-
-        let hsv_color: Hsv = self.state.color.into();
-        let mut color_changed = false;
-
-        let sat_value_bounds = hsv_color_children
-            .next()
-            .expect("Native: Layout should have a sat/value layout")
-            .bounds();
-        let hue_bounds = hsv_color_children
-            .next()
-            .expect("Native: Layout should have a hue layout")
-            .bounds();
-
-        match event {
-            Event::Mouse(mouse::Event::WheelScrolled { delta }) => match delta {
-                mouse::ScrollDelta::Lines { y, .. } | mouse::ScrollDelta::Pixels { y, .. } => {
-                    let move_value =
-                        |value: u16, y: f32| ((i32::from(value) + y as i32).rem_euclid(360)) as u16;
-
-                    if cursor.is_over(hue_bounds) {
-                        self.state.color = Color {
-                            a: self.state.color.a,
-                            ..Hsv {
-                                hue: move_value(hsv_color.hue, *y),
-                                ..hsv_color
-                            }
-                            .into()
-                        };
-                        color_changed = true;
-                    }
-                }
-            },
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if cursor.is_over(sat_value_bounds) {
-                    self.state.color_bar_dragged = ColorBarDragged::SatValue;
-                    self.state.focus = Focus::SatValue;
-                }
-                if cursor.is_over(hue_bounds) {
-                    self.state.color_bar_dragged = ColorBarDragged::Hue;
-                    self.state.focus = Focus::Hue;
-                }
-            }
-            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
-                self.state.color_bar_dragged = ColorBarDragged::None;
-            }
-            _ => {}
-        }
-
-        let calc_percentage_sat =
-            |cursor_position: Point| (cursor_position.x.max(0.0) / sat_value_bounds.width).min(1.0);
-
-        let calc_percentage_value = |cursor_position: Point| {
-            (cursor_position.y.max(0.0) / sat_value_bounds.height).min(1.0)
-        };
-
-        let calc_hue = |cursor_position: Point| {
-            ((cursor_position.x.max(0.0) / hue_bounds.width).min(1.0) * 360.0) as u16
-        };
-
-        match self.state.color_bar_dragged {
-            ColorBarDragged::SatValue => {
-                self.state.color = Color {
-                    a: self.state.color.a,
-                    ..Hsv {
-                        saturation: cursor
-                            .position_in(sat_value_bounds)
-                            .map(calc_percentage_sat)
-                            .unwrap_or_default(),
-                        value: cursor
-                            .position_in(sat_value_bounds)
-                            .map(calc_percentage_value)
-                            .unwrap_or_default(),
-                        ..hsv_color
-                    }
-                    .into()
-                };
-                color_changed = true;
+    /// Gives the picked color to the `on_change` function, once per frame if it changed
+    /// since it was last given.
+    fn publish_change(&mut self, shell: &mut Shell<Message>) {
+        if let Some(on_change) = self.on_change {
+            if std::mem::take(&mut self.state.change_pending)
+                && self.state.changed_color != Some(self.state.color)
+            {
+                self.state.changed_color = Some(self.state.color);
+                shell.publish(on_change(self.state.color));
             }
-            ColorBarDragged::Hue => {
-                self.state.color = Color {
-                    a: self.state.color.a,
-                    ..Hsv {
-                        hue: cursor
-                            .position_in(hue_bounds)
-                            .map(calc_hue)
-                            .unwrap_or_default(),
-                        ..hsv_color
-                    }
-                    .into()
-                };
-                color_changed = true;
-            }
-            _ => {}
-        }
-
-        if color_changed {
-            event::Status::Captured
-        } else {
-            event::Status::Ignored
         }
     }
 
-    /// The event handling for the RGBA color area.
-    #[allow(clippy::too_many_lines)]
-    fn on_event_rgba_color(
-        &mut self,
-        event: &Event,
-        layout: Layout<'_>,
-        cursor: Cursor,
-        _shell: &mut Shell<Message>,
-        _renderer: &Renderer,
-        _clipboard: &mut dyn Clipboard,
-    ) -> event::Status {
-        let mut rgba_color_children = layout.children();
-        let mut color_changed = false;
-
-        let mut red_row_children = rgba_color_children
-            .next()
-            .expect("Native: Layout should have a red row layout")
-            .children();
-        let _ = red_row_children.next();
-        let red_bar_bounds = red_row_children
-            .next()
-            .expect("Native: Layout should have a red bar layout")
-            .bounds();
-
-        let mut green_row_children = rgba_color_children
-            .next()
-            .expect("Native: Layout should have a green row layout")
-            .children();
-        let _ = green_row_children.next();
-        let green_bar_bounds = green_row_children
-            .next()
-            .expect("Native: Layout should have a green bar layout")
-            .bounds();
-
-        let mut blue_row_children = rgba_color_children
-            .next()
-            .expect("Native: Layout should have a blue row layout")
-            .children();
-        let _ = blue_row_children.next();
-        let blue_bar_bounds = blue_row_children
-            .next()
-            .expect("Native: Layout should have a blue bar layout")
-            .bounds();
-
-        let mut alpha_row_children = rgba_color_children
-            .next()
-            .expect("Native: Layout should have an alpha row layout")
-            .children();
-        let _ = alpha_row_children.next();
-        let alpha_bar_bounds = alpha_row_children
-            .next()
-            .expect("Native: Layout should have an alpha bar layout")
-            .bounds();
-
-        match event {
-            Event::Mouse(mouse::Event::WheelScrolled { delta }) => match delta {
-                mouse::ScrollDelta::Lines { y, .. } | mouse::ScrollDelta::Pixels { y, .. } => {
-                    let move_value =
-                        //|value: f32, y: f32| (value * 255.0 + y).clamp(0.0, 255.0) / 255.0;
-                        |value: f32, y: f32| value.mul_add(255.0, y).clamp(0.0, 255.0) / 255.0;
-
-                    if cursor.is_over(red_bar_bounds) {
-                        self.state.color = Color {
-                            r: move_value(self.state.color.r, *y),
-                            ..self.state.color
-                        };
-                        color_changed = true;
-                    }
-                    if cursor.is_over(green_bar_bounds) {
-                        self.state.color = Color {
-                            g: move_value(self.state.color.g, *y),
-                            ..self.state.color
-                        };
-                        color_changed = true;
-                    }
-                    if cursor.is_over(blue_bar_bounds) {
-                        self.state.color = Color {
-                            b: move_value(self.state.color.b, *y),
-                            ..self.state.color
-                        };
-                        color_changed = true;
-                    }
-                    if cursor.is_over(alpha_bar_bounds) {
-                        self.state.color = Color {
-                            a: move_value(self.state.color.a, *y),
-                            ..self.state.color
-                        };
-                        color_changed = true;
-                    }
-                }
-            },
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if cursor.is_over(red_bar_bounds) {
-                    self.state.color_bar_dragged = ColorBarDragged::Red;
-                    self.state.focus = Focus::Red;
-                }
-                if cursor.is_over(green_bar_bounds) {
-                    self.state.color_bar_dragged = ColorBarDragged::Green;
-                    self.state.focus = Focus::Green;
-                }
-                if cursor.is_over(blue_bar_bounds) {
-                    self.state.color_bar_dragged = ColorBarDragged::Blue;
-                    self.state.focus = Focus::Blue;
-                }
-                if cursor.is_over(alpha_bar_bounds) {
-                    self.state.color_bar_dragged = ColorBarDragged::Alpha;
-                    self.state.focus = Focus::Alpha;
-                }
-            }
-            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
-                self.state.color_bar_dragged = ColorBarDragged::None;
-            }
-            _ => {}
-        }
-
-        let calc_percentage = |bounds: Rectangle, cursor_position: Point| {
-            (cursor_position.x.max(0.0) / bounds.width).min(1.0)
+    /// The event handling for the keyboard input submitting or cancelling the
+    /// [`ColorPickerOverlay`].
+    ///
+    /// Escape cancels, enter submits the picked color unless the cancel button is focused.
+    fn on_event_submit_cancel(&self, event: &Event, shell: &mut Shell<Message>) -> event::Status {
+        let Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(key),
+            ..
+        }) = event
+        else {
+            return event::Status::Ignored;
         };
 
-        match self.state.color_bar_dragged {
-            ColorBarDragged::Red => {
-                self.state.color = Color {
-                    r: cursor
-                        .position_in(red_bar_bounds)
-                        .map(|position| calc_percentage(red_bar_bounds, position))
-                        .unwrap_or_default(),
-                    ..self.state.color
-                };
-                color_changed = true;
-            }
-            ColorBarDragged::Green => {
-                self.state.color = Color {
-                    g: cursor
-                        .position_in(green_bar_bounds)
-                        .map(|position| calc_percentage(green_bar_bounds, position))
-                        .unwrap_or_default(),
-                    ..self.state.color
-                };
-                color_changed = true;
-            }
-            ColorBarDragged::Blue => {
-                self.state.color = Color {
-                    b: cursor
-                        .position_in(blue_bar_bounds)
-                        .map(|position| calc_percentage(blue_bar_bounds, position))
-                        .unwrap_or_default(),
-                    ..self.state.color
-                };
-                color_changed = true;
+        match key {
+            keyboard::key::Named::Escape => {
+                shell.publish(self.on_cancel.clone());
+                event::Status::Captured
             }
-            ColorBarDragged::Alpha => {
-                self.state.color = Color {
-                    a: cursor
-                        .position_in(alpha_bar_bounds)
-                        .map(|position| calc_percentage(alpha_bar_bounds, position))
-                        .unwrap_or_default(),
-                    ..self.state.color
-                };
-                color_changed = true;
+            keyboard::key::Named::Enter if self.state.focus == Focus::Cancel => {
+                shell.publish(self.on_cancel.clone());
+                event::Status::Captured
             }
-            _ => {}
-        }
-
-        if color_changed {
-            event::Status::Captured
-        } else {
-            event::Status::Ignored
-        }
-    }
-
-    /// The even handling for the keyboard input.
-    fn on_event_keyboard(
-        &mut self,
-        event: &Event,
-        _layout: Layout<'_>,
-        _cursor: Cursor,
-        _shell: &mut Shell<Message>,
-        _renderer: &Renderer,
-        _clipboard: &mut dyn Clipboard,
-    ) -> event::Status {
-        if self.state.focus == Focus::None {
-            return event::Status::Ignored;
-        }
-
-        if let Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) = event {
-            let mut status = event::Status::Ignored;
-
-            if matches!(key, keyboard::Key::Named(keyboard::key::Named::Tab)) {
-                if self.state.keyboard_modifiers.shift() {
-                    self.state.focus = self.state.focus.previous();
-                } else {
-                    self.state.focus = self.state.focus.next();
-                }
-                // TODO: maybe place this better
-                self.state.sat_value_canvas_cache.clear();
-                self.state.hue_canvas_cache.clear();
-            } else {
-                let sat_value_handle = |key_code: &keyboard::Key, color: &mut Color| {
-                    let mut hsv_color: Hsv = (*color).into();
-                    let mut status = event::Status::Ignored;
-
-                    match key_code {
-                        keyboard::Key::Named(keyboard::key::Named::ArrowLeft) => {
-                            hsv_color.saturation -= SAT_VALUE_STEP;
-                            status = event::Status::Captured;
-                        }
-                        keyboard::Key::Named(keyboard::key::Named::ArrowRight) => {
-                            hsv_color.saturation += SAT_VALUE_STEP;
-                            status = event::Status::Captured;
-                        }
-                        keyboard::Key::Named(keyboard::key::Named::ArrowUp) => {
-                            hsv_color.value -= SAT_VALUE_STEP;
-                            status = event::Status::Captured;
-                        }
-                        keyboard::Key::Named(keyboard::key::Named::ArrowDown) => {
-                            hsv_color.value += SAT_VALUE_STEP;
-                            status = event::Status::Captured;
-                        }
-                        _ => {}
-                    }
-
-                    hsv_color.saturation = hsv_color.saturation.clamp(0.0, 1.0);
-                    hsv_color.value = hsv_color.value.clamp(0.0, 1.0);
-
-                    *color = Color {
-                        a: color.a,
-                        ..hsv_color.into()
-                    };
-                    status
-                };
-
-                let hue_handle = |key_code: &keyboard::Key, color: &mut Color| {
-                    let mut hsv_color: Hsv = (*color).into();
-                    let mut status = event::Status::Ignored;
-
-                    let mut value = i32::from(hsv_color.hue);
-
-                    match key_code {
-                        keyboard::Key::Named(
-                            keyboard::key::Named::ArrowLeft | keyboard::key::Named::ArrowDown,
-                        ) => {
-                            value -= HUE_STEP;
-                            status = event::Status::Captured;
-                        }
-                        keyboard::Key::Named(
-                            keyboard::key::Named::ArrowRight | keyboard::key::Named::ArrowUp,
-                        ) => {
-                            value += HUE_STEP;
-                            status = event::Status::Captured;
-                        }
-                        _ => {}
-                    }
-
-                    hsv_color.hue = value.rem_euclid(360) as u16;
-
-                    *color = Color {
-                        a: color.a,
-                        ..hsv_color.into()
-                    };
-
-                    status
-                };
-
-                let rgba_bar_handle = |key_code: &keyboard::Key, value: &mut f32| {
-                    let mut byte_value = (*value * 255.0) as i16;
-                    let mut status = event::Status::Captured;
-
-                    match key_code {
-                        keyboard::Key::Named(
-                            keyboard::key::Named::ArrowLeft | keyboard::key::Named::ArrowDown,
-                        ) => {
-                            byte_value -= RGBA_STEP;
-                            status = event::Status::Captured;
-                        }
-                        keyboard::Key::Named(
-                            keyboard::key::Named::ArrowRight | keyboard::key::Named::ArrowUp,
-                        ) => {
-                            byte_value += RGBA_STEP;
-                            status = event::Status::Captured;
-                        }
-                        _ => {}
-                    }
-                    *value = f32::from(byte_value.clamp(0, 255)) / 255.0;
-
-                    status
-                };
-
-                match self.state.focus {
-                    Focus::SatValue => status = sat_value_handle(key, &mut self.state.color),
-                    Focus::Hue => status = hue_handle(key, &mut self.state.color),
-                    Focus::Red => status = rgba_bar_handle(key, &mut self.state.color.r),
-                    Focus::Green => status = rgba_bar_handle(key, &mut self.state.color.g),
-                    Focus::Blue => status = rgba_bar_handle(key, &mut self.state.color.b),
-                    Focus::Alpha => status = rgba_bar_handle(key, &mut self.state.color.a),
-                    _ => {}
-                }
+            keyboard::key::Named::Enter
+                if self.state.focus != Focus::None
+                    || is_any_text_input_focused(self.state, &self.tree.children[2..]) =>
+            {
+                shell.publish((self.on_submit)(self.state.color));
+                event::Status::Captured
             }
-
-            status
-        } else if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) = event {
-            self.state.keyboard_modifiers = *modifiers;
-            event::Status::Ignored
-        } else {
-            event::Status::Ignored
+            _ => event::Status::Ignored,
         }
     }
 }
@@ -550,7 +220,7 @@ impl<'a, Message, Theme> Overlay<Message, Theme, Renderer>
     for ColorPickerOverlay<'a, Message, Theme>
 where
     Message: 'static + Clone,
-    Theme: 'a + StyleSheet + button::StyleSheet + widget::text::StyleSheet,
+    Theme: 'a + StyleSheet + button::StyleSheet + widget::text::StyleSheet + text_input::StyleSheet,
 {
     fn layout(
         &mut self,
@@ -559,64 +229,79 @@ where
         position: Point,
         _translation: Vector,
     ) -> Node {
+        let Metrics {
+            padding,
+            spacing,
+            button_spacing,
+            ..
+        } = self.metrics;
+        let size = DEFAULT_SIZE * self.state.scale;
         let (max_width, max_height) = if bounds.width > bounds.height {
-            (600.0, 300.0)
+            (size, size / 2.0)
         } else {
-            (300.0, 600.0)
+            (size / 2.0, size)
         };
 
         let limits = Limits::new(Size::ZERO, bounds)
-            .shrink(Padding::from(PADDING))
+            .shrink(Padding::from(padding))
             .width(Length::Fill)
             .height(Length::Fill)
             .max_width(max_width)
             .max_height(max_height);
 
-        let divider = if bounds.width > bounds.height {
-            Row::<(), Theme, Renderer>::new()
-                .spacing(SPACING)
-                .push(Row::new().width(Length::Fill).height(Length::Fill))
-                .push(Row::new().width(Length::Fill).height(Length::Fill))
-                .layout(self.tree, renderer, &limits)
-        } else {
-            Column::<(), Theme, Renderer>::new()
-                .spacing(SPACING)
-                .push(Row::new().width(Length::Fill).height(Length::Fill))
-                .push(Row::new().width(Length::Fill).height(Length::Fill))
-                .layout(self.tree, renderer, &limits)
-        };
+        // Pre-Buttons TODO: get rid of it
+        let cancel_button =
+            self.cancel_button
+                .layout(&mut self.tree.children[0], renderer, &limits);
+        let button_height = cancel_button.bounds().height;
 
-        let mut divider_children = divider.children().iter();
+        let panel = self.panel.layout(
+            &mut self.tree.children[2..],
+            renderer,
+            limits,
+            bounds.width > bounds.height,
+            button_height + spacing,
+            self.state,
+        );
 
-        let block1_bounds = divider_children
+        let mut panel_children = panel.children().iter();
+        let block1_node = panel_children
             .next()
-            .expect("Divider should have a first child")
-            .bounds();
-        let block2_bounds = divider_children
+            .expect("Panel should have a first block")
+            .clone();
+        let block2_node = panel_children
             .next()
-            .expect("Divider should have a second child")
+            .expect("Panel should have a second block");
+        let rgba_bounds = block2_node
+            .children()
+            .first()
+            .expect("Second block should have a RGBA color node")
             .bounds();
 
-        // ----------- Block 1 ----------------------
-        let block1_node = block1_layout(self, renderer, block1_bounds, position);
+        // ----------- Buttons ----------------------
+        let buttons_y = block2_node.size().height - padding - button_height;
+        let button_limits = limits.max_width(((rgba_bounds.width / 2.0) - button_spacing).max(0.0));
 
-        // ----------- Block 2 ----------------------
-        let block2_node = block2_layout(self, renderer, block2_bounds, position);
+        let cancel_button = self
+            .cancel_button
+            .layout(&mut self.tree.children[0], renderer, &button_limits)
+            .move_to(Point::new(padding, buttons_y));
 
-        let (width, height) = if bounds.width > bounds.height {
-            (
-                block1_node.size().width + block2_node.size().width + SPACING, // + (2.0 * PADDING as f32),
-                block2_node.size().height,
-            )
-        } else {
-            (
-                block2_node.size().width,
-                block1_node.size().height + block2_node.size().height + SPACING,
-            )
-        };
+        let submit_button =
+            self.submit_button
+                .layout(&mut self.tree.children[1], renderer, &button_limits);
+        let submit_button = submit_button.clone().move_to(Point::new(
+            rgba_bounds.width - submit_button.bounds().width + padding,
+            buttons_y,
+        ));
 
-        let mut node =
-            Node::with_children(Size::new(width, height), vec![block1_node, block2_node]);
+        let mut block2_children = block2_node.children().to_vec();
+        block2_children.extend([cancel_button, submit_button]);
+
+        let block2_node = Node::with_children(block2_node.size(), block2_children)
+            .move_to(block2_node.bounds().position());
+
+        let mut node = Node::with_children(panel.size(), vec![block1_node, block2_node]);
 
         node.center_and_bounce(position, bounds);
         node
@@ -631,52 +316,38 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<Message>,
     ) -> event::Status {
-        if event::Status::Captured
-            == self.on_event_keyboard(&event, layout, cursor, shell, renderer, clipboard)
-        {
-            self.state.sat_value_canvas_cache.clear();
-            self.state.hue_canvas_cache.clear();
-            return event::Status::Captured;
-        }
-
-        let mut children = layout.children();
-
-        let status = event::Status::Ignored;
+        let pointer_status = crate::core::overlay::pointer_status(&event, layout.bounds(), cursor);
 
-        // ----------- Block 1 ----------------------
-        let block1_layout = children
-            .next()
-            .expect("Native: Layout should have a 1. block layout");
-        let hsv_color_status =
-            self.on_event_hsv_color(&event, block1_layout, cursor, shell, renderer, clipboard);
-        // ----------- Block 1 end ------------------
+        // The changes of the color are published with the next frame
+        if let Event::Window(_, window::Event::RedrawRequested(_)) = event {
+            self.publish_change(shell);
+        }
+        let picked_color = self.state.color;
 
-        // ----------- Block 2 ----------------------
-        let mut block2_children = children
-            .next()
+        let mut block2_children = layout
+            .children()
+            .nth(1)
             .expect("Native: Layout should have a 2. block layout")
-            .children();
+            .children()
+            .skip(3);
 
-        // ----------- RGB Color -----------------------
-        let rgba_color_layout = block2_children
-            .next()
-            .expect("Native: Layout should have a RGBA color layout");
-        let rgba_color_status = self.on_event_rgba_color(
+        if event::Status::Captured == self.on_event_submit_cancel(&event, shell) {
+            return event::Status::Captured;
+        }
+
+        let panel_status = self.panel.on_event(
+            self.state,
+            &mut self.tree.children[2..],
             &event,
-            rgba_color_layout,
+            layout,
             cursor,
-            shell,
             renderer,
             clipboard,
+            true,
         );
 
         let mut fake_messages: Vec<Message> = Vec::new();
 
-        // ----------- Text input ----------------------
-        let _text_input_layout = block2_children
-            .next()
-            .expect("Native: Layout should have a hex text layout");
-
         // ----------- Buttons -------------------------
         let cancel_button_layout = block2_children
             .next()
@@ -709,20 +380,16 @@ where
         if !fake_messages.is_empty() {
             shell.publish((self.on_submit)(self.state.color));
         }
-        // ----------- Block 2 end ------------------
 
-        if hsv_color_status == event::Status::Captured
-            || rgba_color_status == event::Status::Captured
-        {
-            self.state.sat_value_canvas_cache.clear();
-            self.state.hue_canvas_cache.clear();
+        if self.on_change.is_some() && self.state.color != picked_color {
+            self.state.change_pending = true;
+            shell.request_redraw(window::RedrawRequest::NextFrame);
         }
 
-        status
-            .merge(hsv_color_status)
-            .merge(rgba_color_status)
+        panel_status
             .merge(cancel_button_status)
             .merge(submit_button_status)
+            .merge(pointer_status)
     }
 
     fn mouse_interaction(
@@ -732,74 +399,12 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
-        let mut children = layout.children();
-
-        let mouse_interaction = mouse::Interaction::default();
-
-        // Block 1
-        let block1_layout = children
-            .next()
-            .expect("Graphics: Layout should have a 1. block layout");
-        let mut block1_mouse_interaction = mouse::Interaction::default();
-        // HSV color
-        let mut hsv_color_children = block1_layout.children();
-        let sat_value_layout = hsv_color_children
-            .next()
-            .expect("Graphics: Layout should have a sat/value layout");
-        if cursor.is_over(sat_value_layout.bounds()) {
-            block1_mouse_interaction = block1_mouse_interaction.max(mouse::Interaction::Pointer);
-        }
-        let hue_layout = hsv_color_children
-            .next()
-            .expect("Graphics: Layout should have a hue layout");
-        if cursor.is_over(hue_layout.bounds()) {
-            block1_mouse_interaction = block1_mouse_interaction.max(mouse::Interaction::Pointer);
-        }
-
-        // Block 2
-        let block2_layout = children
-            .next()
-            .expect("Graphics: Layout should have a 2. block layout");
-        let mut block2_mouse_interaction = mouse::Interaction::default();
-        let mut block2_children = block2_layout.children();
-        // RGBA color
-        let rgba_color_layout = block2_children
-            .next()
-            .expect("Graphics: Layout should have a RGBA color layout");
-        let mut rgba_color_children = rgba_color_layout.children();
-
-        let f = |layout: Layout<'_>, cursor: Cursor| {
-            let mut children = layout.children();
-
-            let _label_layout = children.next();
-            let bar_layout = children
-                .next()
-                .expect("Graphics: Layout should have a bar layout");
-
-            if cursor.is_over(bar_layout.bounds()) {
-                mouse::Interaction::ResizingHorizontally
-            } else {
-                mouse::Interaction::default()
-            }
-        };
-        let red_row_layout = rgba_color_children
-            .next()
-            .expect("Graphics: Layout should have a red row layout");
-        block2_mouse_interaction = block2_mouse_interaction.max(f(red_row_layout, cursor));
-        let green_row_layout = rgba_color_children
-            .next()
-            .expect("Graphics: Layout should have a green row layout");
-        block2_mouse_interaction = block2_mouse_interaction.max(f(green_row_layout, cursor));
-        let blue_row_layout = rgba_color_children
-            .next()
-            .expect("Graphics: Layout should have a blue row layout");
-        block2_mouse_interaction = block2_mouse_interaction.max(f(blue_row_layout, cursor));
-        let alpha_row_layout = rgba_color_children
-            .next()
-            .expect("Graphics: Layout should have an alpha row layout");
-        block2_mouse_interaction = block2_mouse_interaction.max(f(alpha_row_layout, cursor));
-
-        let _hex_text_layout = block2_children.next();
+        let mut block2_children = layout
+            .children()
+            .nth(1)
+            .expect("Graphics: Layout should have a 2. block layout")
+            .children()
+            .skip(3);
 
         // Buttons
         let cancel_button_layout = block2_children
@@ -824,9 +429,15 @@ where
             renderer,
         );
 
-        mouse_interaction
-            .max(block1_mouse_interaction)
-            .max(block2_mouse_interaction)
+        self.panel
+            .mouse_interaction(
+                self.state,
+                &self.tree.children[2..],
+                layout,
+                cursor,
+                viewport,
+                renderer,
+            )
             .max(cancel_mouse_interaction)
             .max(submit_mouse_interaction)
     }
@@ -840,78 +451,1582 @@ where
         cursor: Cursor,
     ) {
         let bounds = layout.bounds();
-        let mut children = layout.children();
+        let style_sheet = style_sheet(theme, &self.style);
 
-        let mut style_sheet: HashMap<StyleState, Appearance> = HashMap::new();
-        let _ = style_sheet.insert(StyleState::Active, StyleSheet::active(theme, &self.style));
-        let _ = style_sheet.insert(
-            StyleState::Selected,
-            StyleSheet::selected(theme, &self.style),
+        self.panel.draw(
+            self.state,
+            &self.tree.children[2..],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            &style_sheet,
+            &StyleSheet::invalid(theme, &self.style),
         );
-        let _ = style_sheet.insert(StyleState::Hovered, StyleSheet::hovered(theme, &self.style));
-        let _ = style_sheet.insert(StyleState::Focused, StyleSheet::focused(theme, &self.style));
 
-        let mut style_state = StyleState::Active;
-        if self.state.focus == Focus::Overlay {
-            style_state = style_state.max(StyleState::Focused);
-        }
-        if cursor.is_over(bounds) {
-            style_state = style_state.max(StyleState::Hovered);
-        }
-
-        renderer.fill_quad(
-            renderer::Quad {
-                bounds,
-                border: Border {
-                    radius: style_sheet[&style_state].border_radius.into(),
-                    width: style_sheet[&style_state].border_width,
-                    color: style_sheet[&style_state].border_color,
-                },
-                shadow: Shadow::default(),
-            },
-            style_sheet[&style_state].background,
-        );
+        // ----------- Buttons -------------------------
+        let mut block2_children = layout
+            .children()
+            .nth(1)
+            .expect("Graphics: Layout should have a 2. block layout")
+            .children()
+            .skip(3);
 
-        // ----------- Block 1 ----------------------
-        let block1_layout = children
+        let cancel_button_layout = block2_children
+            .next()
+            .expect("Graphics: Layout should have a cancel button layout for a ColorPicker");
+
+        self.cancel_button.draw(
+            &self.tree.children[0],
+            renderer,
+            theme,
+            style,
+            cancel_button_layout,
+            cursor,
+            &bounds,
+        );
+
+        let submit_button_layout = block2_children
+            .next()
+            .expect("Graphics: Layout should have a submit button layout for a ColorPicker");
+
+        self.submit_button.draw(
+            &self.tree.children[1],
+            renderer,
+            theme,
+            style,
+            submit_button_layout,
+            cursor,
+            &bounds,
+        );
+
+        // Buttons are not focusable right now...
+        if self.state.focus == Focus::Cancel {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: cancel_button_layout.bounds(),
+                    border: Border {
+                        radius: style_sheet[&StyleState::Focused].border_radius.into(),
+                        width: style_sheet[&StyleState::Focused].border_width,
+                        color: style_sheet[&StyleState::Focused].border_color,
+                    },
+                    shadow: Shadow::default(),
+                },
+                Color::TRANSPARENT,
+            );
+        }
+
+        if self.state.focus == Focus::Submit {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: submit_button_layout.bounds(),
+                    border: Border {
+                        radius: style_sheet[&StyleState::Focused].border_radius.into(),
+                        width: style_sheet[&StyleState::Focused].border_width,
+                        color: style_sheet[&StyleState::Focused].border_color,
+                    },
+                    shadow: Shadow::default(),
+                },
+                Color::TRANSPARENT,
+            );
+        }
+    }
+}
+
+/// The panel of the color picker shared by the [`ColorPickerOverlay`] and the inline
+/// color picker: the HSV block, the RGBA block and, if the picker is large enough, the hex
+/// field next to the comparison of the initial and the picked color and the fields of the
+/// channels with the button switching them.
+///
+/// Its trees are the one of the RGBA bars, the one of the hex field, the ones of the
+/// fields of the four channels and the one of the mode button, see [`Panel::children`].
+pub(crate) struct Panel<'a, Theme>
+where
+    Theme: StyleSheet + button::StyleSheet + text_input::StyleSheet,
+{
+    /// The text field to type the hex code into.
+    hex_input: TextInput<'a, HexInput, Theme, Renderer>,
+    /// The text fields to type the values of the channels into.
+    channel_inputs: Vec<TextInput<'a, ChannelInput, Theme, Renderer>>,
+    /// The button switching the channels between RGB and HSV.
+    mode_button: Button<'a, ChannelInput, Theme, Renderer>,
+    /// The spacing and the sizes of the panel.
+    metrics: Metrics,
+}
+
+impl<'a, Theme> Panel<'a, Theme>
+where
+    Theme: 'a + StyleSheet + button::StyleSheet + widget::text::StyleSheet + text_input::StyleSheet,
+{
+    /// Creates the panel showing the given state.
+    pub(crate) fn new(state: &State, metrics: Metrics) -> Self {
+        Self {
+            hex_input: hex_input(&state.hex_text(), metrics),
+            channel_inputs: channel_inputs(state, metrics),
+            mode_button: mode_button(state.channel_mode, metrics),
+            metrics,
+        }
+    }
+
+    /// The trees of the panel.
+    pub(crate) fn children(&self) -> Vec<Tree> {
+        let mut children = vec![
+            Tree::empty(),
+            Tree::new(&self.hex_input as &dyn Widget<HexInput, Theme, Renderer>),
+        ];
+        children.extend(self.channel_inputs.iter().map(|channel_input| {
+            Tree::new(channel_input as &dyn Widget<ChannelInput, Theme, Renderer>)
+        }));
+        children.push(Tree::new(
+            &self.mode_button as &dyn Widget<ChannelInput, Theme, Renderer>,
+        ));
+        children
+    }
+
+    /// Reconciles the given trees of the panel with its text fields.
+    pub(crate) fn diff(&self, trees: &mut [Tree]) {
+        trees[1].diff(&self.hex_input as &dyn Widget<HexInput, Theme, Renderer>);
+        for (channel, channel_input) in self.channel_inputs.iter().enumerate() {
+            trees[2 + channel].diff(channel_input as &dyn Widget<ChannelInput, Theme, Renderer>);
+        }
+        trees[6].diff(&self.mode_button as &dyn Widget<ChannelInput, Theme, Renderer>);
+    }
+
+    /// Lays out the panel within the given limits, leaving `footer` space below the fields
+    /// of the channels.
+    ///
+    /// The blocks are placed side by side if `horizontal`, else above each other. The 2.
+    /// block has a last child holding the text fields, in place of the hex text.
+    pub(crate) fn layout(
+        &self,
+        trees: &mut [Tree],
+        renderer: &Renderer,
+        limits: Limits,
+        horizontal: bool,
+        footer: f32,
+        state: &State,
+    ) -> Node {
+        let Metrics {
+            padding,
+            spacing,
+            button_spacing,
+            ..
+        } = self.metrics;
+
+        let channel_height = Widget::<ChannelInput, Theme, Renderer>::layout(
+            &self.channel_inputs[0],
+            &mut trees[2],
+            renderer,
+            &limits,
+        )
+        .bounds()
+        .height;
+
+        // Small pickers have no row for the fields of the channels
+        let channel_row_height = if state.has_text_fields() {
+            channel_height + spacing
+        } else {
+            0.0
+        };
+
+        let panel = panel_layout::<Theme>(
+            &mut trees[0],
+            renderer,
+            limits,
+            horizontal,
+            channel_row_height + footer,
+            state,
+        );
+
+        let mut panel_children = panel.children().iter();
+        let block1_node = panel_children
+            .next()
+            .expect("Panel should have a first block")
+            .clone();
+        let block2_node = panel_children
+            .next()
+            .expect("Panel should have a second block");
+
+        let mut block2_children = block2_node.children().iter();
+        let rgba_colors = block2_children
+            .next()
+            .expect("Second block should have a RGBA color node")
+            .clone();
+        let hex_text_layout = block2_children
+            .next()
+            .expect("Second block should have a hex text node")
+            .clone();
+        let rgba_bounds = rgba_colors.bounds();
+        let hex_bounds = hex_text_layout.bounds();
+
+        // ----------- Channels ----------------------
+        let channels_y = rgba_bounds.height + hex_bounds.height + padding + 2.0 * spacing;
+
+        let mode_button = self.mode_button.layout(&mut trees[6], renderer, &limits);
+        let mode_button_width = mode_button.bounds().width;
+        let mode_button = mode_button.move_to(Point::new(padding, channels_y));
+
+        let channel_count = state.channel_count();
+        let channel_width =
+            ((rgba_bounds.width - mode_button_width - channel_count as f32 * button_spacing)
+                / channel_count as f32)
+                .max(0.0);
+        let channel_limits =
+            Limits::new(Size::ZERO, Size::new(channel_width, channel_height)).width(Length::Fill);
+
+        let channel_inputs = (0..channel_count).map(|channel| {
+            Widget::<ChannelInput, Theme, Renderer>::layout(
+                &self.channel_inputs[channel],
+                &mut trees[2 + channel],
+                renderer,
+                &channel_limits,
+            )
+            .move_to(Point::new(
+                padding
+                    + mode_button_width
+                    + button_spacing
+                    + channel as f32 * (channel_width + button_spacing),
+                channels_y,
+            ))
+        });
+        let channel_inputs: Vec<Node> = channel_inputs.collect();
+
+        // ----------- Hex field ----------------------
+        // The hex field takes the place of the hex text, next to the comparison of the
+        // initial and the picked color
+        let comparison_width = 2.0 * hex_bounds.height;
+        let hex_input = Widget::<HexInput, Theme, Renderer>::layout(
+            &self.hex_input,
+            &mut trees[1],
+            renderer,
+            &Limits::new(
+                Size::ZERO,
+                Size::new(
+                    (hex_bounds.width - comparison_width - button_spacing).max(0.0),
+                    hex_bounds.height,
+                ),
+            )
+            .width(Length::Fill),
+        );
+        let hex_input = hex_input.clone().move_to(Point::new(
+            hex_bounds.x,
+            hex_bounds.center_y() - hex_input.bounds().height / 2.0,
+        ));
+        let comparison =
+            Node::new(Size::new(comparison_width, hex_input.bounds().height)).move_to(Point::new(
+                hex_bounds.x + hex_bounds.width - comparison_width,
+                hex_input.bounds().y,
+            ));
+
+        let mut fields = vec![hex_input, comparison, mode_button];
+        fields.extend(channel_inputs);
+        let fields = Node::with_children(block2_node.size(), fields);
+
+        let block2_node = Node::with_children(
+            block2_node.size(),
+            vec![rgba_colors, hex_text_layout, fields],
+        )
+        .move_to(block2_node.bounds().position());
+
+        Node::with_children(panel.size(), vec![block1_node, block2_node])
+    }
+
+    /// Handles the events of the panel within its given layout, those of the text fields
+    /// first.
+    ///
+    /// The text typed into the text fields is kept until the color is changed otherwise.
+    /// `buttons` tells if the submit and cancel buttons can be focused.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn on_event(
+        &mut self,
+        state: &mut State,
+        trees: &mut [Tree],
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        buttons: bool,
+    ) -> event::Status {
+        // Tab moves between the fields of the channels and out of the hex field
+        if event::Status::Captured == on_event_tab(state, trees, event, buttons) {
+            let color = state.color;
+            self.sync(state, trees, color);
+            return event::Status::Captured;
+        }
+        let focus = state.focus;
+
+        // ----------- Text fields ---------------------
+        let fields_layout = fields_layout(layout);
+        let hex_input_layout = fields_layout
+            .children()
             .next()
-            .expect("Graphics: Layout should have a 1. block layout");
-        block1(renderer, self, block1_layout, cursor, &style_sheet);
+            .expect("Native: Layout should have a hex field layout for a ColorPicker");
+        // Small pickers have no text fields
+        let fields_status = if state.has_text_fields() {
+            self.on_event_hex_input(
+                state,
+                trees,
+                event,
+                hex_input_layout,
+                cursor,
+                renderer,
+                clipboard,
+            )
+            .merge(self.on_event_channel_inputs(
+                state,
+                trees,
+                event,
+                fields_layout,
+                cursor,
+                renderer,
+                clipboard,
+            ))
+        } else {
+            event::Status::Ignored
+        };
+        if is_text_input_focused(&trees[1]) {
+            state.focus = Focus::Hex;
+        }
+
+        let color = state.color;
+
+        // The keys edit the text fields while they are focused
+        let panel_status = if is_any_text_input_focused(state, trees)
+            && matches!(
+                event,
+                Event::Keyboard(
+                    keyboard::Event::KeyPressed { .. } | keyboard::Event::KeyReleased { .. }
+                )
+            ) {
+            event::Status::Ignored
+        } else {
+            on_event_panel(state, event, layout, cursor, buttons)
+        };
+
+        // Tab moves into the hex field
+        if state.focus == Focus::Hex && focus != Focus::Hex {
+            let hex_input_state = text_input_state(&mut trees[1]);
+            hex_input_state.focus();
+            hex_input_state.select_all();
+        }
+
+        // ----------- Comparison ----------------------
+        let comparison_layout = fields_layout
+            .children()
+            .nth(1)
+            .expect("Native: Layout should have a comparison layout for a ColorPicker");
+        let comparison_status = if state.has_text_fields() {
+            on_event_comparison(state, event, comparison_layout, cursor)
+        } else {
+            event::Status::Ignored
+        };
+
+        self.sync(state, trees, color);
+
+        fields_status.merge(panel_status).merge(comparison_status)
+    }
+
+    /// The event handling for the hex field.
+    #[allow(clippy::too_many_arguments)]
+    fn on_event_hex_input(
+        &mut self,
+        state: &mut State,
+        trees: &mut [Tree],
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+    ) -> event::Status {
+        let mut messages = Vec::new();
+
+        let status = Widget::<HexInput, Theme, Renderer>::on_event(
+            &mut self.hex_input,
+            &mut trees[1],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            &mut Shell::new(&mut messages),
+            &layout.bounds(),
+        );
+
+        for HexInput::Changed(text) in messages {
+            // A valid hex code changes the color right away
+            if let Some(color) = color::parse_hex(&text, state.show_alpha) {
+                state.set_color(color);
+            }
+            state.typed_hex = Some(text);
+        }
+
+        status
+    }
+
+    /// The event handling for the fields of the channels and the button switching them,
+    /// within the given layout of the text fields.
+    #[allow(clippy::too_many_arguments)]
+    fn on_event_channel_inputs(
+        &mut self,
+        state: &mut State,
+        trees: &mut [Tree],
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+    ) -> event::Status {
+        let mut messages = Vec::new();
+        let mut children = layout.children().skip(2);
+
+        let mode_button_layout = children
+            .next()
+            .expect("Native: Layout should have a mode button layout for a ColorPicker");
+        let mut status = self.mode_button.on_event(
+            &mut trees[6],
+            event.clone(),
+            mode_button_layout,
+            cursor,
+            renderer,
+            clipboard,
+            &mut Shell::new(&mut messages),
+            &layout.bounds(),
+        );
+
+        for (channel, channel_input_layout) in children.enumerate() {
+            status = status.merge(Widget::<ChannelInput, Theme, Renderer>::on_event(
+                &mut self.channel_inputs[channel],
+                &mut trees[2 + channel],
+                event.clone(),
+                channel_input_layout,
+                cursor,
+                renderer,
+                clipboard,
+                &mut Shell::new(&mut messages),
+                &layout.bounds(),
+            ));
+        }
+
+        for message in messages {
+            match message {
+                // A valid value changes the color right away, clamped to its range
+                ChannelInput::Changed(channel, text) => {
+                    let mode = state.channel_mode;
+                    if let Some(value) = mode.parse(&text, channel) {
+                        state.set_color(mode.with_value(state.color, channel, value));
+                    }
+                    state.typed_channel = Some((channel, text));
+                }
+                // The color stays the same, only its channels are shown otherwise
+                ChannelInput::ToggleMode => {
+                    state.channel_mode = state.channel_mode.toggled();
+                    state.typed_channel = None;
+                }
+            }
+        }
+
+        status
+    }
+
+    /// Shows the color in the text fields again once it's changed with something else
+    /// than them, or once they're left.
+    ///
+    /// It takes the color picked before.
+    fn sync(&mut self, state: &mut State, trees: &[Tree], color: Color) {
+        let changed = state.color != color;
+
+        if changed || !is_text_input_focused(&trees[1]) {
+            state.typed_hex = None;
+        }
+        if changed || focused_channel_input(state, trees) != state.typed_channel() {
+            state.typed_channel = None;
+        }
+
+        *self = Self::new(state, self.metrics);
+    }
+
+    /// The mouse interaction of the panel within its given layout.
+    pub(crate) fn mouse_interaction(
+        &self,
+        state: &State,
+        trees: &[Tree],
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let panel_mouse_interaction = panel_mouse_interaction(layout, cursor);
+
+        if !state.has_text_fields() {
+            return panel_mouse_interaction;
+        }
+
+        let mut fields_children = fields_layout(layout).children();
+
+        let hex_input_layout = fields_children
+            .next()
+            .expect("Graphics: Layout should have a hex field layout for a ColorPicker");
+        let hex_input_mouse_interaction = Widget::<HexInput, Theme, Renderer>::mouse_interaction(
+            &self.hex_input,
+            &trees[1],
+            hex_input_layout,
+            cursor,
+            viewport,
+            renderer,
+        );
+
+        // The initial color can be picked again
+        let comparison_layout = fields_children
+            .next()
+            .expect("Graphics: Layout should have a comparison layout for a ColorPicker");
+        let comparison_mouse_interaction =
+            if cursor.is_over(initial_half(comparison_layout.bounds())) {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::default()
+            };
+
+        let mode_button_layout = fields_children
+            .next()
+            .expect("Graphics: Layout should have a mode button layout for a ColorPicker");
+        let mode_mouse_interaction = self.mode_button.mouse_interaction(
+            &trees[6],
+            mode_button_layout,
+            cursor,
+            viewport,
+            renderer,
+        );
+
+        let channel_mouse_interaction = fields_children
+            .enumerate()
+            .map(|(channel, channel_input_layout)| {
+                Widget::<ChannelInput, Theme, Renderer>::mouse_interaction(
+                    &self.channel_inputs[channel],
+                    &trees[2 + channel],
+                    channel_input_layout,
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default();
+
+        panel_mouse_interaction
+            .max(hex_input_mouse_interaction)
+            .max(comparison_mouse_interaction)
+            .max(mode_mouse_interaction)
+            .max(channel_mouse_interaction)
+    }
+
+    /// Draws the background and the panel within its given layout.
+    ///
+    /// The fields whose typed text isn't valid are outlined with the `invalid` appearance.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn draw(
+        &self,
+        state: &State,
+        trees: &[Tree],
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        style_sheet: &HashMap<StyleState, Appearance>,
+        invalid: &Appearance,
+    ) {
+        let bounds = layout.bounds();
+
+        // Small pickers show the hex text instead of the text fields
+        draw_panel(
+            renderer,
+            state,
+            layout,
+            cursor,
+            style,
+            style_sheet,
+            !state.has_text_fields(),
+        );
+
+        if !state.has_text_fields() {
+            return;
+        }
+
+        let mut fields_children = fields_layout(layout).children();
+
+        // ----------- Hex field -----------------------
+        let hex_input_layout = fields_children
+            .next()
+            .expect("Graphics: Layout should have a hex field layout for a ColorPicker");
+
+        Widget::<HexInput, Theme, Renderer>::draw(
+            &self.hex_input,
+            &trees[1],
+            renderer,
+            theme,
+            style,
+            hex_input_layout,
+            cursor,
+            &bounds,
+        );
+
+        let draw_invalid = |renderer: &mut Renderer, bounds: Rectangle| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border {
+                        radius: invalid.bar_border_radius.into(),
+                        width: invalid.bar_border_width,
+                        color: invalid.bar_border_color,
+                    },
+                    shadow: Shadow::default(),
+                },
+                Color::TRANSPARENT,
+            );
+        };
+
+        if state.is_typed_hex_invalid() {
+            draw_invalid(renderer, hex_input_layout.bounds());
+        }
+
+        // ----------- Comparison ----------------------
+        let comparison_layout = fields_children
+            .next()
+            .expect("Graphics: Layout should have a comparison layout for a ColorPicker");
+        self::comparison(renderer, state, comparison_layout, cursor, style_sheet);
+
+        // ----------- Channels ------------------------
+        let mode_button_layout = fields_children
+            .next()
+            .expect("Graphics: Layout should have a mode button layout for a ColorPicker");
+
+        self.mode_button.draw(
+            &trees[6],
+            renderer,
+            theme,
+            style,
+            mode_button_layout,
+            cursor,
+            &bounds,
+        );
+
+        for (channel, channel_input_layout) in fields_children.enumerate() {
+            Widget::<ChannelInput, Theme, Renderer>::draw(
+                &self.channel_inputs[channel],
+                &trees[2 + channel],
+                renderer,
+                theme,
+                style,
+                channel_input_layout,
+                cursor,
+                &bounds,
+            );
+
+            if state.is_typed_channel_invalid(channel) {
+                draw_invalid(renderer, channel_input_layout.bounds());
+            }
+        }
+    }
+}
+
+/// The layout of the text fields within the given layout of the panel.
+fn fields_layout(layout: Layout<'_>) -> Layout<'_> {
+    layout
+        .children()
+        .nth(1)
+        .expect("Layout should have a 2. block layout")
+        .children()
+        .nth(2)
+        .expect("Layout should have a text fields layout for a ColorPicker")
+}
+
+/// Moves the keyboard focus from a field of the channels to the next one on tab, or
+/// to the previous one with shift, within the given trees of the panel.
+///
+/// Tab leaves the hex field for the controls next to it, `buttons` telling if the submit
+/// and cancel buttons can be focused.
+fn on_event_tab(
+    state: &mut State,
+    trees: &mut [Tree],
+    event: &Event,
+    buttons: bool,
+) -> event::Status {
+    let Event::Keyboard(keyboard::Event::KeyPressed {
+        key: keyboard::Key::Named(keyboard::key::Named::Tab),
+        modifiers,
+        ..
+    }) = event
+    else {
+        return event::Status::Ignored;
+    };
+
+    if is_text_input_focused(&trees[1]) {
+        text_input_state(&mut trees[1]).unfocus();
+        state.focus = Focus::Hex;
+        state.step_focus(modifiers.shift(), buttons);
+        return event::Status::Captured;
+    }
+
+    let Some(channel) = focused_channel_input(state, trees) else {
+        return event::Status::Ignored;
+    };
+
+    let count = state.channel_count();
+    let next = if modifiers.shift() {
+        (channel + count - 1) % count
+    } else {
+        (channel + 1) % count
+    };
+
+    text_input_state(&mut trees[2 + channel]).unfocus();
+    let next_state = text_input_state(&mut trees[2 + next]);
+    next_state.focus();
+    next_state.select_all();
+
+    event::Status::Captured
+}
+
+/// Checks if any text field has the keyboard focus, within the given trees of the panel.
+fn is_any_text_input_focused(state: &State, trees: &[Tree]) -> bool {
+    is_text_input_focused(&trees[1]) || focused_channel_input(state, trees).is_some()
+}
+
+/// The field of the channels having the keyboard focus, if any, within the given trees
+/// of the panel.
+fn focused_channel_input(state: &State, trees: &[Tree]) -> Option<usize> {
+    (0..state.channel_count()).find(|&channel| is_text_input_focused(&trees[2 + channel]))
+}
+
+/// Checks if the text field with the given tree has the keyboard focus.
+fn is_text_input_focused(tree: &Tree) -> bool {
+    tree.state
+        .downcast_ref::<text_input::State<<Renderer as text::Renderer>::Paragraph>>()
+        .is_focused()
+}
+
+/// The state of the text field with the given tree.
+fn text_input_state(
+    tree: &mut Tree,
+) -> &mut text_input::State<<Renderer as text::Renderer>::Paragraph> {
+    tree.state
+        .downcast_mut::<text_input::State<<Renderer as text::Renderer>::Paragraph>>()
+}
+
+/// Lays out the panel of the color picker within the given limits: the HSV block and the
+/// RGBA block with the hex text, leaving `footer` space below the hex text.
+///
+/// The blocks are placed side by side if `horizontal`, else above each other. The alpha
+/// bar is left out while hidden, the panel being shorter by its height, and the swatches
+/// of the palette and of the recent colors are placed below the hue bar.
+fn panel_layout<Theme>(
+    rgba_tree: &mut Tree,
+    renderer: &Renderer,
+    limits: Limits,
+    horizontal: bool,
+    footer: f32,
+    state: &State,
+) -> Node
+where
+    Theme: widget::text::StyleSheet,
+{
+    let metrics = state.metrics(renderer);
+    let divider: Element<(), Theme, Renderer> = if horizontal {
+        Row::new()
+            .spacing(metrics.spacing)
+            .push(Row::new().width(Length::Fill).height(Length::Fill))
+            .push(Row::new().width(Length::Fill).height(Length::Fill))
+            .into()
+    } else {
+        Column::new()
+            .spacing(metrics.spacing)
+            .push(Row::new().width(Length::Fill).height(Length::Fill))
+            .push(Row::new().width(Length::Fill).height(Length::Fill))
+            .into()
+    };
+    let divider = divider
+        .as_widget()
+        .layout(&mut Tree::new(&divider), renderer, &limits);
+
+    let mut divider_children = divider.children().iter();
+
+    let block1_bounds = divider_children
+        .next()
+        .expect("Divider should have a first child")
+        .bounds();
+    let block2_bounds = divider_children
+        .next()
+        .expect("Divider should have a second child")
+        .bounds();
+
+    // ----------- Block 2 ----------------------
+    let block2_node = block2_layout::<Theme>(
+        rgba_tree,
+        renderer,
+        block2_bounds,
+        footer,
+        state.show_alpha,
+        metrics,
+    );
+
+    // ----------- Block 1 ----------------------
+    // Side by side, the HSV block is as high as the RGBA block
+    let block1_bounds = if horizontal {
+        Rectangle {
+            height: block2_node.size().height,
+            ..block1_bounds
+        }
+    } else {
+        block1_bounds
+    };
+    let block1_node = block1_layout::<Theme>(
+        renderer,
+        block1_bounds,
+        state.palette.len(),
+        state.recent.len(),
+        metrics,
+    );
+
+    let (width, height) = if horizontal {
+        (
+            block1_node.size().width + block2_node.size().width + metrics.spacing, // + (2.0 * PADDING as f32),
+            block2_node.size().height,
+        )
+    } else {
+        (
+            block2_node.size().width,
+            block1_node.size().height + block2_node.size().height + metrics.spacing,
+        )
+    };
+
+    Node::with_children(Size::new(width, height), vec![block1_node, block2_node])
+}
+
+/// Handles the events of the panel of the color picker.
+///
+/// `buttons` tells if the submit and cancel buttons can be focused.
+fn on_event_panel(
+    state: &mut State,
+    event: &Event,
+    layout: Layout<'_>,
+    cursor: Cursor,
+    buttons: bool,
+) -> event::Status {
+    if event::Status::Captured == on_event_keyboard(state, event, buttons) {
+        return event::Status::Captured;
+    }
+
+    let mut children = layout.children();
+
+    // ----------- Block 1 ----------------------
+    let block1_layout = children
+        .next()
+        .expect("Native: Layout should have a 1. block layout");
+    let hsv_color_status = on_event_hsv_color(state, event, block1_layout, cursor);
+    let palette_status = block1_layout
+        .children()
+        .nth(2)
+        .map_or(event::Status::Ignored, |palette_layout| {
+            on_event_palette(state, event, palette_layout, cursor)
+        });
+    // ----------- Block 1 end ------------------
+
+    // ----------- Block 2 ----------------------
+    let rgba_color_layout = children
+        .next()
+        .expect("Native: Layout should have a 2. block layout")
+        .children()
+        .next()
+        .expect("Native: Layout should have a RGBA color layout");
+    let rgba_color_status = on_event_rgba_color(state, event, rgba_color_layout, cursor);
+    // ----------- Block 2 end ------------------
+
+    hsv_color_status
+        .merge(palette_status)
+        .merge(rgba_color_status)
+}
+
+/// The mouse interaction of the panel of the color picker.
+fn panel_mouse_interaction(layout: Layout<'_>, cursor: Cursor) -> mouse::Interaction {
+    let mut children = layout.children();
+
+    let mouse_interaction = mouse::Interaction::default();
+
+    // Block 1
+    let block1_layout = children
+        .next()
+        .expect("Graphics: Layout should have a 1. block layout");
+    let mut block1_mouse_interaction = mouse::Interaction::default();
+    // HSV color
+    let mut hsv_color_children = block1_layout.children();
+    let sat_value_layout = hsv_color_children
+        .next()
+        .expect("Graphics: Layout should have a sat/value layout");
+    if cursor.is_over(sat_value_layout.bounds()) {
+        block1_mouse_interaction = block1_mouse_interaction.max(mouse::Interaction::Pointer);
+    }
+    let hue_layout = hsv_color_children
+        .next()
+        .expect("Graphics: Layout should have a hue layout");
+    if cursor.is_over(hue_layout.bounds()) {
+        block1_mouse_interaction = block1_mouse_interaction.max(mouse::Interaction::Pointer);
+    }
+    // Palette
+    if let Some(palette_layout) = hsv_color_children.next() {
+        if palette_layout
+            .children()
+            .any(|swatch| cursor.is_over(swatch.bounds()))
+        {
+            block1_mouse_interaction = block1_mouse_interaction.max(mouse::Interaction::Pointer);
+        }
+    }
+
+    // Block 2
+    let block2_layout = children
+        .next()
+        .expect("Graphics: Layout should have a 2. block layout");
+    let mut block2_mouse_interaction = mouse::Interaction::default();
+    let mut block2_children = block2_layout.children();
+    // RGBA color
+    let rgba_color_layout = block2_children
+        .next()
+        .expect("Graphics: Layout should have a RGBA color layout");
+    let rgba_color_children = rgba_color_layout.children();
+
+    let f = |layout: Layout<'_>, cursor: Cursor| {
+        let mut children = layout.children();
+
+        let _label_layout = children.next();
+        let bar_layout = children
+            .next()
+            .expect("Graphics: Layout should have a bar layout");
+
+        if cursor.is_over(bar_layout.bounds()) {
+            mouse::Interaction::ResizingHorizontally
+        } else {
+            mouse::Interaction::default()
+        }
+    };
+    // Red, green, blue and alpha rows
+    for row_layout in rgba_color_children {
+        block2_mouse_interaction = block2_mouse_interaction.max(f(row_layout, cursor));
+    }
+
+    mouse_interaction
+        .max(block1_mouse_interaction)
+        .max(block2_mouse_interaction)
+}
+
+/// The appearances of the color picker for each [`StyleState`].
+pub(crate) fn style_sheet<Theme>(
+    theme: &Theme,
+    style: &<Theme as StyleSheet>::Style,
+) -> HashMap<StyleState, Appearance>
+where
+    Theme: StyleSheet,
+{
+    let mut style_sheet: HashMap<StyleState, Appearance> = HashMap::new();
+    let _ = style_sheet.insert(StyleState::Active, StyleSheet::active(theme, style));
+    let _ = style_sheet.insert(StyleState::Selected, StyleSheet::selected(theme, style));
+    let _ = style_sheet.insert(StyleState::Hovered, StyleSheet::hovered(theme, style));
+    let _ = style_sheet.insert(StyleState::Focused, StyleSheet::focused(theme, style));
+    style_sheet
+}
+
+/// Draws the background and the panel of the color picker.
+///
+/// The hex text is left out without `hex_text`, when a hex field is drawn in its place.
+fn draw_panel(
+    renderer: &mut Renderer,
+    state: &State,
+    layout: Layout<'_>,
+    cursor: Cursor,
+    style: &renderer::Style,
+    style_sheet: &HashMap<StyleState, Appearance>,
+    hex_text: bool,
+) {
+    let bounds = layout.bounds();
+    let mut children = layout.children();
+
+    let mut style_state = StyleState::Active;
+    if state.focus == Focus::Overlay {
+        style_state = style_state.max(StyleState::Focused);
+    }
+    if cursor.is_over(bounds) {
+        style_state = style_state.max(StyleState::Hovered);
+    }
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border: Border {
+                radius: style_sheet[&style_state].border_radius.into(),
+                width: style_sheet[&style_state].border_width,
+                color: style_sheet[&style_state].border_color,
+            },
+            shadow: Shadow::default(),
+        },
+        style_sheet[&style_state].background,
+    );
+
+    // ----------- Block 1 ----------------------
+    let block1_layout = children
+        .next()
+        .expect("Graphics: Layout should have a 1. block layout");
+    hsv_color(renderer, state, block1_layout, cursor, style_sheet);
+    if let Some(palette_layout) = block1_layout.children().nth(2) {
+        palette(renderer, state, palette_layout, cursor, style_sheet);
+    }
+
+    // ----------- Block 2 ----------------------
+    let mut block2_children = children
+        .next()
+        .expect("Graphics: Layout should have a 2. block layout")
+        .children();
+
+    // ----------- RGBA Color ----------------------
+    let rgba_color_layout = block2_children
+        .next()
+        .expect("Graphics: Layout should have a RGBA color layout");
+    rgba_color(
+        renderer,
+        rgba_color_layout,
+        state,
+        cursor,
+        style,
+        style_sheet,
+    );
+
+    // ----------- Hex text ----------------------
+    let hex_text_layout = block2_children
+        .next()
+        .expect("Graphics: Layout should have a hex text layout");
+    if hex_text {
+        self::hex_text(
+            renderer,
+            hex_text_layout,
+            state,
+            cursor,
+            style,
+            style_sheet,
+            state.focus,
+        );
+    }
+}
+
+/// The event handling for the comparison of the initial and the picked color: clicking
+/// the initial color picks it again.
+fn on_event_comparison(
+    state: &mut State,
+    event: &Event,
+    layout: Layout<'_>,
+    cursor: Cursor,
+) -> event::Status {
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. })
+            if cursor.is_over(initial_half(layout.bounds())) =>
+        {
+            state.set_color(state.initial_color);
+            event::Status::Captured
+        }
+        _ => event::Status::Ignored,
+    }
+}
+
+/// The left half of the comparison with the given bounds, showing the initial color.
+fn initial_half(bounds: Rectangle) -> Rectangle {
+    Rectangle {
+        width: bounds.width / 2.0,
+        ..bounds
+    }
+}
+
+/// The event handling for the swatches of the palette and of the recent colors.
+fn on_event_palette(
+    state: &mut State,
+    event: &Event,
+    layout: Layout<'_>,
+    cursor: Cursor,
+) -> event::Status {
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) => {
+            let swatch = layout
+                .children()
+                .position(|swatch| cursor.is_over(swatch.bounds()));
+
+            let color = swatch.and_then(|index| state.swatches().nth(index));
+
+            color.map_or(event::Status::Ignored, |color| {
+                state.set_color(color);
+                state.focus = Focus::Palette;
+                event::Status::Captured
+            })
+        }
+        _ => event::Status::Ignored,
+    }
+}
+
+/// The event handling for the HSV color area.
+fn on_event_hsv_color(
+    state: &mut State,
+    event: &Event,
+    layout: Layout<'_>,
+    cursor: Cursor,
+) -> event::Status {
+    let mut hsv_color_children = layout.children();
+
+    let model = state.model;
+    let coordinates = model.coordinates(state.color);
+    let mut color_changed = false;
+
+    let sat_value_bounds = hsv_color_children
+        .next()
+        .expect("Native: Layout should have a sat/value layout")
+        .bounds();
+    let hue_bounds = hsv_color_children
+        .next()
+        .expect("Native: Layout should have a hue layout")
+        .bounds();
+
+    match event {
+        Event::Mouse(mouse::Event::WheelScrolled { delta }) => match delta {
+            mouse::ScrollDelta::Lines { y, .. } | mouse::ScrollDelta::Pixels { y, .. } => {
+                if cursor.is_over(hue_bounds) {
+                    state.color = Color {
+                        a: state.color.a,
+                        ..model.color(Coordinates {
+                            hue: (coordinates.hue + y.trunc()).rem_euclid(360.0),
+                            ..coordinates
+                        })
+                    };
+                    color_changed = true;
+                }
+            }
+        },
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) => {
+            if cursor.is_over(sat_value_bounds) {
+                state.color_bar_dragged = ColorBarDragged::SatValue;
+                state.focus = Focus::SatValue;
+            }
+            if cursor.is_over(hue_bounds) {
+                state.color_bar_dragged = ColorBarDragged::Hue;
+                state.focus = Focus::Hue;
+            }
+        }
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+            state.color_bar_dragged = ColorBarDragged::None;
+        }
+        _ => {}
+    }
+
+    let calc_percentage_sat =
+        |cursor_position: Point| (cursor_position.x.max(0.0) / sat_value_bounds.width).min(1.0);
+
+    let calc_percentage_value =
+        |cursor_position: Point| (cursor_position.y.max(0.0) / sat_value_bounds.height).min(1.0);
+
+    let calc_hue =
+        |cursor_position: Point| (cursor_position.x.max(0.0) / hue_bounds.width).min(1.0) * 360.0;
+
+    match state.color_bar_dragged {
+        ColorBarDragged::SatValue => {
+            state.color = Color {
+                a: state.color.a,
+                ..model.color(Coordinates {
+                    x: cursor
+                        .position_in(sat_value_bounds)
+                        .map(calc_percentage_sat)
+                        .unwrap_or_default(),
+                    y: cursor
+                        .position_in(sat_value_bounds)
+                        .map(calc_percentage_value)
+                        .unwrap_or_default(),
+                    ..coordinates
+                })
+            };
+            color_changed = true;
+        }
+        ColorBarDragged::Hue => {
+            state.color = Color {
+                a: state.color.a,
+                ..model.color(Coordinates {
+                    hue: cursor
+                        .position_in(hue_bounds)
+                        .map(calc_hue)
+                        .unwrap_or_default(),
+                    ..coordinates
+                })
+            };
+            color_changed = true;
+        }
+        _ => {}
+    }
 
-        // ----------- Block 2 ----------------------
-        let block2_layout = children
-            .next()
-            .expect("Graphics: Layout should have a 2. block layout");
-        block2(
-            renderer,
-            self,
-            block2_layout,
-            cursor,
-            theme,
-            style,
-            &bounds,
-            &style_sheet,
-        );
+    if color_changed {
+        event::Status::Captured
+    } else {
+        event::Status::Ignored
+    }
+}
+
+/// The event handling for the RGBA color area.
+#[allow(clippy::too_many_lines)]
+fn on_event_rgba_color(
+    state: &mut State,
+    event: &Event,
+    layout: Layout<'_>,
+    cursor: Cursor,
+) -> event::Status {
+    let mut rgba_color_children = layout.children();
+    let mut color_changed = false;
+
+    let mut red_row_children = rgba_color_children
+        .next()
+        .expect("Native: Layout should have a red row layout")
+        .children();
+    let _ = red_row_children.next();
+    let red_bar_bounds = red_row_children
+        .next()
+        .expect("Native: Layout should have a red bar layout")
+        .bounds();
+
+    let mut green_row_children = rgba_color_children
+        .next()
+        .expect("Native: Layout should have a green row layout")
+        .children();
+    let _ = green_row_children.next();
+    let green_bar_bounds = green_row_children
+        .next()
+        .expect("Native: Layout should have a green bar layout")
+        .bounds();
+
+    let mut blue_row_children = rgba_color_children
+        .next()
+        .expect("Native: Layout should have a blue row layout")
+        .children();
+    let _ = blue_row_children.next();
+    let blue_bar_bounds = blue_row_children
+        .next()
+        .expect("Native: Layout should have a blue bar layout")
+        .bounds();
+
+    // The alpha bar is hidden while only opaque colors are picked
+    let alpha_bar_bounds = rgba_color_children.next().map(|alpha_row| {
+        alpha_row
+            .children()
+            .nth(1)
+            .expect("Native: Layout should have an alpha bar layout")
+            .bounds()
+    });
+    let is_over_alpha_bar = alpha_bar_bounds.is_some_and(|bounds| cursor.is_over(bounds));
+
+    match event {
+        Event::Mouse(mouse::Event::WheelScrolled { delta }) => match delta {
+            mouse::ScrollDelta::Lines { y, .. } | mouse::ScrollDelta::Pixels { y, .. } => {
+                let move_value =
+                    //|value: f32, y: f32| (value * 255.0 + y).clamp(0.0, 255.0) / 255.0;
+                    |value: f32, y: f32| value.mul_add(255.0, y).clamp(0.0, 255.0) / 255.0;
+
+                if cursor.is_over(red_bar_bounds) {
+                    state.color = Color {
+                        r: move_value(state.color.r, *y),
+                        ..state.color
+                    };
+                    color_changed = true;
+                }
+                if cursor.is_over(green_bar_bounds) {
+                    state.color = Color {
+                        g: move_value(state.color.g, *y),
+                        ..state.color
+                    };
+                    color_changed = true;
+                }
+                if cursor.is_over(blue_bar_bounds) {
+                    state.color = Color {
+                        b: move_value(state.color.b, *y),
+                        ..state.color
+                    };
+                    color_changed = true;
+                }
+                if is_over_alpha_bar {
+                    state.color = Color {
+                        a: move_value(state.color.a, *y),
+                        ..state.color
+                    };
+                    color_changed = true;
+                }
+            }
+        },
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) => {
+            if cursor.is_over(red_bar_bounds) {
+                state.color_bar_dragged = ColorBarDragged::Red;
+                state.focus = Focus::Red;
+            }
+            if cursor.is_over(green_bar_bounds) {
+                state.color_bar_dragged = ColorBarDragged::Green;
+                state.focus = Focus::Green;
+            }
+            if cursor.is_over(blue_bar_bounds) {
+                state.color_bar_dragged = ColorBarDragged::Blue;
+                state.focus = Focus::Blue;
+            }
+            if is_over_alpha_bar {
+                state.color_bar_dragged = ColorBarDragged::Alpha;
+                state.focus = Focus::Alpha;
+            }
+        }
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+            state.color_bar_dragged = ColorBarDragged::None;
+        }
+        _ => {}
+    }
+
+    let calc_percentage = |bounds: Rectangle, cursor_position: Point| {
+        (cursor_position.x.max(0.0) / bounds.width).min(1.0)
+    };
+
+    match state.color_bar_dragged {
+        ColorBarDragged::Red => {
+            state.color = Color {
+                r: cursor
+                    .position_in(red_bar_bounds)
+                    .map(|position| calc_percentage(red_bar_bounds, position))
+                    .unwrap_or_default(),
+                ..state.color
+            };
+            color_changed = true;
+        }
+        ColorBarDragged::Green => {
+            state.color = Color {
+                g: cursor
+                    .position_in(green_bar_bounds)
+                    .map(|position| calc_percentage(green_bar_bounds, position))
+                    .unwrap_or_default(),
+                ..state.color
+            };
+            color_changed = true;
+        }
+        ColorBarDragged::Blue => {
+            state.color = Color {
+                b: cursor
+                    .position_in(blue_bar_bounds)
+                    .map(|position| calc_percentage(blue_bar_bounds, position))
+                    .unwrap_or_default(),
+                ..state.color
+            };
+            color_changed = true;
+        }
+        ColorBarDragged::Alpha => {
+            if let Some(alpha_bar_bounds) = alpha_bar_bounds {
+                state.color = Color {
+                    a: cursor
+                        .position_in(alpha_bar_bounds)
+                        .map(|position| calc_percentage(alpha_bar_bounds, position))
+                        .unwrap_or_default(),
+                    ..state.color
+                };
+                color_changed = true;
+            }
+        }
+        _ => {}
+    }
+
+    if color_changed {
+        event::Status::Captured
+    } else {
+        event::Status::Ignored
+    }
+}
+
+/// The even handling for the keyboard input.
+fn on_event_keyboard(state: &mut State, event: &Event, buttons: bool) -> event::Status {
+    if state.focus == Focus::None {
+        return event::Status::Ignored;
+    }
+
+    if let Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. }) = event {
+        let mut status = event::Status::Ignored;
+
+        if matches!(key, keyboard::Key::Named(keyboard::key::Named::Tab)) {
+            state.step_focus(modifiers.shift(), buttons);
+        } else {
+            // Shift makes the steps larger
+            let factor = if modifiers.shift() {
+                SHIFT_STEP_FACTOR
+            } else {
+                1
+            };
+            let model = state.model;
+
+            let sat_value_handle = |key_code: &keyboard::Key, color: &mut Color| {
+                let mut coordinates = model.coordinates(*color);
+                let step = SAT_VALUE_STEP * f32::from(factor);
+
+                match key_code {
+                    keyboard::Key::Named(keyboard::key::Named::ArrowLeft) => {
+                        coordinates.x -= step;
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::ArrowRight) => {
+                        coordinates.x += step;
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::ArrowUp) => {
+                        coordinates.y -= step;
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::ArrowDown) => {
+                        coordinates.y += step;
+                    }
+                    _ => return event::Status::Ignored,
+                }
+
+                *color = Color {
+                    a: color.a,
+                    ..model.color(coordinates)
+                };
+                event::Status::Captured
+            };
+
+            let hue_handle = |key_code: &keyboard::Key, color: &mut Color| {
+                let mut coordinates = model.coordinates(*color);
+                let step = HUE_STEP * f32::from(factor);
+
+                match key_code {
+                    keyboard::Key::Named(
+                        keyboard::key::Named::ArrowLeft | keyboard::key::Named::ArrowDown,
+                    ) => {
+                        coordinates.hue -= step;
+                    }
+                    keyboard::Key::Named(
+                        keyboard::key::Named::ArrowRight | keyboard::key::Named::ArrowUp,
+                    ) => {
+                        coordinates.hue += step;
+                    }
+                    _ => return event::Status::Ignored,
+                }
+
+                *color = Color {
+                    a: color.a,
+                    ..model.color(coordinates)
+                };
+                event::Status::Captured
+            };
+
+            // The value of a bar is changed in steps of the given fraction of its range
+            let rgba_bar_handle =
+                |key_code: &keyboard::Key, value: &mut f32, max: i16, step: i16| {
+                    let mut step_value = (*value * f32::from(max)).round() as i16;
+                    let mut status = event::Status::Ignored;
+
+                    match key_code {
+                        keyboard::Key::Named(
+                            keyboard::key::Named::ArrowLeft | keyboard::key::Named::ArrowDown,
+                        ) => {
+                            step_value -= step * factor;
+                            status = event::Status::Captured;
+                        }
+                        keyboard::Key::Named(
+                            keyboard::key::Named::ArrowRight | keyboard::key::Named::ArrowUp,
+                        ) => {
+                            step_value += step * factor;
+                            status = event::Status::Captured;
+                        }
+                        _ => {}
+                    }
+                    *value = f32::from(step_value.clamp(0, max)) / f32::from(max);
+
+                    status
+                };
+
+            // The arrow keys pick the swatch next to the picked one
+            let palette_handle = |key_code: &keyboard::Key, state: &mut State| {
+                let len = state.swatches().count();
+                let last = len.saturating_sub(1);
+                let picked = state.swatches().position(|swatch| state.is_picked(swatch));
+
+                let index = match key_code {
+                    keyboard::Key::Named(
+                        keyboard::key::Named::ArrowLeft | keyboard::key::Named::ArrowUp,
+                    ) => picked.map_or(last, |index| (index + last) % len),
+                    keyboard::Key::Named(
+                        keyboard::key::Named::ArrowRight | keyboard::key::Named::ArrowDown,
+                    ) => picked.map_or(0, |index| (index + 1) % len),
+                    _ => return event::Status::Ignored,
+                };
+
+                let color = state.swatches().nth(index);
+
+                color.map_or(event::Status::Ignored, |color| {
+                    state.set_color(color);
+                    event::Status::Captured
+                })
+            };
+
+            match state.focus {
+                Focus::SatValue => status = sat_value_handle(key, &mut state.color),
+                Focus::Hue => status = hue_handle(key, &mut state.color),
+                Focus::Red => status = rgba_bar_handle(key, &mut state.color.r, 255, RGB_STEP),
+                Focus::Green => status = rgba_bar_handle(key, &mut state.color.g, 255, RGB_STEP),
+                Focus::Blue => status = rgba_bar_handle(key, &mut state.color.b, 255, RGB_STEP),
+                Focus::Alpha => status = rgba_bar_handle(key, &mut state.color.a, 100, ALPHA_STEP),
+                Focus::Palette => status = palette_handle(key, state),
+                _ => {}
+            }
+        }
+
+        status
+    } else if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) = event {
+        state.keyboard_modifiers = *modifiers;
+        event::Status::Ignored
+    } else {
+        event::Status::Ignored
     }
 }
 
 /// Defines the layout of the 1. block of the color picker containing the HSV part.
-fn block1_layout<'a, Message, Theme>(
-    color_picker: &mut ColorPickerOverlay<'a, Message, Theme>,
+///
+/// The swatches of the `palette` are followed by the `recent` ones, starting on a new row.
+fn block1_layout<Theme>(
     renderer: &Renderer,
     bounds: Rectangle,
-    _position: Point,
+    palette: usize,
+    recent: usize,
+    metrics: Metrics,
 ) -> Node
 where
-    Message: 'static + Clone,
-    Theme: 'a + StyleSheet + button::StyleSheet + widget::text::StyleSheet,
+    Theme: widget::text::StyleSheet,
 {
+    let Metrics {
+        padding,
+        swatch_size,
+        swatch_spacing,
+        ..
+    } = metrics;
+
     let block1_limits = Limits::new(Size::ZERO, bounds.size())
         .width(Length::Fill)
         .height(Length::Fill);
 
-    let block1_node = Column::<(), Theme, Renderer>::new()
-        .spacing(PADDING)
+    // The swatches of the palette wrap into as many rows as needed
+    let swatches_per_row = ((block1_limits.max().width - 2.0 * padding + swatch_spacing)
+        / (swatch_size + swatch_spacing))
+        .max(1.0) as usize;
+    let palette_rows = palette.div_ceil(swatches_per_row);
+    let swatch_rows = palette_rows + recent.div_ceil(swatches_per_row);
+    let palette_height = if swatch_rows == 0 {
+        0.0
+    } else {
+        swatch_rows as f32 * (swatch_size + swatch_spacing) - swatch_spacing + padding
+    };
+    let block1_limits = block1_limits.shrink(Size::new(0.0, palette_height));
+
+    let block1: Element<(), Theme, Renderer> = Column::new()
+        .spacing(padding)
         .push(
             Row::new()
                 .width(Length::Fill)
@@ -922,59 +2037,95 @@ where
                 .width(Length::Fill)
                 .height(Length::FillPortion(1)),
         )
-        .layout(color_picker.tree, renderer, &block1_limits);
+        .into();
+    let block1_node = block1
+        .as_widget()
+        .layout(&mut Tree::new(&block1), renderer, &block1_limits);
+
+    let block1_node = if swatch_rows == 0 {
+        block1_node
+    } else {
+        let swatch = |i: usize, first_row: usize| {
+            Node::new(Size::new(swatch_size, swatch_size)).move_to(Point::new(
+                (i % swatches_per_row) as f32 * (swatch_size + swatch_spacing),
+                (first_row + i / swatches_per_row) as f32 * (swatch_size + swatch_spacing),
+            ))
+        };
+        let swatches = (0..palette)
+            .map(|i| swatch(i, 0))
+            .chain((0..recent).map(|i| swatch(i, palette_rows)))
+            .collect();
+        let palette_node = Node::with_children(
+            Size::new(block1_node.size().width, palette_height - padding),
+            swatches,
+        )
+        .move_to(Point::new(0.0, block1_node.size().height + padding));
+
+        let size = Size::new(
+            block1_node.size().width,
+            block1_node.size().height + palette_height,
+        );
+        let mut children = block1_node.children().to_vec();
+        children.push(palette_node);
+
+        Node::with_children(size, children)
+    };
 
-    block1_node.move_to(Point::new(bounds.x + PADDING, bounds.y + PADDING))
+    block1_node.move_to(Point::new(bounds.x + padding, bounds.y + padding))
 }
 
-/// Defines the layout of the 2. block of the color picker containing the RGBA part, Hex and buttons.
-fn block2_layout<'a, Message, Theme>(
-    color_picker: &mut ColorPickerOverlay<'a, Message, Theme>,
+/// Defines the layout of the 2. block of the color picker containing the RGBA part and Hex,
+/// leaving `footer` space below them.
+///
+/// Without `alpha` the RGB bars keep the height they have next to the alpha bar.
+fn block2_layout<Theme>(
+    rgba_tree: &mut Tree,
     renderer: &Renderer,
     bounds: Rectangle,
-    _position: Point,
+    footer: f32,
+    alpha: bool,
+    metrics: Metrics,
 ) -> Node
 where
-    Message: 'static + Clone,
-    Theme: 'a + StyleSheet + button::StyleSheet + widget::text::StyleSheet,
+    Theme: widget::text::StyleSheet,
 {
+    let Metrics {
+        padding,
+        spacing,
+        text_size,
+        ..
+    } = metrics;
+
     let block2_limits = Limits::new(Size::ZERO, bounds.size())
         .width(Length::Fill)
         .height(Length::Fill);
 
-    // Pre-Buttons TODO: get rid of it
-    let cancel_limits = block2_limits;
-    let cancel_button = color_picker.cancel_button.layout(
-        &mut color_picker.tree.children[0],
-        renderer,
-        &cancel_limits,
-    );
-
-    let hex_text_limits = block2_limits;
-
-    let mut hex_text_layout = Row::<Message, Theme, Renderer>::new()
-        .width(Length::Fill)
-        .height(Length::Fixed(renderer.default_size().0 + 2.0 * PADDING))
-        .layout(color_picker.tree, renderer, &hex_text_limits);
+    let mut hex_text_layout = Node::new(Size::new(
+        block2_limits.max().width,
+        text_size.0 + 2.0 * padding,
+    ));
 
     let block2_limits = block2_limits.shrink(Size::new(
         0.0,
-        cancel_button.bounds().height + hex_text_layout.bounds().height + 2.0 * SPACING,
+        hex_text_layout.bounds().height + spacing + footer,
     ));
 
+    let rows = if alpha { 4 } else { 3 };
+    let block2_limits = block2_limits.max_height(block2_limits.max().height * rows as f32 / 4.0);
+
     // RGBA Colors
-    let mut rgba_colors: Column<'_, Message, Theme, Renderer> =
-        Column::<Message, Theme, Renderer>::new();
+    let mut rgba_colors: Column<'_, (), Theme, Renderer> = Column::new();
 
-    for _ in 0..4 {
+    for _ in 0..rows {
         rgba_colors = rgba_colors.push(
             Row::new()
                 .align_items(Alignment::Center)
-                .spacing(SPACING)
-                .padding(PADDING)
+                .spacing(spacing)
+                .padding(padding)
                 .height(Length::Fill)
                 .push(
                     widget::Text::new("X:")
+                        .size(text_size)
                         .horizontal_alignment(Horizontal::Center)
                         .vertical_alignment(Vertical::Center),
                 )
@@ -985,397 +2136,222 @@ where
                 )
                 .push(
                     widget::Text::new("XXX")
+                        .size(text_size)
                         .horizontal_alignment(Horizontal::Center)
                         .vertical_alignment(Vertical::Center),
                 ),
         );
     }
-    let element: Element<Message, Theme, Renderer> = Element::new(rgba_colors);
-    let rgba_tree = if let Some(child_tree) = color_picker.tree.children.get_mut(2) {
-        child_tree.diff(element.as_widget());
-        child_tree
-    } else {
-        let child_tree = Tree::new(element.as_widget());
-        color_picker.tree.children.insert(2, child_tree);
-        &mut color_picker.tree.children[2]
-    };
+    let element: Element<(), Theme, Renderer> = Element::new(rgba_colors);
+    rgba_tree.diff(element.as_widget());
 
     let mut rgba_colors = element
         .as_widget()
         .layout(rgba_tree, renderer, &block2_limits);
 
     let rgba_bounds = rgba_colors.bounds();
-    rgba_colors = rgba_colors.move_to(Point::new(rgba_bounds.x + PADDING, rgba_bounds.y + PADDING));
+    rgba_colors = rgba_colors.move_to(Point::new(rgba_bounds.x + padding, rgba_bounds.y + padding));
     let rgba_bounds = rgba_colors.bounds();
 
     // Hex text
     let hex_bounds = hex_text_layout.bounds();
     hex_text_layout = hex_text_layout.move_to(Point::new(
-        hex_bounds.x + PADDING,
-        hex_bounds.y + rgba_bounds.height + PADDING + SPACING,
+        hex_bounds.x + padding,
+        hex_bounds.y + rgba_bounds.height + padding + spacing,
     ));
     let hex_bounds = hex_text_layout.bounds();
 
-    // Buttons
-    let cancel_limits =
-        block2_limits.max_width(((rgba_bounds.width / 2.0) - BUTTON_SPACING).max(0.0));
-
-    let mut cancel_button = color_picker.cancel_button.layout(
-        &mut color_picker.tree.children[0],
-        renderer,
-        &cancel_limits,
-    );
-
-    let submit_limits =
-        block2_limits.max_width(((rgba_bounds.width / 2.0) - BUTTON_SPACING).max(0.0));
-
-    let mut submit_button = color_picker.submit_button.layout(
-        &mut color_picker.tree.children[1],
-        renderer,
-        &submit_limits,
-    );
-
-    let cancel_bounds = cancel_button.bounds();
-    cancel_button = cancel_button.move_to(Point::new(
-        cancel_bounds.x + PADDING,
-        cancel_bounds.y + rgba_bounds.height + hex_bounds.height + PADDING + 2.0 * SPACING,
-    ));
-    let cancel_bounds = cancel_button.bounds();
-
-    let submit_bounds = submit_button.bounds();
-    submit_button = submit_button.move_to(Point::new(
-        submit_bounds.x + rgba_colors.bounds().width - submit_bounds.width + PADDING,
-        submit_bounds.y + rgba_bounds.height + hex_bounds.height + PADDING + 2.0 * SPACING,
-    ));
-
-    Node::with_children(
-        Size::new(
-            rgba_bounds.width + (2.0 * PADDING),
-            rgba_bounds.height
-                + hex_bounds.height
-                + cancel_bounds.height
-                + (2.0 * PADDING)
-                + (2.0 * SPACING),
-        ),
-        vec![rgba_colors, hex_text_layout, cancel_button, submit_button],
-    )
-    .move_to(Point::new(bounds.x, bounds.y))
-}
-
-/// Draws the 1. block of the color picker containing the HSV part.
-fn block1<Message, Theme>(
-    renderer: &mut Renderer,
-    color_picker: &ColorPickerOverlay<'_, Message, Theme>,
-    layout: Layout<'_>,
-    cursor: Cursor,
-    style_sheet: &HashMap<StyleState, Appearance>,
-) where
-    Message: Clone,
-    Theme: StyleSheet + button::StyleSheet + widget::text::StyleSheet,
-{
-    // ----------- Block 1 ----------------------
-    let hsv_color_layout = layout;
-
-    // ----------- HSV Color ----------------------
-    //let hsv_color_layout = block1_children.next().unwrap();
-    hsv_color(
-        renderer,
-        color_picker,
-        hsv_color_layout,
-        cursor,
-        style_sheet,
-    );
-
-    // ----------- Block 1 end ------------------
-}
-
-/// Draws the 2. block of the color picker containing the RGBA part, Hex and buttons.
-#[allow(clippy::too_many_arguments)]
-fn block2<Message, Theme>(
-    renderer: &mut Renderer,
-    color_picker: &ColorPickerOverlay<'_, Message, Theme>,
-    layout: Layout<'_>,
-    cursor: Cursor,
-    theme: &Theme,
-    style: &renderer::Style,
-    viewport: &Rectangle,
-    style_sheet: &HashMap<StyleState, Appearance>,
-) where
-    Message: Clone,
-    Theme: StyleSheet + button::StyleSheet + widget::text::StyleSheet,
-{
-    // ----------- Block 2 ----------------------
-    let mut block2_children = layout.children();
-
-    // ----------- RGBA Color ----------------------
-    let rgba_color_layout = block2_children
-        .next()
-        .expect("Graphics: Layout should have a RGBA color layout");
-    rgba_color(
-        renderer,
-        rgba_color_layout,
-        &color_picker.state.color,
-        cursor,
-        style,
-        style_sheet,
-        color_picker.state.focus,
-    );
-
-    // ----------- Hex text ----------------------
-    let hex_text_layout = block2_children
-        .next()
-        .expect("Graphics: Layout should have a hex text layout");
-    hex_text(
-        renderer,
-        hex_text_layout,
-        &color_picker.state.color,
-        cursor,
-        style,
-        style_sheet,
-        color_picker.state.focus,
-    );
-
-    // ----------- Buttons -------------------------
-    let cancel_button_layout = block2_children
-        .next()
-        .expect("Graphics: Layout should have a cancel button layout for a ColorPicker");
-
-    color_picker.cancel_button.draw(
-        &color_picker.tree.children[0],
-        renderer,
-        theme,
-        style,
-        cancel_button_layout,
-        cursor,
-        viewport,
-    );
-
-    let submit_button_layout = block2_children
-        .next()
-        .expect("Graphics: Layout should have a submit button layout for a ColorPicker");
-
-    color_picker.submit_button.draw(
-        &color_picker.tree.children[1],
-        renderer,
-        theme,
-        style,
-        submit_button_layout,
-        cursor,
-        viewport,
-    );
-
-    // Buttons are not focusable right now...
-    if color_picker.state.focus == Focus::Cancel {
-        renderer.fill_quad(
-            renderer::Quad {
-                bounds: cancel_button_layout.bounds(),
-                border: Border {
-                    radius: style_sheet[&StyleState::Focused].border_radius.into(),
-                    width: style_sheet[&StyleState::Focused].border_width,
-                    color: style_sheet[&StyleState::Focused].border_color,
-                },
-                shadow: Shadow::default(),
-            },
-            Color::TRANSPARENT,
-        );
-    }
-
-    if color_picker.state.focus == Focus::Submit {
-        renderer.fill_quad(
-            renderer::Quad {
-                bounds: submit_button_layout.bounds(),
-                border: Border {
-                    radius: style_sheet[&StyleState::Focused].border_radius.into(),
-                    width: style_sheet[&StyleState::Focused].border_width,
-                    color: style_sheet[&StyleState::Focused].border_color,
-                },
-                shadow: Shadow::default(),
-            },
-            Color::TRANSPARENT,
-        );
-    }
-    // ----------- Block 2 end ------------------
+    Node::with_children(
+        Size::new(
+            rgba_bounds.width + (2.0 * padding),
+            rgba_bounds.height + hex_bounds.height + (2.0 * padding) + spacing + footer,
+        ),
+        vec![rgba_colors, hex_text_layout],
+    )
+    .move_to(Point::new(bounds.x, bounds.y))
 }
 
 /// Draws the HSV color area.
+///
+/// The gradients are cached: the one of the sat/value area is only generated again once
+/// the hue changes visibly, the markers and the borders are drawn on top of them.
 #[allow(clippy::too_many_lines)]
-fn hsv_color<Message, Theme>(
+fn hsv_color(
     renderer: &mut Renderer,
-    color_picker: &ColorPickerOverlay<'_, Message, Theme>,
+    state: &State,
     layout: Layout<'_>,
     cursor: Cursor,
     style_sheet: &HashMap<StyleState, Appearance>,
-) where
-    Message: Clone,
-    Theme: StyleSheet + button::StyleSheet + widget::text::StyleSheet,
-{
+) {
     let mut hsv_color_children = layout.children();
-    let hsv_color: Hsv = color_picker.state.color.into();
+    let model = state.model;
+    let coordinates = model.coordinates(state.color);
 
     let sat_value_layout = hsv_color_children
         .next()
         .expect("Graphics: Layout should have a sat/value layout");
     let mut sat_value_style_state = StyleState::Active;
-    if color_picker.state.focus == Focus::SatValue {
+    if state.focus == Focus::SatValue {
         sat_value_style_state = sat_value_style_state.max(StyleState::Focused);
     }
     if cursor.is_over(sat_value_layout.bounds()) {
         sat_value_style_state = sat_value_style_state.max(StyleState::Hovered);
     }
 
-    let geometry = color_picker.state.sat_value_canvas_cache.draw(
-        renderer,
-        sat_value_layout.bounds().size(),
-        |frame| {
-            let column_count = frame.width() as u16;
-            let row_count = frame.height() as u16;
-
-            for column in 0..column_count {
-                for row in 0..row_count {
-                    let saturation = f32::from(column) / frame.width();
-                    let value = f32::from(row) / frame.height();
-
-                    frame.fill_rectangle(
-                        Point::new(f32::from(column), f32::from(row)),
-                        Size::new(1.0, 1.0),
-                        Color::from(Hsv::from_hsv(hsv_color.hue, saturation, value)),
-                    );
-                }
+    let key = state.sat_value_key();
+    if state.sat_value_cache_key.replace(Some(key)) != Some(key) {
+        state.sat_value_canvas_cache.clear();
+    }
+
+    let size = sat_value_layout.bounds().size();
+    let gradient = state.sat_value_canvas_cache.draw(renderer, size, |frame| {
+        state
+            .sat_value_regenerations
+            .set(state.sat_value_regenerations.get() + 1);
+
+        let column_count = frame.width() as u16;
+        let row_count = frame.height() as u16;
+
+        for column in 0..column_count {
+            for row in 0..row_count {
+                let x = f32::from(column) / frame.width();
+                let y = f32::from(row) / frame.height();
+
+                frame.fill_rectangle(
+                    Point::new(f32::from(column), f32::from(row)),
+                    Size::new(1.0, 1.0),
+                    model.color(Coordinates {
+                        x,
+                        y,
+                        ..coordinates
+                    }),
+                );
             }
+        }
+    });
 
-            let stroke = Stroke {
-                style: Style::Solid(
-                    Hsv {
-                        hue: 0,
-                        saturation: 0.0,
-                        value: 1.0 - hsv_color.value,
-                    }
-                    .into(),
-                ),
-                width: 3.0,
-                line_cap: LineCap::Round,
-                ..Stroke::default()
-            };
+    let mut frame = canvas::Frame::new(renderer, size);
 
-            let saturation = hsv_color.saturation * frame.width();
-            let value = hsv_color.value * frame.height();
+    let contrast = 1.0 - coordinates.y;
+    let stroke = Stroke {
+        style: Style::Solid(Color::from_rgb(contrast, contrast, contrast)),
+        width: 3.0,
+        line_cap: LineCap::Round,
+        ..Stroke::default()
+    };
 
-            frame.stroke(
-                &Path::line(
-                    Point::new(saturation, 0.0),
-                    Point::new(saturation, frame.height()),
-                ),
-                stroke.clone(),
-            );
+    let x = coordinates.x * frame.width();
+    let y = coordinates.y * frame.height();
 
-            frame.stroke(
-                &Path::line(Point::new(0.0, value), Point::new(frame.width(), value)),
-                stroke,
-            );
+    frame.stroke(
+        &Path::line(Point::new(x, 0.0), Point::new(x, frame.height())),
+        stroke.clone(),
+    );
 
-            let stroke = Stroke {
-                style: Style::Solid(
-                    style_sheet
-                        .get(&sat_value_style_state)
-                        .expect("Style Sheet not found.")
-                        .bar_border_color,
-                ),
-                width: 2.0,
-                line_cap: LineCap::Round,
-                ..Stroke::default()
-            };
+    frame.stroke(
+        &Path::line(Point::new(0.0, y), Point::new(frame.width(), y)),
+        stroke,
+    );
 
-            frame.stroke(
-                &Path::rectangle(
-                    Point::new(0.0, 0.0),
-                    Size::new(frame.size().width - 0.0, frame.size().height - 0.0),
-                ),
-                stroke,
-            );
-        },
+    let stroke = Stroke {
+        style: Style::Solid(
+            style_sheet
+                .get(&sat_value_style_state)
+                .expect("Style Sheet not found.")
+                .bar_border_color,
+        ),
+        width: 2.0,
+        line_cap: LineCap::Round,
+        ..Stroke::default()
+    };
+
+    frame.stroke(
+        &Path::rectangle(
+            Point::new(0.0, 0.0),
+            Size::new(frame.size().width - 0.0, frame.size().height - 0.0),
+        ),
+        stroke,
     );
 
     let translation = Vector::new(sat_value_layout.bounds().x, sat_value_layout.bounds().y);
     renderer.with_translation(translation, |renderer| {
-        renderer.draw(vec![geometry]);
+        renderer.draw(vec![gradient, frame.into_geometry()]);
     });
 
     let hue_layout = hsv_color_children
         .next()
         .expect("Graphics: Layout should have a hue layout");
     let mut hue_style_state = StyleState::Active;
-    if color_picker.state.focus == Focus::Hue {
+    if state.focus == Focus::Hue {
         hue_style_state = hue_style_state.max(StyleState::Focused);
     }
     if cursor.is_over(hue_layout.bounds()) {
         hue_style_state = hue_style_state.max(StyleState::Hovered);
     }
 
-    let geometry =
-        color_picker
-            .state
-            .hue_canvas_cache
-            .draw(renderer, hue_layout.bounds().size(), |frame| {
-                let column_count = frame.width() as u16;
+    let size = hue_layout.bounds().size();
+    let gradient = state.hue_canvas_cache.draw(renderer, size, |frame| {
+        let column_count = frame.width() as u16;
 
-                for column in 0..column_count {
-                    let hue = (f32::from(column) * 360.0 / frame.width()) as u16;
+        for column in 0..column_count {
+            let hue = f32::from(column) * 360.0 / frame.width();
 
-                    let hsv_color = Hsv::from_hsv(hue, 1.0, 1.0);
-                    let stroke = Stroke {
-                        style: Style::Solid(hsv_color.into()),
-                        width: 1.0,
-                        line_cap: LineCap::Round,
-                        ..Stroke::default()
-                    };
+            let stroke = Stroke {
+                style: Style::Solid(model.hue_color(hue)),
+                width: 1.0,
+                line_cap: LineCap::Round,
+                ..Stroke::default()
+            };
 
-                    frame.stroke(
-                        &Path::line(
-                            Point::new(f32::from(column), 0.0),
-                            Point::new(f32::from(column), frame.height()),
-                        ),
-                        stroke,
-                    );
-                }
+            frame.stroke(
+                &Path::line(
+                    Point::new(f32::from(column), 0.0),
+                    Point::new(f32::from(column), frame.height()),
+                ),
+                stroke,
+            );
+        }
+    });
 
-                let stroke = Stroke {
-                    style: Style::Solid(Color::BLACK),
-                    width: 3.0,
-                    line_cap: LineCap::Round,
-                    ..Stroke::default()
-                };
+    let mut frame = canvas::Frame::new(renderer, size);
 
-                let column = f32::from(hsv_color.hue) * frame.width() / 360.0;
+    let stroke = Stroke {
+        style: Style::Solid(Color::BLACK),
+        width: 3.0,
+        line_cap: LineCap::Round,
+        ..Stroke::default()
+    };
 
-                frame.stroke(
-                    &Path::line(Point::new(column, 0.0), Point::new(column, frame.height())),
-                    stroke,
-                );
+    let column = coordinates.hue * frame.width() / 360.0;
 
-                let stroke = Stroke {
-                    style: Style::Solid(
-                        style_sheet
-                            .get(&hue_style_state)
-                            .expect("Style Sheet not found.")
-                            .bar_border_color,
-                    ),
-                    width: 2.0,
-                    line_cap: LineCap::Round,
-                    ..Stroke::default()
-                };
+    frame.stroke(
+        &Path::line(Point::new(column, 0.0), Point::new(column, frame.height())),
+        stroke,
+    );
 
-                frame.stroke(
-                    &Path::rectangle(
-                        Point::new(0.0, 0.0),
-                        Size::new(frame.size().width, frame.size().height),
-                    ),
-                    stroke,
-                );
-            });
+    let stroke = Stroke {
+        style: Style::Solid(
+            style_sheet
+                .get(&hue_style_state)
+                .expect("Style Sheet not found.")
+                .bar_border_color,
+        ),
+        width: 2.0,
+        line_cap: LineCap::Round,
+        ..Stroke::default()
+    };
+
+    frame.stroke(
+        &Path::rectangle(
+            Point::new(0.0, 0.0),
+            Size::new(frame.size().width, frame.size().height),
+        ),
+        stroke,
+    );
 
     let translation = Vector::new(hue_layout.bounds().x, hue_layout.bounds().y);
     renderer.with_translation(translation, |renderer| {
-        renderer.draw(vec![geometry]);
+        renderer.draw(vec![gradient, frame.into_geometry()]);
     });
 }
 
@@ -1384,12 +2360,14 @@ fn hsv_color<Message, Theme>(
 fn rgba_color(
     renderer: &mut Renderer,
     layout: Layout<'_>,
-    color: &Color,
+    state: &State,
     cursor: Cursor,
     style: &renderer::Style,
     style_sheet: &HashMap<StyleState, Appearance>,
-    focus: Focus,
 ) {
+    let color = &state.color;
+    let focus = state.focus;
+    let text_size = state.metrics(renderer).text_size;
     let mut rgba_color_children = layout.children();
 
     let f = |renderer: &mut Renderer,
@@ -1416,7 +2394,7 @@ fn rgba_color(
             Text {
                 content: label,
                 bounds: Size::new(label_layout.bounds().width, label_layout.bounds().height),
-                size: renderer.default_size(),
+                size: text_size,
                 font: crate::BOOTSTRAP_FONT,
                 horizontal_alignment: Horizontal::Center,
                 vertical_alignment: Vertical::Center,
@@ -1494,7 +2472,7 @@ fn rgba_color(
             Text {
                 content: &format!("{}", (255.0 * value) as u8),
                 bounds: Size::new(value_layout.bounds().width, value_layout.bounds().height),
-                size: renderer.default_size(),
+                size: text_size,
                 font: renderer.default_font(),
                 horizontal_alignment: Horizontal::Center,
                 vertical_alignment: Vertical::Center,
@@ -1580,33 +2558,177 @@ fn rgba_color(
         Focus::Blue,
     );
 
-    // Alpha
-    let alpha_row_layout = rgba_color_children
-        .next()
-        .expect("Graphics: Layout should have an alpha row layout");
+    // Alpha, if shown
+    if let Some(alpha_row_layout) = rgba_color_children.next() {
+        f(
+            renderer,
+            alpha_row_layout,
+            "A:",
+            Color::from_rgba(0.0, 0.0, 0.0, color.a),
+            color.a,
+            cursor,
+            Focus::Alpha,
+        );
+    }
+}
 
-    f(
-        renderer,
-        alpha_row_layout,
-        "A:",
-        Color::from_rgba(0.0, 0.0, 0.0, color.a),
-        color.a,
-        cursor,
-        Focus::Alpha,
+/// Draws the swatches of the palette and of the recent colors, the picked one with a
+/// highlighted border.
+fn palette(
+    renderer: &mut Renderer,
+    state: &State,
+    layout: Layout<'_>,
+    cursor: Cursor,
+    style_sheet: &HashMap<StyleState, Appearance>,
+) {
+    for (swatch_layout, swatch) in layout.children().zip(state.swatches()) {
+        let bounds = swatch_layout.bounds();
+
+        let style_state = if cursor.is_over(bounds) {
+            StyleState::Hovered
+        } else {
+            StyleState::Active
+        };
+
+        if state.is_picked(swatch) {
+            let highlight_state = if state.focus == Focus::Palette {
+                StyleState::Focused
+            } else {
+                StyleState::Selected
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: bounds.expand(3.0),
+                    border: Border {
+                        radius: style_sheet[&highlight_state].bar_border_radius.into(),
+                        width: 2.0 * style_sheet[&highlight_state].bar_border_width,
+                        color: style_sheet[&highlight_state].bar_border_color,
+                    },
+                    shadow: Shadow::default(),
+                },
+                Color::TRANSPARENT,
+            );
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: Border {
+                    radius: style_sheet[&style_state].bar_border_radius.into(),
+                    width: style_sheet[&style_state].bar_border_width,
+                    color: style_sheet[&style_state].bar_border_color,
+                },
+                shadow: Shadow::default(),
+            },
+            swatch,
+        );
+    }
+}
+
+/// Draws the comparison of the initial color, on the left, and the picked color, on the
+/// right, with a checkerboard behind transparent colors.
+fn comparison(
+    renderer: &mut Renderer,
+    state: &State,
+    layout: Layout<'_>,
+    cursor: Cursor,
+    style_sheet: &HashMap<StyleState, Appearance>,
+) {
+    let bounds = layout.bounds();
+    let initial_bounds = initial_half(bounds);
+    let picked_bounds = Rectangle {
+        x: initial_bounds.x + initial_bounds.width,
+        ..initial_bounds
+    };
+    let initial_color = state.shown(state.initial_color);
+
+    for (bounds, color) in [
+        (initial_bounds, initial_color),
+        (picked_bounds, state.color),
+    ] {
+        if color.a < 1.0 {
+            checkerboard(renderer, bounds);
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: Border::default(),
+                shadow: Shadow::default(),
+            },
+            color,
+        );
+    }
+
+    let style_state = if cursor.is_over(initial_bounds) {
+        StyleState::Hovered
+    } else {
+        StyleState::Active
+    };
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border: Border {
+                radius: style_sheet[&style_state].bar_border_radius.into(),
+                width: style_sheet[&style_state].bar_border_width,
+                color: style_sheet[&style_state].bar_border_color,
+            },
+            shadow: Shadow::default(),
+        },
+        Color::TRANSPARENT,
     );
 }
 
+/// Draws a checkerboard within the given bounds, showing through transparent colors.
+fn checkerboard(renderer: &mut Renderer, bounds: Rectangle) {
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border: Border::default(),
+            shadow: Shadow::default(),
+        },
+        Color::WHITE,
+    );
+
+    let columns = (bounds.width / CHECKER_SIZE).ceil() as usize;
+    let rows = (bounds.height / CHECKER_SIZE).ceil() as usize;
+
+    for row in 0..rows {
+        for column in (row % 2..columns).step_by(2) {
+            let x = column as f32 * CHECKER_SIZE;
+            let y = row as f32 * CHECKER_SIZE;
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + x,
+                        y: bounds.y + y,
+                        width: CHECKER_SIZE.min(bounds.width - x),
+                        height: CHECKER_SIZE.min(bounds.height - y),
+                    },
+                    border: Border::default(),
+                    shadow: Shadow::default(),
+                },
+                Color::from_rgb(0.8, 0.8, 0.8),
+            );
+        }
+    }
+}
+
 /// Draws the hex text representation of the color.
 fn hex_text(
     renderer: &mut Renderer,
     layout: Layout<'_>,
-    color: &Color,
+    state: &State,
     cursor: Cursor,
     _style: &renderer::Style,
     style_sheet: &HashMap<StyleState, Appearance>,
     _focus: Focus,
 ) {
-    let hsv: Hsv = (*color).into();
+    let color = state.color;
+    let hsv: Hsv = color.into();
 
     let hex_text_style_state = if cursor.is_over(layout.bounds()) {
         StyleState::Hovered
@@ -1624,14 +2746,14 @@ fn hex_text(
             },
             shadow: Shadow::default(),
         },
-        *color,
+        color,
     );
 
     renderer.fill_text(
         Text {
-            content: &color.as_hex_string(),
+            content: &state.hex_text(),
             bounds: Size::new(layout.bounds().width, layout.bounds().height),
-            size: renderer.default_size(),
+            size: state.metrics(renderer).text_size,
             font: renderer.default_font(),
             horizontal_alignment: Horizontal::Center,
             vertical_alignment: Vertical::Center,
@@ -1659,6 +2781,10 @@ pub struct State {
     pub(crate) color: Color,
     /// The cache of the sat/value canvas of the [`ColorPickerOverlay`].
     pub(crate) sat_value_canvas_cache: canvas::Cache,
+    /// The key of the gradient in the cache of the sat/value canvas, if generated.
+    pub(crate) sat_value_cache_key: Cell<Option<(ColorModel, u32)>>,
+    /// The number of times the gradient of the sat/value canvas was generated.
+    pub(crate) sat_value_regenerations: Cell<usize>,
     /// The cache of the hue canvas of the [`ColorPickerOverlay`].
     pub(crate) hue_canvas_cache: canvas::Cache,
     /// The dragged color bar of the [`ColorPickerOverlay`].
@@ -1667,6 +2793,28 @@ pub struct State {
     pub(crate) focus: Focus,
     /// The previously pressed keyboard modifiers.
     pub(crate) keyboard_modifiers: keyboard::Modifiers,
+    /// Toggle the alpha bar of the [`ColorPickerOverlay`].
+    pub(crate) show_alpha: bool,
+    /// The text typed into the hex field, while it's not changed otherwise.
+    pub(crate) typed_hex: Option<String>,
+    /// The preset colors of the palette of the [`ColorPickerOverlay`].
+    pub(crate) palette: Vec<Color>,
+    /// The recently used colors of the [`ColorPickerOverlay`], without duplicates.
+    pub(crate) recent: Vec<Color>,
+    /// The color model of the gradient area and the hue bar of the [`ColorPickerOverlay`].
+    pub(crate) model: ColorModel,
+    /// The scale of the [`ColorPickerOverlay`] relative to its default size.
+    pub(crate) scale: f32,
+    /// The channels shown by the text fields of the [`ColorPickerOverlay`].
+    pub(crate) channel_mode: ChannelMode,
+    /// The channel and the text typed into its field, while it's not changed otherwise.
+    pub(crate) typed_channel: Option<(usize, String)>,
+    /// The color given to the [`ColorPickerOverlay`] when it was opened.
+    pub(crate) initial_color: Color,
+    /// Whether the color changed since the last frame.
+    pub(crate) change_pending: bool,
+    /// The color last published as changed, if any.
+    pub(crate) changed_color: Option<Color>,
 }
 
 impl State {
@@ -1675,9 +2823,196 @@ impl State {
     pub fn new(color: Color) -> Self {
         Self {
             color,
+            initial_color: color,
             ..Self::default()
         }
     }
+
+    /// Shows or hides the alpha bar.
+    ///
+    /// The color is made opaque once the alpha bar is hidden.
+    pub(crate) fn set_show_alpha(&mut self, show_alpha: bool) {
+        if self.show_alpha == show_alpha {
+            return;
+        }
+
+        self.show_alpha = show_alpha;
+        self.typed_hex = None;
+        self.typed_channel = None;
+
+        if !show_alpha {
+            self.color.a = 1.0;
+
+            if matches!(self.color_bar_dragged, ColorBarDragged::Alpha) {
+                self.color_bar_dragged = ColorBarDragged::None;
+            }
+            if self.focus == Focus::Alpha {
+                self.focus = Focus::Blue;
+            }
+        }
+    }
+
+    /// Moves the focus to the next or, `backwards`, to the previous focusable element.
+    ///
+    /// The inline picker has no `buttons` to focus, small pickers have no hex field and
+    /// the alpha bar and the palette may be hidden.
+    pub(crate) fn step_focus(&mut self, backwards: bool, buttons: bool) {
+        let step = if backwards {
+            Focus::previous
+        } else {
+            Focus::next
+        };
+
+        self.focus = step(self.focus);
+        while (!buttons && matches!(self.focus, Focus::Cancel | Focus::Submit))
+            || (!self.has_text_fields() && self.focus == Focus::Hex)
+            || (!self.show_alpha && self.focus == Focus::Alpha)
+            || (self.swatches().next().is_none() && self.focus == Focus::Palette)
+        {
+            self.focus = step(self.focus);
+        }
+    }
+
+    /// The spacing and the sizes of the color picker at its scale.
+    pub(crate) fn metrics(&self, renderer: &Renderer) -> Metrics {
+        Metrics::new(self.scale, renderer.default_size())
+    }
+
+    /// Checks if the [`ColorPickerOverlay`] is large enough for the text fields.
+    pub(crate) fn has_text_fields(&self) -> bool {
+        self.scale >= MIN_TEXT_FIELDS_SCALE
+    }
+
+    /// Sets the color model of the gradient area and the hue bar, drawing the hue bar again
+    /// once it changes.
+    pub(crate) fn set_model(&mut self, model: ColorModel) {
+        if self.model != model {
+            self.model = model;
+            self.hue_canvas_cache.clear();
+        }
+    }
+
+    /// The key of the cached gradient of the sat/value area: its model and its hue, in
+    /// steps small enough to be invisible.
+    fn sat_value_key(&self) -> (ColorModel, u32) {
+        let hue = self.model.coordinates(self.color).hue;
+        (self.model, (hue / SAT_VALUE_CACHE_HUE_STEP).round() as u32)
+    }
+
+    /// The number of times the gradient of the sat/value area was generated, e.g. to check
+    /// that moving the cursor over it doesn't generate it again.
+    #[must_use]
+    pub fn sat_value_regenerations(&self) -> usize {
+        self.sat_value_regenerations.get()
+    }
+
+    /// Sets the color, made opaque while the alpha bar is hidden.
+    pub(crate) fn set_color(&mut self, color: Color) {
+        let color = self.shown(color);
+
+        if self.color != color {
+            self.color = color;
+            self.typed_hex = None;
+            self.typed_channel = None;
+        }
+    }
+
+    /// Sets the preset colors of the palette.
+    pub(crate) fn set_palette(&mut self, palette: &[Color]) {
+        if self.palette != palette {
+            self.palette = palette.to_vec();
+        }
+    }
+
+    /// Sets the recently used colors, most recent first.
+    ///
+    /// Colors looking the same as a more recent one are left out, as are the colors
+    /// beyond the first `max` ones.
+    pub(crate) fn set_recent(&mut self, recent: &[Color], max: usize) {
+        let show_alpha = self.show_alpha;
+        let mut seen = HashSet::new();
+        let recent: Vec<Color> = recent
+            .iter()
+            .copied()
+            .filter(|&color| seen.insert(color::hex_string(color, show_alpha)))
+            .take(max)
+            .collect();
+
+        if self.recent != recent {
+            self.recent = recent;
+        }
+    }
+
+    /// The colors of the swatches: the palette, then the recent colors.
+    fn swatches(&self) -> impl Iterator<Item = Color> + '_ {
+        self.palette.iter().chain(&self.recent).copied()
+    }
+
+    /// Checks if the given swatch is the picked color.
+    fn is_picked(&self, swatch: Color) -> bool {
+        self.color == self.shown(swatch)
+    }
+
+    /// The given color as it's shown, opaque while the alpha bar is hidden.
+    fn shown(&self, color: Color) -> Color {
+        if self.show_alpha {
+            color
+        } else {
+            Color { a: 1.0, ..color }
+        }
+    }
+
+    /// The text shown in the hex field, the typed text or the hex code of the color.
+    fn hex_text(&self) -> String {
+        self.typed_hex
+            .clone()
+            .unwrap_or_else(|| color::hex_string(self.color, self.show_alpha))
+    }
+
+    /// Forgets the changes of the color to publish.
+    pub(crate) fn reset_change(&mut self) {
+        self.change_pending = false;
+        self.changed_color = None;
+    }
+
+    /// The number of channels shown, without the alpha while it's hidden.
+    const fn channel_count(&self) -> usize {
+        if self.show_alpha {
+            4
+        } else {
+            3
+        }
+    }
+
+    /// The channel whose field holds typed text, if any.
+    fn typed_channel(&self) -> Option<usize> {
+        self.typed_channel.as_ref().map(|(channel, _)| *channel)
+    }
+
+    /// The text shown in the field of the given channel, the typed text or its value.
+    fn channel_text(&self, channel: usize) -> String {
+        match &self.typed_channel {
+            Some((typed, text)) if *typed == channel => text.clone(),
+            _ => self.channel_mode.text(self.color, channel),
+        }
+    }
+
+    /// Checks if the text typed into the field of the given channel, if any, isn't a
+    /// valid value.
+    fn is_typed_channel_invalid(&self, channel: usize) -> bool {
+        matches!(
+            &self.typed_channel,
+            Some((typed, text))
+                if *typed == channel && self.channel_mode.parse(text, channel).is_none()
+        )
+    }
+
+    /// Checks if the text typed into the hex field, if any, isn't a valid hex code.
+    fn is_typed_hex_invalid(&self) -> bool {
+        self.typed_hex
+            .as_ref()
+            .is_some_and(|text| color::parse_hex(text, self.show_alpha).is_none())
+    }
 }
 
 impl Default for State {
@@ -1685,31 +3020,107 @@ impl Default for State {
         Self {
             color: Color::from_rgb(0.5, 0.25, 0.25),
             sat_value_canvas_cache: canvas::Cache::default(),
+            sat_value_cache_key: Cell::new(None),
+            sat_value_regenerations: Cell::new(0),
             hue_canvas_cache: canvas::Cache::default(),
             color_bar_dragged: ColorBarDragged::None,
             focus: Focus::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
+            show_alpha: true,
+            typed_hex: None,
+            palette: Vec::new(),
+            recent: Vec::new(),
+            channel_mode: ChannelMode::default(),
+            typed_channel: None,
+            model: ColorModel::default(),
+            scale: 1.0,
+            initial_color: Color::from_rgb(0.5, 0.25, 0.25),
+            change_pending: false,
+            changed_color: None,
         }
     }
 }
 
+/// The messages of the hex field of the [`ColorPickerOverlay`].
+#[derive(Clone, Debug)]
+enum HexInput {
+    /// The text was changed.
+    Changed(String),
+}
+
+/// The messages of the fields of the channels of the [`ColorPickerOverlay`].
+#[derive(Clone, Debug)]
+enum ChannelInput {
+    /// The text of the field of the given channel was changed.
+    Changed(usize, String),
+
+    /// The channels are switched between RGB and HSV.
+    ToggleMode,
+}
+
+/// The fields of the channels of the [`ColorPickerOverlay`] with the given state.
+fn channel_inputs<'a, Theme>(
+    state: &State,
+    metrics: Metrics,
+) -> Vec<TextInput<'a, ChannelInput, Theme, Renderer>>
+where
+    Theme: text_input::StyleSheet,
+{
+    (0..4)
+        .map(|channel| {
+            TextInput::new("", &state.channel_text(channel))
+                .on_input(move |text| ChannelInput::Changed(channel, text))
+                .width(Length::Fill)
+                .size(metrics.text_size)
+                .padding(metrics.input_padding)
+        })
+        .collect()
+}
+
+/// The button of the [`ColorPickerOverlay`] switching the channels from the given mode.
+fn mode_button<'a, Theme>(
+    mode: ChannelMode,
+    metrics: Metrics,
+) -> Button<'a, ChannelInput, Theme, Renderer>
+where
+    Theme: 'a + button::StyleSheet + widget::text::StyleSheet,
+{
+    Button::new(iced_widget::Text::new(mode.label()).size(metrics.text_size))
+        .padding(metrics.input_padding)
+        .on_press(ChannelInput::ToggleMode)
+}
+
+/// The hex field of the [`ColorPickerOverlay`] showing the given text.
+fn hex_input<'a, Theme>(text: &str, metrics: Metrics) -> TextInput<'a, HexInput, Theme, Renderer>
+where
+    Theme: text_input::StyleSheet,
+{
+    TextInput::new("", text)
+        .on_input(HexInput::Changed)
+        .width(Length::Fill)
+        .size(metrics.text_size)
+        .padding(metrics.input_padding)
+}
+
 /// Just a workaround to pass the button states from the tree to the overlay
 #[allow(missing_debug_implementations)]
 pub struct ColorPickerOverlayButtons<'a, Message, Theme>
 where
     Message: Clone,
-    Theme: StyleSheet + button::StyleSheet,
+    Theme: StyleSheet + button::StyleSheet + text_input::StyleSheet,
 {
     /// The cancel button of the [`ColorPickerOverlay`].
     cancel_button: Element<'a, Message, Theme, Renderer>,
     /// The submit button of the [`ColorPickerOverlay`].
     submit_button: Element<'a, Message, Theme, Renderer>,
+    /// The panel of the [`ColorPickerOverlay`] with its text fields.
+    panel: Panel<'a, Theme>,
 }
 
 impl<'a, Message, Theme> Default for ColorPickerOverlayButtons<'a, Message, Theme>
 where
     Message: 'a + Clone,
-    Theme: 'a + StyleSheet + button::StyleSheet + widget::text::StyleSheet,
+    Theme: 'a + StyleSheet + button::StyleSheet + widget::text::StyleSheet + text_input::StyleSheet,
 {
     fn default() -> Self {
         Self {
@@ -1721,6 +3132,8 @@ where
                 widget::Text::new(icon_to_string(BootstrapIcon::Check)).font(crate::BOOTSTRAP_FONT),
             )
             .into(),
+            // Only the states of the widgets are used, their sizes don't matter
+            panel: Panel::new(&State::default(), Metrics::new(1.0, Pixels(16.0))),
         }
     }
 }
@@ -1730,17 +3143,26 @@ impl<'a, Message, Theme> Widget<Message, Theme, Renderer>
     for ColorPickerOverlayButtons<'a, Message, Theme>
 where
     Message: Clone,
-    Theme: StyleSheet + button::StyleSheet + widget::text::StyleSheet,
+    Theme: StyleSheet + button::StyleSheet + widget::text::StyleSheet + text_input::StyleSheet,
 {
     fn children(&self) -> Vec<Tree> {
-        vec![
+        let mut children = vec![
             Tree::new(&self.cancel_button),
             Tree::new(&self.submit_button),
-        ]
+        ];
+        children.extend(self.panel.children());
+        children
     }
 
     fn diff(&self, tree: &mut Tree) {
-        tree.diff_children(&[&self.cancel_button, &self.submit_button]);
+        // The trees of the panel are kept behind the ones of the buttons
+        if tree.children.len() < 9 {
+            tree.children = self.children();
+        }
+
+        tree.children[0].diff(&self.cancel_button);
+        tree.children[1].diff(&self.submit_button);
+        self.panel.diff(&mut tree.children[2..]);
     }
 
     fn size(&self) -> iced_widget::core::Size<Length> {
@@ -1769,7 +3191,7 @@ impl<'a, Message, Theme> From<ColorPickerOverlayButtons<'a, Message, Theme>>
     for Element<'a, Message, Theme, Renderer>
 where
     Message: 'a + Clone,
-    Theme: 'a + StyleSheet + button::StyleSheet + widget::text::StyleSheet,
+    Theme: 'a + StyleSheet + button::StyleSheet + widget::text::StyleSheet + text_input::StyleSheet,
 {
     fn from(overlay: ColorPickerOverlayButtons<'a, Message, Theme>) -> Self {
         Self::new(overlay)
@@ -1807,6 +3229,40 @@ impl Default for ColorBarDragged {
     }
 }
 
+/// The spacing and the sizes of the color picker, scaled along with it.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Metrics {
+    /// The padding around the blocks.
+    padding: f32,
+    /// The spacing between the elements.
+    spacing: f32,
+    /// The spacing between the buttons.
+    button_spacing: f32,
+    /// The size of the swatches of the palette.
+    swatch_size: f32,
+    /// The spacing between the swatches of the palette.
+    swatch_spacing: f32,
+    /// The padding of the text fields and the buttons.
+    input_padding: f32,
+    /// The size of the text.
+    text_size: Pixels,
+}
+
+impl Metrics {
+    /// The metrics at the given scale, the text being scaled from the given size.
+    pub(crate) fn new(scale: f32, text_size: Pixels) -> Self {
+        Self {
+            padding: PADDING * scale,
+            spacing: SPACING * scale,
+            button_spacing: BUTTON_SPACING * scale,
+            swatch_size: SWATCH_SIZE * scale,
+            swatch_spacing: SWATCH_SPACING * scale,
+            input_padding: INPUT_PADDING * scale,
+            text_size: text_size * scale,
+        }
+    }
+}
+
 /// An enumeration of all focusable element of the [`ColorPickerOverlay`].
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Focus {
@@ -1822,6 +3278,9 @@ pub enum Focus {
     /// The hue bar is in focus.
     Hue,
 
+    /// The palette and the recent colors are in focus.
+    Palette,
+
     /// The red bar is in focus.
     Red,
 
@@ -1834,6 +3293,9 @@ pub enum Focus {
     /// The alpha bar is in focus.
     Alpha,
 
+    /// The hex field is in focus.
+    Hex,
+
     /// The cancel button is in focus.
     Cancel,
 
@@ -1848,11 +3310,13 @@ impl Focus {
         match self {
             Self::Overlay => Self::SatValue,
             Self::SatValue => Self::Hue,
-            Self::Hue => Self::Red,
+            Self::Hue => Self::Palette,
+            Self::Palette => Self::Red,
             Self::Red => Self::Green,
             Self::Green => Self::Blue,
             Self::Blue => Self::Alpha,
-            Self::Alpha => Self::Cancel,
+            Self::Alpha => Self::Hex,
+            Self::Hex => Self::Cancel,
             Self::Cancel => Self::Submit,
             Self::Submit | Self::None => Self::Overlay,
         }
@@ -1866,11 +3330,13 @@ impl Focus {
             Self::Overlay => Self::Submit,
             Self::SatValue => Self::Overlay,
             Self::Hue => Self::SatValue,
-            Self::Red => Self::Hue,
+            Self::Palette => Self::Hue,
+            Self::Red => Self::Palette,
             Self::Green => Self::Red,
             Self::Blue => Self::Green,
             Self::Alpha => Self::Blue,
-            Self::Cancel => Self::Alpha,
+            Self::Hex => Self::Alpha,
+            Self::Cancel => Self::Hex,
             Self::Submit => Self::Cancel,
         }
     }
@@ -12,9 +12,10 @@ use iced_widget::core::{
     mouse::{self, Cursor},
     overlay, renderer, touch,
     widget::tree::Tree,
-    window, Border, Clipboard, Color, Element, Event, Layout, Point, Rectangle, Shadow, Shell,
-    Size, Vector,
+    window, Background, Border, Clipboard, Color, Element, Event, Layout, Point, Rectangle, Shadow,
+    Shell, Size, Transformation, Vector,
 };
+use std::time::{Duration, Instant};
 
 /// The overlay of the [`ContextMenu`](crate::native::ContextMenu).
 #[allow(missing_debug_implementations)]
@@ -34,6 +35,14 @@ pub struct ContextMenuOverlay<
     content: Element<'a, Message, Theme, Renderer>,
     /// The style of the [`ContextMenuOverlay`].
     style: <Theme as StyleSheet>::Style,
+    /// The duration of the open animation of the [`ContextMenuOverlay`].
+    animation: Option<Duration>,
+    /// The inactivity after which the [`ContextMenuOverlay`] closes itself, if any.
+    auto_close: Option<Duration>,
+    /// The bounds the [`ContextMenuOverlay`] is confined to, if any.
+    confine: Option<Rectangle>,
+    /// The message published when the [`ContextMenuOverlay`] closes itself, if any.
+    on_toggle: Option<&'a dyn Fn(bool) -> Message>,
     /// The state shared between [`ContextMenu`](crate::native::ContextMenu) and [`ContextMenuOverlay`].
     state: &'a mut context_menu::State,
 }
@@ -45,10 +54,15 @@ where
     Theme: 'a + StyleSheet,
 {
     /// Creates a new [`ContextMenuOverlay`].
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn new<C>(
         tree: &'a mut Tree,
         content: C,
         style: <Theme as StyleSheet>::Style,
+        animation: Option<Duration>,
+        auto_close: Option<Duration>,
+        confine: Option<Rectangle>,
+        on_toggle: Option<&'a dyn Fn(bool) -> Message>,
         state: &'a mut context_menu::State,
     ) -> Self
     where
@@ -58,6 +72,10 @@ where
             tree,
             content: content.into(),
             style,
+            animation,
+            auto_close,
+            confine,
+            on_toggle,
             state,
         }
     }
@@ -82,8 +100,17 @@ where
         position: Point,
         _translation: Vector,
     ) -> Node {
-        let limits = Limits::new(Size::ZERO, bounds);
-        let max_size = limits.max();
+        let area = self
+            .confine
+            .unwrap_or_else(|| Rectangle::new(Point::ORIGIN, bounds));
+        let max_size = Limits::new(Size::ZERO, bounds).max();
+
+        // A confined menu may be taller than its area and scroll instead
+        let limits = if self.confine.is_some() {
+            Limits::new(Size::ZERO, Size::new(area.width, f32::INFINITY))
+        } else {
+            Limits::new(Size::ZERO, bounds)
+        };
 
         let mut content = self
             .content
@@ -91,17 +118,25 @@ where
             .layout(self.tree, renderer, &limits);
 
         // Try to stay inside borders
+        let size = content.size();
+        let (right, bottom) = (area.x + area.width, area.y + area.height);
         let mut position = position;
-        if position.x + content.size().width > bounds.width {
-            position.x = f32::max(0.0, position.x - content.size().width);
+        if position.x + size.width > right {
+            position.x = f32::max(area.x, position.x - size.width);
         }
-        if position.y + content.size().height > bounds.height {
-            position.y = f32::max(0.0, position.y - content.size().height);
+        if position.y + size.height > bottom {
+            position.y = f32::max(area.y, position.y - size.height);
         }
 
         content.move_to_mut(position);
 
-        Node::with_children(max_size, vec![content])
+        let viewport = Node::new(Size::new(
+            size.width,
+            f32::min(size.height, bottom - position.y),
+        ))
+        .move_to(position);
+
+        Node::with_children(max_size, vec![content, viewport])
     }
 
     fn draw(
@@ -116,7 +151,18 @@ where
 
         let style_sheet = theme.active(&self.style);
 
-        // Background
+        // Ease out the open animation
+        let progress = 1.0 - (1.0 - self.state.animation_progress).powi(3);
+        let opacity = progress;
+        let style = &renderer::Style {
+            text_color: Color {
+                a: style.text_color.a * opacity,
+                ..style.text_color
+            },
+        };
+
+        // Backdrop, the background of the whole overlay unless a backdrop is set
+        let backdrop = style_sheet.backdrop.unwrap_or(style_sheet.background);
         renderer.fill_quad(
             renderer::Quad {
                 bounds,
@@ -127,7 +173,7 @@ where
                 },
                 shadow: Shadow::default(),
             },
-            style_sheet.background,
+            with_alpha(backdrop, opacity),
         );
 
         let content_layout = layout
@@ -135,16 +181,64 @@ where
             .next()
             .expect("Native: Layout should have a content layout.");
 
-        // Modal
-        self.content.as_widget().draw(
-            self.tree,
-            renderer,
-            theme,
-            style,
-            content_layout,
-            cursor,
-            &bounds,
-        );
+        // Scale from the point where the menu was opened
+        let anchor = self.state.cursor_position;
+        let scale = 0.95 + 0.05 * progress;
+        let transformation = Transformation::translate(anchor.x, anchor.y)
+            * Transformation::scale(scale)
+            * Transformation::translate(-anchor.x, -anchor.y);
+
+        let viewport_bounds = layout
+            .children()
+            .nth(1)
+            .expect("Native: Layout should have a viewport layout.")
+            .bounds();
+
+        renderer.with_transformation(transformation, |renderer| {
+            // Background, only of the menu behind a backdrop
+            if style_sheet.backdrop.is_some() {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: viewport_bounds,
+                        border: Border {
+                            radius: (0.0).into(),
+                            width: 0.0,
+                            color: Color::TRANSPARENT,
+                        },
+                        shadow: Shadow::default(),
+                    },
+                    with_alpha(style_sheet.background, opacity),
+                );
+            }
+
+            // Modal
+            if self.confine.is_some() {
+                let offset = self.state.scroll_offset;
+                renderer.with_layer(viewport_bounds, |renderer| {
+                    renderer.with_translation(Vector::new(0.0, -offset), |renderer| {
+                        self.content.as_widget().draw(
+                            self.tree,
+                            renderer,
+                            theme,
+                            style,
+                            content_layout,
+                            scrolled(cursor, offset),
+                            &bounds,
+                        );
+                    });
+                });
+            } else {
+                self.content.as_widget().draw(
+                    self.tree,
+                    renderer,
+                    theme,
+                    style,
+                    content_layout,
+                    cursor,
+                    &bounds,
+                );
+            }
+        });
     }
 
     fn on_event(
@@ -156,13 +250,36 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<Message>,
     ) -> Status {
-        let layout_children = layout
-            .children()
+        let mut children = layout.children();
+        let layout_children = children
             .next()
             .expect("Native: Layout should have a content layout.");
+        let viewport_bounds = children
+            .next()
+            .expect("Native: Layout should have a viewport layout.")
+            .bounds();
 
         let mut forward_event_to_children = true;
 
+        // Any input within the menu starts the auto close countdown over
+        if let Some(timeout) = self.auto_close {
+            let interacted = match &event {
+                Event::Mouse(
+                    mouse::Event::CursorMoved { .. }
+                    | mouse::Event::ButtonPressed(_)
+                    | mouse::Event::WheelScrolled { .. },
+                ) => cursor.is_over(viewport_bounds),
+                Event::Keyboard(_) | Event::Touch(_) => true,
+                _ => false,
+            };
+
+            if interacted {
+                let now = Instant::now();
+                self.state.last_interaction = Some(now);
+                shell.request_redraw(window::RedrawRequest::At(now + timeout));
+            }
+        }
+
         let status = match &event {
             Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) => {
                 if *key == keyboard::Key::Named(keyboard::key::Named::Escape) {
@@ -174,23 +291,76 @@ where
                 }
             }
 
-            Event::Mouse(mouse::Event::ButtonPressed(
-                mouse::Button::Left | mouse::Button::Right,
-            ))
+            Event::Mouse(mouse::Event::ButtonPressed(_))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if !cursor.is_over(layout_children.bounds()) {
+                if !cursor.is_over(viewport_bounds) {
                     self.state.show = false;
                     forward_event_to_children = false;
                 }
                 Status::Captured
             }
 
-            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
                 // close when released because because button send message on release
                 self.state.show = false;
                 Status::Captured
             }
 
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if self.confine.is_some() && cursor.is_over(viewport_bounds) =>
+            {
+                let delta_y = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y * 60.0,
+                    mouse::ScrollDelta::Pixels { y, .. } => *y,
+                };
+                let max_offset = f32::max(
+                    0.0,
+                    layout_children.bounds().height - viewport_bounds.height,
+                );
+
+                self.state.scroll_offset =
+                    (self.state.scroll_offset - delta_y).clamp(0.0, max_offset);
+                forward_event_to_children = false;
+                Status::Captured
+            }
+
+            // Keep the underlay from scrolling behind the open menu, or from
+            // seeing the release of the button which opened it
+            Event::Mouse(mouse::Event::WheelScrolled { .. } | mouse::Event::ButtonReleased(_))
+            | Event::Touch(touch::Event::FingerLost { .. }) => Status::Captured,
+
+            Event::Window(_id, window::Event::RedrawRequested(now)) => {
+                if let (Some(duration), Some(opened_at)) = (self.animation, self.state.opened_at) {
+                    let elapsed = now.saturating_duration_since(opened_at).as_secs_f32();
+                    let total = duration.as_secs_f32();
+
+                    if elapsed < total {
+                        self.state.animation_progress = elapsed / total;
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    } else {
+                        self.state.animation_progress = 1.0;
+                        self.state.opened_at = None;
+                    }
+                }
+
+                if let (Some(timeout), Some(last_interaction)) =
+                    (self.auto_close, self.state.last_interaction)
+                {
+                    let deadline = last_interaction + timeout;
+
+                    if *now >= deadline {
+                        self.state.show = false;
+                        self.state.last_interaction = None;
+                        shell.invalidate_layout();
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    } else {
+                        shell.request_redraw(window::RedrawRequest::At(deadline));
+                    }
+                }
+                Status::Ignored
+            }
+
             Event::Window(_id, window::Event::Resized { .. }) => {
                 self.state.show = false;
                 forward_event_to_children = false;
@@ -200,6 +370,12 @@ where
             _ => Status::Ignored,
         };
 
+        let cursor = if self.confine.is_some() {
+            scrolled(cursor, self.state.scroll_offset)
+        } else {
+            cursor
+        };
+
         let child_status = if forward_event_to_children {
             self.content.as_widget_mut().on_event(
                 self.tree,
@@ -215,6 +391,9 @@ where
             Status::Ignored
         };
 
+        // The underlay does not see the captured events which closed the menu
+        self.state.notify_toggle(self.on_toggle, shell);
+
         match child_status {
             Status::Ignored => status,
             Status::Captured => Status::Captured,
@@ -228,6 +407,12 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
+        let cursor = if self.confine.is_some() {
+            scrolled(cursor, self.state.scroll_offset)
+        } else {
+            cursor
+        };
+
         self.content.as_widget().mouse_interaction(
             self.tree,
             layout
@@ -240,3 +425,22 @@ where
         )
     }
 }
+
+/// Multiplies the alpha channel of the given [`Background`] by `alpha`.
+fn with_alpha(background: Background, alpha: f32) -> Background {
+    match background {
+        Background::Color(color) => Background::Color(Color {
+            a: color.a * alpha,
+            ..color
+        }),
+        Background::Gradient(gradient) => Background::Gradient(gradient.mul_alpha(alpha)),
+    }
+}
+
+/// Moves the given [`Cursor`] into the content of a menu scrolled by `offset`.
+fn scrolled(cursor: Cursor, offset: f32) -> Cursor {
+    match cursor {
+        Cursor::Available(position) => Cursor::Available(position + Vector::new(0.0, offset)),
+        Cursor::Unavailable => Cursor::Unavailable,
+    }
+}
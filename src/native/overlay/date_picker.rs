@@ -5,6 +5,8 @@
 use crate::{
     core::{
         date::{Date, IsInMonth},
+        ellipsis::Ellipsis,
+        locale::{self, Provider},
         overlay::Position,
     },
     date_picker,
@@ -25,7 +27,7 @@ use iced_widget::{
         layout::{Limits, Node},
         mouse::{self, Cursor},
         overlay, renderer,
-        text::Renderer as _,
+        text::{Paragraph as _, Renderer as _},
         touch,
         widget::tree::Tree,
         Alignment, Border, Clipboard, Color, Element, Event, Layout, Length, Overlay, Padding,
@@ -34,16 +36,26 @@ use iced_widget::{
     renderer::Renderer,
     text, Button, Column, Container, Row, Text,
 };
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 
 /// The padding around the elements.
-const PADDING: f32 = 10.0;
+pub(crate) const PADDING: f32 = 10.0;
 /// The spacing between the elements.
 const SPACING: f32 = 15.0;
 /// The padding of the day cells.
 const DAY_CELL_PADDING: f32 = 7.0;
 /// The spacing between the buttons.
 const BUTTON_SPACING: f32 = 5.0;
+/// The diameter of the dots marking highlighted days.
+const HIGHLIGHT_SIZE: f32 = 4.0;
+/// The number of disabled days skipped at most when moving to another day.
+const MAX_SKIPPED_DAYS: usize = 366;
+/// The number of columns of the month and the year grids.
+const GRID_COLUMNS: usize = 3;
+/// The number of rows of the month and the year grids.
+const GRID_ROWS: usize = 4;
+/// The number of years on a page of the year grid.
+const YEARS_PER_PAGE: i32 = (GRID_COLUMNS * GRID_ROWS) as i32;
 
 /// The overlay of the [`DatePicker`](crate::native::DatePicker).
 #[allow(missing_debug_implementations)]
@@ -58,12 +70,20 @@ where
     cancel_button: Button<'a, Message, Theme, Renderer>,
     /// The submit button of the [`DatePickerOverlay`].
     submit_button: Button<'a, Message, Theme, Renderer>,
+    /// The button of the [`DatePickerOverlay`] going back to the current day, if shown.
+    today_button: Option<Button<'a, Message, Theme, Renderer>>,
+    /// The message that is send if the [`DatePickerOverlay`] is cancelled with the keyboard.
+    on_cancel: Message,
     /// The function that produces a message when the submit button of the [`DatePickerOverlay`] is pressed.
-    on_submit: &'a dyn Fn(Date) -> Message,
+    on_submit: &'a OnSubmit<Message>,
+    /// Whether a click outside of the [`DatePickerOverlay`] cancels it.
+    close_on_outside_click: bool,
     /// The position of the [`DatePickerOverlay`].
     position: Point,
     /// The style of the [`DatePickerOverlay`].
     style: <Theme as StyleSheet>::Style,
+    /// The calendar of the [`DatePickerOverlay`].
+    body: Body<'a>,
     /// The reference to the tree holding the state of this overlay.
     tree: &'a mut Tree,
 }
@@ -74,38 +94,45 @@ where
     Theme: 'a + StyleSheet + button::StyleSheet + text::StyleSheet + container::StyleSheet,
 {
     /// Creates a new [`DatePickerOverlay`] on the given position.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         state: &'a mut date_picker::State,
         on_cancel: Message,
-        on_submit: &'a dyn Fn(Date) -> Message,
+        on_submit: &'a OnSubmit<Message>,
         position: Point,
         style: <Theme as StyleSheet>::Style,
+        body: Body<'a>,
+        today_label: Option<String>,
+        close_on_outside_click: bool,
         tree: &'a mut Tree,
         //button_style: impl Clone +  Into<<Renderer as button::Renderer>::Style>, // clone not satisfied
     ) -> Self {
         let date_picker::State { overlay_state } = state;
+        let locale = body.locale;
 
         DatePickerOverlay {
             state: overlay_state,
-            cancel_button: Button::new(
-                text::Text::new(icon_to_string(BootstrapIcon::X))
-                    .font(crate::BOOTSTRAP_FONT)
-                    .horizontal_alignment(Horizontal::Center)
-                    .width(Length::Fill),
-            )
-            .width(Length::Fill)
-            .on_press(on_cancel.clone()),
-            submit_button: Button::new(
-                text::Text::new(icon_to_string(BootstrapIcon::Check))
-                    .font(crate::BOOTSTRAP_FONT)
-                    .horizontal_alignment(Horizontal::Center)
-                    .width(Length::Fill),
-            )
-            .width(Length::Fill)
-            .on_press(on_cancel), // Sending a fake message
+            cancel_button: Button::new(button_label(locale.cancel_label(), BootstrapIcon::X))
+                .width(Length::Fill)
+                .on_press(on_cancel.clone()),
+            submit_button: Button::new(button_label(locale.submit_label(), BootstrapIcon::Check))
+                .width(Length::Fill)
+                .on_press(on_cancel.clone()), // Sending a fake message
+            today_button: today_label.map(|label| {
+                Button::new(
+                    Text::new(label)
+                        .horizontal_alignment(Horizontal::Center)
+                        .width(Length::Fill),
+                )
+                .width(Length::Fill)
+                .on_press(on_cancel.clone()) // Sending a fake message
+            }),
+            on_cancel,
             on_submit,
+            close_on_outside_click,
             position,
             style,
+            body,
             tree,
         }
     }
@@ -116,41 +143,340 @@ where
         overlay::Element::new(self.position, Box::new(self))
     }
 
-    /// String representation of the current year.
-    fn year_as_string(&self) -> String {
-        crate::core::date::year_as_string(self.state.date)
+    /// Publishes the message with the picked day, or the picked days in
+    /// order when picking multiple days.
+    ///
+    /// Nothing is published if the single picked day is disabled.
+    fn submit(&self, shell: &mut Shell<Message>) {
+        match self.on_submit {
+            OnSubmit::Single(on_submit) => {
+                if !self.body.is_disabled(self.state.date) {
+                    shell.publish(on_submit(self.state.date.into()));
+                }
+            }
+            OnSubmit::Multiple(on_submit) => {
+                shell.publish(on_submit(
+                    self.state
+                        .selected
+                        .iter()
+                        .copied()
+                        .map(Date::from)
+                        .collect(),
+                ));
+            }
+        }
+    }
+
+    /// The event handling for the keyboard input.
+    fn on_event_keyboard(&mut self, event: &Event, messages: &mut Shell<Message>) -> event::Status {
+        if event::Status::Captured == self.body.on_event_keyboard(self.state, event) {
+            return event::Status::Captured;
+        }
+
+        let Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(key),
+            ..
+        }) = event
+        else {
+            return event::Status::Ignored;
+        };
+
+        match key {
+            keyboard::key::Named::Escape => {
+                messages.publish(self.on_cancel.clone());
+                event::Status::Captured
+            }
+            _ if self.state.focus == Focus::None => event::Status::Ignored,
+            keyboard::key::Named::Tab => {
+                if self.state.keyboard_modifiers.shift() {
+                    self.state.focus = self.state.focus.previous();
+                } else {
+                    self.state.focus = self.state.focus.next();
+                }
+                event::Status::Ignored
+            }
+            // Only the days and the buttons act on enter, the header
+            // controls are changed with the arrow keys
+            keyboard::key::Named::Enter => match self.state.focus {
+                // Picking multiple days, enter toggles the day
+                Focus::Day if self.body.multiple => {
+                    if !self.body.is_disabled(self.state.date) {
+                        self.body.toggle(self.state, self.state.date);
+                    }
+                    event::Status::Captured
+                }
+                Focus::Day | Focus::Submit => {
+                    self.submit(messages);
+                    event::Status::Captured
+                }
+                Focus::Cancel => {
+                    messages.publish(self.on_cancel.clone());
+                    event::Status::Captured
+                }
+                Focus::Month | Focus::Year | Focus::Overlay => event::Status::Captured,
+                Focus::None => event::Status::Ignored,
+            },
+            _ => event::Status::Ignored,
+        }
+    }
+}
+
+/// The calendar of the [`DatePickerOverlay`] and of the
+/// [`Calendar`](crate::native::date_picker::Calendar): the month / year bar
+/// above the days of the month, or above the month and the year grids.
+#[allow(missing_debug_implementations)]
+#[derive(Clone, Copy)]
+pub struct Body<'a> {
+    /// The locale of the calendar.
+    pub(crate) locale: &'a dyn Provider,
+    /// The weekday the weeks of the calendar start with (0 = Monday).
+    pub(crate) first_weekday: u32,
+    /// The function checking if a day can't be picked.
+    pub(crate) disabled: Option<&'a dyn Fn(Date) -> bool>,
+    /// The function giving the color of the dot marking a day, if any.
+    pub(crate) highlights: Option<&'a dyn Fn(Date) -> Option<Color>>,
+    /// The function formatting the header shown by a single control instead
+    /// of the month and the year controls.
+    pub(crate) header_format: Option<&'a dyn Fn(Date) -> String>,
+    /// The function formatting the picked day shown above the month / year
+    /// bar, if shown.
+    pub(crate) format: Option<&'a dyn Fn(&Date) -> String>,
+    /// Whether multiple days are picked instead of one.
+    pub(crate) multiple: bool,
+    /// The maximum number of days picked at once when picking multiple days.
+    pub(crate) max_selected: Option<usize>,
+}
+
+impl Body<'_> {
+    /// Checks if the given day can't be picked.
+    pub(crate) fn is_disabled(&self, date: NaiveDate) -> bool {
+        self.disabled.is_some_and(|disabled| disabled(date.into()))
+    }
+
+    /// The color of the dot marking the given day, if any.
+    fn highlight(&self, date: NaiveDate) -> Option<Color> {
+        self.highlights
+            .and_then(|highlights| highlights(date.into()))
+    }
+
+    /// Checks if the given day is picked.
+    fn is_selected(&self, state: &State, date: NaiveDate) -> bool {
+        if self.multiple {
+            state.selected.contains(&date)
+        } else {
+            date == state.date
+        }
+    }
+
+    /// Adds the given day to the picked days, or removes it if it's already
+    /// picked, when picking multiple days.
+    ///
+    /// No day is added once the maximum number of picked days is reached.
+    pub(crate) fn toggle(&self, state: &mut State, date: NaiveDate) {
+        if self.multiple {
+            let is_full = self
+                .max_selected
+                .is_some_and(|max| state.selected.len() >= max);
+
+            if !state.selected.remove(&date) && !is_full {
+                let _ = state.selected.insert(date);
+            }
+        }
+    }
+
+    /// Moves the selected day with the given step, skipping disabled days.
+    ///
+    /// The selection stays if no enabled day is found.
+    fn step_day(&self, state: &mut State, step: fn(NaiveDate) -> NaiveDate) {
+        self.find_day(state, step(state.date), step);
+    }
+
+    /// Selects the given day, or the first enabled day reached from it with
+    /// the given step.
+    ///
+    /// The selection stays if no enabled day is found.
+    fn find_day(&self, state: &mut State, mut date: NaiveDate, step: fn(NaiveDate) -> NaiveDate) {
+        for _ in 0..MAX_SKIPPED_DAYS {
+            if !self.is_disabled(date) {
+                state.date = date;
+                state.day = date.day();
+                return;
+            }
+            date = step(date);
+        }
+    }
+
+    /// Moves to another month or year with the given step, keeping the
+    /// picked day of the month where the month has it and can be picked.
+    fn step_month(&self, state: &mut State, step: fn(NaiveDate) -> NaiveDate) {
+        self.go_to(
+            state,
+            crate::core::date::with_day_clamped(step(state.date), state.day),
+        );
+    }
+
+    /// Goes to the given day, or to the nearest enabled day if it's disabled.
+    ///
+    /// The picked day of the month is kept for the next months, the given day
+    /// is taken if no enabled day is found.
+    fn go_to(&self, state: &mut State, date: NaiveDate) {
+        let mut earlier = date;
+        let mut later = date;
+
+        for _ in 0..MAX_SKIPPED_DAYS {
+            if !self.is_disabled(later) {
+                state.date = later;
+                return;
+            }
+            earlier = crate::core::date::pred_day(earlier);
+            if !self.is_disabled(earlier) {
+                state.date = earlier;
+                return;
+            }
+            later = crate::core::date::succ_day(later);
+        }
+
+        state.date = date;
+    }
+
+    /// Goes back to the current day, or to the nearest enabled day if it
+    /// can't be picked.
+    pub(crate) fn go_to_today(&self, state: &mut State) {
+        let today = Local::now().naive_local().date();
+
+        state.day = today.day();
+        self.go_to(state, today);
+
+        state.view = View::Days;
+        state.focus = Focus::Day;
+    }
+
+    /// String representations of the current month and year.
+    fn month_year_as_strings(&self, state: &State) -> (String, String) {
+        (
+            self.locale.month_name(state.date.month()),
+            crate::core::date::year_as_string(state.date),
+        )
+    }
+
+    /// The layouts of the month and the year controls of the month / year bar.
+    ///
+    /// With a [`Body::header_format`] the bar only has the control of the
+    /// header, given as the month control.
+    fn month_year_layouts<'b>(&self, layout: Layout<'b>) -> (Layout<'b>, Option<Layout<'b>>) {
+        let mut children = layout.children();
+
+        let first = children
+            .next()
+            .expect("Native: Layout should have a month layout");
+
+        if self.header_format.is_some() {
+            return (first, None);
+        }
+
+        let second = children
+            .next()
+            .expect("Native: Layout should have a year layout");
+
+        if self.locale.year_first() {
+            (second, Some(first))
+        } else {
+            (first, Some(second))
+        }
     }
 
-    /// String representation of the current month.
-    fn month_as_string(&self) -> String {
-        crate::core::date::month_as_string(self.state.date)
+    /// The event handling for the control of the header of a
+    /// [`Body::header_format`].
+    ///
+    /// Its arrows step the months, the years in the month grid and the pages
+    /// of the year grid. Clicking it shows the month grid, then the year grid,
+    /// then the days again.
+    fn on_event_header(
+        &self,
+        state: &mut State,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) -> event::Status {
+        let mut children = layout.children();
+
+        let left_bounds = children
+            .next()
+            .expect("Native: Layout should have a left header arrow layout")
+            .bounds();
+        let center_bounds = children
+            .next()
+            .expect("Native: Layout should have a center header layout")
+            .bounds();
+        let right_bounds = children
+            .next()
+            .expect("Native: Layout should have a right header arrow layout")
+            .bounds();
+
+        let mut status = event::Status::Ignored;
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if cursor.is_over(layout.bounds()) {
+                    state.focus = Focus::Month;
+                }
+
+                if cursor.is_over(left_bounds) {
+                    match state.view {
+                        View::Days => self.step_month(state, crate::core::date::pred_month),
+                        View::Months => self.step_month(state, crate::core::date::pred_year),
+                        View::Years => state.year_page -= YEARS_PER_PAGE,
+                    }
+                    status = event::Status::Captured;
+                } else if cursor.is_over(right_bounds) {
+                    match state.view {
+                        View::Days => self.step_month(state, crate::core::date::succ_month),
+                        View::Months => self.step_month(state, crate::core::date::succ_year),
+                        View::Years => state.year_page += YEARS_PER_PAGE,
+                    }
+                    status = event::Status::Captured;
+                } else if cursor.is_over(center_bounds) {
+                    state.view = match state.view {
+                        View::Days => View::Months,
+                        View::Months => {
+                            state.year_page = year_page(state.date.year());
+                            View::Years
+                        }
+                        View::Years => View::Days,
+                    };
+                    status = event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        status
     }
 
     /// The event handling for the month / year bar.
     fn on_event_month_year(
-        &mut self,
+        &self,
+        state: &mut State,
         event: &Event,
         layout: Layout<'_>,
         cursor: Cursor,
-        _messages: &mut Shell<Message>,
-        _renderer: &Renderer,
-        _clipboard: &mut dyn Clipboard,
     ) -> event::Status {
-        let mut children = layout.children();
+        let (month_layout, year_layout) = self.month_year_layouts(layout);
+        let Some(year_layout) = year_layout else {
+            return self.on_event_header(state, event, month_layout, cursor);
+        };
 
         let mut status = event::Status::Ignored;
 
         // ----------- Month ----------------------
-        let month_layout = children
-            .next()
-            .expect("Native: Layout should have a month layout");
         let mut month_children = month_layout.children();
 
         let left_bounds = month_children
             .next()
             .expect("Native: Layout should have a left month arrow layout")
             .bounds();
-        let _center_bounds = month_children
+        let center_bounds = month_children
             .next()
             .expect("Native: Layout should have a center month layout")
             .bounds();
@@ -163,14 +489,21 @@ where
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
                 if cursor.is_over(month_layout.bounds()) {
-                    self.state.focus = Focus::Month;
+                    state.focus = Focus::Month;
                 }
 
                 if cursor.is_over(left_bounds) {
-                    self.state.date = crate::core::date::pred_month(self.state.date);
+                    self.step_month(state, crate::core::date::pred_month);
                     status = event::Status::Captured;
                 } else if cursor.is_over(right_bounds) {
-                    self.state.date = crate::core::date::succ_month(self.state.date);
+                    self.step_month(state, crate::core::date::succ_month);
+                    status = event::Status::Captured;
+                } else if cursor.is_over(center_bounds) {
+                    state.view = if state.view == View::Months {
+                        View::Days
+                    } else {
+                        View::Months
+                    };
                     status = event::Status::Captured;
                 }
             }
@@ -178,16 +511,13 @@ where
         }
 
         // ----------- Year -----------------------
-        let year_layout = children
-            .next()
-            .expect("Native: Layout should have a year layout");
         let mut year_children = year_layout.children();
 
         let left_bounds = year_children
             .next()
             .expect("Native: Layout should have a left year arrow layout")
             .bounds();
-        let _center_bounds = year_children
+        let center_bounds = year_children
             .next()
             .expect("Native: Layout should have a center year layout")
             .bounds();
@@ -200,14 +530,31 @@ where
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
                 if cursor.is_over(year_layout.bounds()) {
-                    self.state.focus = Focus::Year;
+                    state.focus = Focus::Year;
                 }
 
+                // The arrows turn the pages of the year grid
                 if cursor.is_over(left_bounds) {
-                    self.state.date = crate::core::date::pred_year(self.state.date);
+                    if state.view == View::Years {
+                        state.year_page -= YEARS_PER_PAGE;
+                    } else {
+                        self.step_month(state, crate::core::date::pred_year);
+                    }
                     status = event::Status::Captured;
                 } else if cursor.is_over(right_bounds) {
-                    self.state.date = crate::core::date::succ_year(self.state.date);
+                    if state.view == View::Years {
+                        state.year_page += YEARS_PER_PAGE;
+                    } else {
+                        self.step_month(state, crate::core::date::succ_year);
+                    }
+                    status = event::Status::Captured;
+                } else if cursor.is_over(center_bounds) {
+                    if state.view == View::Years {
+                        state.view = View::Days;
+                    } else {
+                        state.view = View::Years;
+                        state.year_page = year_page(state.date.year());
+                    }
                     status = event::Status::Captured;
                 }
             }
@@ -219,60 +566,29 @@ where
 
     /// The event handling for the calendar days.
     fn on_event_days(
-        &mut self,
+        &self,
+        state: &mut State,
         event: &Event,
         layout: Layout<'_>,
         cursor: Cursor,
-        _messages: &mut Shell<Message>,
-        _renderer: &Renderer,
-        _clipboard: &mut dyn Clipboard,
     ) -> event::Status {
-        let mut children = layout.children();
-
-        let _day_labels_layout = children
-            .next()
-            .expect("Native: Layout should have a day label layout");
-
         let mut status = event::Status::Ignored;
 
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
                 if cursor.is_over(layout.bounds()) {
-                    self.state.focus = Focus::Day;
+                    state.focus = Focus::Day;
                 }
 
-                'outer: for (y, row) in children.enumerate() {
-                    for (x, label) in row.children().enumerate() {
-                        let bounds = label.bounds();
-                        if cursor.is_over(bounds) {
-                            let (day, is_in_month) = crate::core::date::position_to_day(
-                                x,
-                                y,
-                                self.state.date.year(),
-                                self.state.date.month(),
-                            );
-
-                            self.state.date = match is_in_month {
-                                IsInMonth::Previous => {
-                                    crate::core::date::pred_month(self.state.date)
-                                        .with_day(day as u32)
-                                        .expect("Previous month with day should be valid")
-                                }
-                                IsInMonth::Same => self
-                                    .state
-                                    .date
-                                    .with_day(day as u32)
-                                    .expect("Same month with day should be valid"),
-                                IsInMonth::Next => crate::core::date::succ_month(self.state.date)
-                                    .with_day(day as u32)
-                                    .expect("Succeeding month with day should be valid"),
-                            };
-
-                            status = event::Status::Captured;
-                            break 'outer;
-                        }
+                if let Some(date) = self.day_cell(state, layout, cursor) {
+                    if !self.is_disabled(date) {
+                        state.date = date;
+                        state.day = date.day();
+                        self.toggle(state, date);
                     }
+
+                    status = event::Status::Captured;
                 }
             }
             _ => {}
@@ -281,84 +597,390 @@ where
         status
     }
 
-    /// The event handling for the keyboard input.
-    fn on_event_keyboard(
-        &mut self,
+    /// The event handling for the month and the year grids shown in place of
+    /// the calendar days.
+    fn on_event_grid(
+        &self,
+        state: &mut State,
         event: &Event,
-        _layout: Layout<'_>,
-        _cursor: Cursor,
-        _messages: &mut Shell<Message>,
-        _renderer: &Renderer,
-        _clipboard: &mut dyn Clipboard,
+        layout: Layout<'_>,
+        cursor: Cursor,
     ) -> event::Status {
-        if self.state.focus == Focus::None {
-            return event::Status::Ignored;
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(index) = grid_cells(layout.bounds()).position(|cell| cursor.is_over(cell))
+                else {
+                    return event::Status::Ignored;
+                };
+
+                let (year, month) = match state.view {
+                    View::Months => (state.date.year(), index as u32 + 1),
+                    View::Years => (state.year_page + index as i32, state.date.month()),
+                    View::Days => return event::Status::Ignored,
+                };
+
+                if let Some(date) = NaiveDate::from_ymd_opt(year, month, 1) {
+                    self.go_to(state, crate::core::date::with_day_clamped(date, state.day));
+                }
+
+                // Picking a year drills down to its months, picking a month to its days
+                state.view = if state.view == View::Years {
+                    View::Months
+                } else {
+                    View::Days
+                };
+                state.focus = Focus::Day;
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
         }
+    }
 
-        if let Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) = event {
-            let mut status = event::Status::Ignored;
+    /// The day of the cell under the cursor in the given table of the days,
+    /// if any.
+    fn day_cell(&self, state: &State, layout: Layout<'_>, cursor: Cursor) -> Option<NaiveDate> {
+        // The first row holds the day labels
+        layout.children().skip(1).enumerate().find_map(|(y, row)| {
+            row.children()
+                .position(|cell| cursor.is_over(cell.bounds()))
+                .map(|x| cell_date(state.date, x, y, self.first_weekday))
+        })
+    }
 
-            match key.as_ref() {
-                keyboard::Key::Named(keyboard::key::Named::Tab) => {
-                    if self.state.keyboard_modifiers.shift() {
-                        self.state.focus = self.state.focus.previous();
-                    } else {
-                        self.state.focus = self.state.focus.next();
-                    }
-                }
-                keyboard::Key::Named(k) => match self.state.focus {
+    /// The enabled day picked by the given event on the calendar with the
+    /// given layout, if any.
+    pub(crate) fn picked_day(
+        &self,
+        state: &State,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) -> Option<NaiveDate> {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if state.view == View::Days =>
+            {
+                self.day_cell(state, days_layout(layout), cursor)
+                    .filter(|&date| !self.is_disabled(date))
+            }
+            _ => None,
+        }
+    }
+
+    /// The event handling for the calendar with the given layout.
+    pub(crate) fn on_event(
+        &self,
+        state: &mut State,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) -> event::Status {
+        // ----------- Year/Month----------------------
+        let month_year_layout = layout
+            .children()
+            .next()
+            .expect("Native: Layout should have a month/year layout");
+        let month_year_status = self.on_event_month_year(state, event, month_year_layout, cursor);
+
+        // ----------- Days ----------------------
+        let days_layout = days_layout(layout);
+        let days_status = if state.view == View::Days {
+            self.on_event_days(state, event, days_layout, cursor)
+        } else {
+            self.on_event_grid(state, event, days_layout, cursor)
+        };
+
+        month_year_status.merge(days_status)
+    }
+
+    /// The event handling for the keyboard input moving through the
+    /// calendar.
+    ///
+    /// Escape leaves the month and the year grids, the arrow keys change the
+    /// focused month, year or day.
+    pub(crate) fn on_event_keyboard(&self, state: &mut State, event: &Event) -> event::Status {
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                ..
+            }) if state.view != View::Days => {
+                state.view = View::Days;
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(k),
+                ..
+            }) => {
+                let mut status = event::Status::Ignored;
+
+                match state.focus {
                     Focus::Month => match k {
                         keyboard::key::Named::ArrowLeft => {
-                            self.state.date = crate::core::date::pred_month(self.state.date);
+                            self.step_month(state, crate::core::date::pred_month);
                             status = event::Status::Captured;
                         }
                         keyboard::key::Named::ArrowRight => {
-                            self.state.date = crate::core::date::succ_month(self.state.date);
+                            self.step_month(state, crate::core::date::succ_month);
                             status = event::Status::Captured;
                         }
                         _ => {}
                     },
                     Focus::Year => match k {
                         keyboard::key::Named::ArrowLeft => {
-                            self.state.date = crate::core::date::pred_year(self.state.date);
+                            self.step_month(state, crate::core::date::pred_year);
                             status = event::Status::Captured;
                         }
                         keyboard::key::Named::ArrowRight => {
-                            self.state.date = crate::core::date::succ_year(self.state.date);
+                            self.step_month(state, crate::core::date::succ_year);
                             status = event::Status::Captured;
                         }
                         _ => {}
                     },
                     Focus::Day => match k {
                         keyboard::key::Named::ArrowLeft => {
-                            self.state.date = crate::core::date::pred_day(self.state.date);
+                            self.step_day(state, crate::core::date::pred_day);
                             status = event::Status::Captured;
                         }
                         keyboard::key::Named::ArrowRight => {
-                            self.state.date = crate::core::date::succ_day(self.state.date);
+                            self.step_day(state, crate::core::date::succ_day);
                             status = event::Status::Captured;
                         }
                         keyboard::key::Named::ArrowUp => {
-                            self.state.date = crate::core::date::pred_week(self.state.date);
+                            self.step_day(state, crate::core::date::pred_week);
                             status = event::Status::Captured;
                         }
                         keyboard::key::Named::ArrowDown => {
-                            self.state.date = crate::core::date::succ_week(self.state.date);
+                            self.step_day(state, crate::core::date::succ_week);
+                            status = event::Status::Captured;
+                        }
+                        keyboard::key::Named::PageUp => {
+                            self.step_month(
+                                state,
+                                if state.keyboard_modifiers.shift() {
+                                    crate::core::date::pred_year
+                                } else {
+                                    crate::core::date::pred_month
+                                },
+                            );
+                            status = event::Status::Captured;
+                        }
+                        keyboard::key::Named::PageDown => {
+                            self.step_month(
+                                state,
+                                if state.keyboard_modifiers.shift() {
+                                    crate::core::date::succ_year
+                                } else {
+                                    crate::core::date::succ_month
+                                },
+                            );
+                            status = event::Status::Captured;
+                        }
+                        keyboard::key::Named::Home => {
+                            self.find_day(
+                                state,
+                                crate::core::date::with_day_clamped(state.date, 1),
+                                crate::core::date::succ_day,
+                            );
+                            status = event::Status::Captured;
+                        }
+                        keyboard::key::Named::End => {
+                            self.find_day(
+                                state,
+                                crate::core::date::with_day_clamped(state.date, 31),
+                                crate::core::date::pred_day,
+                            );
                             status = event::Status::Captured;
                         }
                         _ => {}
                     },
                     _ => {}
-                },
-                _ => {}
+                }
+
+                status
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.keyboard_modifiers = *modifiers;
+                event::Status::Ignored
             }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    /// The mouse interaction of the calendar with the given layout.
+    pub(crate) fn mouse_interaction(
+        &self,
+        state: &State,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) -> mouse::Interaction {
+        // Month and year mouse interaction
+        let month_year_layout = layout
+            .children()
+            .next()
+            .expect("Graphics: Layout should have a month/year layout");
+        let mut month_year_children = month_year_layout.children();
+        let month_layout = month_year_children
+            .next()
+            .expect("Graphics: Layout should have a month layout");
+        let year_layout = month_year_children.next();
+
+        let f = |layout: Layout<'_>| {
+            let mut children = layout.children();
 
-            status
-        } else if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) = event {
-            self.state.keyboard_modifiers = *modifiers;
-            event::Status::Ignored
+            let left_bounds = children
+                .next()
+                .expect("Graphics: Layout should have a left arrow layout")
+                .bounds();
+            let center_bounds = children
+                .next()
+                .expect("Graphics: Layout should have a center layout")
+                .bounds();
+            let right_bounds = children
+                .next()
+                .expect("Graphics: Layout should have a right arrow layout")
+                .bounds();
+
+            let mut mouse_interaction = mouse::Interaction::default();
+
+            let left_arrow_hovered = cursor.is_over(left_bounds);
+            let right_arrow_hovered = cursor.is_over(right_bounds);
+            let center_hovered = cursor.is_over(center_bounds);
+
+            if left_arrow_hovered || right_arrow_hovered || center_hovered {
+                mouse_interaction = mouse_interaction.max(mouse::Interaction::Pointer);
+            }
+
+            mouse_interaction
+        };
+
+        let month_mouse_interaction = f(month_layout);
+        let year_mouse_interaction = year_layout.map_or_else(mouse::Interaction::default, f);
+
+        // Days
+        let days_layout = days_layout(layout);
+        let table_mouse_interaction = if state.view == View::Days {
+            self.day_cell(state, days_layout, cursor)
+                .filter(|&date| !self.is_disabled(date))
+                .map_or_else(mouse::Interaction::default, |_| mouse::Interaction::Pointer)
+        } else if grid_cells(days_layout.bounds()).any(|cell| cursor.is_over(cell)) {
+            mouse::Interaction::Pointer
         } else {
-            event::Status::Ignored
+            mouse::Interaction::default()
+        };
+
+        month_mouse_interaction
+            .max(year_mouse_interaction)
+            .max(table_mouse_interaction)
+    }
+
+    /// Draws the calendar with the given layout.
+    pub(crate) fn draw(
+        &self,
+        state: &State,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        style_sheet: &HashMap<StyleState, Appearance>,
+    ) {
+        // ----------- Year/Month----------------------
+        let month_year_layout = layout
+            .children()
+            .next()
+            .expect("Graphics: Layout should have a month/year layout");
+
+        let (month_layout, year_layout) = self.month_year_layouts(month_year_layout);
+        let years = || {
+            format!(
+                "{} – {}",
+                state.year_page,
+                state.year_page + YEARS_PER_PAGE - 1
+            )
+        };
+        let (month, year) = match self.header_format {
+            Some(_) if state.view == View::Years => (years(), None),
+            Some(header_format) => (header_format(state.date.into()), None),
+            None if state.view == View::Years => {
+                (self.month_year_as_strings(state).0, Some(years()))
+            }
+            None => {
+                let (month, year) = self.month_year_as_strings(state);
+                (month, Some(year))
+            }
+        };
+
+        month_year(
+            renderer,
+            month_layout,
+            year_layout.zip(year.as_deref()),
+            &month,
+            cursor.position().unwrap_or_default(),
+            style_sheet,
+            state.focus,
+        );
+
+        // ----------- Picked day ----------------------
+        if let Some((format, header_layout)) = self.format.zip(layout.children().nth(2)) {
+            let bounds = header_layout.bounds();
+            let content =
+                state
+                    .ellipsis
+                    .shorten(&format(&state.date.into()), bounds.width, |content| {
+                        <Renderer as core::text::Renderer>::Paragraph::with_text(header_text(
+                            renderer,
+                            content,
+                            Size::INFINITY,
+                        ))
+                        .min_width()
+                    });
+
+            renderer.fill_text(
+                header_text(renderer, &content, bounds.size()),
+                Point::new(bounds.center_x(), bounds.center_y()),
+                style_sheet[&StyleState::Active].text_color,
+                bounds,
+            );
+        }
+
+        // ----------- Days ---------------------------
+        let days_layout = days_layout(layout);
+
+        match state.view {
+            View::Days => days(
+                renderer,
+                days_layout,
+                state.date,
+                self.locale,
+                self.first_weekday,
+                cursor.position().unwrap_or_default(),
+                style_sheet,
+                state.focus,
+                &|date| self.is_selected(state, date),
+                &|date| self.is_disabled(date),
+                &|date| self.highlight(date),
+            ),
+            View::Months => grid(
+                renderer,
+                days_layout.bounds(),
+                &(1..=12)
+                    .map(|month| self.locale.month_name(month))
+                    .collect::<Vec<_>>(),
+                Some(state.date.month0() as usize),
+                cursor.position().unwrap_or_default(),
+                style_sheet,
+            ),
+            View::Years => grid(
+                renderer,
+                days_layout.bounds(),
+                &(0..YEARS_PER_PAGE)
+                    .map(|i| (state.year_page + i).to_string())
+                    .collect::<Vec<_>>(),
+                usize::try_from(state.date.year() - state.year_page).ok(),
+                cursor.position().unwrap_or_default(),
+                style_sheet,
+            ),
         }
     }
 }
@@ -391,119 +1013,35 @@ where
 
         let limits = limits.shrink(Size::new(0.0, cancel_button.bounds().height + SPACING));
 
-        // Month/Year
-        let font_size = renderer.default_size();
-
-        let month_year = Row::<Message, Theme, Renderer>::new()
-            .width(Length::Fill)
-            .spacing(SPACING)
-            .push(
-                Row::new()
-                    .width(Length::Fill)
-                    .push(Container::new(
-                        Row::new().push(
-                            Text::new(icon_to_string(BootstrapIcon::CaretLeftFill))
-                                .size(font_size.0 + 1.0)
-                                .font(crate::BOOTSTRAP_FONT),
-                        ),
-                    ))
-                    .push(
-                        // Month
-                        Text::new("October").width(Length::Fill),
-                    )
-                    .push(
-                        // Right Month arrow
-                        Container::new(
-                            Text::new(icon_to_string(BootstrapIcon::CaretRightFill))
-                                .size(font_size.0 + 1.0)
-                                .font(crate::BOOTSTRAP_FONT),
-                        )
-                        .height(Length::Shrink)
-                        .width(Length::Shrink),
-                    ),
-            )
-            .push(
-                Row::new()
-                    .width(Length::Fill)
-                    .push(Container::new(
-                        Row::new().push(
-                            Text::new(icon_to_string(BootstrapIcon::CaretLeftFill))
-                                .size(font_size.0 + 1.0)
-                                .font(BOOTSTRAP_FONT),
-                        ),
-                    ))
-                    .push(
-                        // Year
-                        Text::new("9999").width(Length::Fill),
-                    )
-                    .push(
-                        // Right Year arrow
-                        Container::new(
-                            Row::new().push(
-                                Text::new(icon_to_string(BootstrapIcon::CaretRightFill))
-                                    .size(font_size.0 + 1.0)
-                                    .font(BOOTSTRAP_FONT),
-                            ),
-                        )
-                        .height(Length::Shrink)
-                        .width(Length::Shrink),
-                    ),
-            );
-
-        let days = Container::<Message, Theme, Renderer>::new((0..7).fold(
-            Column::new().width(Length::Fill).height(Length::Fill),
-            |column, _y| {
-                column.push(
-                    (0..7).fold(
-                        Row::new()
-                            .height(Length::Fill)
-                            .width(Length::Fill)
-                            .padding(DAY_CELL_PADDING),
-                        |row, _x| {
-                            row.push(
-                                Container::new(Row::new().push(Text::new("31").size(font_size)))
-                                    .width(Length::Fill)
-                                    .height(Length::Fill),
-                            )
-                        },
-                    ),
-                )
-            },
-        ))
-        .width(Length::Fill)
-        .height(Length::Fill)
-        .center_y();
-
-        let col = Column::<Message, Theme, Renderer>::new()
-            .spacing(SPACING)
-            .align_items(Alignment::Center)
-            .push(month_year)
-            .push(days);
-
-        let element: Element<Message, Theme, Renderer> = Element::new(col);
-        let col_tree = if let Some(child_tree) = self.tree.children.get_mut(2) {
-            child_tree.diff(element.as_widget());
-            child_tree
-        } else {
-            let child_tree = Tree::new(element.as_widget());
-            self.tree.children.insert(2, child_tree);
-            &mut self.tree.children[2]
-        };
-
-        let mut col = element.as_widget().layout(col_tree, renderer, &limits);
+        // Month/Year and days
+        if self.tree.children.len() < 4 {
+            self.tree.children.push(Tree::empty());
+        }
+        let mut col = body_layout::<Message, Theme>(
+            &mut self.tree.children[3],
+            renderer,
+            &limits,
+            self.body.format.is_some(),
+            self.body.header_format.is_some(),
+        );
         let col_bounds = col.bounds();
         col = col.move_to(Point::new(col_bounds.x + PADDING, col_bounds.y + PADDING));
 
         // Buttons
+        let button_count = if self.today_button.is_some() {
+            3.0
+        } else {
+            2.0
+        };
         let cancel_limits =
-            limits.max_width(((col.bounds().width / 2.0) - BUTTON_SPACING).max(0.0));
+            limits.max_width(((col.bounds().width / button_count) - BUTTON_SPACING).max(0.0));
 
         let mut cancel_button =
             self.cancel_button
                 .layout(&mut self.tree.children[0], renderer, &cancel_limits);
 
         let submit_limits =
-            limits.max_width(((col.bounds().width / 2.0) - BUTTON_SPACING).max(0.0));
+            limits.max_width(((col.bounds().width / button_count) - BUTTON_SPACING).max(0.0));
 
         let mut submit_button =
             self.submit_button
@@ -521,12 +1059,30 @@ where
             y: submit_bounds.y + col.bounds().height + PADDING + SPACING,
         });
 
+        let today_button = self.today_button.as_ref().map(|today_button| {
+            let today_limits =
+                limits.max_width(((col.bounds().width / button_count) - BUTTON_SPACING).max(0.0));
+
+            let today_button =
+                today_button.layout(&mut self.tree.children[2], renderer, &today_limits);
+
+            let today_bounds = today_button.bounds();
+            today_button.move_to(Point {
+                x: today_bounds.x + (col.bounds().width - today_bounds.width) / 2.0 + PADDING,
+                y: today_bounds.y + col.bounds().height + PADDING + SPACING,
+            })
+        });
+
+        let size = Size::new(
+            col.bounds().width + (2.0 * PADDING),
+            col.bounds().height + cancel_button.bounds().height + (2.0 * PADDING) + SPACING,
+        );
         let mut node = Node::with_children(
-            Size::new(
-                col.bounds().width + (2.0 * PADDING),
-                col.bounds().height + cancel_button.bounds().height + (2.0 * PADDING) + SPACING,
-            ),
-            vec![col, cancel_button, submit_button],
+            size,
+            [col, cancel_button, submit_button]
+                .into_iter()
+                .chain(today_button)
+                .collect(),
         );
         node.center_and_bounce(position, bounds);
         node
@@ -541,41 +1097,33 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<Message>,
     ) -> event::Status {
-        if event::Status::Captured
-            == self.on_event_keyboard(&event, layout, cursor, shell, renderer, clipboard)
-        {
+        if event::Status::Captured == self.on_event_keyboard(&event, shell) {
             return event::Status::Captured;
         }
 
-        let mut children = layout.children();
+        if self.close_on_outside_click
+            && matches!(
+                event,
+                Event::Mouse(mouse::Event::ButtonPressed(_))
+                    | Event::Touch(touch::Event::FingerPressed { .. })
+            )
+            && cursor
+                .position()
+                .is_some_and(|position| !layout.bounds().contains(position))
+        {
+            shell.publish(self.on_cancel.clone());
+            return event::Status::Captured;
+        }
 
-        let mut date_children = children
-            .next()
-            .expect("Native: Layout should have date children")
-            .children();
+        let pointer_status = crate::core::overlay::pointer_status(&event, layout.bounds(), cursor);
 
-        // ----------- Year/Month----------------------
-        let month_year_layout = date_children
-            .next()
-            .expect("Native: Layout should have a month/year layout");
-        let month_year_status = self.on_event_month_year(
-            &event,
-            month_year_layout,
-            cursor,
-            shell,
-            renderer,
-            clipboard,
-        );
+        let mut children = layout.children();
 
-        // ----------- Days ----------------------
-        let days_layout = date_children
+        // ----------- Year/Month and days ----------------------
+        let body_layout = children
             .next()
-            .expect("Native: Layout should have a days table parent")
-            .children()
-            .next()
-            .expect("Native: Layout should have a days table layout");
-        let days_status =
-            self.on_event_days(&event, days_layout, cursor, shell, renderer, clipboard);
+            .expect("Native: Layout should have a calendar layout");
+        let body_status = self.body.on_event(self.state, &event, body_layout, cursor);
 
         // ----------- Buttons ------------------------
         let cancel_button_layout = children
@@ -601,7 +1149,7 @@ where
 
         let submit_status = self.submit_button.on_event(
             &mut self.tree.children[1],
-            event,
+            event.clone(),
             submit_button_layout,
             cursor,
             renderer,
@@ -611,13 +1159,34 @@ where
         );
 
         if !fake_messages.is_empty() {
-            shell.publish((self.on_submit)(self.state.date.into()));
+            self.submit(shell);
+        }
+
+        let mut fake_messages: Vec<Message> = Vec::new();
+
+        let today_status = match (&mut self.today_button, children.next()) {
+            (Some(today_button), Some(today_button_layout)) => today_button.on_event(
+                &mut self.tree.children[2],
+                event,
+                today_button_layout,
+                cursor,
+                renderer,
+                clipboard,
+                &mut Shell::new(&mut fake_messages),
+                &layout.bounds(),
+            ),
+            _ => event::Status::Ignored,
+        };
+
+        if !fake_messages.is_empty() {
+            self.body.go_to_today(self.state);
         }
 
-        month_year_status
-            .merge(days_status)
+        body_status
             .merge(cancel_status)
             .merge(submit_status)
+            .merge(today_status)
+            .merge(pointer_status)
     }
 
     fn mouse_interaction(
@@ -627,77 +1196,14 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
-        let mouse_interaction = mouse::Interaction::default();
-
         let mut children = layout.children();
-        let mut date_children = children
-            .next()
-            .expect("Graphics: Layout should have a date layout")
-            .children();
-
-        // Month and year mouse interaction
-        let month_year_layout = date_children
-            .next()
-            .expect("Graphics: Layout should have a month/year layout");
-        let mut month_year_children = month_year_layout.children();
-        let month_layout = month_year_children
-            .next()
-            .expect("Graphics: Layout should have a month layout");
-        let year_layout = month_year_children
-            .next()
-            .expect("Graphics: Layout should have a year layout");
-
-        let f = |layout: Layout<'_>| {
-            let mut children = layout.children();
-
-            let left_bounds = children
-                .next()
-                .expect("Graphics: Layout should have a left arrow layout")
-                .bounds();
-            let _center = children.next();
-            let right_bounds = children
+        let body_mouse_interaction = self.body.mouse_interaction(
+            self.state,
+            children
                 .next()
-                .expect("Graphics: Layout should have a right arrow layout")
-                .bounds();
-
-            let mut mouse_interaction = mouse::Interaction::default();
-
-            let left_arrow_hovered = cursor.is_over(left_bounds);
-            let right_arrow_hovered = cursor.is_over(right_bounds);
-
-            if left_arrow_hovered || right_arrow_hovered {
-                mouse_interaction = mouse_interaction.max(mouse::Interaction::Pointer);
-            }
-
-            mouse_interaction
-        };
-
-        let month_mouse_interaction = f(month_layout);
-        let year_mouse_interaction = f(year_layout);
-
-        // Days
-        let days_layout = date_children
-            .next()
-            .expect("Graphics: Layout should have a days layout parent")
-            .children()
-            .next()
-            .expect("Graphics: Layout should have a days layout");
-        let mut days_children = days_layout.children();
-        let _day_labels_layout = days_children.next();
-
-        let mut table_mouse_interaction = mouse::Interaction::default();
-
-        for row in days_children {
-            for label in row.children() {
-                let bounds = label.bounds();
-
-                let mouse_over = cursor.is_over(bounds);
-                if mouse_over {
-                    table_mouse_interaction =
-                        table_mouse_interaction.max(mouse::Interaction::Pointer);
-                }
-            }
-        }
+                .expect("Graphics: Layout should have a calendar layout"),
+            cursor,
+        );
 
         // Buttons
         let cancel_button_layout = children
@@ -724,12 +1230,25 @@ where
             renderer,
         );
 
-        mouse_interaction
-            .max(month_mouse_interaction)
-            .max(year_mouse_interaction)
-            .max(table_mouse_interaction)
+        let today_button_mouse_interaction = self
+            .today_button
+            .as_ref()
+            .zip(children.next())
+            .map(|(today_button, today_button_layout)| {
+                today_button.mouse_interaction(
+                    &self.tree.children[2],
+                    today_button_layout,
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            })
+            .unwrap_or_default();
+
+        body_mouse_interaction
             .max(cancel_button_mouse_interaction)
             .max(submit_button_mouse_interaction)
+            .max(today_button_mouse_interaction)
     }
 
     fn draw(
@@ -742,19 +1261,7 @@ where
     ) {
         let bounds = layout.bounds();
         let mut children = layout.children();
-        let mut date_children = children
-            .next()
-            .expect("Graphics: Layout should have a date layout")
-            .children();
-
-        let mut style_sheet: HashMap<StyleState, Appearance> = HashMap::new();
-        let _ = style_sheet.insert(StyleState::Active, StyleSheet::active(theme, &self.style));
-        let _ = style_sheet.insert(
-            StyleState::Selected,
-            StyleSheet::selected(theme, &self.style),
-        );
-        let _ = style_sheet.insert(StyleState::Hovered, StyleSheet::hovered(theme, &self.style));
-        let _ = style_sheet.insert(StyleState::Focused, StyleSheet::focused(theme, &self.style));
+        let style_sheet = style_sheet(theme, &self.style);
 
         let mut style_state = StyleState::Active;
         if self.state.focus == Focus::Overlay {
@@ -778,37 +1285,12 @@ where
             style_sheet[&style_state].background,
         );
 
-        // ----------- Year/Month----------------------
-        let month_year_layout = date_children
-            .next()
-            .expect("Graphics: Layout should have a month/year layout");
-
-        month_year(
-            renderer,
-            month_year_layout,
-            &self.month_as_string(),
-            &self.year_as_string(),
-            cursor.position().unwrap_or_default(),
-            &style_sheet,
-            self.state.focus,
-        );
-
-        // ----------- Days ---------------------------
-        let days_layout = date_children
-            .next()
-            .expect("Graphics: Layout should have a days layout parent")
-            .children()
+        // ----------- Year/Month and days ----------------------
+        let body_layout = children
             .next()
-            .expect("Graphics: Layout should have a days layout");
-
-        days(
-            renderer,
-            days_layout,
-            self.state.date,
-            cursor.position().unwrap_or_default(),
-            &style_sheet,
-            self.state.focus,
-        );
+            .expect("Graphics: Layout should have a calendar layout");
+        self.body
+            .draw(self.state, renderer, body_layout, cursor, &style_sheet);
 
         // ----------- Buttons ------------------------
         let cancel_button_layout = children
@@ -839,6 +1321,20 @@ where
             &bounds,
         );
 
+        if let (Some(today_button), Some(today_button_layout)) =
+            (&self.today_button, children.next())
+        {
+            today_button.draw(
+                &self.tree.children[2],
+                renderer,
+                theme,
+                style,
+                today_button_layout,
+                cursor,
+                &bounds,
+            );
+        }
+
         // Buttons are not focusable right now...
         if self.state.focus == Focus::Cancel {
             renderer.fill_quad(
@@ -881,6 +1377,16 @@ pub struct State {
     pub(crate) focus: Focus,
     /// The previously pressed keyboard modifiers.
     pub(crate) keyboard_modifiers: keyboard::Modifiers,
+    /// The picked day of the month, kept across month and year changes.
+    pub(crate) day: u32,
+    /// The view shown below the month / year bar.
+    pub(crate) view: View,
+    /// The first year of the page shown by the year grid.
+    pub(crate) year_page: i32,
+    /// The picked days when picking multiple days.
+    pub(crate) selected: BTreeSet<NaiveDate>,
+    /// The shortened picked day shown above the month / year bar.
+    pub(crate) ellipsis: Ellipsis,
 }
 
 impl State {
@@ -889,6 +1395,7 @@ impl State {
     pub fn new(date: NaiveDate) -> Self {
         Self {
             date,
+            day: date.day(),
             ..Self::default()
         }
     }
@@ -896,16 +1403,23 @@ impl State {
 
 impl Default for State {
     fn default() -> Self {
+        let date = Local::now().naive_local().date();
+
         Self {
-            date: Local::now().naive_local().date(),
+            date,
             focus: Focus::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
+            day: date.day(),
+            view: View::default(),
+            year_page: year_page(date.year()),
+            selected: BTreeSet::new(),
+            ellipsis: Ellipsis::default(),
         }
     }
 }
 
 /// Just a workaround to pass the button states from the tree to the overlay
-#[allow(missing_debug_implementations)]
+#[allow(missing_debug_implementations, clippy::struct_field_names)]
 pub struct DatePickerOverlayButtons<'a, Message, Theme>
 where
     Message: Clone,
@@ -915,6 +1429,8 @@ where
     cancel_button: Element<'a, Message, Theme, Renderer>,
     /// The submit button of the [`DatePickerOverlay`].
     submit_button: Element<'a, Message, Theme, Renderer>,
+    /// The today button of the [`DatePickerOverlay`].
+    today_button: Element<'a, Message, Theme, Renderer>,
 }
 
 impl<'a, Message, Theme> Default for DatePickerOverlayButtons<'a, Message, Theme>
@@ -938,6 +1454,12 @@ where
                     .width(Length::Fill),
             )
             .into(),
+            today_button: Button::new(
+                text::Text::new(locale::default_provider().today_label())
+                    .horizontal_alignment(Horizontal::Center)
+                    .width(Length::Fill),
+            )
+            .into(),
         }
     }
 }
@@ -953,11 +1475,12 @@ where
         vec![
             Tree::new(&self.cancel_button),
             Tree::new(&self.submit_button),
+            Tree::new(&self.today_button),
         ]
     }
 
     fn diff(&self, tree: &mut Tree) {
-        tree.diff_children(&[&self.cancel_button, &self.submit_button]);
+        tree.diff_children(&[&self.cancel_button, &self.submit_button, &self.today_button]);
     }
 
     fn size(&self) -> Size<Length> {
@@ -1053,26 +1576,59 @@ impl Default for Focus {
     }
 }
 
-/// Draws the month/year row
+/// The function producing the message sent when the days picked with the
+/// [`DatePickerOverlay`] are submitted.
+#[allow(missing_debug_implementations)]
+pub enum OnSubmit<Message> {
+    /// Submits the single picked day.
+    Single(Box<dyn Fn(Date) -> Message>),
+
+    /// Submits the picked days in order.
+    Multiple(Box<dyn Fn(Vec<Date>) -> Message>),
+}
+
+/// An enumeration of the views shown below the month / year bar of the
+/// [`DatePickerOverlay`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum View {
+    /// The days of the month.
+    #[default]
+    Days,
+
+    /// The months of the year.
+    Months,
+
+    /// A page of years.
+    Years,
+}
+
+/// The content of a button, its label or its icon if it has none.
+fn button_label<'a, Theme>(label: Option<String>, icon: BootstrapIcon) -> Text<'a, Theme, Renderer>
+where
+    Theme: text::StyleSheet,
+{
+    label
+        .map_or_else(
+            || Text::new(icon_to_string(icon)).font(BOOTSTRAP_FONT),
+            Text::new,
+        )
+        .horizontal_alignment(Horizontal::Center)
+        .width(Length::Fill)
+}
+
+/// Draws the month/year row, or only the month control showing the header of
+/// a [`Body::header_format`] without a year.
+#[allow(clippy::too_many_arguments)]
 fn month_year(
     renderer: &mut Renderer,
-    layout: Layout<'_>,
+    month_layout: Layout<'_>,
+    year: Option<(Layout<'_>, &str)>,
     month: &str,
-    year: &str,
     cursor: Point,
     //style: &Style,
     style: &HashMap<StyleState, Appearance>,
     focus: Focus,
 ) {
-    let mut children = layout.children();
-
-    let month_layout = children
-        .next()
-        .expect("Graphics: Layout should have a month layout");
-    let year_layout = children
-        .next()
-        .expect("Graphics: Layout should have a year layout");
-
     let mut f = |layout: Layout<'_>, text: &str, target: Focus| {
         let style_state = if focus == target {
             StyleState::Focused
@@ -1159,7 +1715,7 @@ fn month_year(
                 horizontal_alignment: Horizontal::Center,
                 vertical_alignment: Vertical::Center,
                 line_height: text::LineHeight::Relative(1.3),
-                shaping: text::Shaping::Basic,
+                shaping: text::Shaping::Advanced,
             },
             Point::new(center_bounds.center_x(), center_bounds.center_y()),
             style
@@ -1196,33 +1752,67 @@ fn month_year(
     f(month_layout, month, Focus::Month);
 
     // Draw year
-    f(year_layout, year, Focus::Year);
+    if let Some((year_layout, year)) = year {
+        f(year_layout, year, Focus::Year);
+    }
 }
 
 /// Draws the days
+#[allow(clippy::too_many_arguments)]
 fn days(
     renderer: &mut Renderer,
     layout: Layout<'_>,
     date: chrono::NaiveDate,
+    locale: &dyn Provider,
+    first_weekday: u32,
     cursor: Point,
     //style: &Style,
     style: &HashMap<StyleState, Appearance>,
     focus: Focus,
+    is_selected: &dyn Fn(NaiveDate) -> bool,
+    is_disabled: &dyn Fn(NaiveDate) -> bool,
+    highlight: &dyn Fn(NaiveDate) -> Option<Color>,
 ) {
     let mut children = layout.children();
 
     let day_labels_layout = children
         .next()
         .expect("Graphics: Layout should have a day labels layout");
-    day_labels(renderer, day_labels_layout, style, focus);
+    day_labels(
+        renderer,
+        day_labels_layout,
+        locale,
+        first_weekday,
+        style,
+        focus,
+    );
+
+    day_table(
+        renderer,
+        &mut children,
+        date,
+        first_weekday,
+        cursor,
+        style,
+        focus,
+        is_selected,
+        is_disabled,
+        highlight,
+    );
+}
 
-    day_table(renderer, &mut children, date, cursor, style, focus);
+/// The label of the weekday in the given column of a calendar whose weeks
+/// start with the given weekday.
+fn weekday_label(locale: &dyn Provider, first_weekday: u32, column: usize) -> String {
+    locale.weekday_short_name((first_weekday + column as u32) % 7)
 }
 
 /// Draws the day labels
 fn day_labels(
     renderer: &mut Renderer,
     layout: Layout<'_>,
+    locale: &dyn Provider,
+    first_weekday: u32,
     style: &HashMap<StyleState, Appearance>,
     _focus: Focus,
 ) {
@@ -1231,7 +1821,7 @@ fn day_labels(
 
         renderer.fill_text(
             core::text::Text {
-                content: &crate::core::date::WEEKDAY_LABELS[i],
+                content: &weekday_label(locale, first_weekday, i),
                 bounds: Size::new(bounds.width, bounds.height),
                 size: renderer.default_size(),
                 font: renderer.default_font(),
@@ -1251,23 +1841,35 @@ fn day_labels(
 }
 
 /// Draws the day table
+#[allow(clippy::too_many_arguments)]
 fn day_table(
     renderer: &mut Renderer,
     children: &mut dyn Iterator<Item = Layout<'_>>,
     date: chrono::NaiveDate,
+    first_weekday: u32,
     cursor: Point,
     style: &HashMap<StyleState, Appearance>,
     focus: Focus,
+    is_selected: &dyn Fn(NaiveDate) -> bool,
+    is_disabled: &dyn Fn(NaiveDate) -> bool,
+    highlight: &dyn Fn(NaiveDate) -> Option<Color>,
 ) {
     for (y, row) in children.enumerate() {
         for (x, label) in row.children().enumerate() {
             let bounds = label.bounds();
-            let (number, is_in_month) =
-                crate::core::date::position_to_day(x, y, date.year(), date.month());
+            let (number, is_in_month) = crate::core::date::position_to_day_from(
+                x,
+                y,
+                date.year(),
+                date.month(),
+                first_weekday,
+            );
 
             let mouse_over = bounds.contains(cursor);
 
-            let selected = date.day() == number as u32 && is_in_month == IsInMonth::Same;
+            let cell = cell_date(date, x, y, first_weekday);
+            let selected = is_selected(cell);
+            let focused = focus == Focus::Day && cell == date;
 
             let mut style_state = StyleState::Active;
             if selected {
@@ -1276,6 +1878,12 @@ fn day_table(
             if mouse_over {
                 style_state = style_state.max(StyleState::Hovered);
             }
+            if focused {
+                style_state = StyleState::FocusedItem;
+            }
+            if is_disabled(cell) {
+                style_state = StyleState::Disabled;
+            }
 
             renderer.fill_quad(
                 renderer::Quad {
@@ -1293,22 +1901,22 @@ fn day_table(
                     .day_background,
             );
 
-            if focus == Focus::Day && selected {
+            if focused {
                 renderer.fill_quad(
                     renderer::Quad {
                         bounds,
                         border: Border {
                             radius: style
-                                .get(&StyleState::Focused)
+                                .get(&StyleState::FocusedItem)
                                 .expect("Style Sheet not found.")
                                 .border_radius
                                 .into(),
                             width: style
-                                .get(&StyleState::Focused)
+                                .get(&StyleState::FocusedItem)
                                 .expect("Style Sheet not found.")
                                 .border_width,
                             color: style
-                                .get(&StyleState::Focused)
+                                .get(&StyleState::FocusedItem)
                                 .expect("Style Sheet not found.")
                                 .border_color,
                         },
@@ -1343,6 +1951,426 @@ fn day_table(
                 },
                 bounds,
             );
+
+            if let Some(color) = highlight(cell) {
+                // Below the number, within the cell
+                let y = (bounds.center_y() + renderer.default_size().0 / 2.0)
+                    .min(bounds.y + bounds.height - HIGHLIGHT_SIZE);
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.center_x() - HIGHLIGHT_SIZE / 2.0,
+                            y,
+                            width: HIGHLIGHT_SIZE,
+                            height: HIGHLIGHT_SIZE,
+                        },
+                        border: Border {
+                            radius: (HIGHLIGHT_SIZE / 2.0).into(),
+                            width: 0.0,
+                            color: Color::TRANSPARENT,
+                        },
+                        shadow: Shadow::default(),
+                    },
+                    color,
+                );
+            }
+        }
+    }
+}
+
+/// Draws the month or the year grid with the given labels
+fn grid(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    labels: &[String],
+    selected: Option<usize>,
+    cursor: Point,
+    style: &HashMap<StyleState, Appearance>,
+) {
+    for (i, (cell, label)) in grid_cells(bounds).zip(labels).enumerate() {
+        let mut style_state = StyleState::Active;
+        if selected == Some(i) {
+            style_state = style_state.max(StyleState::Selected);
+        }
+        if cell.contains(cursor) {
+            style_state = style_state.max(StyleState::Hovered);
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: cell,
+                border: Border {
+                    radius: (cell.height / 2.0).into(),
+                    width: 0.0,
+                    color: Color::TRANSPARENT,
+                },
+                shadow: Shadow::default(),
+            },
+            style
+                .get(&style_state)
+                .expect("Style Sheet not found.")
+                .day_background,
+        );
+
+        renderer.fill_text(
+            core::text::Text {
+                content: label,
+                bounds: Size::new(cell.width, cell.height),
+                size: renderer.default_size(),
+                font: renderer.default_font(),
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Center,
+                line_height: text::LineHeight::Relative(1.3),
+                shaping: text::Shaping::Advanced,
+            },
+            Point::new(cell.center_x(), cell.center_y()),
+            style
+                .get(&style_state)
+                .expect("Style Sheet not found.")
+                .text_color,
+            cell,
+        );
+    }
+}
+
+/// The appearances of the states of a date picker with the given style.
+pub(crate) fn style_sheet<Theme>(
+    theme: &Theme,
+    style: &<Theme as StyleSheet>::Style,
+) -> HashMap<StyleState, Appearance>
+where
+    Theme: StyleSheet,
+{
+    let mut style_sheet: HashMap<StyleState, Appearance> = HashMap::new();
+    let _ = style_sheet.insert(StyleState::Active, StyleSheet::active(theme, style));
+    let _ = style_sheet.insert(StyleState::Selected, StyleSheet::selected(theme, style));
+    let _ = style_sheet.insert(StyleState::Hovered, StyleSheet::hovered(theme, style));
+    let _ = style_sheet.insert(StyleState::Focused, StyleSheet::focused(theme, style));
+    let _ = style_sheet.insert(StyleState::Disabled, StyleSheet::disabled(theme, style));
+    let _ = style_sheet.insert(
+        StyleState::FocusedItem,
+        StyleSheet::focused_day(theme, style),
+    );
+    style_sheet
+}
+
+/// Lays out the calendar of a date picker, the month / year bar above the
+/// days, within the given limits.
+///
+/// With `header`, the picked day is shown above the month / year bar. Its
+/// layout is the last child, so the month / year bar stays the first one.
+///
+/// With `header_format`, the month / year bar only has one control showing
+/// the header of a [`Body::header_format`].
+pub(crate) fn body_layout<Message, Theme>(
+    tree: &mut Tree,
+    renderer: &Renderer,
+    limits: &Limits,
+    header: bool,
+    header_format: bool,
+) -> Node
+where
+    Theme: text::StyleSheet + container::StyleSheet,
+{
+    // Month/Year
+    let font_size = renderer.default_size();
+
+    let month_year = Row::<Message, Theme, Renderer>::new()
+        .width(Length::Fill)
+        .spacing(SPACING)
+        .push(
+            Row::new()
+                .width(Length::Fill)
+                .push(Container::new(
+                    Row::new().push(
+                        Text::new(icon_to_string(BootstrapIcon::CaretLeftFill))
+                            .size(font_size.0 + 1.0)
+                            .font(crate::BOOTSTRAP_FONT),
+                    ),
+                ))
+                .push(
+                    // Month
+                    Text::new("October").width(Length::Fill),
+                )
+                .push(
+                    // Right Month arrow
+                    Container::new(
+                        Text::new(icon_to_string(BootstrapIcon::CaretRightFill))
+                            .size(font_size.0 + 1.0)
+                            .font(crate::BOOTSTRAP_FONT),
+                    )
+                    .height(Length::Shrink)
+                    .width(Length::Shrink),
+                ),
+        )
+        .push_maybe((!header_format).then(|| {
+            Row::new()
+                .width(Length::Fill)
+                .push(Container::new(
+                    Row::new().push(
+                        Text::new(icon_to_string(BootstrapIcon::CaretLeftFill))
+                            .size(font_size.0 + 1.0)
+                            .font(BOOTSTRAP_FONT),
+                    ),
+                ))
+                .push(
+                    // Year
+                    Text::new("9999").width(Length::Fill),
+                )
+                .push(
+                    // Right Year arrow
+                    Container::new(
+                        Row::new().push(
+                            Text::new(icon_to_string(BootstrapIcon::CaretRightFill))
+                                .size(font_size.0 + 1.0)
+                                .font(BOOTSTRAP_FONT),
+                        ),
+                    )
+                    .height(Length::Shrink)
+                    .width(Length::Shrink),
+                )
+        }));
+
+    let days = Container::<Message, Theme, Renderer>::new((0..7).fold(
+        Column::new().width(Length::Fill).height(Length::Fill),
+        |column, _y| {
+            column.push(
+                (0..7).fold(
+                    Row::new()
+                        .height(Length::Fill)
+                        .width(Length::Fill)
+                        .padding(DAY_CELL_PADDING),
+                    |row, _x| {
+                        row.push(
+                            Container::new(Row::new().push(Text::new("31").size(font_size)))
+                                .width(Length::Fill)
+                                .height(Length::Fill),
+                        )
+                    },
+                ),
+            )
+        },
+    ))
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .center_y();
+
+    let col = Column::<Message, Theme, Renderer>::new()
+        .spacing(SPACING)
+        .align_items(Alignment::Center)
+        .push_maybe(header.then(|| Text::new("9999").width(Length::Fill)))
+        .push(month_year)
+        .push(days);
+
+    let element: Element<Message, Theme, Renderer> = Element::new(col);
+    tree.diff(element.as_widget());
+
+    let node = element.as_widget().layout(tree, renderer, limits);
+
+    match node.children() {
+        [header, month_year, days] => Node::with_children(
+            node.size(),
+            vec![month_year.clone(), days.clone(), header.clone()],
+        ),
+        _ => node,
+    }
+}
+
+/// The text of the picked day shown above the month / year bar.
+fn header_text<'b>(
+    renderer: &Renderer,
+    content: &'b str,
+    bounds: Size,
+) -> core::text::Text<'b, core::Font> {
+    core::text::Text {
+        content,
+        bounds,
+        size: renderer.default_size(),
+        font: renderer.default_font(),
+        horizontal_alignment: Horizontal::Center,
+        vertical_alignment: Vertical::Center,
+        line_height: text::LineHeight::Relative(1.3),
+        shaping: text::Shaping::Advanced,
+    }
+}
+
+/// The layout of the table of the days, or of the month and the year grids,
+/// of the calendar with the given layout.
+fn days_layout(layout: Layout<'_>) -> Layout<'_> {
+    layout
+        .children()
+        .nth(1)
+        .expect("Graphics: Layout should have a days layout parent")
+        .children()
+        .next()
+        .expect("Graphics: Layout should have a days layout")
+}
+
+/// The bounds of the cells of the month and the year grids filling the given
+/// bounds, row by row.
+fn grid_cells(bounds: Rectangle) -> impl Iterator<Item = Rectangle> {
+    let width = bounds.width / GRID_COLUMNS as f32;
+    let height = bounds.height / GRID_ROWS as f32;
+
+    (0..GRID_COLUMNS * GRID_ROWS).map(move |i| Rectangle {
+        x: bounds.x + (i % GRID_COLUMNS) as f32 * width + DAY_CELL_PADDING / 2.0,
+        y: bounds.y + (i / GRID_COLUMNS) as f32 * height + DAY_CELL_PADDING / 2.0,
+        width: (width - DAY_CELL_PADDING).max(0.0),
+        height: (height - DAY_CELL_PADDING).max(0.0),
+    })
+}
+
+/// The first year of the page of the year grid showing the given year.
+const fn year_page(year: i32) -> i32 {
+    year - year.rem_euclid(YEARS_PER_PAGE)
+}
+
+/// The date of the day cell at the given position of the table showing the
+/// month of `date`.
+fn cell_date(date: NaiveDate, x: usize, y: usize, first_weekday: u32) -> NaiveDate {
+    let (day, is_in_month) =
+        crate::core::date::position_to_day_from(x, y, date.year(), date.month(), first_weekday);
+
+    match is_in_month {
+        IsInMonth::Previous => crate::core::date::pred_month(date)
+            .with_day(day as u32)
+            .expect("Previous month with day should be valid"),
+        IsInMonth::Same => date
+            .with_day(day as u32)
+            .expect("Same month with day should be valid"),
+        IsInMonth::Next => crate::core::date::succ_month(date)
+            .with_day(day as u32)
+            .expect("Succeeding month with day should be valid"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{weekday_label, Body, State, View};
+    use crate::core::{
+        date::Date,
+        locale::{DeDe, EnUs, Provider},
+    };
+    use chrono::{Datelike, NaiveDate};
+    use iced_widget::core::{
+        event,
+        layout::Node,
+        mouse::{self, Cursor},
+        Event, Layout, Point, Size,
+    };
+
+    fn body(locale: &dyn Provider) -> Body<'_> {
+        Body {
+            locale,
+            first_weekday: locale.first_weekday(),
+            disabled: None,
+            highlights: None,
+            header_format: None,
+            format: None,
+            multiple: false,
+            max_selected: None,
         }
     }
+
+    #[test]
+    fn locale_test() {
+        let state = State::new(
+            NaiveDate::from_ymd_opt(2024, 3, 6).expect("Year, Month or Day doesnt Exist"),
+        );
+
+        assert_eq!(
+            body(&DeDe).month_year_as_strings(&state),
+            ("März".to_owned(), "2024".to_owned())
+        );
+        assert_eq!(
+            body(&EnUs).month_year_as_strings(&state),
+            ("March".to_owned(), "2024".to_owned())
+        );
+
+        assert_eq!(weekday_label(&DeDe, 0, 2), "Mi");
+        assert_eq!(weekday_label(&EnUs, 0, 2), "We");
+        assert_eq!(weekday_label(&EnUs, 6, 0), "Su");
+    }
+
+    #[test]
+    fn step_month_disabled_test() {
+        let date = |month, day| {
+            NaiveDate::from_ymd_opt(2024, month, day).expect("Year, Month or Day doesnt Exist")
+        };
+        // Weekends and the 10th of every month
+        let disabled = |date: Date| {
+            let weekday = NaiveDate::from_ymd_opt(date.year, date.month, date.day)
+                .expect("Year, Month or Day doesnt Exist")
+                .weekday();
+            weekday.number_from_monday() > 5 || date.day == 10
+        };
+        let body = Body {
+            disabled: Some(&disabled),
+            ..body(&EnUs)
+        };
+        let mut state = State::new(date(1, 10));
+
+        // The 10th of February is a Saturday, the Friday before is nearer
+        // than the Monday after
+        body.step_month(&mut state, crate::core::date::succ_month);
+        assert_eq!(state.date, date(2, 9));
+        assert!(!body.is_disabled(state.date));
+
+        // The picked day of the month is kept, the 10th of April is disabled
+        body.step_month(&mut state, crate::core::date::succ_month);
+        body.step_month(&mut state, crate::core::date::succ_month);
+        assert_eq!(state.date, date(4, 9));
+        assert_eq!(state.day, 10);
+
+        // The 10th of November is a Sunday, the 9th a Saturday
+        state.date = date(10, 9);
+        body.step_month(&mut state, crate::core::date::succ_month);
+        assert_eq!(state.date, date(11, 11));
+    }
+
+    #[test]
+    fn header_format_test() {
+        let header_format = |date: Date| format!("{}年{}月", date.year, date.month);
+        let body = Body {
+            header_format: Some(&header_format),
+            ..body(&EnUs)
+        };
+        let mut state = State::new(
+            NaiveDate::from_ymd_opt(2024, 3, 6).expect("Year, Month or Day doesnt Exist"),
+        );
+
+        // The arrows, the header and the arrows again
+        let node = Node::with_children(
+            Size::new(100.0, 10.0),
+            vec![
+                Node::new(Size::new(10.0, 10.0)),
+                Node::new(Size::new(80.0, 10.0)).move_to(Point::new(10.0, 0.0)),
+                Node::new(Size::new(10.0, 10.0)).move_to(Point::new(90.0, 0.0)),
+            ],
+        );
+        let layout = Layout::new(&node);
+        let click = |state: &mut State, x| {
+            let event = Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left));
+            let cursor = Cursor::Available(Point::new(x, 5.0));
+            assert_eq!(
+                body.on_event_header(state, &event, layout, cursor),
+                event::Status::Captured
+            );
+        };
+
+        // The arrows step the months of the days, then the years of the
+        // month grid
+        click(&mut state, 95.0);
+        click(&mut state, 50.0);
+        click(&mut state, 95.0);
+        assert_eq!(header_format(state.date.into()), "2025年4月");
+        assert_eq!(state.view, View::Months);
+
+        // The header cycles through the grids
+        click(&mut state, 50.0);
+        assert_eq!(state.view, View::Years);
+        click(&mut state, 50.0);
+        assert_eq!(state.view, View::Days);
+    }
 }
@@ -32,6 +32,8 @@ pub struct FloatingElementOverlay<
     offset: &'b Offset,
     /// The bounds of the underlay element.
     underlay_bounds: Rectangle,
+    /// Whether the cursor is passed through the element to the underlay.
+    pass_through: bool,
 }
 
 impl<'a, 'b, Message, Theme, Renderer> FloatingElementOverlay<'a, 'b, Message, Theme, Renderer>
@@ -53,8 +55,17 @@ where
             anchor,
             offset,
             underlay_bounds,
+            pass_through: false,
         }
     }
+
+    /// Passes the cursor through the element to the underlay, e.g. for an element that
+    /// only decorates the underlay.
+    #[must_use]
+    pub fn pass_through(mut self, pass_through: bool) -> Self {
+        self.pass_through = pass_through;
+        self
+    }
 }
 
 impl<'a, 'b, Message, Theme, Renderer> core::Overlay<Message, Theme, Renderer>
@@ -164,6 +175,10 @@ where
             .draw(self.state, renderer, theme, style, layout, cursor, &bounds);
     }
 
+    fn is_over(&self, layout: Layout<'_>, _renderer: &Renderer, cursor_position: Point) -> bool {
+        !self.pass_through && layout.bounds().contains(cursor_position)
+    }
+
     fn overlay<'c>(
         &'c mut self,
         layout: Layout<'_>,
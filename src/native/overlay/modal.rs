@@ -32,6 +32,11 @@ where
     style: <Theme as StyleSheet>::Style,
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
+    /// Whether mouse wheel events are passed through to the underlay.
+    scroll_passthrough: bool,
+    /// The last cursor seen by the [`ModalOverlay`](ModalOverlay), used by
+    /// the underlay for passed through mouse wheel events.
+    cursor: &'b mut Cursor,
 }
 
 impl<'a, 'b, Message, Theme, Renderer> ModalOverlay<'a, 'b, Message, Theme, Renderer>
@@ -41,6 +46,7 @@ where
     Theme: StyleSheet,
 {
     /// Creates a new [`ModalOverlay`](ModalOverlay).
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         state: &'b mut Tree,
         content: &'b mut Element<'a, Message, Theme, Renderer>,
@@ -49,6 +55,8 @@ where
         style: <Theme as StyleSheet>::Style,
         horizontal_alignment: alignment::Horizontal,
         vertical_alignment: alignment::Vertical,
+        scroll_passthrough: bool,
+        cursor: &'b mut Cursor,
     ) -> Self {
         ModalOverlay {
             state,
@@ -58,6 +66,8 @@ where
             style,
             horizontal_alignment,
             vertical_alignment,
+            scroll_passthrough,
+            cursor,
         }
     }
 }
@@ -102,6 +112,8 @@ where
         shell: &mut Shell<Message>,
     ) -> event::Status {
         let viewport = layout.bounds();
+        *self.cursor = cursor;
+
         // TODO clean this up
         let esc_status = self
             .esc
@@ -137,21 +149,35 @@ where
             },
         );
 
-        match esc_status.merge(backdrop_status) {
-            event::Status::Ignored => self.content.as_widget_mut().on_event(
-                self.state,
-                event,
-                layout
-                    .children()
-                    .next()
-                    .expect("Native: Layout should have a content layout."),
-                cursor,
-                renderer,
-                clipboard,
-                shell,
-                &viewport,
-            ),
-            event::Status::Captured => event::Status::Captured,
+        if esc_status.merge(backdrop_status) == event::Status::Captured {
+            return event::Status::Captured;
+        }
+
+        // Keep the underlay inert, except for window events and passed through scrolling
+        let inert = match &event {
+            Event::Window(..) => false,
+            Event::Mouse(mouse::Event::WheelScrolled { .. }) => !self.scroll_passthrough,
+            _ => true,
+        };
+
+        let status = self.content.as_widget_mut().on_event(
+            self.state,
+            event,
+            layout
+                .children()
+                .next()
+                .expect("Native: Layout should have a content layout."),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            &viewport,
+        );
+
+        if inert {
+            event::Status::Captured
+        } else {
+            status
         }
     }
 
@@ -3,12 +3,8 @@
 //! *This API requires the following crate features to be activated: `time_picker`*
 
 use crate::{
-    core::clock::{
-        NearestRadius, HOUR_RADIUS_PERCENTAGE, HOUR_RADIUS_PERCENTAGE_NO_SECONDS,
-        MINUTE_RADIUS_PERCENTAGE, MINUTE_RADIUS_PERCENTAGE_NO_SECONDS, PERIOD_PERCENTAGE,
-        SECOND_RADIUS_PERCENTAGE,
-    },
-    core::{clock, overlay::Position, time::Period},
+    core::clock::NearestRadius,
+    core::{clock, locale, overlay::Position, time},
     graphics::icons::{
         bootstrap::{icon_to_string, BootstrapIcon},
         BOOTSTRAP_FONT,
@@ -37,16 +33,16 @@ use iced_widget::{
     },
     graphics::geometry::Renderer as _,
     renderer::Renderer,
-    text, Button, Column, Container, Row,
+    text, text_input, Button, Column, Container, Row, TextInput,
 };
 use std::collections::HashMap;
 
 pub use crate::style::time_picker::{Appearance, StyleSheet};
 
 /// The padding around the elements.
-const PADDING: f32 = 10.0;
+pub(crate) const PADDING: f32 = 10.0;
 /// The spacing between the elements.
-const SPACING: f32 = 15.0;
+pub(crate) const SPACING: f32 = 15.0;
 /// The spacing between the buttons.
 const BUTTON_SPACING: f32 = 5.0;
 /// The percentage size of the numbers.
@@ -59,16 +55,27 @@ const PERIOD_SIZE_PERCENTAGE: f32 = 0.2;
 pub struct TimePickerOverlay<'a, Message, Theme>
 where
     Message: Clone,
-    Theme: StyleSheet + button::StyleSheet,
+    Theme: StyleSheet + button::StyleSheet + text_input::StyleSheet,
 {
     /// The state of the [`TimePickerOverlay`].
     state: &'a mut State,
+    /// The text field of the [`TimePickerOverlay`] to type the time into.
+    time_input: TextInput<'a, TimeInput, Theme, Renderer>,
     /// The cancel button of the [`TimePickerOverlay`].
     cancel_button: Button<'a, Message, Theme, Renderer>,
     /// The submit button of the [`TimePickerOverlay`].
     submit_button: Button<'a, Message, Theme, Renderer>,
+    /// The button of the [`TimePickerOverlay`] jumping to the current time.
+    now_button: Button<'a, Message, Theme, Renderer>,
+    /// The message that is send if the [`TimePickerOverlay`] is cancelled with the keyboard.
+    on_cancel: Message,
     /// The function that produces a message when the submit button of the [`TimePickerOverlay`] is pressed.
     on_submit: &'a dyn Fn(Time) -> Message,
+    /// The function that produces a message when the submit button of the [`TimePickerOverlay`] is pressed
+    /// while picking a duration.
+    on_submit_duration: Option<&'a dyn Fn(std::time::Duration) -> Message>,
+    /// The function that produces a message when the time picked with the [`TimePickerOverlay`] changes.
+    on_change: Option<&'a dyn Fn(Time) -> Message>,
     /// The position of the [`TimePickerOverlay`].
     position: Point,
     /// The style of the [`TimePickerOverlay`].
@@ -80,20 +87,33 @@ where
 impl<'a, Message, Theme> TimePickerOverlay<'a, Message, Theme>
 where
     Message: 'static + Clone,
-    Theme: 'a + StyleSheet + button::StyleSheet + text::StyleSheet + container::StyleSheet,
+    Theme: 'a
+        + StyleSheet
+        + button::StyleSheet
+        + text::StyleSheet
+        + container::StyleSheet
+        + text_input::StyleSheet,
 {
     /// Creates a new [`TimePickerOverlay`] on the given position.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         state: &'a mut time_picker::State,
         on_cancel: Message,
         on_submit: &'a dyn Fn(Time) -> Message,
+        on_submit_duration: Option<&'a dyn Fn(std::time::Duration) -> Message>,
+        on_change: Option<&'a dyn Fn(Time) -> Message>,
+        now_label: &'a str,
         position: Point,
         style: <Theme as StyleSheet>::Style,
         tree: &'a mut Tree,
     ) -> Self {
         let time_picker::State { overlay_state } = state;
 
+        // The submit button is disabled while the time can't be picked
+        let in_range = overlay_state.is_in_range(overlay_state.time);
+
         TimePickerOverlay {
+            time_input: time_input(&overlay_state.time_text()),
             state: overlay_state,
             cancel_button: Button::new(
                 text::Text::new(icon_to_string(BootstrapIcon::X))
@@ -110,8 +130,18 @@ where
                     .width(Length::Fill),
             )
             .width(Length::Fill)
-            .on_press(on_cancel), // Sending a fake message
+            .on_press_maybe(in_range.then(|| on_cancel.clone())), // Sending a fake message
+            now_button: Button::new(
+                text::Text::new(now_label)
+                    .horizontal_alignment(Horizontal::Center)
+                    .width(Length::Fill),
+            )
+            .width(Length::Fill)
+            .on_press(on_cancel.clone()), // Sending a fake message
+            on_cancel,
             on_submit,
+            on_submit_duration,
+            on_change,
             position,
             style,
             tree,
@@ -124,390 +154,183 @@ where
         overlay::Element::new(self.position, Box::new(self))
     }
 
-    /// The event handling for the clock.
-    #[allow(clippy::too_many_lines)]
-    fn on_event_clock(
-        &mut self,
-        event: &Event,
-        layout: Layout<'_>,
-        cursor: Cursor,
-        _shell: &mut Shell<Message>,
-        _renderer: &Renderer,
-        _clipboard: &mut dyn Clipboard,
-    ) -> event::Status {
-        if cursor.is_over(layout.bounds()) {
-            self.state.clock_cache_needs_clearance = true;
-            self.state.clock_cache.clear();
-        } else if self.state.clock_cache_needs_clearance {
-            self.state.clock_cache.clear();
-            self.state.clock_cache_needs_clearance = false;
-        }
-
-        let clock_bounds = layout.bounds();
-        if cursor.is_over(clock_bounds) {
-            let center = clock_bounds.center();
-            let radius = clock_bounds.width.min(clock_bounds.height) * 0.5;
-
-            let period_radius = radius * PERIOD_PERCENTAGE;
-
-            let (hour_radius, minute_radius, second_radius) = if self.state.show_seconds {
-                (
-                    radius * HOUR_RADIUS_PERCENTAGE,
-                    radius * MINUTE_RADIUS_PERCENTAGE,
-                    radius * SECOND_RADIUS_PERCENTAGE,
-                )
+    /// Publishes the picked time, if it can be picked, or the picked duration.
+    fn submit(&self, shell: &mut Shell<Message>) {
+        if let Some(on_submit_duration) = self.on_submit_duration {
+            let seconds = if self.state.show_seconds {
+                self.state.duration()
             } else {
-                (
-                    radius * HOUR_RADIUS_PERCENTAGE_NO_SECONDS,
-                    radius * MINUTE_RADIUS_PERCENTAGE_NO_SECONDS,
-                    f32::MAX,
-                )
-            };
-
-            let nearest_radius = crate::core::clock::nearest_radius(
-                &if self.state.show_seconds {
-                    vec![
-                        (period_radius, NearestRadius::Period),
-                        (hour_radius, NearestRadius::Hour),
-                        (minute_radius, NearestRadius::Minute),
-                        (second_radius, NearestRadius::Second),
-                    ]
-                } else {
-                    vec![
-                        (period_radius, NearestRadius::Period),
-                        (hour_radius, NearestRadius::Hour),
-                        (minute_radius, NearestRadius::Minute),
-                    ]
-                },
-                cursor.position().unwrap_or_default(),
-                center,
-            );
-
-            let clock_clicked_status = match event {
-                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-                | Event::Touch(touch::Event::FingerPressed { .. }) => match nearest_radius {
-                    NearestRadius::Period => {
-                        let (pm, hour) = self.state.time.hour12();
-                        let hour = if hour == 12 {
-                            if pm {
-                                12
-                            } else {
-                                0
-                            }
-                        } else {
-                            hour
-                        };
-
-                        self.state.time = self
-                            .state
-                            .time
-                            .with_hour(if pm && hour != 12 { hour } else { hour + 12 } % 24)
-                            .expect("New time with hour should be valid");
-                        event::Status::Captured
-                    }
-                    NearestRadius::Hour => {
-                        self.state.focus = Focus::DigitalHour;
-                        self.state.clock_dragged = ClockDragged::Hour;
-                        event::Status::Captured
-                    }
-                    NearestRadius::Minute => {
-                        self.state.focus = Focus::DigitalMinute;
-                        self.state.clock_dragged = ClockDragged::Minute;
-                        event::Status::Captured
-                    }
-                    NearestRadius::Second => {
-                        self.state.focus = Focus::DigitalSecond;
-                        self.state.clock_dragged = ClockDragged::Second;
-                        event::Status::Captured
-                    }
-                    NearestRadius::None => event::Status::Ignored,
-                },
-                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
-                | Event::Touch(
-                    touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. },
-                ) => {
-                    self.state.clock_dragged = ClockDragged::None;
-                    event::Status::Captured
-                }
-                _ => event::Status::Ignored,
+                self.state.duration() / 60 * 60
             };
 
-            let clock_dragged_status = match self.state.clock_dragged {
-                ClockDragged::Hour => {
-                    let hour_points = crate::core::clock::circle_points(hour_radius, center, 12);
-                    let nearest_point = crate::core::clock::nearest_point(
-                        &hour_points,
-                        cursor.position().unwrap_or_default(),
-                    );
-
-                    let (pm, _) = self.state.time.hour12();
-
-                    self.state.time = self
-                        .state
-                        .time
-                        .with_hour((nearest_point as u32 + if pm { 12 } else { 0 }) % 24)
-                        .expect("New time with hour should be valid");
-                    event::Status::Captured
-                }
-                ClockDragged::Minute => {
-                    let minute_points =
-                        crate::core::clock::circle_points(minute_radius, center, 60);
-                    let nearest_point = crate::core::clock::nearest_point(
-                        &minute_points,
-                        cursor.position().unwrap_or_default(),
-                    );
-
-                    self.state.time = self
-                        .state
-                        .time
-                        .with_minute(nearest_point as u32)
-                        .expect("New time with minute should be valid");
-                    event::Status::Captured
-                }
-                ClockDragged::Second => {
-                    let second_points =
-                        crate::core::clock::circle_points(second_radius, center, 60);
-                    let nearest_point = crate::core::clock::nearest_point(
-                        &second_points,
-                        cursor.position().unwrap_or_default(),
-                    );
+            shell.publish(on_submit_duration(std::time::Duration::from_secs(
+                seconds.into(),
+            )));
+            return;
+        }
 
-                    self.state.time = self
-                        .state
-                        .time
-                        .with_second(nearest_point as u32)
-                        .expect("New time with second should be valid");
-                    event::Status::Captured
-                }
-                ClockDragged::None => event::Status::Ignored,
-            };
+        if self.state.is_in_range(self.state.time) {
+            shell.publish((self.on_submit)(self.state.picked_time()));
+        }
+    }
 
-            clock_clicked_status.merge(clock_dragged_status)
-        } else {
-            match event {
-                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
-                | Event::Touch(
-                    touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. },
-                ) => {
-                    self.state.clock_dragged = ClockDragged::None;
-                    event::Status::Captured
-                }
-                _ => event::Status::Ignored,
+    /// Publishes the picked time if it's changed from the given time.
+    ///
+    /// Nothing is published while picking a duration, or while a dragged hand
+    /// stays on the same value.
+    fn publish_change(&self, time: NaiveTime, shell: &mut Shell<Message>) {
+        if let Some(on_change) = self.on_change {
+            if self.state.max_hours.is_none() && self.state.time != time {
+                shell.publish(on_change(self.state.picked_time()));
             }
         }
     }
 
-    /// The event handling for the digital clock.
-    #[allow(clippy::too_many_lines)]
-    fn on_event_digital_clock(
+    /// The event handling for the text field.
+    fn on_event_time_input(
         &mut self,
         event: &Event,
         layout: Layout<'_>,
         cursor: Cursor,
-        _shell: &mut Shell<Message>,
-        _renderer: &Renderer,
-        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
     ) -> event::Status {
-        let mut digital_clock_children = layout.children();
-
-        if !self.state.use_24h {
-            // Placeholder
-            let _ = digital_clock_children.next();
-        }
-
-        let hour_layout = digital_clock_children
-            .next()
-            .expect("Native: Layout should have a hour layout");
-        let mut hour_children = hour_layout.children();
-
-        let hour_up_arrow = hour_children
-            .next()
-            .expect("Native: Layout should have an up arrow for hours");
-        let _ = hour_children.next();
-        let hour_down_arrow = hour_children
-            .next()
-            .expect("Native: Layout should have a down arrow for hours");
-
-        let _ = digital_clock_children.next();
+        let mut messages = Vec::new();
 
-        let minute_layout = digital_clock_children
-            .next()
-            .expect("Native: Layout should have a minute layout");
-        let mut minute_children = minute_layout.children();
-
-        let minute_up_arrow = minute_children
-            .next()
-            .expect("Native: Layout should have an up arrow for minutes");
-        let _ = minute_children.next();
-        let minute_down_arrow = minute_children
-            .next()
-            .expect("Native: Layout should have a down arrow for minutes");
-
-        let calculate_time = |time: &mut NaiveTime,
-                              up_arrow: Layout<'_>,
-                              down_arrow: Layout<'_>,
-                              duration: Duration| {
-            if cursor.is_over(up_arrow.bounds()) {
-                *time += duration;
-                event::Status::Captured
-            } else if cursor.is_over(down_arrow.bounds()) {
-                *time -= duration;
-                event::Status::Captured
-            } else {
-                event::Status::Ignored
-            }
-        };
-
-        let digital_clock_status = match event {
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if cursor.is_over(hour_layout.bounds()) {
-                    self.state.focus = Focus::DigitalHour;
-
-                    calculate_time(
-                        &mut self.state.time,
-                        hour_up_arrow,
-                        hour_down_arrow,
-                        Duration::hours(1),
-                    )
-                } else if cursor.is_over(minute_layout.bounds()) {
-                    self.state.focus = Focus::DigitalMinute;
+        let status = Widget::<TimeInput, Theme, Renderer>::on_event(
+            &mut self.time_input,
+            &mut self.tree.children[3],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            &mut Shell::new(&mut messages),
+            &layout.bounds(),
+        );
 
-                    calculate_time(
-                        &mut self.state.time,
-                        minute_up_arrow,
-                        minute_down_arrow,
-                        Duration::minutes(1),
-                    )
-                } else {
-                    event::Status::Ignored
+        for message in messages {
+            match message {
+                // A valid time moves the clock right away
+                TimeInput::Changed(text) => {
+                    if self.state.max_hours.is_some() {
+                        if let Some(duration) = self.state.parse_duration(&text) {
+                            self.state.set_duration(duration);
+                        }
+                    } else if let Some(time) = self.state.parse_time(&text) {
+                        self.state.time = time;
+                        self.state.clock_cache.clear();
+                    }
+                    self.state.typed_time = Some(text);
+                    self.time_input = time_input(&self.state.time_text());
                 }
-            }
-            _ => event::Status::Ignored,
-        };
-
-        let second_status = if self.state.show_seconds {
-            let _ = digital_clock_children.next();
-
-            let second_layout = digital_clock_children
-                .next()
-                .expect("Native: Layout should have a second layout");
-            let mut second_children = second_layout.children();
-
-            let second_up_arrow = second_children
-                .next()
-                .expect("Native: Layout should have an up arrow for seconds");
-            let _ = second_children.next();
-            let second_down_arrow = second_children
-                .next()
-                .expect("Native: Layout should have a down arrow for seconds");
-
-            match event {
-                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-                | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                    if cursor.is_over(second_layout.bounds()) {
-                        self.state.focus = Focus::DigitalSecond;
-
-                        calculate_time(
-                            &mut self.state.time,
-                            second_up_arrow,
-                            second_down_arrow,
-                            Duration::seconds(1),
-                        )
-                    } else {
-                        event::Status::Ignored
+                TimeInput::Submitted => {
+                    if !self.state.is_typed_time_invalid() {
+                        self.submit(shell);
                     }
                 }
-                _ => event::Status::Ignored,
             }
-        } else {
-            event::Status::Ignored
-        };
+        }
 
-        let digital_clock_status = digital_clock_status.merge(second_status);
+        status
+    }
 
-        if digital_clock_status == event::Status::Captured {
-            self.state.clock_cache.clear();
+    /// Shows the picked time in the text field again once it's changed with
+    /// something else than the text field.
+    ///
+    /// It takes the hours of the duration and the time picked before.
+    fn sync_time_input(&mut self, (hours, time): (u32, NaiveTime)) {
+        if self.state.hours != hours || self.state.time != time {
+            self.state.typed_time = None;
+            self.time_input = time_input(&self.state.time_text());
         }
+    }
 
-        digital_clock_status
+    /// Checks if the text field has the keyboard focus.
+    fn is_time_input_focused(&self) -> bool {
+        self.tree.children[3]
+            .state
+            .downcast_ref::<text_input::State<<Renderer as core::text::Renderer>::Paragraph>>()
+            .is_focused()
     }
 
     /// The event handling for the keyboard input.
+    ///
+    /// Enter submits the picked time and escape cancels, the arrow keys are
+    /// handled by [`State::on_event_keyboard`].
     fn on_event_keyboard(
         &mut self,
         event: &Event,
         _layout: Layout<'_>,
         _cursor: Cursor,
-        _shell: &mut Shell<Message>,
+        shell: &mut Shell<Message>,
         _renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
     ) -> event::Status {
-        if self.state.focus == Focus::None {
+        let Event::Keyboard(keyboard_event) = event else {
             return event::Status::Ignored;
-        }
+        };
 
-        if let Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) = event {
-            let mut status = event::Status::Ignored;
+        let key = match keyboard_event {
+            keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                ..
+            } => key,
+            keyboard::Event::ModifiersChanged(modifiers) => {
+                self.state.keyboard_modifiers = *modifiers;
+                return event::Status::Ignored;
+            }
+            _ => return event::Status::Ignored,
+        };
 
-            if matches!(key, keyboard::Key::Named(keyboard::key::Named::Tab)) {
-                if self.state.keyboard_modifiers.shift() {
-                    self.state.focus = self.state.focus.previous(self.state.show_seconds);
-                } else {
-                    self.state.focus = self.state.focus.next(self.state.show_seconds);
-                }
-            } else {
-                let mut keyboard_handle =
-                    |key_code: &keyboard::Key, time: &mut NaiveTime, duration: Duration| {
-                        match key_code {
-                            keyboard::Key::Named(
-                                keyboard::key::Named::ArrowLeft | keyboard::key::Named::ArrowDown,
-                            ) => {
-                                *time -= duration;
-                                status = event::Status::Captured;
-                            }
-                            keyboard::Key::Named(
-                                keyboard::key::Named::ArrowRight | keyboard::key::Named::ArrowUp,
-                            ) => {
-                                *time += duration;
-                                status = event::Status::Captured;
-                            }
-                            _ => {}
-                        }
-                    };
+        let show_seconds = self.state.show_seconds;
+        let show_period = !self.state.use_24h;
+        let focus = self.state.focus;
 
-                match self.state.focus {
-                    Focus::DigitalHour => {
-                        keyboard_handle(key, &mut self.state.time, Duration::hours(1));
-                    }
-                    Focus::DigitalMinute => {
-                        keyboard_handle(key, &mut self.state.time, Duration::minutes(1));
-                    }
-                    Focus::DigitalSecond => {
-                        keyboard_handle(key, &mut self.state.time, Duration::seconds(1));
-                    }
-                    _ => {}
+        let status = match key {
+            keyboard::key::Named::Escape => {
+                shell.publish(self.on_cancel.clone());
+                event::Status::Captured
+            }
+            keyboard::key::Named::Enter => {
+                if focus == Focus::Cancel {
+                    shell.publish(self.on_cancel.clone());
+                } else {
+                    self.submit(shell);
                 }
+                event::Status::Captured
             }
-
-            if status == event::Status::Captured {
-                self.state.clock_cache.clear();
+            _ if focus == Focus::None => event::Status::Ignored,
+            keyboard::key::Named::Tab => {
+                self.state.focus = if self.state.keyboard_modifiers.shift() {
+                    focus.previous(show_seconds, show_period)
+                } else {
+                    focus.next(show_seconds, show_period)
+                };
+                event::Status::Ignored
             }
+            _ => self.state.on_event_keyboard(*key),
+        };
 
-            status
-        } else if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) = event {
-            self.state.keyboard_modifiers = *modifiers;
-            event::Status::Ignored
-        } else {
-            event::Status::Ignored
+        // The focused unit is highlighted on the clock
+        if status == event::Status::Captured || self.state.focus != focus {
+            self.state.clock_cache.clear();
         }
+
+        status
     }
 }
 
 impl<'a, Message, Theme> Overlay<Message, Theme, Renderer> for TimePickerOverlay<'a, Message, Theme>
 where
     Message: 'static + Clone,
-    Theme: 'a + StyleSheet + button::StyleSheet + text::StyleSheet + container::StyleSheet,
+    Theme: 'a
+        + StyleSheet
+        + button::StyleSheet
+        + text::StyleSheet
+        + container::StyleSheet
+        + text_input::StyleSheet,
 {
     fn layout(
         &mut self,
@@ -516,16 +339,39 @@ where
         position: Point,
         _translation: Vector,
     ) -> Node {
+        // The overlay is scaled down to fit into smaller windows, with less
+        // space between its elements
+        let max_size = Size::new(self.state.size, self.state.size * 7.0 / 6.0);
+        let compact = self.state.compact
+            || bounds.width < max_size.width + 2.0 * PADDING
+            || bounds.height < max_size.height + 2.0 * PADDING;
+        let (padding, spacing) = if compact {
+            (PADDING / 2.0, SPACING / 3.0)
+        } else {
+            (PADDING, SPACING)
+        };
+
         let limits = Limits::new(Size::ZERO, bounds)
-            .shrink(Padding::from(PADDING))
+            .shrink(Padding::from(padding))
             .width(Length::Fill)
             .height(Length::Fill)
-            .max_width(300.0)
-            .max_height(350.0);
+            .max_width(max_size.width)
+            .max_height(max_size.height);
 
-        // Digital Clock
-        let digital_clock_limits = limits;
-        let mut digital_clock = digital_clock(self, renderer, digital_clock_limits);
+        // Text field
+        let mut time_input = Widget::<TimeInput, Theme, Renderer>::layout(
+            &self.time_input,
+            &mut self.tree.children[3],
+            renderer,
+            &limits,
+        );
+        let header = time_input.bounds().height + spacing;
+
+        let time_input_bounds = time_input.bounds();
+        time_input = time_input.move_to(Point::new(
+            time_input_bounds.x + padding,
+            time_input_bounds.y + padding,
+        ));
 
         // Pre-Buttons TODO: get rid of it
         let cancel_limits = limits;
@@ -535,72 +381,75 @@ where
 
         let limits = limits.shrink(Size::new(
             0.0,
-            digital_clock.bounds().height + cancel_button.bounds().height + 2.0 * SPACING,
-        ));
-
-        // Clock-Canvas
-        let mut clock = Row::<(), Renderer>::new()
-            .width(Length::Fill)
-            .height(Length::Fill)
-            .layout(self.tree, renderer, &limits);
-
-        let clock_bounds = clock.bounds();
-        clock = clock.move_to(Point::new(
-            clock_bounds.x + PADDING,
-            clock_bounds.y + PADDING,
+            header + cancel_button.bounds().height + spacing,
         ));
 
-        let digital_bounds = digital_clock.bounds();
-        digital_clock = digital_clock.move_to(Point::new(
-            digital_bounds.x + PADDING,
-            digital_bounds.y + PADDING + SPACING + clock.bounds().height,
+        // Clock and digital clock, their tree comes after the buttons and the
+        // text field
+        if self.tree.children.len() < 5 {
+            self.tree.children.push(Tree::empty());
+        }
+        let mut body = body_layout::<Message, Theme>(
+            self.state,
+            &mut self.tree.children[4],
+            renderer,
+            &limits,
+            spacing,
+            compact,
+        );
+        let body_bounds = body.bounds();
+        body = body.move_to(Point::new(
+            body_bounds.x + padding,
+            body_bounds.y + padding + header,
         ));
 
         // Buttons
         let cancel_limits =
-            limits.max_width(((clock.bounds().width / 2.0) - BUTTON_SPACING).max(0.0));
+            limits.max_width(((body.bounds().width / 3.0) - BUTTON_SPACING).max(0.0));
 
         let mut cancel_button =
             self.cancel_button
                 .layout(&mut self.tree.children[0], renderer, &cancel_limits);
 
         let submit_limits =
-            limits.max_width(((clock.bounds().width / 2.0) - BUTTON_SPACING).max(0.0));
+            limits.max_width(((body.bounds().width / 3.0) - BUTTON_SPACING).max(0.0));
 
         let mut submit_button =
             self.submit_button
                 .layout(&mut self.tree.children[1], renderer, &submit_limits);
 
+        let now_limits = limits.max_width(((body.bounds().width / 3.0) - BUTTON_SPACING).max(0.0));
+
+        let mut now_button =
+            self.now_button
+                .layout(&mut self.tree.children[2], renderer, &now_limits);
+
+        let buttons_y = padding + header + body.bounds().height + spacing;
+
         let cancel_bounds = cancel_button.bounds();
         cancel_button = cancel_button.move_to(Point {
-            x: cancel_bounds.x + PADDING,
-            y: cancel_bounds.y
-                + clock.bounds().height
-                + PADDING
-                + digital_clock.bounds().height
-                + 2.0 * SPACING,
+            x: cancel_bounds.x + padding,
+            y: cancel_bounds.y + buttons_y,
         });
 
         let submit_bounds = submit_button.bounds();
         submit_button = submit_button.move_to(Point {
-            x: submit_bounds.x + clock.bounds().width - submit_bounds.width + PADDING,
-            y: submit_bounds.y
-                + clock.bounds().height
-                + PADDING
-                + digital_clock.bounds().height
-                + 2.0 * SPACING,
+            x: submit_bounds.x + body.bounds().width - submit_bounds.width + padding,
+            y: submit_bounds.y + buttons_y,
+        });
+
+        let now_bounds = now_button.bounds();
+        now_button = now_button.move_to(Point {
+            x: now_bounds.x + (body.bounds().width - now_bounds.width) / 2.0 + padding,
+            y: now_bounds.y + buttons_y,
         });
 
         let mut node = Node::with_children(
             Size::new(
-                clock.bounds().width + (2.0 * PADDING),
-                clock.bounds().height
-                    + digital_clock.bounds().height
-                    + cancel_button.bounds().height
-                    + (2.0 * PADDING)
-                    + 2.0 * SPACING,
+                body.bounds().width + (2.0 * padding),
+                buttons_y + cancel_button.bounds().height + padding,
             ),
-            vec![clock, digital_clock, cancel_button, submit_button],
+            vec![body, cancel_button, submit_button, now_button, time_input],
         );
 
         node.center_and_bounce(position, bounds);
@@ -616,37 +465,45 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<Message>,
     ) -> event::Status {
-        if event::Status::Captured
-            == self.on_event_keyboard(&event, layout, cursor, shell, renderer, clipboard)
-        {
-            return event::Status::Captured;
-        }
+        let picked_time = self.state.time;
 
-        let mut children = layout.children();
-
-        // Clock canvas
-        let clock_layout = children
-            .next()
-            .expect("Native: Layout should have a clock canvas layout");
-        let clock_status =
-            self.on_event_clock(&event, clock_layout, cursor, shell, renderer, clipboard);
-
-        // ----------- Digital clock ------------------
-        let digital_clock_layout = children
-            .next()
-            .expect("Native: Layout should have a digital clock parent")
+        // ----------- Text field ---------------------
+        let time_input_layout = layout
             .children()
-            .next()
-            .expect("Native: Layout should have a digital clock layout");
-        let digital_clock_status = self.on_event_digital_clock(
+            .nth(4)
+            .expect("Native: Layout should have a text field layout for a TimePicker");
+        let time_input_status = self.on_event_time_input(
             &event,
-            digital_clock_layout,
+            time_input_layout,
             cursor,
             shell,
             renderer,
             clipboard,
         );
 
+        // The time typed into the text field is kept until it's changed otherwise
+        let time = (self.state.hours, self.state.time);
+
+        // The arrow keys move the text cursor while typing
+        if !self.is_time_input_focused()
+            && event::Status::Captured
+                == self.on_event_keyboard(&event, layout, cursor, shell, renderer, clipboard)
+        {
+            self.sync_time_input(time);
+            self.publish_change(picked_time, shell);
+            return event::Status::Captured;
+        }
+
+        let pointer_status = crate::core::overlay::pointer_status(&event, layout.bounds(), cursor);
+
+        let mut children = layout.children();
+
+        // ----------- Clock and digital clock --------
+        let body_layout = children
+            .next()
+            .expect("Native: Layout should have a clock layout");
+        let body_status = self.state.on_event(&event, body_layout, cursor);
+
         // ----------- Buttons ------------------------
         let cancel_button_layout = children
             .next()
@@ -671,7 +528,7 @@ where
 
         let submit_status = self.submit_button.on_event(
             &mut self.tree.children[1],
-            event,
+            event.clone(),
             submit_button_layout,
             cursor,
             renderer,
@@ -681,35 +538,45 @@ where
         );
 
         if !fake_messages.is_empty() {
-            let (hour, period) = if self.state.use_24h {
-                (self.state.time.hour(), Period::H24)
-            } else {
-                let (period, hour) = self.state.time.hour12();
-                (hour, if period { Period::Pm } else { Period::Am })
-            };
+            self.submit(shell);
+        }
 
-            let time = if self.state.show_seconds {
-                Time::Hms {
-                    hour,
-                    minute: self.state.time.minute(),
-                    second: self.state.time.second(),
-                    period,
-                }
-            } else {
-                Time::Hm {
-                    hour,
-                    minute: self.state.time.minute(),
-                    period,
-                }
-            };
+        let now_button_layout = children
+            .next()
+            .expect("Native: Layout should have a now button layout for a TimePicker");
+
+        let mut fake_messages: Vec<Message> = Vec::new();
+
+        let now_status = self.now_button.on_event(
+            &mut self.tree.children[2],
+            event,
+            now_button_layout,
+            cursor,
+            renderer,
+            clipboard,
+            &mut Shell::new(&mut fake_messages),
+            &layout.bounds(),
+        );
 
-            shell.publish((self.on_submit)(time));
+        // A duration goes back to zero
+        if !fake_messages.is_empty() {
+            if self.state.max_hours.is_some() {
+                self.state.set_duration(0);
+            } else {
+                self.state.time = self.state.clamp(Local::now().naive_local().time());
+            }
+            self.state.clock_cache.clear();
         }
 
-        clock_status
-            .merge(digital_clock_status)
+        self.sync_time_input(time);
+        self.publish_change(picked_time, shell);
+
+        time_input_status
+            .merge(body_status)
             .merge(cancel_status)
             .merge(submit_status)
+            .merge(now_status)
+            .merge(pointer_status)
     }
 
     fn mouse_interaction(
@@ -722,79 +589,11 @@ where
         let mut children = layout.children();
         let mouse_interaction = mouse::Interaction::default();
 
-        // Clock canvas
-        let clock_layout = children
-            .next()
-            .expect("Graphics: Layout should have a clock canvas layout");
-        let clock_mouse_interaction = if cursor.is_over(clock_layout.bounds()) {
-            mouse::Interaction::Pointer
-        } else {
-            mouse::Interaction::default()
-        };
-
-        // Digital clock
-        let digital_clock_layout = children
-            .next()
-            .expect("Graphics: Layout should have a digital clock layout");
-        //let digital_clock_mouse_interaction = mouse::Interaction::default();
-        let mut digital_clock_children = digital_clock_layout
-            .children()
-            .next()
-            .expect("Graphics: Layout should have digital clock children")
-            .children();
-
-        let f = |layout: Layout<'_>| {
-            let mut children = layout.children();
-
-            let up_bounds = children
-                .next()
-                .expect("Graphics: Layout should have a up arrow bounds")
-                .bounds();
-            let _center_bounds = children.next();
-            let down_bounds = children
-                .next()
-                .expect("Graphics: Layout should have a down arrow bounds")
-                .bounds();
-
-            let mut mouse_interaction = mouse::Interaction::default();
-
-            let up_arrow_hovered = cursor.is_over(up_bounds);
-            let down_arrow_hovered = cursor.is_over(down_bounds);
-
-            if up_arrow_hovered || down_arrow_hovered {
-                mouse_interaction = mouse_interaction.max(mouse::Interaction::Pointer);
-            }
-
-            mouse_interaction
-        };
-
-        if !self.state.use_24h {
-            // Placeholder
-            let _ = digital_clock_children.next();
-        }
-
-        let hour_layout = digital_clock_children
-            .next()
-            .expect("Graphics: Layout should have a hour layout");
-        let hour_mouse_interaction = f(hour_layout);
-
-        let _hour_minute_separator = digital_clock_children.next();
-
-        let minute_layout = digital_clock_children
+        // Clock and digital clock
+        let body_layout = children
             .next()
-            .expect("Graphics: Layout should have a minute layout");
-        let minute_mouse_interaction = f(minute_layout);
-
-        let second_mouse_interaction = if self.state.show_seconds {
-            let _minute_second_separator = digital_clock_children.next();
-
-            let second_layout = digital_clock_children
-                .next()
-                .expect("Graphics: Layout should have a second layout");
-            f(second_layout)
-        } else {
-            mouse::Interaction::default()
-        };
+            .expect("Graphics: Layout should have a clock layout");
+        let body_mouse_interaction = self.state.mouse_interaction(body_layout, cursor);
 
         // Buttons
         let cancel_button_layout = children
@@ -821,13 +620,38 @@ where
             renderer,
         );
 
+        let now_button_layout = children
+            .next()
+            .expect("Graphics: Layout should have a now button layout for a TimePicker");
+
+        let now_mouse_interaction = self.now_button.mouse_interaction(
+            &self.tree.children[2],
+            now_button_layout,
+            cursor,
+            viewport,
+            renderer,
+        );
+
+        // Text field
+        let time_input_layout = children
+            .next()
+            .expect("Graphics: Layout should have a text field layout for a TimePicker");
+
+        let time_input_mouse_interaction = Widget::<TimeInput, Theme, Renderer>::mouse_interaction(
+            &self.time_input,
+            &self.tree.children[3],
+            time_input_layout,
+            cursor,
+            viewport,
+            renderer,
+        );
+
         mouse_interaction
-            .max(clock_mouse_interaction)
-            .max(hour_mouse_interaction)
-            .max(minute_mouse_interaction)
-            .max(second_mouse_interaction)
+            .max(time_input_mouse_interaction)
+            .max(body_mouse_interaction)
             .max(cancel_mouse_interaction)
             .max(submit_mouse_interaction)
+            .max(now_mouse_interaction)
     }
 
     fn draw(
@@ -841,14 +665,7 @@ where
         let bounds = layout.bounds();
         let mut children = layout.children();
 
-        let mut style_sheet: HashMap<StyleState, Appearance> = HashMap::new();
-        let _ = style_sheet.insert(StyleState::Active, StyleSheet::active(theme, &self.style));
-        let _ = style_sheet.insert(
-            StyleState::Selected,
-            StyleSheet::selected(theme, &self.style),
-        );
-        let _ = style_sheet.insert(StyleState::Hovered, StyleSheet::hovered(theme, &self.style));
-        let _ = style_sheet.insert(StyleState::Focused, StyleSheet::focused(theme, &self.style));
+        let style_sheet = style_sheet(theme, &self.style);
 
         let mut style_state = StyleState::Active;
         if self.state.focus == Focus::Overlay {
@@ -872,17 +689,11 @@ where
             style_sheet[&style_state].background,
         );
 
-        // ----------- Clock canvas --------------------
-        let clock_layout = children
-            .next()
-            .expect("Graphics: Layout should have a clock canvas layout");
-        draw_clock(renderer, self, clock_layout, cursor, &style_sheet);
-
-        // ----------- Digital clock ------------------
-        let digital_clock_layout = children
+        // ----------- Clock and digital clock --------
+        let body_layout = children
             .next()
-            .expect("Graphics: Layout should have a digital clock layout");
-        draw_digital_clock(renderer, self, digital_clock_layout, cursor, &style_sheet);
+            .expect("Graphics: Layout should have a clock layout");
+        draw_body(renderer, self.state, body_layout, cursor, &style_sheet);
 
         // ----------- Buttons ------------------------
         let cancel_button_layout = children
@@ -913,6 +724,51 @@ where
             &bounds,
         );
 
+        let now_button_layout = children
+            .next()
+            .expect("Graphics: Layout should have a now button layout for a TimePicker");
+
+        self.now_button.draw(
+            &self.tree.children[2],
+            renderer,
+            theme,
+            style,
+            now_button_layout,
+            cursor,
+            &bounds,
+        );
+
+        // ----------- Text field ---------------------
+        let time_input_layout = children
+            .next()
+            .expect("Graphics: Layout should have a text field layout for a TimePicker");
+
+        Widget::<TimeInput, Theme, Renderer>::draw(
+            &self.time_input,
+            &self.tree.children[3],
+            renderer,
+            theme,
+            style,
+            time_input_layout,
+            cursor,
+            &bounds,
+        );
+
+        if self.state.is_typed_time_invalid() {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: time_input_layout.bounds(),
+                    border: Border {
+                        radius: (2.0).into(),
+                        width: 1.0,
+                        color: StyleSheet::invalid(theme, &self.style).border_color,
+                    },
+                    shadow: Shadow::default(),
+                },
+                Color::TRANSPARENT,
+            );
+        }
+
         // Buttons are not focusable right now...
         if self.state.focus == Focus::Cancel {
             renderer.fill_quad(
@@ -946,18 +802,36 @@ where
     }
 }
 
+/// The appearances of the states of a time picker with the given style.
+pub(crate) fn style_sheet<Theme>(
+    theme: &Theme,
+    style: &<Theme as StyleSheet>::Style,
+) -> HashMap<StyleState, Appearance>
+where
+    Theme: StyleSheet,
+{
+    let mut style_sheet: HashMap<StyleState, Appearance> = HashMap::new();
+    let _ = style_sheet.insert(StyleState::Active, StyleSheet::active(theme, style));
+    let _ = style_sheet.insert(StyleState::Selected, StyleSheet::selected(theme, style));
+    let _ = style_sheet.insert(StyleState::Hovered, StyleSheet::hovered(theme, style));
+    let _ = style_sheet.insert(StyleState::Focused, StyleSheet::focused(theme, style));
+    let _ = style_sheet.insert(StyleState::Disabled, StyleSheet::disabled(theme, style));
+    style_sheet
+}
+
 /// Defines the layout of the digital clock of the time picker.
 fn digital_clock<Message, Theme>(
-    time_picker: &mut TimePickerOverlay<'_, Message, Theme>,
+    state: &State,
+    tree: &mut Tree,
     renderer: &Renderer,
-    limits: Limits,
+    limits: &Limits,
+    compact: bool,
 ) -> Node
 where
-    Message: 'static + Clone,
-    Theme: StyleSheet + button::StyleSheet + text::StyleSheet + container::StyleSheet,
+    Theme: text::StyleSheet + container::StyleSheet,
 {
     let arrow_size = renderer.default_size().0;
-    let font_size = 1.2 * renderer.default_size().0;
+    let font_size = if compact { 1.0 } else { 1.2 } * renderer.default_size().0;
 
     let mut digital_clock_row = Row::<Message, Theme, Renderer>::new()
         .align_items(Alignment::Center)
@@ -965,7 +839,7 @@ where
         .width(Length::Shrink)
         .spacing(1);
 
-    if !time_picker.state.use_24h {
+    if !state.use_24h {
         digital_clock_row = digital_clock_row.push(
             Column::new() // Just a placeholder
                 .height(Length::Shrink)
@@ -985,10 +859,7 @@ where
                         .width(Length::Fixed(arrow_size))
                         .height(Length::Fixed(arrow_size)),
                 )
-                .push(
-                    text::Text::new(format!("{:02}", time_picker.state.time.hour()))
-                        .size(font_size),
-                )
+                .push(text::Text::new(format!("{:02}", state.digital_hour())).size(font_size))
                 .push(
                     // Down Hour arrow
                     Row::new()
@@ -1011,10 +882,7 @@ where
                         .width(Length::Fixed(arrow_size))
                         .height(Length::Fixed(arrow_size)),
                 )
-                .push(
-                    text::Text::new(format!("{:02}", time_picker.state.time.hour()))
-                        .size(font_size),
-                )
+                .push(text::Text::new(format!("{:02}", state.time.hour())).size(font_size))
                 .push(
                     // Down Minute arrow
                     Row::new()
@@ -1023,7 +891,7 @@ where
                 ),
         );
 
-    if time_picker.state.show_seconds {
+    if state.show_seconds {
         digital_clock_row = digital_clock_row
             .push(
                 Column::new()
@@ -1040,10 +908,7 @@ where
                             .width(Length::Fixed(arrow_size))
                             .height(Length::Fixed(arrow_size)),
                     )
-                    .push(
-                        text::Text::new(format!("{:02}", time_picker.state.time.hour()))
-                            .size(font_size),
-                    )
+                    .push(text::Text::new(format!("{:02}", state.time.hour())).size(font_size))
                     .push(
                         // Down Minute arrow
                         Row::new()
@@ -1053,7 +918,7 @@ where
             );
     }
 
-    if !time_picker.state.use_24h {
+    if !state.use_24h {
         digital_clock_row = digital_clock_row.push(
             Column::new()
                 .height(Length::Shrink)
@@ -1068,44 +933,105 @@ where
         .center_y();
 
     let element: Element<Message, Theme, Renderer> = Element::new(container);
-    let container_tree = if let Some(child_tree) = time_picker.tree.children.get_mut(2) {
-        child_tree.diff(element.as_widget());
-        child_tree
+    tree.diff(element.as_widget());
+
+    element.as_widget().layout(tree, renderer, limits)
+}
+
+/// Lays out the clock of a time picker above its digital clock within the
+/// given limits, with the given spacing between them.
+///
+/// Without the clock face only the digital clock takes up space.
+pub(crate) fn body_layout<Message, Theme>(
+    state: &State,
+    tree: &mut Tree,
+    renderer: &Renderer,
+    limits: &Limits,
+    spacing: f32,
+    compact: bool,
+) -> Node
+where
+    Theme: text::StyleSheet + container::StyleSheet,
+{
+    let digital_clock = digital_clock::<Message, Theme>(state, tree, renderer, limits, compact);
+
+    let (clock_height, clock_spacing) = if state.show_clock {
+        (Length::Fill, spacing)
     } else {
-        let child_tree = Tree::new(element.as_widget());
-        time_picker.tree.children.insert(2, child_tree);
-        &mut time_picker.tree.children[2]
+        (Length::Fixed(0.0), 0.0)
     };
 
-    element
-        .as_widget()
-        .layout(container_tree, renderer, &limits)
+    // Clock-Canvas
+    let clock = Row::<(), Renderer>::new()
+        .width(Length::Fill)
+        .height(clock_height)
+        .layout(
+            tree,
+            renderer,
+            &limits.shrink(Size::new(
+                0.0,
+                digital_clock.bounds().height + clock_spacing,
+            )),
+        );
+
+    let digital_clock =
+        digital_clock.move_to(Point::new(0.0, clock.bounds().height + clock_spacing));
+
+    Node::with_children(
+        Size::new(
+            clock.bounds().width,
+            clock.bounds().height + clock_spacing + digital_clock.bounds().height,
+        ),
+        vec![clock, digital_clock],
+    )
+}
+
+/// Draws the clock, if shown, and the digital clock of a time picker with the
+/// given layout.
+pub(crate) fn draw_body(
+    renderer: &mut Renderer,
+    state: &State,
+    layout: Layout<'_>,
+    cursor: Cursor,
+    style_sheet: &HashMap<StyleState, Appearance>,
+) {
+    let mut children = layout.children();
+
+    // ----------- Clock canvas --------------------
+    let clock_layout = children
+        .next()
+        .expect("Graphics: Layout should have a clock canvas layout");
+    if state.show_clock {
+        draw_clock(renderer, state, clock_layout, cursor, style_sheet);
+    }
+
+    // ----------- Digital clock ------------------
+    let digital_clock_layout = children
+        .next()
+        .expect("Graphics: Layout should have a digital clock layout");
+    draw_digital_clock(renderer, state, digital_clock_layout, cursor, style_sheet);
 }
 
 /// Draws the analog clock.
 #[allow(clippy::too_many_lines)]
-fn draw_clock<Message, Theme>(
+fn draw_clock(
     renderer: &mut Renderer,
-    time_picker: &TimePickerOverlay<'_, Message, Theme>,
+    state: &State,
     layout: Layout<'_>,
     cursor: Cursor,
     style: &HashMap<StyleState, Appearance>,
-) where
-    Message: 'static + Clone,
-    Theme: StyleSheet + button::StyleSheet + text::StyleSheet,
-{
+) {
     let mut clock_style_state = StyleState::Active;
     if cursor.is_over(layout.bounds()) {
         clock_style_state = clock_style_state.max(StyleState::Hovered);
     }
 
-    let geometry = time_picker
-        .state
+    let geometry = state
         .clock_cache
         .draw(renderer, layout.bounds().size(), |frame| {
             let center = frame.center();
             let radius = frame.width().min(frame.height()) * 0.5;
-            let period = if time_picker.state.time.hour12().0 {
+            let period = if state.time.hour12().0 {
                 clock::Period::PM
             } else {
                 clock::Period::AM
@@ -1114,44 +1040,22 @@ fn draw_clock<Message, Theme>(
             let number_size = radius * NUMBER_SIZE_PERCENTAGE;
             let period_size = radius * PERIOD_SIZE_PERCENTAGE;
 
-            let period_radius = radius * PERIOD_PERCENTAGE;
-
-            let (hour_radius, minute_radius, second_radius) = if time_picker.state.show_seconds {
-                (
-                    radius * HOUR_RADIUS_PERCENTAGE,
-                    radius * MINUTE_RADIUS_PERCENTAGE,
-                    radius * SECOND_RADIUS_PERCENTAGE,
-                )
-            } else {
-                (
-                    radius * HOUR_RADIUS_PERCENTAGE_NO_SECONDS,
-                    radius * MINUTE_RADIUS_PERCENTAGE_NO_SECONDS,
-                    f32::MAX,
-                )
+            let rings = clock::rings(radius, state.show_seconds);
+            let ring_radius = |ring: NearestRadius| {
+                rings
+                    .iter()
+                    .find(|(_, nearest)| *nearest == ring)
+                    .map_or(f32::MAX, |(radius, _)| *radius)
             };
+            let hour_radius = ring_radius(NearestRadius::Hour);
+            let minute_radius = ring_radius(NearestRadius::Minute);
+            let second_radius = ring_radius(NearestRadius::Second);
 
             let internal_cursor = cursor.position().unwrap_or_default()
                 - Vector::new(layout.bounds().x, layout.bounds().y);
 
             let nearest_radius = if cursor.is_over(layout.bounds()) {
-                crate::core::clock::nearest_radius(
-                    &if time_picker.state.show_seconds {
-                        vec![
-                            (period_radius, NearestRadius::Period),
-                            (hour_radius, NearestRadius::Hour),
-                            (minute_radius, NearestRadius::Minute),
-                            (second_radius, NearestRadius::Second),
-                        ]
-                    } else {
-                        vec![
-                            (period_radius, NearestRadius::Period),
-                            (hour_radius, NearestRadius::Hour),
-                            (minute_radius, NearestRadius::Minute),
-                        ]
-                    },
-                    internal_cursor,
-                    center,
-                )
+                clock::nearest_radius(&rings, internal_cursor, center)
             } else {
                 NearestRadius::None
             };
@@ -1160,68 +1064,113 @@ fn draw_clock<Message, Theme>(
             let minute_points = crate::core::clock::circle_points(minute_radius, center, 60);
             let second_points = crate::core::clock::circle_points(second_radius, center, 60);
 
-            let hand_stroke = Stroke {
-                style: Style::Solid(
-                    style
-                        .get(&clock_style_state)
-                        .expect("Style Sheet not found.")
-                        .clock_hand_color,
-                ),
-                width: style
-                    .get(&clock_style_state)
-                    .expect("Style Sheet not found.")
-                    .clock_hand_width,
-                line_cap: LineCap::Round,
-                ..Stroke::default()
+            // The hours, minutes and seconds that can't be picked
+            let (pm, _) = state.time.hour12();
+            let is_hour_disabled = |i: usize| {
+                !state
+                    .time
+                    .with_hour((i as u32 + if pm { 12 } else { 0 }) % 24)
+                    .is_some_and(|time| state.is_hour_in_range(time))
+            };
+            let is_minute_disabled = |i: usize| {
+                !state
+                    .time
+                    .with_minute(i as u32)
+                    .is_some_and(|time| state.is_minute_in_range(time))
+            };
+            let is_second_disabled = |i: usize| {
+                !state
+                    .time
+                    .with_second(i as u32)
+                    .is_some_and(|time| state.is_in_range(time))
             };
 
-            match nearest_radius {
-                NearestRadius::Period => {
-                    frame.fill(
-                        &Path::circle(center, period_size),
-                        style
-                            .get(&StyleState::Hovered)
-                            .expect("Style Sheet not found.")
-                            .clock_number_background,
-                    );
+            // The hour, minute or second that is picked on click, the same way
+            // as the event handling does
+            let hovered = clock::ring_value(
+                &rings,
+                &nearest_radius,
+                center,
+                internal_cursor,
+                state.minute_step,
+            )
+            .map(|value| value as usize)
+            .filter(|&value| match nearest_radius {
+                NearestRadius::Hour => !is_hour_disabled(value),
+                NearestRadius::Minute => !is_minute_disabled(value),
+                NearestRadius::Second => !is_second_disabled(value),
+                NearestRadius::Period | NearestRadius::None => true,
+            });
+            let is_hovered =
+                |ring: NearestRadius, i: usize| nearest_radius == ring && hovered == Some(i);
+
+            let hand_stroke = |style_state: &StyleState, focus: Focus| {
+                let appearance = style.get(style_state).expect("Style Sheet not found.");
+
+                Stroke {
+                    style: Style::Solid(hand_color(appearance, focus)),
+                    width: appearance.clock_hand_width,
+                    line_cap: LineCap::Round,
+                    ..Stroke::default()
                 }
-                NearestRadius::Hour => {
-                    let nearest_point = hour_points
-                        [crate::core::clock::nearest_point(&hour_points, internal_cursor)];
+            };
 
-                    frame.fill(
-                        &Path::circle(nearest_point, 5.0),
-                        style
-                            .get(&StyleState::Hovered)
-                            .expect("Style Sheet not found.")
-                            .clock_number_background,
-                    );
+            // The hand of the focused unit of the digital clock is highlighted
+            let hand = |focus: Focus| {
+                if state.focus == focus {
+                    hand_stroke(&StyleState::Focused, focus)
+                } else {
+                    hand_stroke(&clock_style_state, focus)
                 }
-                NearestRadius::Minute => {
-                    let nearest_point = minute_points
-                        [crate::core::clock::nearest_point(&minute_points, internal_cursor)];
+            };
 
+            match (&nearest_radius, hovered) {
+                (NearestRadius::Period, _) => {
                     frame.fill(
-                        &Path::circle(nearest_point, 5.0),
+                        &Path::circle(center, period_size),
                         style
                             .get(&StyleState::Hovered)
                             .expect("Style Sheet not found.")
                             .clock_number_background,
                     );
                 }
-                NearestRadius::Second => {
-                    let nearest_point = second_points
-                        [crate::core::clock::nearest_point(&second_points, internal_cursor)];
+                (NearestRadius::Hour | NearestRadius::Minute | NearestRadius::Second, Some(i)) => {
+                    let (nearest_point, focus) = match nearest_radius {
+                        NearestRadius::Hour => (hour_points[i], Focus::DigitalHour),
+                        NearestRadius::Minute => (minute_points[i], Focus::DigitalMinute),
+                        _ => (second_points[i], Focus::DigitalSecond),
+                    };
+
+                    // A ghost hand points at the hovered hour, minute or second
+                    let hovered_appearance = style
+                        .get(&StyleState::Hovered)
+                        .expect("Style Sheet not found.");
+                    let ghost_hand_stroke = Stroke {
+                        style: Style::Solid(Color {
+                            a: 0.5,
+                            ..hand_color(hovered_appearance, focus)
+                        }),
+                        ..hand_stroke(&StyleState::Hovered, focus)
+                    };
 
+                    frame.stroke(&Path::line(center, nearest_point), ghost_hand_stroke);
                     frame.fill(
-                        &Path::circle(nearest_point, 5.0),
+                        &Path::circle(nearest_point, number_size * 0.4),
                         style
                             .get(&StyleState::Hovered)
                             .expect("Style Sheet not found.")
                             .clock_number_background,
                     );
                 }
-                NearestRadius::None => {}
+                _ => {
+                    if let Some(background) = style
+                        .get(&clock_style_state)
+                        .expect("Style Sheet not found.")
+                        .period_button_selected_background
+                    {
+                        frame.fill(&Path::circle(center, period_size), background);
+                    }
+                }
             }
 
             let period_text = Text {
@@ -1240,16 +1189,20 @@ fn draw_clock<Message, Theme>(
             };
             frame.fill_text(period_text);
 
+            if state.focus == Focus::Period {
+                frame.stroke(&Path::circle(center, period_size), hand(Focus::Period));
+            }
+
             hour_points.iter().enumerate().for_each(|(i, p)| {
                 let (pm, selected) = {
-                    let (pm, _) = time_picker.state.time.hour12();
-                    let hour = time_picker.state.time.hour();
+                    let (pm, _) = state.time.hour12();
+                    let hour = state.time.hour();
                     (pm, hour % 12 == i as u32)
                 };
 
                 let mut style_state = StyleState::Active;
                 if selected {
-                    frame.stroke(&Path::line(center, *p), hand_stroke.clone());
+                    frame.stroke(&Path::line(center, *p), hand(Focus::DigitalHour));
                     frame.fill(
                         &Path::circle(*p, number_size * 0.8),
                         style
@@ -1258,14 +1211,18 @@ fn draw_clock<Message, Theme>(
                             .clock_number_background,
                     );
                     style_state = style_state.max(StyleState::Selected);
+                } else if is_hovered(NearestRadius::Hour, i) {
+                    style_state = StyleState::Hovered;
+                } else if is_hour_disabled(i) {
+                    style_state = StyleState::Disabled;
                 }
 
                 let text = Text {
                     content: format!(
                         "{}",
-                        if pm && time_picker.state.use_24h {
+                        if pm && state.use_24h {
                             i + 12
-                        } else if !time_picker.state.use_24h && i == 0 {
+                        } else if !state.use_24h && i == 0 {
                             12
                         } else {
                             i
@@ -1287,58 +1244,72 @@ fn draw_clock<Message, Theme>(
                 frame.fill_text(text);
             });
 
-            minute_points.iter().enumerate().for_each(|(i, p)| {
-                let selected = time_picker.state.time.minute() == i as u32;
+            // The minutes off the minute step aren't drawn
+            minute_points
+                .iter()
+                .enumerate()
+                .step_by(state.minute_step as usize)
+                .for_each(|(i, p)| {
+                    let selected = state.time.minute() == i as u32;
 
-                let mut style_state = StyleState::Active;
-                if selected {
-                    frame.stroke(&Path::line(center, *p), hand_stroke.clone());
-                    frame.fill(
-                        &Path::circle(*p, number_size * 0.6),
-                        style
-                            .get(&StyleState::Selected)
-                            .expect("Style Sheet not found.")
-                            .clock_number_background,
-                    );
-                    style_state = style_state.max(StyleState::Selected);
-                }
+                    let mut style_state = StyleState::Active;
+                    if selected {
+                        frame.stroke(&Path::line(center, *p), hand(Focus::DigitalMinute));
+                        frame.fill(
+                            &Path::circle(*p, number_size * 0.6),
+                            style
+                                .get(&StyleState::Selected)
+                                .expect("Style Sheet not found.")
+                                .clock_number_background,
+                        );
+                        style_state = style_state.max(StyleState::Selected);
+                    } else if is_hovered(NearestRadius::Minute, i) {
+                        style_state = StyleState::Hovered;
+                    } else if is_minute_disabled(i) {
+                        style_state = StyleState::Disabled;
+                    }
 
-                if i % 5 == 0 {
-                    let text = Text {
-                        content: format!("{i:02}"),
-                        position: *p,
-                        color: style
-                            .get(&style_state)
-                            .expect("Style Sheet not found.")
-                            .clock_number_color,
-                        size: core::Pixels(number_size),
-                        font: renderer.default_font(),
-                        horizontal_alignment: Horizontal::Center,
-                        vertical_alignment: Vertical::Center,
-                        shaping: text::Shaping::Basic,
-                        line_height: text::LineHeight::Relative(1.3),
-                    };
+                    if i % 5 == 0 {
+                        let text = Text {
+                            content: format!("{i:02}"),
+                            position: *p,
+                            color: style
+                                .get(&style_state)
+                                .expect("Style Sheet not found.")
+                                .clock_number_color,
+                            size: core::Pixels(number_size),
+                            font: renderer.default_font(),
+                            horizontal_alignment: Horizontal::Center,
+                            vertical_alignment: Vertical::Center,
+                            shaping: text::Shaping::Basic,
+                            line_height: text::LineHeight::Relative(1.3),
+                        };
 
-                    frame.fill_text(text);
-                } else {
-                    let circle = Path::circle(*p, number_size * 0.1);
-                    frame.fill(
-                        &circle,
-                        style
-                            .get(&StyleState::Active)
-                            .expect("Style Sheet not found.")
-                            .clock_dots_color,
-                    );
-                }
-            });
+                        frame.fill_text(text);
+                    } else {
+                        let circle = Path::circle(*p, number_size * 0.1);
+                        frame.fill(
+                            &circle,
+                            tick_color(
+                                style
+                                    .get(&if style_state == StyleState::Disabled {
+                                        StyleState::Disabled
+                                    } else {
+                                        StyleState::Active
+                                    })
+                                    .expect("Style Sheet not found."),
+                            ),
+                        );
+                    }
+                });
 
-            if time_picker.state.show_seconds {
+            if state.show_seconds {
                 second_points.iter().enumerate().for_each(|(i, p)| {
-                    let selected = time_picker.state.time.second() == i as u32;
+                    let selected = state.time.second() == i as u32;
 
                     let mut style_state = StyleState::Active;
                     if selected {
-                        frame.stroke(&Path::line(center, *p), hand_stroke.clone());
+                        frame.stroke(&Path::line(center, *p), hand(Focus::DigitalSecond));
                         frame.fill(
                             &Path::circle(*p, number_size * 0.6),
                             style
@@ -1347,6 +1318,10 @@ fn draw_clock<Message, Theme>(
                                 .clock_number_background,
                         );
                         style_state = style_state.max(StyleState::Selected);
+                    } else if is_hovered(NearestRadius::Second, i) {
+                        style_state = StyleState::Hovered;
+                    } else if is_second_disabled(i) {
+                        style_state = StyleState::Disabled;
                     }
 
                     if i % 10 == 0 {
@@ -1370,10 +1345,15 @@ fn draw_clock<Message, Theme>(
                         let circle = Path::circle(*p, number_size * 0.1);
                         frame.fill(
                             &circle,
-                            style
-                                .get(&StyleState::Active)
-                                .expect("Style Sheet not found.")
-                                .clock_dots_color,
+                            tick_color(
+                                style
+                                    .get(&if style_state == StyleState::Disabled {
+                                        StyleState::Disabled
+                                    } else {
+                                        StyleState::Active
+                                    })
+                                    .expect("Style Sheet not found."),
+                            ),
                         );
                     }
                 });
@@ -1386,18 +1366,31 @@ fn draw_clock<Message, Theme>(
     });
 }
 
+/// The color of the hand of the given unit of the clock.
+fn hand_color(appearance: &Appearance, focus: Focus) -> Color {
+    match focus {
+        Focus::DigitalHour => appearance.hour_hand_color,
+        Focus::DigitalMinute => appearance.minute_hand_color,
+        Focus::DigitalSecond => appearance.second_hand_color,
+        _ => None,
+    }
+    .unwrap_or(appearance.clock_hand_color)
+}
+
+/// The color of the tick marks between the numbers of the clock.
+fn tick_color(appearance: &Appearance) -> Color {
+    appearance.tick_color.unwrap_or(appearance.clock_dots_color)
+}
+
 /// Draws the digital clock.
 #[allow(clippy::too_many_lines)]
-fn draw_digital_clock<Message, Theme>(
+fn draw_digital_clock(
     renderer: &mut Renderer,
-    time_picker: &TimePickerOverlay<'_, Message, Theme>,
+    state: &State,
     layout: Layout<'_>,
     cursor: Cursor,
     style: &HashMap<StyleState, Appearance>,
-) where
-    Message: 'static + Clone,
-    Theme: StyleSheet + button::StyleSheet + text::StyleSheet,
-{
+) {
     //println!("layout: {:#?}", layout);
     let mut children = layout
         .children()
@@ -1406,7 +1399,7 @@ fn draw_digital_clock<Message, Theme>(
         .children();
 
     let f = |renderer: &mut Renderer, layout: Layout<'_>, text: String, target: Focus| {
-        let style_state = if time_picker.state.focus == target {
+        let style_state = if state.focus == target {
             StyleState::Focused
         } else {
             StyleState::Active
@@ -1430,6 +1423,15 @@ fn draw_digital_clock<Message, Theme>(
         let up_arrow_hovered = cursor.is_over(up_bounds);
         let down_arrow_hovered = cursor.is_over(down_bounds);
 
+        // The carets are disabled if the next step can't be picked
+        let caret_style_state = |forward| {
+            if state.can_step(target, forward) {
+                StyleState::Active
+            } else {
+                StyleState::Disabled
+            }
+        };
+
         // Background
         if style_state == StyleState::Focused {
             renderer.fill_quad(
@@ -1477,7 +1479,7 @@ fn draw_digital_clock<Message, Theme>(
             },
             Point::new(up_bounds.center_x(), up_bounds.center_y()),
             style
-                .get(&StyleState::Active)
+                .get(&caret_style_state(true))
                 .expect("Style Sheet not found.")
                 .text_color,
             up_bounds,
@@ -1519,14 +1521,14 @@ fn draw_digital_clock<Message, Theme>(
             },
             Point::new(down_bounds.center_x(), down_bounds.center_y()),
             style
-                .get(&StyleState::Active)
+                .get(&caret_style_state(false))
                 .expect("Style Sheet not found.")
                 .text_color,
             down_bounds,
         );
     };
 
-    if !time_picker.state.use_24h {
+    if !state.use_24h {
         // Placeholder
         let _ = children.next();
     }
@@ -1538,14 +1540,7 @@ fn draw_digital_clock<Message, Theme>(
     f(
         renderer,
         hour_layout,
-        format!(
-            "{:02}",
-            if time_picker.state.use_24h {
-                time_picker.state.time.hour()
-            } else {
-                time_picker.state.time.hour12().1
-            }
-        ),
+        format!("{:02}", state.digital_hour()),
         Focus::DigitalHour,
     );
 
@@ -1583,11 +1578,11 @@ fn draw_digital_clock<Message, Theme>(
     f(
         renderer,
         minute_layout,
-        format!("{:02}", time_picker.state.time.minute()),
+        format!("{:02}", state.time.minute()),
         Focus::DigitalMinute,
     );
 
-    if time_picker.state.show_seconds {
+    if state.show_seconds {
         // Draw separator between minutes and seconds
         let minute_second_separator = children
             .next()
@@ -1621,23 +1616,49 @@ fn draw_digital_clock<Message, Theme>(
         f(
             renderer,
             second_layout,
-            format!("{:02}", time_picker.state.time.second()),
+            format!("{:02}", state.time.second()),
             Focus::DigitalSecond,
         );
     }
 
     // Draw period
-    if !time_picker.state.use_24h {
+    if !state.use_24h {
         let period = children
             .next()
             .expect("Graphics: Layout should have a period layout");
+
+        if state.focus == Focus::Period {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: period.bounds(),
+                    border: Border {
+                        radius: style[&StyleState::Focused].border_radius.into(),
+                        width: style[&StyleState::Focused].border_width,
+                        color: style[&StyleState::Focused].border_color,
+                    },
+                    shadow: Shadow::default(),
+                },
+                style[&StyleState::Focused].background,
+            );
+        } else if let Some(background) =
+            style[&StyleState::Active].period_button_selected_background
+        {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: period.bounds(),
+                    border: Border {
+                        radius: style[&StyleState::Active].border_radius.into(),
+                        ..Border::default()
+                    },
+                    shadow: Shadow::default(),
+                },
+                background,
+            );
+        }
+
         renderer.fill_text(
             core::Text {
-                content: if time_picker.state.time.hour12().0 {
-                    "PM"
-                } else {
-                    "AM"
-                },
+                content: if state.time.hour12().0 { "PM" } else { "AM" },
                 bounds: Size::new(period.bounds().width, period.bounds().height),
                 size: renderer.default_size(),
                 font: renderer.default_font(),
@@ -1655,6 +1676,7 @@ fn draw_digital_clock<Message, Theme>(
 
 /// The state of the [`TimePickerOverlay`].
 #[derive(Debug)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct State {
     /// The selected time of the [`TimePickerOverlay`].
     pub(crate) time: NaiveTime,
@@ -1666,203 +1688,958 @@ pub struct State {
     pub(crate) use_24h: bool,
     /// Toggle the use of the seconds of the [`TimePickerOverlay`].
     pub(crate) show_seconds: bool,
+    /// Toggle the clock face of the [`TimePickerOverlay`].
+    pub(crate) show_clock: bool,
+    /// The maximum width of the [`TimePickerOverlay`].
+    pub(crate) size: f32,
+    /// Toggle the smaller spacing of the [`TimePickerOverlay`].
+    pub(crate) compact: bool,
     /// The dragged clock element of the [`TimePickerOverlay`].
     pub(crate) clock_dragged: ClockDragged,
     /// The focus of the [`TimePickerOverlay`].
     pub(crate) focus: Focus,
     /// The previously pressed keyboard modifiers.
     pub(crate) keyboard_modifiers: keyboard::Modifiers,
+    /// The step of the minutes that can be picked.
+    pub(crate) minute_step: u32,
+    /// The text typed into the text field, while it's not changed otherwise.
+    pub(crate) typed_time: Option<String>,
+    /// The earliest time that can be picked, if any.
+    pub(crate) min_time: Option<NaiveTime>,
+    /// The latest time that can be picked, if any.
+    pub(crate) max_time: Option<NaiveTime>,
+    /// The maximum hours of the picked duration, if a duration is picked
+    /// instead of a time.
+    pub(crate) max_hours: Option<u32>,
+    /// The hours of the picked duration, whose minutes and seconds are kept
+    /// in the time.
+    pub(crate) hours: u32,
 }
 
 impl State {
     /// Creates a new State with the given time.
     #[must_use]
     pub fn new(time: Time, use_24h: bool, show_seconds: bool) -> Self {
+        let time: NaiveTime = time.into();
+
         Self {
             use_24h,
             show_seconds,
-            time: time.into(),
+            // The seconds of the time are dropped while they are hidden
+            time: if show_seconds {
+                time
+            } else {
+                time.with_second(0).unwrap_or(time)
+            },
             ..Self::default()
         }
     }
-}
 
-impl Default for State {
-    fn default() -> Self {
-        Self {
-            time: Local::now().naive_local().time(),
-            clock_cache_needs_clearance: false,
-            clock_cache: canvas::Cache::new(),
-            use_24h: false,
-            show_seconds: false,
-            clock_dragged: ClockDragged::None,
-            focus: Focus::default(),
-            keyboard_modifiers: keyboard::Modifiers::default(),
-        }
+    /// The picked time.
+    pub(crate) fn picked_time(&self) -> Time {
+        time::picked_time(self.time, self.use_24h, self.show_seconds)
     }
-}
 
-/// Just a workaround to pass the button states from the tree to the overlay
-#[allow(missing_debug_implementations)]
-pub struct TimePickerOverlayButtons<'a, Message, Theme>
-where
-    Message: Clone,
-    Theme: StyleSheet + button::StyleSheet,
-{
-    /// The cancel button of the [`TimePickerOverlay`].
-    cancel_button: Element<'a, Message, Theme, Renderer>,
-    /// The submit button of the [`TimePickerOverlay`].
-    submit_button: Element<'a, Message, Theme, Renderer>,
-}
+    /// The text shown in the text field, the typed text or the picked time.
+    fn time_text(&self) -> String {
+        self.typed_time.clone().unwrap_or_else(|| {
+            if self.max_hours.is_some() {
+                let text = format!("{:02}:{:02}", self.hours, self.time.minute());
+                return if self.show_seconds {
+                    format!("{text}:{:02}", self.time.second())
+                } else {
+                    text
+                };
+            }
 
-impl<'a, Message, Theme> Default for TimePickerOverlayButtons<'a, Message, Theme>
-where
-    Message: 'a + Clone,
-    Theme: 'a + StyleSheet + button::StyleSheet + text::StyleSheet,
-{
-    fn default() -> Self {
-        Self {
-            cancel_button: Button::new(
-                text::Text::new(icon_to_string(BootstrapIcon::X))
-                    .font(BOOTSTRAP_FONT)
-                    .horizontal_alignment(Horizontal::Center)
-                    .width(Length::Fill),
-            )
-            .into(),
-            submit_button: Button::new(
-                text::Text::new(icon_to_string(BootstrapIcon::Check))
-                    .font(BOOTSTRAP_FONT)
-                    .horizontal_alignment(Horizontal::Center)
-                    .width(Length::Fill),
-            )
-            .into(),
+            let format = match (self.use_24h, self.show_seconds) {
+                (true, false) => "%H:%M",
+                (true, true) => "%H:%M:%S",
+                (false, false) => "%I:%M %p",
+                (false, true) => "%I:%M:%S %p",
+            };
+
+            self.time.format(format).to_string()
+        })
+    }
+
+    /// Parses the given text typed into the text field, keeping the period
+    /// of the picked time if it has none.
+    ///
+    /// Minutes off the minute step aren't accepted.
+    fn parse_time(&self, text: &str) -> Option<NaiveTime> {
+        time::parse(text, self.use_24h, self.show_seconds, self.time.hour12().0)
+            .filter(|&parsed| time::floor_minute(parsed, self.minute_step) == parsed)
+            .filter(|&parsed| self.is_in_range(parsed))
+    }
+
+    /// Parses the given text typed into the text field while picking a
+    /// duration.
+    ///
+    /// Durations above the maximum hours or with minutes off the minute step
+    /// aren't accepted.
+    fn parse_duration(&self, text: &str) -> Option<u32> {
+        time::parse_duration(text, self.show_seconds)
+            .filter(|&duration| duration <= self.max_duration())
+            .filter(|&duration| {
+                let minute = duration / 60 % 60;
+                minute / self.minute_step * self.minute_step == minute
+            })
+    }
+
+    /// Checks if the text typed into the text field, if any, isn't a valid
+    /// time or duration.
+    fn is_typed_time_invalid(&self) -> bool {
+        self.typed_time.as_ref().is_some_and(|text| {
+            if self.max_hours.is_some() {
+                self.parse_duration(text).is_none()
+            } else {
+                self.parse_time(text).is_none()
+            }
+        })
+    }
+
+    /// The hour shown on the digital clock.
+    fn digital_hour(&self) -> u32 {
+        if self.max_hours.is_some() {
+            self.hours
+        } else if self.use_24h {
+            self.time.hour()
+        } else {
+            self.time.hour12().1
         }
     }
-}
 
-#[allow(clippy::unimplemented)]
-impl<'a, Message, Theme> Widget<Message, Theme, Renderer>
-    for TimePickerOverlayButtons<'a, Message, Theme>
-where
-    Message: Clone,
-    Theme: StyleSheet + button::StyleSheet,
-{
-    fn children(&self) -> Vec<Tree> {
-        vec![
-            Tree::new(&self.cancel_button),
-            Tree::new(&self.submit_button),
-        ]
+    /// The picked duration in seconds.
+    pub(crate) fn duration(&self) -> u32 {
+        self.hours * 3600 + self.time.minute() * 60 + self.time.second()
     }
 
-    fn diff(&self, tree: &mut Tree) {
-        tree.diff_children(&[&self.cancel_button, &self.submit_button]);
+    /// The longest duration that can be picked in seconds.
+    fn max_duration(&self) -> u32 {
+        self.max_hours.unwrap_or_default() * 3600 + 59 * 60 + 59
     }
 
-    fn size(&self) -> Size<Length> {
-        unimplemented!("This should never be reached!")
+    /// Picks the given duration in seconds, up to the longest duration that
+    /// can be picked.
+    pub(crate) fn set_duration(&mut self, seconds: u32) {
+        let seconds = seconds.min(self.max_duration());
+
+        self.hours = seconds / 3600;
+        self.time = time::floor_minute(
+            NaiveTime::from_num_seconds_from_midnight_opt(seconds % 3600, 0).unwrap_or_default(),
+            self.minute_step,
+        );
     }
 
-    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, _limits: &Limits) -> Node {
-        unimplemented!("This should never be reached!")
+    /// The duration one step forward or backward with the given element of
+    /// the digital clock, if it's between zero and the longest duration.
+    fn stepped_duration(&self, focus: Focus, forward: bool) -> Option<u32> {
+        let step = match focus {
+            Focus::DigitalHour => 3600,
+            Focus::DigitalMinute => 60 * self.minute_step,
+            Focus::DigitalSecond => 1,
+            _ => return None,
+        };
+
+        if forward {
+            Some(self.duration() + step).filter(|&duration| duration <= self.max_duration())
+        } else {
+            self.duration().checked_sub(step)
+        }
     }
 
-    fn draw(
-        &self,
-        _state: &Tree,
-        _renderer: &mut Renderer,
-        _theme: &Theme,
-        _style: &renderer::Style,
-        _layout: Layout<'_>,
-        _cursor: Cursor,
-        _viewport: &Rectangle,
-    ) {
-        unimplemented!("This should never be reached!")
+    /// Checks if the time or the duration can be moved one step forward or
+    /// backward with the given element of the digital clock.
+    fn can_step(&self, focus: Focus, forward: bool) -> bool {
+        if self.max_hours.is_some() {
+            self.stepped_duration(focus, forward).is_some()
+        } else {
+            self.stepped(focus, forward).is_some()
+        }
     }
-}
 
-impl<'a, Message, Theme> From<TimePickerOverlayButtons<'a, Message, Theme>>
-    for Element<'a, Message, Theme, Renderer>
-where
-    Message: 'a + Clone,
-    Theme: 'a + StyleSheet + button::StyleSheet,
-{
-    fn from(overlay: TimePickerOverlayButtons<'a, Message, Theme>) -> Self {
-        Self::new(overlay)
+    /// The earliest and the latest time that can be picked.
+    fn range(&self) -> (NaiveTime, NaiveTime) {
+        (
+            self.min_time.unwrap_or_default(),
+            self.max_time.unwrap_or_else(|| {
+                NaiveTime::from_hms_nano_opt(23, 59, 59, 999_999_999)
+                    .expect("The last time of the day should be valid")
+            }),
+        )
     }
-}
 
-/// The state of the currently dragged watch hand.
-#[derive(Copy, Clone, Debug)]
-pub enum ClockDragged {
-    /// Nothing is dragged.
-    None,
+    /// Checks if the given time can be picked.
+    pub(crate) fn is_in_range(&self, time: NaiveTime) -> bool {
+        let (min, max) = self.range();
+        time::overlaps_range(time, time, min, max)
+    }
 
-    /// The hour hand is dragged.
-    Hour,
+    /// Checks if any time of the hour of the given time can be picked.
+    fn is_hour_in_range(&self, time: NaiveTime) -> bool {
+        let (min, max) = self.range();
+        let start = NaiveTime::from_hms_opt(time.hour(), 0, 0).unwrap_or(time);
+        time::overlaps_range(start, start + Duration::seconds(59 * 60 + 59), min, max)
+    }
 
-    /// The minute hand is dragged.
-    Minute,
+    /// Checks if any time of the minute of the given time can be picked.
+    fn is_minute_in_range(&self, time: NaiveTime) -> bool {
+        let (min, max) = self.range();
+        let start = NaiveTime::from_hms_opt(time.hour(), time.minute(), 0).unwrap_or(time);
+        time::overlaps_range(start, start + Duration::seconds(59), min, max)
+    }
 
-    /// The second hand is dragged.
-    Second,
-}
+    /// Moves the given time to the nearest time that can be picked, on the
+    /// minute step where the range allows it.
+    pub(crate) fn clamp(&self, time: NaiveTime) -> NaiveTime {
+        let (min, max) = self.range();
+        let time = time::clamp_to_range(time, min, max);
+        let floored = time::floor_minute(time, self.minute_step);
 
-/// An enumeration of all focusable elements of the [`TimePickerOverlay`].
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
-pub enum Focus {
-    /// Nothing is in focus.
-    None,
+        if self.is_in_range(floored) {
+            floored
+        } else {
+            time::succ_minute_step(floored, self.minute_step)
+        }
+    }
 
-    /// The overlay itself is in focus.
-    Overlay,
+    /// The time one step forward or backward with the given element of the
+    /// digital clock, if that hour, minute or second can be picked.
+    ///
+    /// The minutes move by the minute step. A carry of the seconds to a
+    /// minute off the step is dropped.
+    fn stepped(&self, focus: Focus, forward: bool) -> Option<NaiveTime> {
+        let time = match (focus, forward) {
+            (Focus::DigitalHour, true) => self.time + Duration::hours(1),
+            (Focus::DigitalHour, false) => self.time - Duration::hours(1),
+            (Focus::DigitalMinute, true) => time::succ_minute_step(self.time, self.minute_step),
+            (Focus::DigitalMinute, false) => time::pred_minute_step(self.time, self.minute_step),
+            (Focus::DigitalSecond, true) => self.time + Duration::seconds(1),
+            (Focus::DigitalSecond, false) => self.time - Duration::seconds(1),
+            _ => return None,
+        };
+        let time = time::floor_minute(time, self.minute_step);
 
-    /// The digital hour is in focus.
-    DigitalHour,
+        let in_range = match focus {
+            Focus::DigitalHour => self.is_hour_in_range(time),
+            Focus::DigitalMinute => self.is_minute_in_range(time),
+            _ => self.is_in_range(time),
+        };
 
-    /// The digital minute is in focus.
-    DigitalMinute,
+        in_range.then(|| self.clamp(time))
+    }
 
-    /// The digital second is in focus.
-    DigitalSecond,
+    /// Shows or hides the seconds.
+    ///
+    /// The seconds are dropped once hidden, so they start at zero once shown
+    /// again, and the clock and the text field follow the new format.
+    pub(crate) fn set_show_seconds(&mut self, show_seconds: bool) {
+        if self.show_seconds == show_seconds {
+            return;
+        }
 
-    /// The cancel button is in focus.
-    Cancel,
+        self.show_seconds = show_seconds;
+        self.typed_time = None;
+        self.clock_cache.clear();
 
-    /// The submit button is in focus.
-    Submit,
-}
+        if !show_seconds {
+            self.time = self.clamp(self.time.with_second(0).unwrap_or(self.time));
 
-impl Focus {
-    /// Gets the next focusable element.
-    #[must_use]
-    pub const fn next(self, show_seconds: bool) -> Self {
-        match self {
-            Self::Overlay => Self::DigitalHour,
-            Self::DigitalHour => Self::DigitalMinute,
-            Self::DigitalMinute => {
-                if show_seconds {
-                    Self::DigitalSecond
-                } else {
-                    Self::Cancel
-                }
+            if matches!(self.clock_dragged, ClockDragged::Second) {
+                self.clock_dragged = ClockDragged::None;
+            }
+            if self.focus == Focus::DigitalSecond {
+                self.focus = Focus::DigitalMinute;
             }
-            Self::DigitalSecond => Self::Cancel,
-            Self::Cancel => Self::Submit,
-            Self::Submit | Self::None => Self::Overlay,
         }
     }
 
-    /// Gets the previous focusable element.
-    #[must_use]
-    pub const fn previous(self, show_seconds: bool) -> Self {
-        match self {
-            Self::None => Self::None,
-            Self::Overlay => Self::Submit,
+    /// Switches the time between AM and PM.
+    fn toggle_period(&mut self) {
+        self.time = self.clamp(
+            self.time
+                .with_hour((self.time.hour() + 12) % 24)
+                .expect("New time with hour should be valid"),
+        );
+    }
+
+    /// Moves the time or the duration one step forward or backward with the
+    /// focused element of the digital clock, see [`State::stepped`].
+    fn step(&mut self, forward: bool) {
+        if self.max_hours.is_some() {
+            if let Some(duration) = self.stepped_duration(self.focus, forward) {
+                self.set_duration(duration);
+            }
+        } else if let Some(time) = self.stepped(self.focus, forward) {
+            self.time = time;
+        }
+    }
+
+    /// The event handling for the clock and the digital clock with the
+    /// given layout.
+    pub(crate) fn on_event(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) -> event::Status {
+        let mut children = layout.children();
+
+        // Clock canvas
+        let clock_layout = children
+            .next()
+            .expect("Native: Layout should have a clock canvas layout");
+        let clock_status = if self.show_clock {
+            self.on_event_clock(event, clock_layout, cursor)
+        } else {
+            event::Status::Ignored
+        };
+
+        // ----------- Digital clock ------------------
+        let digital_clock_layout = children
+            .next()
+            .expect("Native: Layout should have a digital clock parent")
+            .children()
+            .next()
+            .expect("Native: Layout should have a digital clock layout");
+        let digital_clock_status = self.on_event_digital_clock(event, digital_clock_layout, cursor);
+
+        clock_status.merge(digital_clock_status)
+    }
+
+    /// The mouse interaction of the clock and the digital clock with the
+    /// given layout.
+    pub(crate) fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) -> mouse::Interaction {
+        let mut children = layout.children();
+
+        // Clock canvas
+        let clock_layout = children
+            .next()
+            .expect("Graphics: Layout should have a clock canvas layout");
+        let clock_mouse_interaction = if cursor.is_over(clock_layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        };
+
+        // Digital clock
+        let digital_clock_layout = children
+            .next()
+            .expect("Graphics: Layout should have a digital clock layout");
+        //let digital_clock_mouse_interaction = mouse::Interaction::default();
+        let mut digital_clock_children = digital_clock_layout
+            .children()
+            .next()
+            .expect("Graphics: Layout should have digital clock children")
+            .children();
+
+        let f = |layout: Layout<'_>| {
+            let mut children = layout.children();
+
+            let up_bounds = children
+                .next()
+                .expect("Graphics: Layout should have a up arrow bounds")
+                .bounds();
+            let _center_bounds = children.next();
+            let down_bounds = children
+                .next()
+                .expect("Graphics: Layout should have a down arrow bounds")
+                .bounds();
+
+            let mut mouse_interaction = mouse::Interaction::default();
+
+            let up_arrow_hovered = cursor.is_over(up_bounds);
+            let down_arrow_hovered = cursor.is_over(down_bounds);
+
+            if up_arrow_hovered || down_arrow_hovered {
+                mouse_interaction = mouse_interaction.max(mouse::Interaction::Pointer);
+            }
+
+            mouse_interaction
+        };
+
+        if !self.use_24h {
+            // Placeholder
+            let _ = digital_clock_children.next();
+        }
+
+        let hour_layout = digital_clock_children
+            .next()
+            .expect("Graphics: Layout should have a hour layout");
+        let hour_mouse_interaction = f(hour_layout);
+
+        let _hour_minute_separator = digital_clock_children.next();
+
+        let minute_layout = digital_clock_children
+            .next()
+            .expect("Graphics: Layout should have a minute layout");
+        let minute_mouse_interaction = f(minute_layout);
+
+        let second_mouse_interaction = if self.show_seconds {
+            let _minute_second_separator = digital_clock_children.next();
+
+            let second_layout = digital_clock_children
+                .next()
+                .expect("Graphics: Layout should have a second layout");
+            f(second_layout)
+        } else {
+            mouse::Interaction::default()
+        };
+
+        let period_mouse_interaction = if self.use_24h {
+            mouse::Interaction::default()
+        } else {
+            let period_layout = digital_clock_children
+                .next()
+                .expect("Graphics: Layout should have a period layout");
+
+            if cursor.is_over(period_layout.bounds()) {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::default()
+            }
+        };
+
+        clock_mouse_interaction
+            .max(hour_mouse_interaction)
+            .max(minute_mouse_interaction)
+            .max(second_mouse_interaction)
+            .max(period_mouse_interaction)
+    }
+
+    /// The event handling for the arrow keys.
+    ///
+    /// The arrow keys up and down change the focused hour, minute, second or
+    /// period, left and right move the focus between them.
+    pub(crate) fn on_event_keyboard(&mut self, key: keyboard::key::Named) -> event::Status {
+        let focus = self.focus;
+
+        let status = match key {
+            keyboard::key::Named::ArrowLeft => {
+                self.focus = focus.previous_unit(self.show_seconds);
+                event::Status::Captured
+            }
+            keyboard::key::Named::ArrowRight => {
+                self.focus = focus.next_unit(self.show_seconds, !self.use_24h);
+                event::Status::Captured
+            }
+            keyboard::key::Named::ArrowUp | keyboard::key::Named::ArrowDown => match focus {
+                Focus::DigitalHour | Focus::DigitalMinute | Focus::DigitalSecond => {
+                    self.step(key == keyboard::key::Named::ArrowUp);
+                    event::Status::Captured
+                }
+                Focus::Period => {
+                    self.toggle_period();
+                    event::Status::Captured
+                }
+                _ => event::Status::Ignored,
+            },
+            _ => event::Status::Ignored,
+        };
+
+        // The focused unit is highlighted on the clock
+        if status == event::Status::Captured {
+            self.clock_cache.clear();
+        }
+
+        status
+    }
+
+    /// The event handling for the clock.
+    #[allow(clippy::too_many_lines)]
+    fn on_event_clock(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) -> event::Status {
+        if cursor.is_over(layout.bounds()) {
+            self.clock_cache_needs_clearance = true;
+            self.clock_cache.clear();
+        } else if self.clock_cache_needs_clearance {
+            self.clock_cache.clear();
+            self.clock_cache_needs_clearance = false;
+        }
+
+        let clock_bounds = layout.bounds();
+        if cursor.is_over(clock_bounds) {
+            let center = clock_bounds.center();
+            let radius = clock_bounds.width.min(clock_bounds.height) * 0.5;
+            let cursor_position = cursor.position().unwrap_or_default();
+
+            let rings = clock::rings(radius, self.show_seconds);
+            let nearest_radius = clock::nearest_radius(&rings, cursor_position, center);
+            let minute_step = self.minute_step;
+            let ring_value = |ring: &NearestRadius| {
+                clock::ring_value(&rings, ring, center, cursor_position, minute_step)
+            };
+
+            let clock_clicked_status = match event {
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. }) => match nearest_radius {
+                    NearestRadius::Period => {
+                        self.toggle_period();
+                        event::Status::Captured
+                    }
+                    NearestRadius::Hour => {
+                        self.focus = Focus::DigitalHour;
+                        self.clock_dragged = ClockDragged::Hour;
+                        event::Status::Captured
+                    }
+                    NearestRadius::Minute => {
+                        self.focus = Focus::DigitalMinute;
+                        self.clock_dragged = ClockDragged::Minute;
+                        event::Status::Captured
+                    }
+                    NearestRadius::Second => {
+                        self.focus = Focus::DigitalSecond;
+                        self.clock_dragged = ClockDragged::Second;
+                        event::Status::Captured
+                    }
+                    NearestRadius::None => event::Status::Ignored,
+                },
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(
+                    touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. },
+                ) => {
+                    self.clock_dragged = ClockDragged::None;
+                    event::Status::Captured
+                }
+                _ => event::Status::Ignored,
+            };
+
+            let clock_dragged_status = match self.clock_dragged {
+                ClockDragged::Hour => {
+                    let hour = ring_value(&NearestRadius::Hour).unwrap_or_default();
+                    let (pm, _) = self.time.hour12();
+
+                    let time = self
+                        .time
+                        .with_hour((hour + if pm { 12 } else { 0 }) % 24)
+                        .expect("New time with hour should be valid");
+
+                    // The hours out of the range are skipped
+                    if self.is_hour_in_range(time) {
+                        self.time = self.clamp(time);
+                    }
+                    event::Status::Captured
+                }
+                ClockDragged::Minute => {
+                    // Only the minutes of the minute step can be picked
+                    let time = self
+                        .time
+                        .with_minute(ring_value(&NearestRadius::Minute).unwrap_or_default())
+                        .expect("New time with minute should be valid");
+
+                    if self.is_minute_in_range(time) {
+                        self.time = self.clamp(time);
+                    }
+                    event::Status::Captured
+                }
+                ClockDragged::Second => {
+                    let time = self
+                        .time
+                        .with_second(ring_value(&NearestRadius::Second).unwrap_or_default())
+                        .expect("New time with second should be valid");
+
+                    if self.is_in_range(time) {
+                        self.time = time;
+                    }
+                    event::Status::Captured
+                }
+                ClockDragged::None => event::Status::Ignored,
+            };
+
+            clock_clicked_status.merge(clock_dragged_status)
+        } else {
+            match event {
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(
+                    touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. },
+                ) => {
+                    self.clock_dragged = ClockDragged::None;
+                    event::Status::Captured
+                }
+                _ => event::Status::Ignored,
+            }
+        }
+    }
+
+    /// The event handling for the digital clock.
+    #[allow(clippy::too_many_lines)]
+    fn on_event_digital_clock(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) -> event::Status {
+        let focus = self.focus;
+        let mut digital_clock_children = layout.children();
+
+        if !self.use_24h {
+            // Placeholder
+            let _ = digital_clock_children.next();
+        }
+
+        let hour_layout = digital_clock_children
+            .next()
+            .expect("Native: Layout should have a hour layout");
+        let mut hour_children = hour_layout.children();
+
+        let hour_up_arrow = hour_children
+            .next()
+            .expect("Native: Layout should have an up arrow for hours");
+        let _ = hour_children.next();
+        let hour_down_arrow = hour_children
+            .next()
+            .expect("Native: Layout should have a down arrow for hours");
+
+        let _ = digital_clock_children.next();
+
+        let minute_layout = digital_clock_children
+            .next()
+            .expect("Native: Layout should have a minute layout");
+        let mut minute_children = minute_layout.children();
+
+        let minute_up_arrow = minute_children
+            .next()
+            .expect("Native: Layout should have an up arrow for minutes");
+        let _ = minute_children.next();
+        let minute_down_arrow = minute_children
+            .next()
+            .expect("Native: Layout should have a down arrow for minutes");
+
+        let calculate_time = |state: &mut Self, up_arrow: Layout<'_>, down_arrow: Layout<'_>| {
+            if cursor.is_over(up_arrow.bounds()) {
+                state.step(true);
+                event::Status::Captured
+            } else if cursor.is_over(down_arrow.bounds()) {
+                state.step(false);
+                event::Status::Captured
+            } else {
+                event::Status::Ignored
+            }
+        };
+
+        let digital_clock_status = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if cursor.is_over(hour_layout.bounds()) {
+                    self.focus = Focus::DigitalHour;
+
+                    calculate_time(self, hour_up_arrow, hour_down_arrow)
+                } else if cursor.is_over(minute_layout.bounds()) {
+                    self.focus = Focus::DigitalMinute;
+
+                    calculate_time(self, minute_up_arrow, minute_down_arrow)
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            _ => event::Status::Ignored,
+        };
+
+        let second_status = if self.show_seconds {
+            let _ = digital_clock_children.next();
+
+            let second_layout = digital_clock_children
+                .next()
+                .expect("Native: Layout should have a second layout");
+            let mut second_children = second_layout.children();
+
+            let second_up_arrow = second_children
+                .next()
+                .expect("Native: Layout should have an up arrow for seconds");
+            let _ = second_children.next();
+            let second_down_arrow = second_children
+                .next()
+                .expect("Native: Layout should have a down arrow for seconds");
+
+            match event {
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                    if cursor.is_over(second_layout.bounds()) {
+                        self.focus = Focus::DigitalSecond;
+
+                        calculate_time(self, second_up_arrow, second_down_arrow)
+                    } else {
+                        event::Status::Ignored
+                    }
+                }
+                _ => event::Status::Ignored,
+            }
+        } else {
+            event::Status::Ignored
+        };
+
+        // The period toggles between AM and PM
+        let period_status = if self.use_24h {
+            event::Status::Ignored
+        } else {
+            let period_layout = digital_clock_children
+                .next()
+                .expect("Native: Layout should have a period layout");
+
+            match event {
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+                    if cursor.is_over(period_layout.bounds()) =>
+                {
+                    self.focus = Focus::Period;
+                    self.toggle_period();
+                    event::Status::Captured
+                }
+                _ => event::Status::Ignored,
+            }
+        };
+
+        let digital_clock_status = digital_clock_status
+            .merge(second_status)
+            .merge(period_status);
+
+        // The focused unit is highlighted on the clock
+        if digital_clock_status == event::Status::Captured || self.focus != focus {
+            self.clock_cache.clear();
+        }
+
+        digital_clock_status
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            time: Local::now().naive_local().time(),
+            clock_cache_needs_clearance: false,
+            clock_cache: canvas::Cache::new(),
+            use_24h: false,
+            show_seconds: false,
+            show_clock: true,
+            size: 300.0,
+            compact: false,
+            clock_dragged: ClockDragged::None,
+            focus: Focus::default(),
+            keyboard_modifiers: keyboard::Modifiers::default(),
+            minute_step: 1,
+            typed_time: None,
+            min_time: None,
+            max_time: None,
+            max_hours: None,
+            hours: 0,
+        }
+    }
+}
+
+/// The messages of the text field of the [`TimePickerOverlay`].
+#[derive(Clone, Debug)]
+enum TimeInput {
+    /// The text was changed.
+    Changed(String),
+
+    /// Enter was pressed.
+    Submitted,
+}
+
+/// The text field of the [`TimePickerOverlay`] showing the given text.
+fn time_input<'a, Theme>(text: &str) -> TextInput<'a, TimeInput, Theme, Renderer>
+where
+    Theme: text_input::StyleSheet,
+{
+    TextInput::new("", text)
+        .on_input(TimeInput::Changed)
+        .on_submit(TimeInput::Submitted)
+        .width(Length::Fill)
+}
+
+/// Just a workaround to pass the button states from the tree to the overlay
+#[allow(missing_debug_implementations, clippy::struct_field_names)]
+pub struct TimePickerOverlayButtons<'a, Message, Theme>
+where
+    Message: Clone,
+    Theme: StyleSheet + button::StyleSheet,
+{
+    /// The cancel button of the [`TimePickerOverlay`].
+    cancel_button: Element<'a, Message, Theme, Renderer>,
+    /// The submit button of the [`TimePickerOverlay`].
+    submit_button: Element<'a, Message, Theme, Renderer>,
+    /// The now button of the [`TimePickerOverlay`].
+    now_button: Element<'a, Message, Theme, Renderer>,
+    /// The text field of the [`TimePickerOverlay`].
+    time_input: Element<'a, TimeInput, Theme, Renderer>,
+}
+
+impl<'a, Message, Theme> Default for TimePickerOverlayButtons<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a + StyleSheet + button::StyleSheet + text::StyleSheet + text_input::StyleSheet,
+{
+    fn default() -> Self {
+        Self {
+            cancel_button: Button::new(
+                text::Text::new(icon_to_string(BootstrapIcon::X))
+                    .font(BOOTSTRAP_FONT)
+                    .horizontal_alignment(Horizontal::Center)
+                    .width(Length::Fill),
+            )
+            .into(),
+            submit_button: Button::new(
+                text::Text::new(icon_to_string(BootstrapIcon::Check))
+                    .font(BOOTSTRAP_FONT)
+                    .horizontal_alignment(Horizontal::Center)
+                    .width(Length::Fill),
+            )
+            .into(),
+            now_button: Button::new(
+                text::Text::new(locale::default_provider().now_label())
+                    .horizontal_alignment(Horizontal::Center)
+                    .width(Length::Fill),
+            )
+            .into(),
+            time_input: time_input("").into(),
+        }
+    }
+}
+
+#[allow(clippy::unimplemented)]
+impl<'a, Message, Theme> Widget<Message, Theme, Renderer>
+    for TimePickerOverlayButtons<'a, Message, Theme>
+where
+    Message: Clone,
+    Theme: StyleSheet + button::StyleSheet,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![
+            Tree::new(&self.cancel_button),
+            Tree::new(&self.submit_button),
+            Tree::new(&self.now_button),
+            Tree::new(&self.time_input),
+        ]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        // The text field takes other messages than the buttons. The tree of
+        // the digital clock is kept behind them.
+        if tree.children.len() < 4 {
+            tree.children = self.children();
+        }
+
+        tree.children[0].diff(&self.cancel_button);
+        tree.children[1].diff(&self.submit_button);
+        tree.children[2].diff(&self.now_button);
+        tree.children[3].diff(&self.time_input);
+    }
+
+    fn size(&self) -> Size<Length> {
+        unimplemented!("This should never be reached!")
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, _limits: &Limits) -> Node {
+        unimplemented!("This should never be reached!")
+    }
+
+    fn draw(
+        &self,
+        _state: &Tree,
+        _renderer: &mut Renderer,
+        _theme: &Theme,
+        _style: &renderer::Style,
+        _layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        unimplemented!("This should never be reached!")
+    }
+}
+
+impl<'a, Message, Theme> From<TimePickerOverlayButtons<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Theme: 'a + StyleSheet + button::StyleSheet,
+{
+    fn from(overlay: TimePickerOverlayButtons<'a, Message, Theme>) -> Self {
+        Self::new(overlay)
+    }
+}
+
+/// The state of the currently dragged watch hand.
+#[derive(Copy, Clone, Debug)]
+pub enum ClockDragged {
+    /// Nothing is dragged.
+    None,
+
+    /// The hour hand is dragged.
+    Hour,
+
+    /// The minute hand is dragged.
+    Minute,
+
+    /// The second hand is dragged.
+    Second,
+}
+
+/// An enumeration of all focusable elements of the [`TimePickerOverlay`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Focus {
+    /// Nothing is in focus.
+    None,
+
+    /// The overlay itself is in focus.
+    Overlay,
+
+    /// The digital hour is in focus.
+    DigitalHour,
+
+    /// The digital minute is in focus.
+    DigitalMinute,
+
+    /// The digital second is in focus.
+    DigitalSecond,
+
+    /// The AM/PM toggle is in focus.
+    Period,
+
+    /// The cancel button is in focus.
+    Cancel,
+
+    /// The submit button is in focus.
+    Submit,
+}
+
+impl Focus {
+    /// Gets the next focusable element.
+    #[must_use]
+    pub const fn next(self, show_seconds: bool, show_period: bool) -> Self {
+        match self {
+            Self::Overlay => Self::DigitalHour,
+            Self::DigitalHour => Self::DigitalMinute,
+            Self::DigitalMinute if show_seconds => Self::DigitalSecond,
+            Self::DigitalMinute | Self::DigitalSecond if show_period => Self::Period,
+            Self::DigitalMinute | Self::DigitalSecond | Self::Period => Self::Cancel,
+            Self::Cancel => Self::Submit,
+            Self::Submit | Self::None => Self::Overlay,
+        }
+    }
+
+    /// Gets the previous focusable element.
+    #[must_use]
+    pub const fn previous(self, show_seconds: bool, show_period: bool) -> Self {
+        match self {
+            Self::None => Self::None,
+            Self::Overlay => Self::Submit,
             Self::DigitalHour => Self::Overlay,
-            Self::DigitalMinute => Self::DigitalHour,
-            Self::DigitalSecond => Self::DigitalMinute,
+            Self::DigitalMinute | Self::DigitalSecond | Self::Period => {
+                self.previous_unit(show_seconds)
+            }
             Self::Cancel => {
-                if show_seconds {
+                if show_period {
+                    Self::Period
+                } else if show_seconds {
                     Self::DigitalSecond
                 } else {
                     Self::DigitalMinute
@@ -1871,6 +2648,38 @@ impl Focus {
             Self::Submit => Self::Cancel,
         }
     }
+
+    /// Gets the next field of the digital clock, staying on the last one.
+    ///
+    /// The hour is focused if no field of the digital clock is.
+    #[must_use]
+    pub const fn next_unit(self, show_seconds: bool, show_period: bool) -> Self {
+        match self {
+            Self::DigitalHour => Self::DigitalMinute,
+            Self::DigitalMinute if show_seconds => Self::DigitalSecond,
+            Self::DigitalMinute | Self::DigitalSecond if show_period => Self::Period,
+            Self::DigitalMinute | Self::DigitalSecond | Self::Period => self,
+            Self::None | Self::Overlay | Self::Cancel | Self::Submit => Self::DigitalHour,
+        }
+    }
+
+    /// Gets the previous field of the digital clock, staying on the first
+    /// one.
+    ///
+    /// The hour is focused if no field of the digital clock is.
+    #[must_use]
+    pub const fn previous_unit(self, show_seconds: bool) -> Self {
+        match self {
+            Self::Period if show_seconds => Self::DigitalSecond,
+            Self::Period | Self::DigitalSecond => Self::DigitalMinute,
+            Self::DigitalMinute
+            | Self::DigitalHour
+            | Self::None
+            | Self::Overlay
+            | Self::Cancel
+            | Self::Submit => Self::DigitalHour,
+        }
+    }
 }
 
 impl Default for Focus {
@@ -22,10 +22,26 @@ use iced_widget::{
     text::LineHeight,
     Container, Scrollable,
 };
-use std::{fmt::Display, hash::Hash, marker::PhantomData};
+use std::{fmt::Display, hash::Hash, marker::PhantomData, rc::Rc};
+
+use crate::native::common::layout_empty;
 
 pub use list::List;
 
+/// The direction the options of a [`SelectionList`] are laid out in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// The options are shown below each other and scroll vertically.
+    #[default]
+    Vertical,
+    /// The options are shown next to each other, each as wide as its text,
+    /// and scroll horizontally.
+    Horizontal,
+}
+
+/// The function deciding if an option matches the filter of a [`SelectionList`].
+type Matcher<T> = Box<dyn Fn(&T, &str) -> bool>;
+
 /// A widget for selecting a single value from a dynamic scrollable list of options.
 #[allow(missing_debug_implementations)]
 #[allow(clippy::type_repetition_in_bounds)]
@@ -57,6 +73,20 @@ pub struct SelectionList<
     text_size: f32,
     /// Style for Looks
     style: <Theme as StyleSheet>::Style,
+    /// Function Pointer On Select to call on Mouse button press.
+    on_selected: Rc<dyn Fn(usize, T) -> Message>,
+    /// Set the Selected ID manually.
+    selected: Option<usize>,
+    /// The text the options are filtered by.
+    filter: String,
+    /// The custom matcher deciding if an option matches the filter.
+    matcher: Option<Matcher<T>>,
+    /// Whether no option matches the filter.
+    is_empty: bool,
+    /// The element shown while no option matches the filter.
+    empty: Option<Element<'a, Message, Theme, Renderer>>,
+    /// The direction the options are laid out in.
+    direction: Direction,
 }
 
 #[allow(clippy::type_repetition_in_bounds)]
@@ -73,28 +103,15 @@ where
     /// selected. This will default the `style`, `text_size` and `padding`. use `new_with`
     /// to set those.
     pub fn new(options: &'a [T], on_selected: impl Fn(usize, T) -> Message + 'static) -> Self {
-        let container = Container::new(Scrollable::new(List {
+        Self::new_with(
             options,
-            font: Font::default(),
-            text_size: 12.0,
-            padding: 5.0,
-            style: <Theme as StyleSheet>::Style::default(),
-            on_selected: Box::new(on_selected),
-            selected: None,
-            phantomdata: PhantomData,
-        }))
-        .padding(1);
-
-        Self {
-            options,
-            font: Font::default(),
-            style: <Theme as StyleSheet>::Style::default(),
-            container,
-            width: Length::Fill,
-            height: Length::Fill,
-            padding: 5.0,
-            text_size: 12.0,
-        }
+            on_selected,
+            12.0,
+            5.0,
+            <Theme as StyleSheet>::Style::default(),
+            None,
+            Font::default(),
+        )
     }
 
     /// Creates a new [`SelectionList`] with the given list of `options`,
@@ -109,28 +126,114 @@ where
         selected: Option<usize>,
         font: Font,
     ) -> Self {
-        let container = Container::new(Scrollable::new(List {
-            options,
-            font,
-            text_size,
-            padding,
-            style: style.clone(),
-            selected,
-            on_selected: Box::new(on_selected),
-            phantomdata: PhantomData,
-        }))
-        .padding(1);
-
-        Self {
+        let mut selection_list = Self {
             options,
             font,
             style,
-            container,
+            container: Container::new(Scrollable::new(iced_widget::Space::new(
+                Length::Shrink,
+                Length::Shrink,
+            ))),
             width: Length::Fill,
             height: Length::Fill,
             padding,
             text_size,
-        }
+            on_selected: Rc::new(on_selected),
+            selected,
+            filter: String::new(),
+            matcher: None,
+            is_empty: options.is_empty(),
+            empty: None,
+            direction: Direction::Vertical,
+        };
+        selection_list.update_list();
+        selection_list
+    }
+
+    /// Rebuilds the scrollable [`List`] of the options matching the filter.
+    fn update_list(&mut self) {
+        let visible: Vec<usize> = self
+            .options
+            .iter()
+            .enumerate()
+            .filter(|(_, option)| self.matches(option))
+            .map(|(index, _)| index)
+            .collect();
+
+        self.is_empty = visible.is_empty();
+        let direction = match self.direction {
+            Direction::Vertical => scrollable::Direction::default(),
+            Direction::Horizontal => {
+                scrollable::Direction::Horizontal(scrollable::Properties::default())
+            }
+        };
+        self.container = Container::new(
+            Scrollable::new(List {
+                options: self.options,
+                font: self.font,
+                text_size: self.text_size,
+                padding: self.padding,
+                style: self.style.clone(),
+                on_selected: Rc::clone(&self.on_selected),
+                selected: self.selected,
+                visible,
+                highlight: self.filter.clone(),
+                direction: self.direction,
+                phantomdata: PhantomData,
+            })
+            .direction(direction),
+        )
+        .padding(1);
+    }
+
+    /// Checks if the given option matches the filter of the [`SelectionList`].
+    fn matches(&self, option: &T) -> bool {
+        self.filter.is_empty()
+            || self.matcher.as_ref().map_or_else(
+                || list::find_ignore_case(&option.to_string(), &self.filter).is_some(),
+                |matcher| matcher(option, &self.filter),
+            )
+    }
+
+    /// Sets the text the options of the [`SelectionList`] are filtered by.
+    ///
+    /// Only options matching the filter are shown, with the matched text
+    /// highlighted. By default an option matches if its text contains the
+    /// filter, ignoring case. The indices of selected options still refer to
+    /// all options.
+    #[must_use]
+    pub fn filter(mut self, filter: &str) -> Self {
+        filter.clone_into(&mut self.filter);
+        self.update_list();
+        self
+    }
+
+    /// Sets the function deciding if an option matches the filter of the
+    /// [`SelectionList`].
+    #[must_use]
+    pub fn matcher(mut self, matcher: impl Fn(&T, &str) -> bool + 'static) -> Self {
+        self.matcher = Some(Box::new(matcher));
+        self.update_list();
+        self
+    }
+
+    /// Sets the element shown centered in the [`SelectionList`] while it has
+    /// no options or no option matches the filter.
+    ///
+    /// The element receives no events and is removed as soon as an option is shown.
+    #[must_use]
+    pub fn empty(mut self, empty: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.empty = Some(empty.into());
+        self
+    }
+
+    /// Sets the [`Direction`] the options of the [`SelectionList`] are laid
+    /// out and scrolled in.
+    #[must_use]
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self.update_list();
+        self
     }
 
     /// Sets the width of the [`SelectionList`].
@@ -155,6 +258,20 @@ where
     }
 }
 
+#[allow(clippy::type_repetition_in_bounds)]
+impl<'a, T, Message, Theme, Renderer> SelectionList<'a, T, Message, Theme, Renderer>
+where
+    T: Clone + ToString + Eq + Hash,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Theme: StyleSheet + container::StyleSheet,
+{
+    /// The empty element, if no option is shown.
+    fn placeholder(&self) -> Option<&Element<'a, Message, Theme, Renderer>> {
+        self.empty.as_ref().filter(|_| self.is_empty)
+    }
+}
+
 impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for SelectionList<'a, T, Message, Theme, Renderer>
 where
@@ -164,11 +281,15 @@ where
     Theme: StyleSheet + container::StyleSheet,
 {
     fn children(&self) -> Vec<Tree> {
-        vec![Tree::new(&self.container as &dyn Widget<_, _, _>)]
+        std::iter::once(Tree::new(&self.container as &dyn Widget<_, _, _>))
+            .chain(self.placeholder().map(Tree::new))
+            .collect()
     }
 
     fn diff(&self, tree: &mut Tree) {
-        tree.diff_children(&[&self.container as &dyn Widget<_, _, _>]);
+        let mut children = vec![&self.container as &dyn Widget<_, _, _>];
+        children.extend(self.placeholder().map(Element::as_widget));
+        tree.diff_children(&children);
         let state = tree.state.downcast_mut::<State>();
 
         state.values = self
@@ -193,16 +314,24 @@ where
     fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
         use std::f32;
 
+        if let Some(empty) = self.placeholder() {
+            return layout_empty(
+                empty,
+                &mut tree.children[1],
+                renderer,
+                limits,
+                self.width,
+                self.height,
+            );
+        }
+
         let state = tree.state.downcast_mut::<State>();
 
         let limits = limits.width(self.width).height(self.height);
 
         let max_width = match self.width {
-            Length::Shrink => self
-                .options
-                .iter()
-                .enumerate()
-                .map(|(id, val)| {
+            Length::Shrink => {
+                let widths = self.options.iter().enumerate().map(|(id, val)| {
                     let text = Text {
                         content: &val.to_string(),
                         size: Pixels(self.text_size),
@@ -216,9 +345,13 @@ where
 
                     state.values[id].update(text);
                     state.values[id].min_bounds().width.round() as u32 + self.padding as u32 * 2
-                })
-                .max()
-                .unwrap_or(100),
+                });
+
+                match self.direction {
+                    Direction::Vertical => widths.max().unwrap_or(100),
+                    Direction::Horizontal => widths.sum(),
+                }
+            }
             _ => limits.max().width as u32,
         };
 
@@ -242,6 +375,10 @@ where
         shell: &mut Shell<Message>,
         viewport: &Rectangle,
     ) -> event::Status {
+        if self.placeholder().is_some() {
+            return event::Status::Ignored;
+        }
+
         self.container.on_event(
             &mut state.children[0],
             event,
@@ -265,6 +402,10 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
+        if self.placeholder().is_some() {
+            return mouse::Interaction::default();
+        }
+
         self.container
             .mouse_interaction(&state.children[0], layout, cursor, viewport, renderer)
     }
@@ -292,6 +433,22 @@ where
             theme.style(&self.style).background,
         );
 
+        if let Some(empty) = self.placeholder() {
+            empty.as_widget().draw(
+                &state.children[1],
+                renderer,
+                theme,
+                style,
+                layout
+                    .children()
+                    .next()
+                    .expect("Empty Child Missing in Selection List"),
+                cursor,
+                &layout.bounds(),
+            );
+            return;
+        }
+
         self.container.draw(
             &state.children[0],
             renderer,
@@ -1,6 +1,6 @@
 //! Build and show dropdown `ListMenus`.
 
-use crate::selection_list::StyleSheet;
+use crate::selection_list::{Direction, StyleSheet};
 
 use iced_widget::{
     core::{
@@ -8,7 +8,9 @@ use iced_widget::{
         alignment::{Horizontal, Vertical},
         event, layout,
         mouse::{self, Cursor},
-        renderer, touch,
+        renderer,
+        text::Paragraph as _,
+        touch,
         widget::{
             tree::{State, Tag},
             Tree,
@@ -23,6 +25,8 @@ use std::{
     fmt::Display,
     hash::{Hash, Hasher},
     marker::PhantomData,
+    ops::Range,
+    rc::Rc,
 };
 
 /// The Private [`List`] Handles the Actual list rendering.
@@ -42,13 +46,19 @@ where
     /// Style for Font colors and Box hover colors.
     pub style: <Theme as StyleSheet>::Style,
     /// Function Pointer On Select to call on Mouse button press.
-    pub on_selected: Box<dyn Fn(usize, T) -> Message>,
+    pub on_selected: Rc<dyn Fn(usize, T) -> Message>,
     /// The padding Width
     pub padding: f32,
     /// The Text Size
     pub text_size: f32,
     /// Set the Selected ID manually.
     pub selected: Option<usize>,
+    /// The indices of the shown options, in order.
+    pub visible: Vec<usize>,
+    /// The text highlighted in the shown options.
+    pub highlight: String,
+    /// The direction the options are laid out in.
+    pub direction: Direction,
     /// Shadow Type holder for Renderer.
     pub phantomdata: PhantomData<Renderer>,
 }
@@ -56,12 +66,15 @@ where
 /// The Private [`ListState`] Handles the State of the inner list.
 #[derive(Debug, Clone, Default)]
 pub struct ListState {
-    /// Statehood of hovered_option
+    /// Statehood of hovered_option, the row of the shown options
     pub hovered_option: Option<usize>,
     /// The index in the list of options of the last chosen Item Clicked for Processing
     pub last_selected_index: Option<(usize, u64)>,
     /// String Build Cache
     pub options: Vec<String>,
+    /// The start of each shown option in a horizontal list, followed by the
+    /// end of the last one.
+    pub offsets: Vec<f32>,
 }
 
 impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -111,22 +124,45 @@ where
     }
 
     fn size(&self) -> Size<Length> {
-        Size::new(Length::Fill, Length::Shrink)
+        match self.direction {
+            Direction::Vertical => Size::new(Length::Fill, Length::Shrink),
+            Direction::Horizontal => Size::new(Length::Shrink, Length::Fill),
+        }
     }
 
     fn layout(
         &self,
-        _tree: &mut Tree,
+        tree: &mut Tree,
         _renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
         use std::f32;
+
+        if self.direction == Direction::Horizontal {
+            let list_state = tree.state.downcast_mut::<ListState>();
+
+            // Each option is as wide as its text
+            let mut offset = 0.0;
+            list_state.offsets = std::iter::once(0.0)
+                .chain(self.visible.iter().map(|&i| {
+                    offset += Renderer::Paragraph::with_text(
+                        self.text(&list_state.options[i], Size::INFINITY),
+                    )
+                    .min_width()
+                        + self.padding * 2.0;
+                    offset
+                }))
+                .collect();
+
+            return layout::Node::new(Size::new(offset, self.text_size + self.padding * 2.0));
+        }
+
         let limits = limits.height(Length::Fill).width(Length::Fill);
 
         #[allow(clippy::cast_precision_loss)]
         let intrinsic = Size::new(
             limits.max().width,
-            (self.text_size + self.padding * 2.0) * self.options.len() as f32,
+            (self.text_size + self.padding * 2.0) * self.visible.len() as f32,
         );
 
         layout::Node::new(intrinsic)
@@ -151,17 +187,17 @@ where
         if bounds.contains(cursor) {
             match event {
                 Event::Mouse(mouse::Event::CursorMoved { .. }) => {
-                    list_state.hovered_option = Some(
-                        ((cursor.y - bounds.y) / (self.text_size + (self.padding * 2.0))) as usize,
-                    );
+                    list_state.hovered_option = Some(self.row_at(list_state, bounds, cursor));
                 }
                 Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
                 | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                    list_state.hovered_option = Some(
-                        ((cursor.y - bounds.y) / (self.text_size + (self.padding * 2.0))) as usize,
-                    );
+                    list_state.hovered_option = Some(self.row_at(list_state, bounds, cursor));
 
-                    if let Some(index) = list_state.hovered_option {
+                    let index = list_state
+                        .hovered_option
+                        .and_then(|row| self.visible.get(row).copied());
+
+                    if let Some(index) = index {
                         if let Some(option) = self.options.get(index) {
                             let mut hasher = DefaultHasher::new();
                             option.hash(&mut hasher);
@@ -219,24 +255,49 @@ where
 
         let bounds = layout.bounds();
         let option_height = self.text_size + (self.padding * 2.0);
-        let offset = viewport.y - bounds.y;
-        let start = (offset / option_height) as usize;
-        let end = ((offset + viewport.height) / option_height).ceil() as usize;
         let list_state = state.state.downcast_ref::<ListState>();
 
-        for i in start..end.min(self.options.len()) {
+        let (rows, text_inset) = match self.direction {
+            Direction::Vertical => {
+                let offset = viewport.y - bounds.y;
+                let start = (offset / option_height) as usize;
+                let end = ((offset + viewport.height) / option_height).ceil() as usize;
+                (start..end.min(self.visible.len()), 0.0)
+            }
+            Direction::Horizontal => {
+                let start = self.row_at(list_state, bounds, Point::new(viewport.x, bounds.y));
+                let end = self.row_at(
+                    list_state,
+                    bounds,
+                    Point::new(viewport.x + viewport.width, bounds.y),
+                ) + 1;
+                (start..end.min(self.visible.len()), self.padding)
+            }
+        };
+
+        for row in rows {
+            let i = self.visible[row];
             let is_selected = list_state
                 .last_selected_index
                 .map(|u| u.0 == i)
                 .unwrap_or_default();
-            let is_hovered = list_state.hovered_option == Some(i);
+            let is_hovered = list_state.hovered_option == Some(row);
 
-            let bounds = Rectangle {
-                x: bounds.x,
-                y: bounds.y + option_height * i as f32,
-                width: bounds.width,
-                height: self.text_size + (self.padding * 2.0),
+            let bounds = match self.direction {
+                Direction::Vertical => Rectangle {
+                    x: bounds.x,
+                    y: bounds.y + option_height * row as f32,
+                    width: bounds.width,
+                    height: option_height,
+                },
+                Direction::Horizontal => Rectangle {
+                    x: bounds.x + list_state.offsets[row],
+                    y: bounds.y,
+                    width: list_state.offsets[row + 1] - list_state.offsets[row],
+                    height: option_height,
+                },
             };
+            let text_x = bounds.x + text_inset;
 
             if is_selected || is_hovered {
                 renderer.fill_quad(
@@ -265,25 +326,120 @@ where
                 theme.style(&self.style).text_color
             };
 
-            renderer.fill_text(
-                core::text::Text {
-                    content: &list_state.options[i],
-                    bounds: Size::new(f32::INFINITY, bounds.height),
-                    size: Pixels(self.text_size),
-                    font: self.font,
-                    horizontal_alignment: Horizontal::Left,
-                    vertical_alignment: Vertical::Center,
-                    line_height: LineHeight::default(),
-                    shaping: iced_widget::text::Shaping::Advanced,
+            let content = &list_state.options[i];
+            let text = |content| self.text(content, Size::new(f32::INFINITY, bounds.height));
+
+            let Some(matched) = find_ignore_case(content, &self.highlight) else {
+                renderer.fill_text(
+                    text(content),
+                    Point::new(text_x, bounds.center_y()),
+                    text_color,
+                    bounds,
+                );
+                continue;
+            };
+
+            // Draw the text before, in and after the match separately
+            let width =
+                |end: usize| Renderer::Paragraph::with_text(text(&content[..end])).min_width();
+            let (match_start, match_end) = (width(matched.start), width(matched.end));
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: text_x + match_start,
+                        width: match_end - match_start,
+                        ..bounds
+                    },
+                    border: Border {
+                        radius: (0.0).into(),
+                        width: 0.0,
+                        color: Color::TRANSPARENT,
+                    },
+                    shadow: Shadow::default(),
                 },
-                Point::new(bounds.x, bounds.center_y()),
-                text_color,
-                bounds,
+                theme.style(&self.style).highlight_background,
             );
+
+            for (range, x, color) in [
+                (0..matched.start, 0.0, text_color),
+                (
+                    matched.clone(),
+                    match_start,
+                    theme.style(&self.style).highlight_text_color,
+                ),
+                (matched.end..content.len(), match_end, text_color),
+            ] {
+                renderer.fill_text(
+                    text(&content[range]),
+                    Point::new(text_x + x, bounds.center_y()),
+                    color,
+                    bounds,
+                );
+            }
         }
     }
 }
 
+impl<T, Message, Theme, Renderer> List<'_, T, Message, Theme, Renderer>
+where
+    T: Clone + Display + Eq + Hash,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Theme: StyleSheet,
+{
+    /// The text of an option to draw or measure.
+    fn text<'b>(&self, content: &'b str, bounds: Size) -> core::text::Text<'b, core::Font> {
+        core::text::Text {
+            content,
+            bounds,
+            size: Pixels(self.text_size),
+            font: self.font,
+            horizontal_alignment: Horizontal::Left,
+            vertical_alignment: Vertical::Center,
+            line_height: LineHeight::default(),
+            shaping: iced_widget::text::Shaping::Advanced,
+        }
+    }
+
+    /// The row of the shown option at the given position.
+    fn row_at(&self, list_state: &ListState, bounds: Rectangle, position: Point) -> usize {
+        match self.direction {
+            Direction::Vertical => {
+                ((position.y - bounds.y) / (self.text_size + (self.padding * 2.0))) as usize
+            }
+            Direction::Horizontal => list_state
+                .offsets
+                .partition_point(|&offset| offset <= position.x - bounds.x)
+                .saturating_sub(1),
+        }
+    }
+}
+
+/// Finds the first occurrence of `pattern` in `text`, ignoring case.
+///
+/// Returns the byte range of the occurrence in `text`, or `None` if there is
+/// none or `pattern` is empty.
+pub(crate) fn find_ignore_case(text: &str, pattern: &str) -> Option<Range<usize>> {
+    if pattern.is_empty() {
+        return None;
+    }
+
+    text.char_indices().find_map(|(start, _)| {
+        let mut chars = text[start..].chars();
+        let mut end = start;
+
+        for p in pattern.chars() {
+            let c = chars.next()?;
+            if !c.to_lowercase().eq(p.to_lowercase()) {
+                return None;
+            }
+            end += c.len_utf8();
+        }
+
+        Some(start..end)
+    })
+}
+
 impl<'a, T, Message, Theme, Renderer> From<List<'a, T, Message, Theme, Renderer>>
     for Element<'a, Message, Theme, Renderer>
 where
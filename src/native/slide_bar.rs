@@ -45,8 +45,8 @@ where
     value: T,
     /// Change event of the bar when a value is modified
     on_change: Box<dyn Fn(T) -> Message + 'a>,
-    /// Release event when the mouse is released.
-    on_release: Option<Message>,
+    /// Release event when the mouse is released, carrying the final value.
+    on_release: Option<Box<dyn Fn(T) -> Message + 'a>>,
 }
 
 impl<'a, T, Message> SlideBar<'a, T, Message>
@@ -95,14 +95,19 @@ where
     }
 
     /// Sets the release message of the [`Slider`].
-    /// This is called when the mouse is released from the slider.
+    /// This is called with the final value when the mouse is released from the slider.
     ///
     /// Typically, the user's interaction with the slider is finished when this message is produced.
     /// This is useful if you need to spawn a long-running task from the slider's result, where
     /// the default `on_change` message could create too many events.
+    ///
+    /// Clicking the bar without dragging produces both the change and the release message.
     #[must_use]
-    pub fn on_release(mut self, on_release: Message) -> Self {
-        self.on_release = Some(on_release);
+    pub fn on_release<F>(mut self, on_release: F) -> Self
+    where
+        F: 'a + Fn(T) -> Message,
+    {
+        self.on_release = Some(Box::new(on_release));
         self
     }
 
@@ -184,7 +189,7 @@ where
             &self.range,
             self.step,
             self.on_change.as_ref(),
-            &self.on_release,
+            self.on_release.as_deref(),
         )
     }
 
@@ -215,7 +220,7 @@ pub fn update<Message, T>(
     range: &RangeInclusive<T>,
     step: T,
     on_change: &dyn Fn(T) -> Message,
-    on_release: &Option<Message>,
+    on_release: Option<&dyn Fn(T) -> Message>,
 ) -> event::Status
 where
     T: Copy + Into<f64> + num_traits::FromPrimitive,
@@ -266,8 +271,8 @@ where
         Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
         | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
             if is_dragging {
-                if let Some(on_release) = on_release.clone() {
-                    shell.publish(on_release);
+                if let Some(on_release) = on_release {
+                    shell.publish((on_release)(*value));
                 }
                 state.is_dragging = false;
 
@@ -1,7 +1,11 @@
 //! A spinner to suggest something is loading.
 
-use crate::style::spinner::StyleSheet;
+use crate::{
+    core::animation::{self, Animation},
+    style::spinner::StyleSheet,
+};
 
+use iced_widget::core::time::{Duration, Instant};
 use iced_widget::core::{
     self,
     event::Status,
@@ -15,7 +19,6 @@ use iced_widget::core::{
     window, Border, Clipboard, Color, Element, Event, Layout, Length, Rectangle, Shadow, Shell,
     Size, Vector, Widget,
 };
-use std::time::{Duration, Instant};
 
 /// A spinner widget, a circle spinning around the center of the widget.
 #[allow(missing_debug_implementations)]
@@ -71,7 +74,7 @@ impl Spinner {
 }
 
 struct SpinnerState {
-    last_update: Instant,
+    animation: Animation,
     t: f32,
 }
 
@@ -155,7 +158,7 @@ where
 
     fn state(&self) -> State {
         State::new(SpinnerState {
-            last_update: Instant::now(),
+            animation: Animation::new(Instant::now(), self.rate).repeat(),
             t: 0.0,
         })
     }
@@ -171,30 +174,14 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> Status {
-        const FRAMES_PER_SECOND: u64 = 60;
-
         let bounds = layout.bounds();
 
         if let Event::Window(_id, window::Event::RedrawRequested(now)) = event {
             if is_visible(&bounds) {
                 let state = state.state.downcast_mut::<SpinnerState>();
-                let duration = (now - state.last_update).as_secs_f32();
-                let increment = if self.rate == Duration::ZERO {
-                    0.0
-                } else {
-                    duration * 1.0 / self.rate.as_secs_f32()
-                };
-
-                state.t += increment;
-
-                if state.t > 1.0 {
-                    state.t -= 1.0;
-                }
-
-                shell.request_redraw(window::RedrawRequest::At(
-                    now + Duration::from_millis(1000 / FRAMES_PER_SECOND),
-                ));
-                state.last_update = now;
+                state.t = state.animation.progress(now);
+
+                animation::request_next_frame(shell, now);
 
                 return Status::Captured;
             }
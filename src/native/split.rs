@@ -10,16 +10,19 @@ use iced_widget::{
         mouse::{self, Cursor},
         renderer, touch,
         widget::{
+            operation::Outcome,
             tree::{State, Tag},
-            Operation, Tree,
+            Id, Operation, Tree,
         },
-        Border, Clipboard, Color, Element, Event, Layout, Length, Padding, Point, Rectangle,
-        Shadow, Shell, Size, Widget,
+        window, Background, Border, Clipboard, Color, Element, Event, Layout, Length, Padding,
+        Point, Rectangle, Shadow, Shell, Size, Widget,
     },
+    runtime::Command,
     Container, Row,
 };
+use std::any::Any;
 
-pub use crate::style::split::{Appearance, StyleSheet};
+pub use crate::style::split::{Appearance, Status, StyleSheet};
 
 /// A split can divide the available space by half to display two different elements.
 /// It can split horizontally or vertically.
@@ -66,10 +69,16 @@ where
     min_size_first: u16,
     /// The minimum size of the second element of the [`Split`].
     min_size_second: u16,
-    /// The message that is send when the divider of the [`Split`] is moved.
-    on_resize: Box<dyn Fn(u16) -> Message>,
+    /// The message that is send when the divider of the [`Split`] is moved, if it can be.
+    on_resize: Option<Box<dyn Fn(u16) -> Message>>,
+    /// The position of the divider as a ratio of the available space, if any.
+    ratio: Option<f32>,
+    /// The message that is send with the ratio when the divider of the [`Split`] is moved.
+    on_resize_ratio: Option<Box<dyn Fn(f32) -> Message>>,
     /// The style of the [`Split`].
     style: <Theme as StyleSheet>::Style,
+    /// The id of the [`Split`], used by [`status`].
+    id: Option<Id>,
 }
 
 impl<'a, Message, Theme, Renderer> Split<'a, Message, Theme, Renderer>
@@ -115,8 +124,11 @@ where
             height: Length::Fill,
             min_size_first: 5,
             min_size_second: 5,
-            on_resize: Box::new(on_resize),
+            on_resize: Some(Box::new(on_resize)),
+            ratio: None,
+            on_resize_ratio: None,
             style: <Theme as StyleSheet>::Style::default(),
+            id: None,
         }
     }
 
@@ -163,12 +175,51 @@ where
         self
     }
 
+    /// Positions the divider at the given ratio of the available space,
+    /// from `0.0` to `1.0`, instead of the pixel position, and sends the
+    /// ratio of the moved divider with `on_resize` instead of the position.
+    ///
+    /// The divider keeps its ratio while the [`Split`] is resized. The sent
+    /// ratio is clamped like the layout and gives back the same divider
+    /// pixel, so storing it and passing it back doesn't make the divider
+    /// creep. Nothing is sent while the divider stays on the same pixel.
+    #[must_use]
+    pub fn ratio<F>(mut self, ratio: f32, on_resize: F) -> Self
+    where
+        F: 'static + Fn(f32) -> Message,
+    {
+        self.ratio = Some(ratio);
+        self.on_resize_ratio = Some(Box::new(on_resize));
+        self
+    }
+
+    /// Sets the message that is send on moving the divider, or fixes the divider
+    /// with `None`.
+    ///
+    /// A fixed divider can't be dragged and has the [`Status::Disabled`], unless
+    /// the [`Split`] has a [`ratio`](Self::ratio).
+    #[must_use]
+    pub fn on_resize_maybe<F>(mut self, on_resize: Option<F>) -> Self
+    where
+        F: 'static + Fn(u16) -> Message,
+    {
+        self.on_resize = on_resize.map(|on_resize| Box::new(on_resize) as Box<_>);
+        self
+    }
+
     /// Sets the style of the [`Split`].
     #[must_use]
     pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
         self.style = style;
         self
     }
+
+    /// Sets the [`Id`] of the [`Split`], to query its [`Info`] with [`status`].
+    #[must_use]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -203,6 +254,19 @@ where
             .height(Length::Fill)
             .layout(tree, renderer, limits);
 
+        let length = match self.axis {
+            Axis::Horizontal => space.bounds().height,
+            Axis::Vertical => space.bounds().width,
+        };
+        let split_state: &mut SplitState = tree.state.downcast_mut();
+        (split_state.first_collapsed, split_state.second_collapsed) = collapsed(
+            divider_center(self, length),
+            length,
+            self.spacing,
+            self.min_size_first,
+            self.min_size_second,
+        );
+
         match self.axis {
             Axis::Horizontal => horizontal_split(tree, self, renderer, limits, &space),
             Axis::Vertical => vertical_split(tree, self, renderer, limits, &space),
@@ -223,69 +287,93 @@ where
         let split_state: &mut SplitState = state.state.downcast_mut();
         let mut children = layout.children();
 
+        // Pointer events only reach panes within the viewport
+        let is_pointer_event = matches!(
+            event,
+            Event::Mouse(
+                mouse::Event::CursorMoved { .. }
+                    | mouse::Event::ButtonPressed(_)
+                    | mouse::Event::WheelScrolled { .. }
+            ) | Event::Touch(touch::Event::FingerPressed { .. } | touch::Event::FingerMoved { .. })
+        );
+
         let first_layout = children
             .next()
             .expect("Native: Layout should have a first layout");
-        let first_status = self.first.as_widget_mut().on_event(
-            &mut state.children[0],
-            event.clone(),
-            first_layout,
-            cursor,
-            renderer,
-            clipboard,
-            shell,
-            viewport,
-        );
+        let first_status = if is_pointer_event && !first_layout.bounds().intersects(viewport) {
+            event::Status::Ignored
+        } else {
+            self.first.as_widget_mut().on_event(
+                &mut state.children[0],
+                event.clone(),
+                first_layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            )
+        };
 
         let divider_layout = children
             .next()
             .expect("Native: Layout should have a divider layout");
-        match event {
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if divider_layout
-                    .bounds()
-                    .contains(cursor.position().unwrap_or_default())
-                {
-                    split_state.dragging = true;
-                }
-            }
-
-            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerLifted { .. }) => {
-                if split_state.dragging {
-                    split_state.dragging = false;
-                }
-            }
-
-            Event::Mouse(mouse::Event::CursorMoved { position })
-            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
-                if split_state.dragging {
-                    let position = match self.axis {
-                        Axis::Horizontal => position.y,
-                        Axis::Vertical => position.x,
-                    };
+        let resizable = is_resizable(self);
+        let position = if resizable {
+            split_state.drag(
+                &event,
+                divider_layout.bounds(),
+                layout.bounds(),
+                cursor,
+                self.axis,
+            )
+        } else {
+            split_state.dragging = false;
+            None
+        };
+        if let Some(position) = position {
+            if let Some(on_resize) = &self.on_resize_ratio {
+                let bounds = layout.bounds();
+                let (start, length) = match self.axis {
+                    Axis::Horizontal => (bounds.y, bounds.height),
+                    Axis::Vertical => (bounds.x, bounds.width),
+                };
 
-                    shell.publish((self.on_resize)(position as u16));
+                if length >= self.spacing + f32::from(self.min_size_first + self.min_size_second) {
+                    if let Some(center) = dragged_center(
+                        divider_center(self, length),
+                        (position - start) as u16,
+                        length,
+                        self.spacing,
+                        self.min_size_first,
+                        self.min_size_second,
+                    ) {
+                        shell.publish(on_resize(f32::from(center) / length));
+                    }
                 }
+            } else if let Some(on_resize) = &self.on_resize {
+                shell.publish(on_resize(position as u16));
             }
-
-            _ => {}
         }
+        split_state.status = split_state.status(divider_layout.bounds(), cursor, resizable);
 
         let second_layout = children
             .next()
             .expect("Native: Layout should have a second layout");
-        let second_status = self.second.as_widget_mut().on_event(
-            &mut state.children[1],
-            event,
-            second_layout,
-            cursor,
-            renderer,
-            clipboard,
-            shell,
-            viewport,
-        );
+        let second_status = if is_pointer_event && !second_layout.bounds().intersects(viewport) {
+            event::Status::Ignored
+        } else {
+            self.second.as_widget_mut().on_event(
+                &mut state.children[1],
+                event,
+                second_layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            )
+        };
 
         first_status.merge(second_status)
     }
@@ -312,9 +400,10 @@ where
         let divider_layout = children
             .next()
             .expect("Graphics: Layout should have a divider layout");
-        let divider_mouse_interaction = if divider_layout
-            .bounds()
-            .contains(cursor.position().unwrap_or_default())
+        let divider_mouse_interaction = if is_resizable(self)
+            && divider_layout
+                .bounds()
+                .contains(cursor.position().unwrap_or_default())
         {
             match self.axis {
                 Axis::Horizontal => mouse::Interaction::ResizingVertically,
@@ -351,6 +440,38 @@ where
         let split_state: &SplitState = state.state.downcast_ref();
         // TODO: clipping!
         let mut children = layout.children();
+        let first_layout = children
+            .next()
+            .expect("Graphics: Layout should have a first layout");
+        let divider_layout = children
+            .next()
+            .expect("Graphics: Layout should have a divider layout");
+        let second_layout = children
+            .next()
+            .expect("Graphics: Layout should have a second layout");
+
+        let status = split_state.status(divider_layout.bounds(), cursor, is_resizable(self));
+        // Only the divider is hovered or dragged, the rest of the Split is active
+        // unless it is disabled
+        let appearance = theme.appearance(
+            &self.style,
+            if status == Status::Disabled {
+                Status::Disabled
+            } else {
+                Status::Active
+            },
+        );
+        let divider_appearance = theme.appearance(&self.style, status);
+        // The pane under the cursor takes its background from the hovered appearance
+        let pane_background =
+            |pane: Layout<'_>, background: fn(&Appearance) -> Option<Background>| {
+                if cursor.is_over(pane.bounds()) {
+                    background(&theme.appearance(&self.style, Status::Hovered))
+                } else {
+                    background(&appearance)
+                }
+                .unwrap_or_else(|| Color::TRANSPARENT.into())
+            };
 
         // Background
         renderer.fill_quad(
@@ -358,116 +479,82 @@ where
                 bounds: layout.bounds(),
                 border: Border {
                     radius: (0.0).into(),
-                    width: theme.active(&self.style).border_width,
-                    color: theme.active(&self.style).border_color,
+                    width: appearance.border_width,
+                    color: appearance.border_color,
                 },
                 shadow: Shadow::default(),
             },
-            theme
-                .active(&self.style)
+            appearance
                 .background
                 .unwrap_or_else(|| Color::TRANSPARENT.into()),
         );
 
-        let first_layout = children
-            .next()
-            .expect("Graphics: Layout should have a first layout");
-
         // First
-        renderer.fill_quad(
-            renderer::Quad {
-                bounds: first_layout.bounds(),
-                border: Border {
-                    radius: (0.0).into(),
-                    width: 0.0,
-                    color: Color::TRANSPARENT,
+        // Skip a pane scrolled out of the viewport
+        if first_layout.bounds().intersects(viewport) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: first_layout.bounds(),
+                    border: Border {
+                        radius: (0.0).into(),
+                        width: 0.0,
+                        color: Color::TRANSPARENT,
+                    },
+                    shadow: Shadow::default(),
                 },
-                shadow: Shadow::default(),
-            },
-            if first_layout
-                .bounds()
-                .contains(cursor.position().unwrap_or_default())
-            {
-                theme.hovered(&self.style).first_background
-            } else {
-                theme.active(&self.style).first_background
-            }
-            .unwrap_or_else(|| Color::TRANSPARENT.into()),
-        );
-
-        self.first.as_widget().draw(
-            &state.children[0],
-            renderer,
-            theme,
-            style,
-            first_layout,
-            cursor,
-            viewport,
-        );
+                pane_background(first_layout, |appearance| appearance.first_background),
+            );
 
-        let divider_layout = children
-            .next()
-            .expect("Graphics: Layout should have a divider layout");
+            self.first.as_widget().draw(
+                &state.children[0],
+                renderer,
+                theme,
+                style,
+                first_layout,
+                cursor,
+                viewport,
+            );
+        }
 
         // Second
-        let second_layout = children
-            .next()
-            .expect("Graphics: Layout should have a second layout");
-
-        renderer.fill_quad(
-            renderer::Quad {
-                bounds: second_layout.bounds(),
-                border: Border {
-                    radius: (0.0).into(),
-                    width: 0.0,
-                    color: Color::TRANSPARENT,
+        // Skip a pane scrolled out of the viewport
+        if second_layout.bounds().intersects(viewport) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: second_layout.bounds(),
+                    border: Border {
+                        radius: (0.0).into(),
+                        width: 0.0,
+                        color: Color::TRANSPARENT,
+                    },
+                    shadow: Shadow::default(),
                 },
-                shadow: Shadow::default(),
-            },
-            if second_layout
-                .bounds()
-                .contains(cursor.position().unwrap_or_default())
-            {
-                theme.hovered(&self.style).second_background
-            } else {
-                theme.active(&self.style).second_background
-            }
-            .unwrap_or_else(|| Color::TRANSPARENT.into()),
-        );
+                pane_background(second_layout, |appearance| appearance.second_background),
+            );
 
-        self.second.as_widget().draw(
-            &state.children[1],
-            renderer,
-            theme,
-            style,
-            second_layout,
-            cursor,
-            viewport,
-        );
+            self.second.as_widget().draw(
+                &state.children[1],
+                renderer,
+                theme,
+                style,
+                second_layout,
+                cursor,
+                viewport,
+            );
+        }
 
         // Divider
-        let divider_style = if split_state.dragging {
-            theme.dragged(&self.style)
-        } else if divider_layout
-            .bounds()
-            .contains(cursor.position().unwrap_or_default())
-        {
-            theme.hovered(&self.style)
-        } else {
-            theme.active(&self.style)
-        };
-
         renderer.fill_quad(
             renderer::Quad {
                 bounds: divider_layout.bounds(),
                 border: Border {
                     radius: (0.0).into(),
-                    width: divider_style.divider_border_width,
-                    color: divider_style.divider_border_color,
+                    width: divider_appearance.divider_border_width,
+                    color: divider_appearance.divider_border_color,
                 },
                 shadow: Shadow::default(),
             },
-            divider_style.divider_background,
+            divider_appearance.divider_background,
         );
     }
 
@@ -483,6 +570,8 @@ where
         let _divider_layout = children.next().expect("Missing Split Divider");
         let second_layout = children.next().expect("Missing Split Second window");
 
+        operation.custom(state.state.downcast_mut::<SplitState>(), self.id.as_ref());
+
         let (first_state, second_state) = state.children.split_at_mut(1);
 
         self.first
@@ -555,13 +644,12 @@ where
         );
     }
 
-    let divider_position = split
-        .divider_position
-        .unwrap_or_else(|| (space.bounds().height / 2.0) as u16)
-        .max((split.spacing / 2.0) as u16);
-    let divider_position = (divider_position - (split.spacing / 2.0) as u16).clamp(
+    let divider_position = divider_offset(
+        divider_center(split, space.bounds().height),
+        space.bounds().height,
+        split.spacing,
         split.min_size_first,
-        space.bounds().height as u16 - split.min_size_second - split.spacing as u16,
+        split.min_size_second,
     );
 
     let padding = Padding::from(split.padding as u16);
@@ -634,13 +722,12 @@ where
         );
     }
 
-    let divider_position = split
-        .divider_position
-        .unwrap_or_else(|| (space.bounds().width / 2.0) as u16)
-        .max((split.spacing / 2.0) as u16);
-    let divider_position = (divider_position - (split.spacing / 2.0) as u16).clamp(
+    let divider_position = divider_offset(
+        divider_center(split, space.bounds().width),
+        space.bounds().width,
+        split.spacing,
         split.min_size_first,
-        space.bounds().width as u16 - split.min_size_second - split.spacing as u16,
+        split.min_size_second,
     );
 
     let padding = Padding::from(split.padding as u16);
@@ -680,6 +767,99 @@ where
     Node::with_children(space.bounds().size(), vec![first, divider, second])
 }
 
+/// Returns true if the divider of the [`Split`] can be dragged.
+fn is_resizable<Message, Theme, Renderer>(split: &Split<'_, Message, Theme, Renderer>) -> bool
+where
+    Renderer: core::Renderer,
+    Theme: StyleSheet,
+{
+    split.on_resize.is_some() || split.on_resize_ratio.is_some()
+}
+
+/// The center of the divider of a [`Split`] along the given length, from
+/// its ratio or its position.
+fn divider_center<Message, Theme, Renderer>(
+    split: &Split<'_, Message, Theme, Renderer>,
+    length: f32,
+) -> u16
+where
+    Renderer: core::Renderer,
+    Theme: StyleSheet,
+{
+    split.ratio.map_or_else(
+        || {
+            split
+                .divider_position
+                .unwrap_or_else(|| (length / 2.0) as u16)
+        },
+        |ratio| ratio_center(ratio, length),
+    )
+}
+
+/// The center of a divider at the given ratio of the length.
+fn ratio_center(ratio: f32, length: f32) -> u16 {
+    (ratio.clamp(0.0, 1.0) * length).round() as u16
+}
+
+/// The offset of a divider with the given center from the start of the
+/// length, clamped to the minimum sizes of the elements.
+///
+/// The length must fit the spacing and the minimum sizes.
+fn divider_offset(
+    center: u16,
+    length: f32,
+    spacing: f32,
+    min_size_first: u16,
+    min_size_second: u16,
+) -> u16 {
+    let half_spacing = (spacing / 2.0) as u16;
+
+    (center.max(half_spacing) - half_spacing).clamp(
+        min_size_first,
+        length as u16 - min_size_second - spacing as u16,
+    )
+}
+
+/// The clamped center to send for a divider dragged from the `current` to
+/// the `dragged` center, or `None` if it stays at the same offset.
+fn dragged_center(
+    current: u16,
+    dragged: u16,
+    length: f32,
+    spacing: f32,
+    min_size_first: u16,
+    min_size_second: u16,
+) -> Option<u16> {
+    let offset = divider_offset(dragged, length, spacing, min_size_first, min_size_second);
+
+    (offset != divider_offset(current, length, spacing, min_size_first, min_size_second))
+        .then(|| offset + (spacing / 2.0) as u16)
+}
+
+/// Whether the first and the second element are collapsed to their minimum
+/// size by a divider with the given center.
+///
+/// Both are collapsed if the length doesn't fit the spacing and the
+/// minimum sizes.
+fn collapsed(
+    center: u16,
+    length: f32,
+    spacing: f32,
+    min_size_first: u16,
+    min_size_second: u16,
+) -> (bool, bool) {
+    if length < spacing + f32::from(min_size_first + min_size_second) {
+        return (true, true);
+    }
+
+    let offset = divider_offset(center, length, spacing, min_size_first, min_size_second);
+
+    (
+        offset <= min_size_first,
+        offset >= length as u16 - min_size_second - spacing as u16,
+    )
+}
+
 impl<'a, Message, Theme, Renderer> From<Split<'a, Message, Theme, Renderer>>
     for Element<'a, Message, Theme, Renderer>
 where
@@ -697,6 +877,12 @@ where
 pub struct SplitState {
     /// If the divider is dragged by the user.
     dragging: bool,
+    /// The status of the divider after the last event.
+    status: Status,
+    /// If the first element is collapsed to its minimum size.
+    first_collapsed: bool,
+    /// If the second element is collapsed to its minimum size.
+    second_collapsed: bool,
 }
 
 impl SplitState {
@@ -707,7 +893,135 @@ impl SplitState {
     ///     - The [`Axis`] to split at.
     #[must_use]
     pub const fn new() -> Self {
-        Self { dragging: false }
+        Self {
+            dragging: false,
+            status: Status::Active,
+            first_collapsed: false,
+            second_collapsed: false,
+        }
+    }
+
+    /// The status of the divider with the given bounds under the cursor, if it
+    /// can be dragged.
+    fn status(&self, divider: Rectangle, cursor: Cursor, resizable: bool) -> Status {
+        if !resizable {
+            Status::Disabled
+        } else if self.dragging {
+            Status::Dragged
+        } else if cursor.is_over(divider) {
+            Status::Hovered
+        } else {
+            Status::Active
+        }
+    }
+
+    /// The [`Info`] about the [`Split`] with this state.
+    const fn info(&self) -> Info {
+        Info {
+            status: self.status,
+            first_collapsed: self.first_collapsed,
+            second_collapsed: self.second_collapsed,
+        }
+    }
+
+    /// Updates the drag of the divider with the given event and returns the
+    /// new position of the divider if it was moved.
+    ///
+    /// Releases end the drag wherever the cursor is, as do the cursor
+    /// leaving the window and the window losing focus, since the release
+    /// may never arrive then. The position is clamped to the bounds of the
+    /// [`Split`].
+    fn drag(
+        &mut self,
+        event: &Event,
+        divider: Rectangle,
+        bounds: Rectangle,
+        cursor: Cursor,
+        axis: Axis,
+    ) -> Option<f32> {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if divider.contains(cursor.position().unwrap_or_default()) {
+                    self.dragging = true;
+                }
+            }
+
+            Event::Mouse(
+                mouse::Event::ButtonReleased(mouse::Button::Left) | mouse::Event::CursorLeft,
+            )
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+            | Event::Window(_, window::Event::Unfocused) => {
+                self.dragging = false;
+            }
+
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if self.dragging {
+                    return Some(match axis {
+                        Axis::Horizontal => position.y.clamp(bounds.y, bounds.y + bounds.height),
+                        Axis::Vertical => position.x.clamp(bounds.x, bounds.x + bounds.width),
+                    });
+                }
+            }
+
+            _ => {}
+        }
+
+        None
+    }
+}
+
+/// The current state of a [`Split`], as queried with [`status`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Info {
+    /// The status of the divider.
+    pub status: Status,
+    /// Whether the first element is collapsed to its minimum size.
+    pub first_collapsed: bool,
+    /// Whether the second element is collapsed to its minimum size.
+    pub second_collapsed: bool,
+}
+
+/// Produces a [`Command`] querying the [`Info`] of the [`Split`] with the
+/// given [`Id`].
+///
+/// Nothing is produced if there is no such [`Split`].
+pub fn status(id: Id) -> Command<Info> {
+    Command::widget(StatusOperation {
+        target: id,
+        info: None,
+    })
+}
+
+/// An [`Operation`] finding the [`Info`] of the [`Split`] with an id.
+struct StatusOperation {
+    /// The id of the [`Split`].
+    target: Id,
+    /// The [`Info`] found so far.
+    info: Option<Info>,
+}
+
+impl Operation<Info> for StatusOperation {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<Info>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+        if id == Some(&self.target) {
+            if let Some(state) = state.downcast_ref::<SplitState>() {
+                self.info = Some(state.info());
+            }
+        }
+    }
+
+    fn finish(&self) -> Outcome<Info> {
+        self.info.map_or(Outcome::None, Outcome::Some)
     }
 }
 
@@ -725,3 +1039,196 @@ impl Default for Axis {
         Self::Vertical
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        collapsed, divider_offset, dragged_center, ratio_center, Axis, Info, SplitState, Status,
+        StatusOperation,
+    };
+    use iced_widget::core::{
+        mouse,
+        widget::{operation::Outcome, Id, Operation},
+        window, Event, Point, Rectangle,
+    };
+
+    const BOUNDS: Rectangle = Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: 200.0,
+        height: 100.0,
+    };
+    const DIVIDER: Rectangle = Rectangle {
+        x: 95.0,
+        y: 0.0,
+        width: 10.0,
+        height: 100.0,
+    };
+
+    fn start_drag() -> SplitState {
+        let mut state = SplitState::new();
+        let _ = state.drag(
+            &Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            DIVIDER,
+            BOUNDS,
+            mouse::Cursor::Available(Point::new(100.0, 50.0)),
+            Axis::Vertical,
+        );
+        assert!(state.dragging);
+        state
+    }
+
+    fn moved(state: &mut SplitState, position: Point) -> Option<f32> {
+        state.drag(
+            &Event::Mouse(mouse::Event::CursorMoved { position }),
+            DIVIDER,
+            BOUNDS,
+            mouse::Cursor::Available(position),
+            Axis::Vertical,
+        )
+    }
+
+    #[test]
+    fn release_outside_test() {
+        let mut state = start_drag();
+        let outside = Point::new(500.0, 500.0);
+
+        let _ = state.drag(
+            &Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+            DIVIDER,
+            BOUNDS,
+            mouse::Cursor::Available(outside),
+            Axis::Vertical,
+        );
+        assert!(!state.dragging);
+        assert_eq!(moved(&mut state, Point::new(50.0, 50.0)), None);
+    }
+
+    #[test]
+    fn cursor_left_and_unfocused_test() {
+        let mut state = start_drag();
+        let _ = state.drag(
+            &Event::Mouse(mouse::Event::CursorLeft),
+            DIVIDER,
+            BOUNDS,
+            mouse::Cursor::Unavailable,
+            Axis::Vertical,
+        );
+        assert!(!state.dragging);
+
+        let mut state = start_drag();
+        let _ = state.drag(
+            &Event::Window(window::Id::MAIN, window::Event::Unfocused),
+            DIVIDER,
+            BOUNDS,
+            mouse::Cursor::Unavailable,
+            Axis::Vertical,
+        );
+        assert!(!state.dragging);
+    }
+
+    #[test]
+    fn clamp_test() {
+        let mut state = start_drag();
+
+        assert_eq!(moved(&mut state, Point::new(50.0, 50.0)), Some(50.0));
+        assert_eq!(moved(&mut state, Point::new(-30.0, 50.0)), Some(0.0));
+        assert_eq!(
+            moved(&mut state, Point::new(BOUNDS.width + 30.0, 50.0)),
+            Some(BOUNDS.width)
+        );
+    }
+
+    #[test]
+    fn ratio_round_trip_test() {
+        let offset = |ratio, length| divider_offset(ratio_center(ratio, length), length, 5.0, 5, 5);
+
+        // A sent ratio gives back the same divider
+        for dragged in 0..800 {
+            if let Some(center) = dragged_center(400, dragged, 800.0, 5.0, 5, 5) {
+                let ratio = f32::from(center) / 800.0;
+                assert_eq!(
+                    offset(ratio, 800.0),
+                    divider_offset(dragged, 800.0, 5.0, 5, 5)
+                );
+            }
+        }
+
+        // 100 resize frames of a window animating its size and back, the
+        // divider being held where it is and every sent ratio passed back
+        let mut ratio = 0.37;
+        let initial = offset(ratio, 800.0);
+        for frame in 0..100_u16 {
+            let length = 800.0 + f32::from(frame.min(99 - frame)) * 7.3;
+            let pixel = offset(ratio, length);
+            let held = pixel + 2;
+
+            // The ratio of the held pixel, if it were sent, gives it back
+            assert_eq!(offset(f32::from(held) / length, length), pixel);
+
+            if let Some(center) =
+                dragged_center(ratio_center(ratio, length), held, length, 5.0, 5, 5)
+            {
+                ratio = f32::from(center) / length;
+            }
+            assert_eq!(offset(ratio, length), pixel);
+        }
+
+        assert_eq!(ratio.to_bits(), 0.37_f32.to_bits());
+        assert_eq!(offset(ratio, 800.0), initial);
+    }
+
+    #[test]
+    fn collapsed_test() {
+        assert_eq!(collapsed(400, 800.0, 5.0, 5, 5), (false, false));
+        assert_eq!(collapsed(0, 800.0, 5.0, 5, 5), (true, false));
+        assert_eq!(collapsed(7, 800.0, 5.0, 5, 5), (true, false));
+        assert_eq!(collapsed(800, 800.0, 5.0, 5, 5), (false, true));
+        assert_eq!(collapsed(400, 12.0, 5.0, 5, 5), (true, true));
+    }
+
+    #[test]
+    fn status_test() {
+        let over = mouse::Cursor::Available(Point::new(100.0, 50.0));
+        let away = mouse::Cursor::Available(Point::new(20.0, 50.0));
+
+        assert_eq!(
+            SplitState::new().status(DIVIDER, away, true),
+            Status::Active
+        );
+        assert_eq!(
+            SplitState::new().status(DIVIDER, over, true),
+            Status::Hovered
+        );
+        assert_eq!(start_drag().status(DIVIDER, away, true), Status::Dragged);
+        assert_eq!(
+            SplitState::new().status(DIVIDER, over, false),
+            Status::Disabled
+        );
+    }
+
+    #[test]
+    fn status_operation_test() {
+        let id = Id::new("split");
+        let mut state = start_drag();
+        state.status = state.status(DIVIDER, mouse::Cursor::Unavailable, true);
+        state.first_collapsed = true;
+
+        let mut operation = StatusOperation {
+            target: id.clone(),
+            info: None,
+        };
+        operation.custom(&mut state, Some(&Id::new("other")));
+        assert!(matches!(operation.finish(), Outcome::None));
+
+        operation.custom(&mut state, Some(&id));
+        assert!(matches!(
+            operation.finish(),
+            Outcome::Some(Info {
+                status: Status::Dragged,
+                first_collapsed: true,
+                second_collapsed: false,
+            })
+        ));
+    }
+}
@@ -335,25 +335,12 @@ where
         self.width = width;
         self
     }
-}
 
-impl<Message, TabId, Theme, Renderer> Widget<Message, Theme, Renderer>
-    for TabBar<Message, TabId, Theme, Renderer>
-where
-    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
-    Theme: StyleSheet + text::StyleSheet,
-    TabId: Eq + Clone,
-{
-    fn size(&self) -> Size<Length> {
-        Size::new(self.width, self.height)
-    }
-
-    fn layout(
-        &self,
-        tree: &mut Tree,
-        renderer: &Renderer,
-        limits: &layout::Limits,
-    ) -> layout::Node {
+    /// The row of tabs laid out and measured in place of the [`TabBar`].
+    fn tab_row(&self) -> Element<'_, Message, Theme, Renderer>
+    where
+        Theme: text::StyleSheet,
+    {
         fn layout_icon<Theme, Renderer>(
             icon: &char,
             size: f32,
@@ -392,8 +379,7 @@ where
                 .width(Length::Shrink)
         }
 
-        let row = self
-            .tab_labels
+        self.tab_labels
             .iter()
             .fold(Row::<Message, Theme, Renderer>::new(), |row, tab_label| {
                 let mut label_row = Row::new()
@@ -496,21 +482,41 @@ where
             .width(self.width)
             .height(self.height)
             .spacing(self.spacing)
-            .align_items(Alignment::Center);
+            .align_items(Alignment::Center)
+            .into()
+    }
+}
 
-        let element: Element<Message, Theme, Renderer> = Element::new(row);
-        let tab_tree = if let Some(child_tree) = tree.children.get_mut(0) {
-            child_tree.diff(element.as_widget());
-            child_tree
-        } else {
-            let child_tree = Tree::new(element.as_widget());
-            tree.children.insert(0, child_tree);
-            &mut tree.children[0]
-        };
+impl<Message, TabId, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for TabBar<Message, TabId, Theme, Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Theme: StyleSheet + text::StyleSheet,
+    TabId: Eq + Clone,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(self.tab_row())]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[self.tab_row()]);
+    }
 
-        element
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        // The labels are measured again on every layout, so the tabs and their
+        // decorations always follow the current labels.
+        self.tab_row()
             .as_widget()
-            .layout(tab_tree, renderer, &limits.loose())
+            .layout(&mut tree.children[0], renderer, &limits.loose())
     }
 
     fn on_event(
@@ -861,3 +867,95 @@ where
         Element::new(tab_bar)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Position, TabBar, TabLabel, DEFAULT_ICON_SIZE, DEFAULT_TEXT_SIZE};
+    use crate::native::measure::{text_width, Measure};
+    use iced_widget::core::{
+        layout::{Limits, Node},
+        widget::Tree,
+        Length, Size, Widget,
+    };
+
+    type Bar = TabBar<usize, usize, iced_widget::Theme, Measure>;
+
+    fn label(frame: usize) -> &'static str {
+        if frame % 2 == 1 {
+            "file.rs •"
+        } else {
+            "file.rs"
+        }
+    }
+
+    fn tab_bar(frame: usize) -> Bar {
+        let first = if frame % 2 == 1 {
+            TabLabel::IconText('x', label(frame).to_owned())
+        } else {
+            TabLabel::Text(label(frame).to_owned())
+        };
+
+        TabBar::new(|id| id)
+            .push(0, first)
+            .push(1, TabLabel::Text(label(frame).to_owned()))
+            .tab_width(Length::Shrink)
+            .set_position(Position::Top)
+            .on_close(|id| id)
+    }
+
+    fn assert_same(node: &Node, expected: &Node) {
+        assert_eq!(node.bounds(), expected.bounds());
+        assert_eq!(node.children().len(), expected.children().len());
+
+        for (child, expected) in node.children().iter().zip(expected.children()) {
+            assert_same(child, expected);
+        }
+    }
+
+    /// The column of the label of the tab at the given index.
+    fn label_column(node: &Node, index: usize) -> &Node {
+        &node.children()[index].children()[0]
+    }
+
+    #[test]
+    fn label_change_layout_test() {
+        let limits = Limits::new(Size::ZERO, Size::new(400.0, 50.0));
+        let mut tree = Tree::new(&tab_bar(0) as &dyn Widget<usize, iced_widget::Theme, Measure>);
+
+        for frame in 0..10 {
+            let bar = tab_bar(frame);
+            tree.diff(&bar as &dyn Widget<usize, iced_widget::Theme, Measure>);
+
+            let node = bar.layout(&mut tree, &Measure, &limits);
+            let fresh = bar.layout(
+                &mut Tree::new(&bar as &dyn Widget<usize, iced_widget::Theme, Measure>),
+                &Measure,
+                &limits,
+            );
+
+            assert_same(&node, &fresh);
+
+            // The icon is added above the text of the first tab and removed again
+            let first = label_column(&node, 0).children();
+            assert_eq!(first.len(), 1 + frame % 2);
+            if let [icon, text] = first {
+                assert!(
+                    (icon.size().width - text_width("x", DEFAULT_ICON_SIZE + 1.0)).abs()
+                        < f32::EPSILON
+                );
+                assert!(icon.bounds().y + icon.size().height <= text.bounds().y);
+            }
+
+            // The second tab measures its current label and follows the first one
+            let text = &label_column(&node, 1).children()[0];
+            assert!(
+                (text.size().width - text_width(label(frame), DEFAULT_TEXT_SIZE + 1.0)).abs()
+                    < f32::EPSILON
+            );
+            assert!(
+                (node.children()[1].bounds().x - node.children()[0].bounds().width).abs()
+                    < f32::EPSILON
+            );
+        }
+    }
+}
@@ -312,6 +312,7 @@ where
     }
 
     fn diff(&self, tree: &mut Tree) {
+        self.tab_bar.diff(&mut tree.children[0]);
         tree.children[1].diff_children(&self.tabs);
     }
 
@@ -2,9 +2,12 @@
 //!
 //! *This API requires the following crate features to be activated: `time_picker`*
 
+pub mod clock;
+pub use clock::Clock;
+
 use super::overlay::time_picker::{self, TimePickerOverlay, TimePickerOverlayButtons};
 
-use chrono::Local;
+use chrono::{Local, NaiveTime};
 use iced_widget::{
     button, container,
     core::{
@@ -16,9 +19,10 @@ use iced_widget::{
         Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Widget,
     },
     renderer::Renderer,
-    text,
+    text, text_input,
 };
 
+use crate::core::locale::{self, Provider};
 pub use crate::{
     core::time::{Period, Time},
     style::time_picker::{Appearance, StyleSheet},
@@ -48,7 +52,7 @@ pub use crate::{
 ///     Message::Submit,
 /// );
 /// ```
-#[allow(missing_debug_implementations)]
+#[allow(missing_debug_implementations, clippy::struct_excessive_bools)]
 pub struct TimePicker<'a, Message, Theme>
 where
     Message: Clone,
@@ -72,12 +76,35 @@ where
     use_24h: bool,
     /// Toggle the use of the seconds of the [`TimePickerOverlay`].
     show_seconds: bool,
+    /// The label of the now button of the [`TimePickerOverlay`].
+    now_label: String,
+    /// Toggle the clock face of the [`TimePickerOverlay`].
+    show_clock: bool,
+    /// The maximum width of the [`TimePickerOverlay`].
+    size: f32,
+    /// Toggle the smaller spacing of the [`TimePickerOverlay`].
+    compact: bool,
+    /// The step of the minutes that can be picked with the [`TimePickerOverlay`].
+    minute_step: u8,
+    /// The earliest time that can be picked with the [`TimePickerOverlay`], if any.
+    min_time: Option<Time>,
+    /// The latest time that can be picked with the [`TimePickerOverlay`], if any.
+    max_time: Option<Time>,
+    /// The maximum hours of the duration picked with the [`TimePickerOverlay`], if a duration is picked.
+    max_hours: Option<u16>,
+    /// The duration to show, if a duration is picked.
+    duration: std::time::Duration,
+    /// The function that produces a message when the submit button of the [`TimePickerOverlay`] is pressed
+    /// while picking a duration.
+    on_submit_duration: Option<Box<dyn Fn(std::time::Duration) -> Message>>,
+    /// The function that produces a message when the time picked with the [`TimePickerOverlay`] changes.
+    on_change: Option<Box<dyn Fn(Time) -> Message>>,
 }
 
 impl<'a, Message, Theme> TimePicker<'a, Message, Theme>
 where
     Message: 'a + Clone,
-    Theme: 'a + StyleSheet + button::StyleSheet + text::StyleSheet,
+    Theme: 'a + StyleSheet + button::StyleSheet + text::StyleSheet + text_input::StyleSheet,
 {
     /// Creates a new [`TimePicker`] wrapping around the given underlay.
     ///
@@ -108,18 +135,133 @@ where
             on_submit: Box::new(on_submit),
             style: <Theme as StyleSheet>::Style::default(),
             overlay_state: TimePickerOverlayButtons::default().into(),
-            use_24h: false,
+            use_24h: locale::default_provider().use_24h(),
             show_seconds: false,
+            now_label: locale::default_provider().now_label(),
+            show_clock: true,
+            size: 300.0,
+            compact: false,
+            minute_step: 1,
+            min_time: None,
+            max_time: None,
+            max_hours: None,
+            duration: std::time::Duration::ZERO,
+            on_submit_duration: None,
+            on_change: None,
         }
     }
 
     /// Enables the picker to also pick seconds.
+    ///
+    /// It may change between the views, e.g. with a preference: once the
+    /// seconds are hidden they are dropped from the picked time, and they
+    /// start at zero once shown again.
     #[must_use]
     pub fn show_seconds(mut self) -> Self {
         self.show_seconds = true;
         self
     }
 
+    /// Shows or hides the clock face of the overlay, shown by default.
+    ///
+    /// Without it the overlay is smaller and only has the digital clock to
+    /// step through the hours, minutes, seconds and the period.
+    #[must_use]
+    pub fn show_clock(mut self, show_clock: bool) -> Self {
+        self.show_clock = show_clock;
+        self
+    }
+
+    /// Sets the maximum width of the overlay, 300 by default, with the height
+    /// of the clock face following it.
+    ///
+    /// The overlay is still scaled down to fit into smaller windows.
+    #[must_use]
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Uses less space between the elements of the overlay and a smaller
+    /// digital clock, as done anyway once the overlay doesn't fit into the
+    /// window.
+    #[must_use]
+    pub fn compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
+    /// Sets the step of the minutes that can be picked, e.g. 15 for quarter
+    /// hours.
+    ///
+    /// Only the multiples of the step are shown on the clock face, and the
+    /// minutes of the picked time are rounded down to one of them. A step of
+    /// 0 is treated as 1.
+    #[must_use]
+    pub fn minute_step(mut self, step: u8) -> Self {
+        self.minute_step = step.max(1);
+        self
+    }
+
+    /// Sets the earliest time that can be picked.
+    ///
+    /// The hours, minutes and seconds before it are disabled on the clock
+    /// face and the digital clock, and the submit button is disabled while
+    /// the picked time is out of range. The range may wrap past midnight, e.g.
+    /// a minimum of 22:00 with a maximum of 02:00.
+    #[must_use]
+    pub fn min_time(mut self, time: impl Into<Time>) -> Self {
+        self.min_time = Some(time.into());
+        self
+    }
+
+    /// Sets the latest time that can be picked, see
+    /// [`min_time`](Self::min_time).
+    #[must_use]
+    pub fn max_time(mut self, time: impl Into<Time>) -> Self {
+        self.max_time = Some(time.into());
+        self
+    }
+
+    /// Sets the function that will be called whenever the time picked in the
+    /// overlay changes, before it's submitted.
+    ///
+    /// It's called once per change by dragging a hand, clicking, typing or
+    /// with the keyboard, but not while picking a duration.
+    #[must_use]
+    pub fn on_change<F>(mut self, on_change: F) -> Self
+    where
+        F: 'static + Fn(Time) -> Message,
+    {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
+    /// Turns the [`TimePicker`] into a picker of durations like 36:30, from
+    /// zero up to `max_hours` hours and 59 minutes, starting at the given
+    /// duration.
+    ///
+    /// The picked duration is given to `on_submit` instead of the function
+    /// given to [`TimePicker::new`]. The hours, minutes and seconds are picked
+    /// with the digital clock and the text field, the clock face and the
+    /// period are hidden and the minimum and maximum time are ignored. The
+    /// now button goes back to zero.
+    #[must_use]
+    pub fn duration<F>(
+        mut self,
+        duration: std::time::Duration,
+        max_hours: u16,
+        on_submit: F,
+    ) -> Self
+    where
+        F: 'static + Fn(std::time::Duration) -> Message,
+    {
+        self.max_hours = Some(max_hours);
+        self.duration = duration;
+        self.on_submit_duration = Some(Box::new(on_submit));
+        self
+    }
+
     /// Sets the style of the [`TimePicker`].
     #[must_use]
     pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
@@ -127,12 +269,29 @@ where
         self
     }
 
+    /// Sets the initial time of the [`TimePicker`] to the current time.
+    #[must_use]
+    pub fn now(mut self) -> Self {
+        self.time = Time::now_hms(self.use_24h);
+        self
+    }
+
     /// Use 24 hour format instead of AM/PM.
     #[must_use]
     pub fn use_24h(mut self) -> Self {
         self.use_24h = true;
         self
     }
+
+    /// Uses the clock format and labels of the given locale [`Provider`]
+    /// instead of the default one.
+    #[allow(clippy::needless_pass_by_value)]
+    #[must_use]
+    pub fn locale(mut self, locale: impl Provider) -> Self {
+        self.use_24h = locale.use_24h();
+        self.now_label = locale.now_label();
+        self
+    }
 }
 
 /// The state of the [`TimePicker`] / [`TimePickerOverlay`].
@@ -159,17 +318,26 @@ impl State {
         }
     }
 
-    /// Resets the time of the state to the current time.
+    /// Resets the time of the state to the current time, or the duration to
+    /// zero.
     pub fn reset(&mut self) {
         self.overlay_state.clock_cache.clear();
-        self.overlay_state.time = Local::now().naive_local().time();
+        if self.overlay_state.max_hours.is_some() {
+            self.overlay_state.set_duration(0);
+        } else {
+            self.overlay_state.time = self.overlay_state.clamp(Local::now().naive_local().time());
+        }
     }
 }
 
 impl<'a, Message, Theme> Widget<Message, Theme, Renderer> for TimePicker<'a, Message, Theme>
 where
     Message: 'static + Clone,
-    Theme: StyleSheet + button::StyleSheet + text::StyleSheet + container::StyleSheet,
+    Theme: StyleSheet
+        + button::StyleSheet
+        + text::StyleSheet
+        + container::StyleSheet
+        + text_input::StyleSheet,
 {
     fn tag(&self) -> Tag {
         Tag::of::<State>()
@@ -267,12 +435,81 @@ where
         let picker_state: &mut State = state.state.downcast_mut();
 
         if !self.show_picker {
+            // A time typed but not submitted is dropped with the overlay
+            picker_state.overlay_state.typed_time = None;
+
             return self
                 .underlay
                 .as_widget_mut()
                 .overlay(&mut state.children[0], layout, renderer);
         }
 
+        // Picking a duration starts with the given duration
+        let max_hours = self.max_hours.map(u32::from);
+        if picker_state.overlay_state.max_hours != max_hours {
+            picker_state.overlay_state.max_hours = max_hours;
+            picker_state.overlay_state.typed_time = None;
+            if max_hours.is_some() {
+                picker_state
+                    .overlay_state
+                    .set_duration(u32::try_from(self.duration.as_secs()).unwrap_or(u32::MAX));
+            }
+        }
+
+        // A duration has neither a period nor a clock face
+        let use_24h = self.use_24h || self.max_hours.is_some();
+        let show_clock = self.show_clock && self.max_hours.is_none();
+
+        // Follow changes of the clock format, e.g. of the default locale
+        if picker_state.overlay_state.use_24h != use_24h {
+            picker_state.overlay_state.use_24h = use_24h;
+            picker_state.overlay_state.clock_cache.clear();
+        }
+
+        // Follow changes of showing the seconds, e.g. of a preference
+        picker_state
+            .overlay_state
+            .set_show_seconds(self.show_seconds);
+
+        if picker_state.overlay_state.show_clock != show_clock {
+            picker_state.overlay_state.show_clock = show_clock;
+            picker_state.overlay_state.clock_dragged = time_picker::ClockDragged::None;
+        }
+
+        // The clock is drawn again once its size changes
+        picker_state.overlay_state.size = self.size;
+        picker_state.overlay_state.compact = self.compact;
+
+        // The picked minute is always a multiple of the minute step
+        let minute_step = u32::from(self.minute_step);
+        if picker_state.overlay_state.minute_step != minute_step {
+            picker_state.overlay_state.minute_step = minute_step;
+            picker_state.overlay_state.time = picker_state
+                .overlay_state
+                .clamp(picker_state.overlay_state.time);
+            picker_state.overlay_state.clock_cache.clear();
+        }
+
+        // The picked time is moved into the range once it changes
+        let min_time = self
+            .min_time
+            .filter(|_| self.max_hours.is_none())
+            .map(NaiveTime::from);
+        let max_time = self
+            .max_time
+            .filter(|_| self.max_hours.is_none())
+            .map(NaiveTime::from);
+        if picker_state.overlay_state.min_time != min_time
+            || picker_state.overlay_state.max_time != max_time
+        {
+            picker_state.overlay_state.min_time = min_time;
+            picker_state.overlay_state.max_time = max_time;
+            picker_state.overlay_state.time = picker_state
+                .overlay_state
+                .clamp(picker_state.overlay_state.time);
+            picker_state.overlay_state.clock_cache.clear();
+        }
+
         let bounds = layout.bounds();
         let position = Point::new(bounds.center_x(), bounds.center_y());
 
@@ -281,6 +518,13 @@ where
                 picker_state,
                 self.on_cancel.clone(),
                 &self.on_submit,
+                self.on_submit_duration.as_deref(),
+                self.on_change.as_deref(),
+                if self.max_hours.is_some() {
+                    "00:00"
+                } else {
+                    &self.now_label
+                },
                 position,
                 self.style.clone(),
                 &mut state.children[1],
@@ -294,9 +538,64 @@ impl<'a, Message, Theme> From<TimePicker<'a, Message, Theme>>
     for Element<'a, Message, Theme, Renderer>
 where
     Message: 'static + Clone,
-    Theme: 'a + StyleSheet + button::StyleSheet + text::StyleSheet + container::StyleSheet,
+    Theme: 'a
+        + StyleSheet
+        + button::StyleSheet
+        + text::StyleSheet
+        + container::StyleSheet
+        + text_input::StyleSheet,
 {
     fn from(time_picker: TimePicker<'a, Message, Theme>) -> Self {
         Element::new(time_picker)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Period, State, Time, TimePicker};
+    use crate::core::locale::{DeDe, EnUs};
+    use iced_widget::{core::Widget, Text};
+
+    type Picker<'a> = TimePicker<'a, (), iced_widget::Theme>;
+
+    fn time_picker<'a>() -> Picker<'a> {
+        let time = Time::Hm {
+            hour: 14,
+            minute: 30,
+            period: Period::H24,
+        };
+        TimePicker::new(false, time, Text::new("Time"), (), |_| ())
+    }
+
+    fn picked_time(time_picker: &Picker<'_>) -> Time {
+        Widget::state(time_picker)
+            .downcast_ref::<State>()
+            .overlay_state
+            .picked_time()
+    }
+
+    #[test]
+    fn locale_test() {
+        let german = time_picker().locale(DeDe);
+        assert_eq!(german.now_label, "Jetzt");
+        assert!(matches!(
+            picked_time(&german),
+            Time::Hm {
+                hour: 14,
+                minute: 30,
+                period: Period::H24,
+            }
+        ));
+
+        let american = time_picker().locale(EnUs);
+        assert_eq!(american.now_label, "Now");
+        assert!(matches!(
+            picked_time(&american),
+            Time::Hm {
+                hour: 2,
+                minute: 30,
+                period: Period::Pm,
+            }
+        ));
+    }
+}
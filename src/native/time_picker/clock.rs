@@ -0,0 +1,436 @@
+//! Use a clock laid out in place instead of as an overlay.
+//!
+//! *This API requires the following crate features to be activated: `time_picker`*
+
+use crate::{
+    native::overlay::time_picker::{
+        body_layout, draw_body, style_sheet, ClockDragged, Focus, State, PADDING, SPACING,
+    },
+    style::style_state::StyleState,
+};
+
+use chrono::NaiveTime;
+use iced_widget::{
+    container,
+    core::{
+        event, keyboard,
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        renderer, touch,
+        widget::tree::{self, Tag, Tree},
+        Border, Clipboard, Element, Event, Layout, Length, Padding, Point, Rectangle,
+        Renderer as _, Shadow, Shell, Size, Widget,
+    },
+    renderer::Renderer,
+    text,
+};
+
+use super::Time;
+pub use crate::style::time_picker::{Appearance, StyleSheet};
+
+/// The default height of the [`Clock`].
+const DEFAULT_HEIGHT: f32 = 300.0;
+
+/// A clock shown directly in the layout, e.g. in a settings pane.
+///
+/// It has the clock face and the digital clock of the overlay of the
+/// [`TimePicker`](super::TimePicker), but no text field and no cancel, submit
+/// and now buttons: every change of the picked time is reported right away.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::time_picker::{self, Time};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     TimeChanged(Time),
+/// }
+///
+/// let clock = time_picker::Clock::new(Time::now_hm(true), Message::TimeChanged);
+/// ```
+#[allow(missing_debug_implementations, clippy::struct_excessive_bools)]
+pub struct Clock<'a, Message, Theme = iced_widget::style::Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The picked time.
+    time: Time,
+    /// The function that produces a message when the picked time changes.
+    on_change: Box<dyn Fn(Time) -> Message + 'a>,
+    /// The function that produces a message when enter is pressed, if any.
+    on_submit: Option<Box<dyn Fn(Time) -> Message + 'a>>,
+    /// The width of the [`Clock`].
+    width: Length,
+    /// The height of the [`Clock`].
+    height: Length,
+    /// The style of the [`Clock`].
+    style: <Theme as StyleSheet>::Style,
+    /// Toggle the use of the 24h clock of the [`Clock`].
+    use_24h: bool,
+    /// Toggle the use of the seconds of the [`Clock`].
+    show_seconds: bool,
+    /// Toggle the clock face of the [`Clock`].
+    show_face: bool,
+    /// Toggle the smaller digital clock of the [`Clock`].
+    compact: bool,
+    /// The step of the minutes that can be picked.
+    minute_step: u8,
+    /// The earliest time that can be picked, if any.
+    min_time: Option<Time>,
+    /// The latest time that can be picked, if any.
+    max_time: Option<Time>,
+}
+
+impl<'a, Message, Theme> Clock<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`Clock`] showing the given time.
+    ///
+    /// `on_change` is called with the time each time the user picks another
+    /// one.
+    pub fn new<F>(time: impl Into<Time>, on_change: F) -> Self
+    where
+        F: 'a + Fn(Time) -> Message,
+    {
+        Self {
+            time: time.into(),
+            on_change: Box::new(on_change),
+            on_submit: None,
+            width: Length::Fill,
+            height: Length::Fixed(DEFAULT_HEIGHT),
+            style: <Theme as StyleSheet>::Style::default(),
+            use_24h: false,
+            show_seconds: false,
+            show_face: true,
+            compact: false,
+            minute_step: 1,
+            min_time: None,
+            max_time: None,
+        }
+    }
+
+    /// Sets the function that will be called with the picked time when enter
+    /// is pressed while the [`Clock`] is focused.
+    #[must_use]
+    pub fn on_submit<F>(mut self, on_submit: F) -> Self
+    where
+        F: 'a + Fn(Time) -> Message,
+    {
+        self.on_submit = Some(Box::new(on_submit));
+        self
+    }
+
+    /// Sets the width of the [`Clock`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Clock`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`Clock`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Use 24 hour format instead of AM/PM.
+    #[must_use]
+    pub fn use_24h(mut self) -> Self {
+        self.use_24h = true;
+        self
+    }
+
+    /// Enables the [`Clock`] to also pick seconds.
+    #[must_use]
+    pub fn show_seconds(mut self) -> Self {
+        self.show_seconds = true;
+        self
+    }
+
+    /// Shows or hides the clock face, see
+    /// [`TimePicker::show_clock`](super::TimePicker::show_clock).
+    #[must_use]
+    pub fn show_clock(mut self, show_clock: bool) -> Self {
+        self.show_face = show_clock;
+        self
+    }
+
+    /// Uses a smaller digital clock, see
+    /// [`TimePicker::compact`](super::TimePicker::compact).
+    #[must_use]
+    pub fn compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
+    /// Sets the step of the minutes that can be picked, see
+    /// [`TimePicker::minute_step`](super::TimePicker::minute_step).
+    #[must_use]
+    pub fn minute_step(mut self, step: u8) -> Self {
+        self.minute_step = step.max(1);
+        self
+    }
+
+    /// Sets the earliest time that can be picked, see
+    /// [`TimePicker::min_time`](super::TimePicker::min_time).
+    #[must_use]
+    pub fn min_time(mut self, time: impl Into<Time>) -> Self {
+        self.min_time = Some(time.into());
+        self
+    }
+
+    /// Sets the latest time that can be picked, see
+    /// [`TimePicker::max_time`](super::TimePicker::max_time).
+    #[must_use]
+    pub fn max_time(mut self, time: impl Into<Time>) -> Self {
+        self.max_time = Some(time.into());
+        self
+    }
+}
+
+/// The state of a [`Clock`].
+#[derive(Debug)]
+struct ClockState {
+    /// The state of the shown clock.
+    body: State,
+    /// The time last given to the [`Clock`].
+    time: NaiveTime,
+}
+
+impl<'a, Message, Theme> Widget<Message, Theme, Renderer> for Clock<'a, Message, Theme>
+where
+    Theme: StyleSheet + text::StyleSheet + container::StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<ClockState>()
+    }
+
+    fn state(&self) -> tree::State {
+        let mut body = State::new(self.time, self.use_24h, self.show_seconds);
+        body.show_clock = self.show_face;
+        body.minute_step = u32::from(self.minute_step);
+        body.min_time = self.min_time.map(NaiveTime::from);
+        body.max_time = self.max_time.map(NaiveTime::from);
+        body.time = body.clamp(body.time);
+
+        tree::State::new(ClockState {
+            body,
+            time: self.time.into(),
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::empty()]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<ClockState>();
+        let minute_step = u32::from(self.minute_step);
+        let min_time = self.min_time.map(NaiveTime::from);
+        let max_time = self.max_time.map(NaiveTime::from);
+
+        state.body.set_show_seconds(self.show_seconds);
+
+        // The picked time is moved into the range once it changes
+        if state.body.use_24h != self.use_24h
+            || state.body.minute_step != minute_step
+            || state.body.min_time != min_time
+            || state.body.max_time != max_time
+        {
+            state.body.use_24h = self.use_24h;
+            state.body.minute_step = minute_step;
+            state.body.min_time = min_time;
+            state.body.max_time = max_time;
+            state.body.time = state.body.clamp(state.body.time);
+            state.body.clock_cache.clear();
+        }
+
+        if state.body.show_clock != self.show_face {
+            state.body.show_clock = self.show_face;
+            state.body.clock_dragged = ClockDragged::None;
+        }
+
+        // The shown time only follows the given time when it changes
+        let time = NaiveTime::from(self.time);
+        if state.time != time {
+            state.time = time;
+            state.body.time = state.body.clamp(time);
+            state.body.clock_cache.clear();
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let size = limits.width(self.width).height(self.height).resolve(
+            self.width,
+            self.height,
+            Size::ZERO,
+        );
+
+        let body = body_layout::<Message, Theme>(
+            &tree.state.downcast_ref::<ClockState>().body,
+            &mut tree.children[0],
+            renderer,
+            &Limits::new(Size::ZERO, size)
+                .shrink(Padding::from(PADDING))
+                .width(Length::Fill)
+                .height(Length::Fill),
+            SPACING,
+            self.compact,
+        )
+        .move_to(Point::new(PADDING, PADDING));
+
+        Node::with_children(size, vec![body])
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = &mut state.state.downcast_mut::<ClockState>().body;
+        let picked_time = state.time;
+        let body_layout = layout
+            .children()
+            .next()
+            .expect("Native: Layout should have a clock layout");
+
+        // The keyboard steps through the clock only until something else is pressed
+        if matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+        ) {
+            if !cursor.is_over(layout.bounds()) {
+                state.focus = Focus::None;
+            } else if state.focus == Focus::None {
+                state.focus = Focus::Overlay;
+            }
+        }
+
+        let mut status = state.on_event(&event, body_layout, cursor);
+
+        if let Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(key),
+            ..
+        }) = event
+        {
+            if state.focus != Focus::None {
+                status = status.merge(match key {
+                    keyboard::key::Named::Enter => {
+                        if let Some(on_submit) = &self.on_submit {
+                            if state.is_in_range(state.time) {
+                                shell.publish(on_submit(state.picked_time()));
+                            }
+                        }
+                        event::Status::Captured
+                    }
+                    _ => state.on_event_keyboard(key),
+                });
+            }
+        }
+
+        if state.time != picked_time {
+            shell.publish((self.on_change)(state.picked_time()));
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        state
+            .state
+            .downcast_ref::<ClockState>()
+            .body
+            .mouse_interaction(
+                layout
+                    .children()
+                    .next()
+                    .expect("Graphics: Layout should have a clock layout"),
+                cursor,
+            )
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = &state.state.downcast_ref::<ClockState>().body;
+        let bounds = layout.bounds();
+        let style_sheet = style_sheet(theme, &self.style);
+
+        let mut style_state = StyleState::Active;
+        if state.focus == Focus::Overlay {
+            style_state = style_state.max(StyleState::Focused);
+        }
+        if cursor.is_over(bounds) {
+            style_state = style_state.max(StyleState::Hovered);
+        }
+
+        // Background
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: Border {
+                    radius: style_sheet[&style_state].border_radius.into(),
+                    width: style_sheet[&style_state].border_width,
+                    color: style_sheet[&style_state].border_color,
+                },
+                shadow: Shadow::default(),
+            },
+            style_sheet[&style_state].background,
+        );
+
+        draw_body(
+            renderer,
+            state,
+            layout
+                .children()
+                .next()
+                .expect("Graphics: Layout should have a clock layout"),
+            cursor,
+            &style_sheet,
+        );
+    }
+}
+
+impl<'a, Message, Theme> From<Clock<'a, Message, Theme>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a + StyleSheet + text::StyleSheet + container::StyleSheet,
+{
+    fn from(clock: Clock<'a, Message, Theme>) -> Self {
+        Element::new(clock)
+    }
+}
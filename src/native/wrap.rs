@@ -10,7 +10,9 @@ use iced_widget::core::{
     Alignment, Clipboard, Element, Event, Layout, Length, Padding, Point, Rectangle, Shell, Size,
     Widget,
 };
-use std::marker::PhantomData;
+use std::{marker::PhantomData, ops::Range};
+
+use crate::native::common::layout_empty;
 
 /// A container that distributes its contents horizontally.
 #[allow(missing_debug_implementations)]
@@ -41,6 +43,12 @@ pub struct Wrap<
     pub line_spacing: f32,
     /// The minimal length of each line of the [`Wrap`].
     pub line_minimal_length: f32,
+    /// The number of elements each line of the [`Wrap`] holds at least, shrinking them if needed.
+    pub min_items_per_line: usize,
+    /// Whether the spacing of the full lines of the [`Wrap`] is stretched to fill them.
+    pub justify: bool,
+    /// The element shown while the [`Wrap`] has no elements.
+    pub empty: Option<Element<'a, Message, Theme, Renderer>>,
     #[allow(clippy::missing_docs_in_private_items)]
     _direction: PhantomData<Direction>,
 }
@@ -107,6 +115,24 @@ impl<'a, Message, Renderer, Direction, Theme> Wrap<'a, Message, Direction, Theme
         self
     }
 
+    /// Sets the number of elements each line of the [`Wrap`] holds at least.
+    ///
+    /// If fewer elements would fit, the line keeps them anyway and shrinks them
+    /// instead of wrapping.
+    #[must_use]
+    pub const fn min_items_per_line(mut self, items: usize) -> Self {
+        self.min_items_per_line = items;
+        self
+    }
+
+    /// Sets whether the spacing of the lines of the [`Wrap`] is stretched so
+    /// that each line exactly fills it, except for the last one.
+    #[must_use]
+    pub const fn justify(mut self, justify: bool) -> Self {
+        self.justify = justify;
+        self
+    }
+
     /// Sets the padding of the elements in the [`Wrap`].
     #[must_use]
     pub const fn padding(mut self, units: f32) -> Self {
@@ -158,6 +184,23 @@ impl<'a, Message, Renderer, Direction, Theme> Wrap<'a, Message, Direction, Theme
         self.elements.push(element.into());
         self
     }
+
+    /// Sets the [`Element`] shown centered in the [`Wrap`] while it has no elements.
+    ///
+    /// The element receives no events and is removed as soon as an element is pushed.
+    #[must_use]
+    pub fn empty<E>(mut self, empty: E) -> Self
+    where
+        E: Into<Element<'a, Message, Theme, Renderer>>,
+    {
+        self.empty = Some(empty.into());
+        self
+    }
+
+    /// The empty [`Element`], if there are no elements.
+    fn placeholder(&self) -> Option<&Element<'a, Message, Theme, Renderer>> {
+        self.empty.as_ref().filter(|_| self.elements.is_empty())
+    }
 }
 
 impl<'a, Message, Renderer, Direction, Theme> Widget<Message, Theme, Renderer>
@@ -167,11 +210,21 @@ where
     Renderer: core::Renderer,
 {
     fn children(&self) -> Vec<Tree> {
-        self.elements.iter().map(Tree::new).collect()
+        self.elements
+            .iter()
+            .chain(self.placeholder())
+            .map(Tree::new)
+            .collect()
     }
 
     fn diff(&self, tree: &mut Tree) {
-        tree.diff_children(&self.elements);
+        tree.diff_children(
+            &self
+                .elements
+                .iter()
+                .chain(self.placeholder())
+                .collect::<Vec<_>>(),
+        );
     }
 
     fn size(&self) -> Size<Length> {
@@ -179,6 +232,17 @@ where
     }
 
     fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        if let Some(empty) = self.placeholder() {
+            return layout_empty(
+                empty,
+                &mut tree.children[0],
+                renderer,
+                limits,
+                self.width,
+                self.height,
+            );
+        }
+
         self.inner_layout(tree, renderer, limits)
     }
 
@@ -261,6 +325,7 @@ where
         for ((child, state), layout) in self
             .elements
             .iter()
+            .chain(self.placeholder())
             .zip(&state.children)
             .zip(layout.children())
         {
@@ -333,6 +398,9 @@ impl<'a, Message, Renderer, Direction, Theme> Default
             spacing: 0.0,
             line_spacing: 0.0,
             line_minimal_length: 10.0,
+            min_items_per_line: 0,
+            justify: false,
+            empty: None,
             _direction: PhantomData,
         }
     }
@@ -366,63 +434,81 @@ where
             .max_width(self.max_width)
             .max_height(self.max_height);
         let max_width = limits.max().width;
+        let node_limit = Limits::new(
+            Size::new(limits.min().width, line_minimal_length),
+            limits.max(),
+        );
 
-        let mut children = tree.children.iter_mut();
-        let mut curse = padding.left;
-        let mut deep_curse = padding.left;
-        let mut current_line_height = line_minimal_length;
-        let mut max_main = curse;
-        let mut align = vec![];
-        let mut start = 0;
-        let mut end = 0;
+        // Measure each element once, the lines only shrink the ones which don't fit
         let mut nodes: Vec<Node> = self
             .elements
             .iter()
-            .map(|elem| {
-                let node_limit = Limits::new(
-                    Size::new(limits.min().width, line_minimal_length),
-                    limits.max(),
-                );
-                let mut node = elem.as_widget().layout(
-                    children.next().expect("wrap missing expected child"),
-                    renderer,
-                    &node_limit,
-                );
-
-                let size = node.size();
+            .zip(&mut tree.children)
+            .map(|(elem, tree)| elem.as_widget().layout(tree, renderer, &node_limit))
+            .collect();
+        let widths: Vec<f32> = nodes.iter().map(|node| node.size().width).collect();
+        let lines = break_lines(
+            &widths,
+            padding.left,
+            max_width,
+            spacing,
+            self.min_items_per_line,
+        );
 
-                let offset_init = size.width + spacing;
-                let offset = curse + offset_init;
-
-                if offset > max_width {
-                    deep_curse += current_line_height + line_spacing;
-                    align.push((start..end, current_line_height));
-                    start = end;
-                    end += 1;
-                    current_line_height = line_minimal_length;
-                    node.move_to_mut(Point::new(padding.left, deep_curse));
-                    curse = offset_init + padding.left;
-                } else {
-                    node.move_to_mut(Point::new(curse, deep_curse));
-                    curse = offset;
-                    end += 1;
+        for line in lines
+            .iter()
+            .filter(|line| line.len() <= self.min_items_per_line)
+        {
+            if let Some(width) =
+                shrunk_length(&widths[line.clone()], padding.left, max_width, spacing)
+            {
+                for i in line.clone().filter(|&i| widths[i] > width) {
+                    nodes[i] = self.elements[i].as_widget().layout(
+                        &mut tree.children[i],
+                        renderer,
+                        &node_limit.max_width(width),
+                    );
                 }
-                current_line_height = current_line_height.max(size.height);
-                max_main = max_main.max(curse);
-
-                node
-            })
-            .collect();
-        if end != start {
-            align.push((start..end, current_line_height));
+            }
         }
-        for (range, max_length) in align {
-            nodes[range].iter_mut().for_each(|node| {
+
+        let mut deep_curse = padding.left;
+        let mut current_line_height = line_minimal_length;
+        let mut max_main = padding.left;
+        for (index, line) in lines.iter().enumerate() {
+            if index > 0 {
+                deep_curse += current_line_height + line_spacing;
+            }
+            current_line_height = nodes[line.clone()]
+                .iter()
+                .map(|node| node.size().height)
+                .fold(line_minimal_length, f32::max);
+
+            let gap = if self.justify && index + 1 < lines.len() {
+                justified_spacing(
+                    nodes[line.clone()].iter().map(|node| node.size().width),
+                    line.len(),
+                    max_width,
+                    spacing,
+                )
+            } else {
+                spacing
+            };
+
+            let mut curse = padding.left;
+            for node in &mut nodes[line.clone()] {
                 let size = node.size();
-                let space = Size::new(size.width, max_length);
-                node.align_mut(Alignment::Start, self.alignment, space);
-            });
+                node.move_to_mut(Point::new(curse, deep_curse));
+                node.align_mut(
+                    Alignment::Start,
+                    self.alignment,
+                    Size::new(size.width, current_line_height),
+                );
+                curse += size.width + gap;
+            }
+            max_main = max_main.max(curse - gap + spacing);
         }
+
         let (width, height) = (
             max_main - padding.left,
             deep_curse - padding.left + current_line_height,
@@ -453,63 +539,79 @@ where
             .max_width(self.max_width)
             .max_height(self.max_height);
         let max_height = limits.max().height;
+        let node_limit = Limits::new(
+            Size::new(line_minimal_length, limits.min().height),
+            limits.max(),
+        );
 
-        let mut children = tree.children.iter_mut();
-        let mut curse = padding.left;
-        let mut wide_curse = padding.left;
-        let mut current_line_width = line_minimal_length;
-        let mut max_main = curse;
-        let mut align = vec![];
-        let mut start = 0;
-        let mut end = 0;
+        // Measure each element once, the lines only shrink the ones which don't fit
         let mut nodes: Vec<Node> = self
             .elements
             .iter()
-            .map(|elem| {
-                let node_limit = Limits::new(
-                    Size::new(line_minimal_length, limits.min().height),
-                    limits.max(),
-                );
-                let mut node = elem.as_widget().layout(
-                    children.next().expect("wrap missing expected child"),
-                    renderer,
-                    &node_limit,
-                );
-
-                let size = node.size();
+            .zip(&mut tree.children)
+            .map(|(elem, tree)| elem.as_widget().layout(tree, renderer, &node_limit))
+            .collect();
+        let heights: Vec<f32> = nodes.iter().map(|node| node.size().height).collect();
+        let lines = break_lines(
+            &heights,
+            padding.left,
+            max_height,
+            spacing,
+            self.min_items_per_line,
+        );
 
-                let offset_init = size.height + spacing;
-                let offset = curse + offset_init;
-
-                if offset > max_height {
-                    wide_curse += current_line_width + line_spacing;
-                    align.push((start..end, current_line_width));
-                    start = end;
-                    end += 1;
-                    current_line_width = line_minimal_length;
-                    node = node.move_to(Point::new(wide_curse, padding.left));
-                    curse = offset_init + padding.left;
-                } else {
-                    node = node.move_to(Point::new(wide_curse, curse));
-                    end += 1;
-                    curse = offset;
+        for line in lines
+            .iter()
+            .filter(|line| line.len() <= self.min_items_per_line)
+        {
+            if let Some(height) =
+                shrunk_length(&heights[line.clone()], padding.left, max_height, spacing)
+            {
+                for i in line.clone().filter(|&i| heights[i] > height) {
+                    nodes[i] = self.elements[i].as_widget().layout(
+                        &mut tree.children[i],
+                        renderer,
+                        &node_limit.max_height(height),
+                    );
                 }
-                current_line_width = current_line_width.max(size.width);
-                max_main = max_main.max(curse);
-
-                node
-            })
-            .collect();
-        if end != start {
-            align.push((start..end, current_line_width));
+            }
         }
 
-        for (range, max_length) in align {
-            nodes[range].iter_mut().for_each(|node| {
+        let mut wide_curse = padding.left;
+        let mut current_line_width = line_minimal_length;
+        let mut max_main = padding.left;
+        for (index, line) in lines.iter().enumerate() {
+            if index > 0 {
+                wide_curse += current_line_width + line_spacing;
+            }
+            current_line_width = nodes[line.clone()]
+                .iter()
+                .map(|node| node.size().width)
+                .fold(line_minimal_length, f32::max);
+
+            let gap = if self.justify && index + 1 < lines.len() {
+                justified_spacing(
+                    nodes[line.clone()].iter().map(|node| node.size().height),
+                    line.len(),
+                    max_height,
+                    spacing,
+                )
+            } else {
+                spacing
+            };
+
+            let mut curse = padding.left;
+            for node in &mut nodes[line.clone()] {
                 let size = node.size();
-                let space = Size::new(max_length, size.height);
-                node.align_mut(self.alignment, Alignment::Start, space);
-            });
+                node.move_to_mut(Point::new(wide_curse, curse));
+                node.align_mut(
+                    self.alignment,
+                    Alignment::Start,
+                    Size::new(current_line_width, size.height),
+                );
+                curse += size.height + gap;
+            }
+            max_main = max_main.max(curse - gap + spacing);
         }
 
         let (width, height) = (
@@ -522,6 +624,67 @@ where
     }
 }
 
+/// Breaks the elements with the given lengths along the main axis into lines
+/// starting at `start`.
+///
+/// A line ends before the element reaching past `max`, unless it holds fewer
+/// than `min_items` elements.
+fn break_lines(
+    lengths: &[f32],
+    start: f32,
+    max: f32,
+    spacing: f32,
+    min_items: usize,
+) -> Vec<Range<usize>> {
+    let mut lines = Vec::new();
+    let mut line_start = 0;
+    let mut curse = start;
+
+    for (i, length) in lengths.iter().enumerate() {
+        let offset = curse + length + spacing;
+
+        if offset > max && i - line_start >= min_items {
+            lines.push(line_start..i);
+            line_start = i;
+            curse = start + length + spacing;
+        } else {
+            curse = offset;
+        }
+    }
+
+    if line_start != lengths.len() {
+        lines.push(line_start..lengths.len());
+    }
+
+    lines
+}
+
+/// The length the elements of a line reaching past `max` are shrunk to, so
+/// that the line fits.
+#[allow(clippy::cast_precision_loss)]
+fn shrunk_length(lengths: &[f32], start: f32, max: f32, spacing: f32) -> Option<f32> {
+    let end = start + lengths.iter().map(|length| length + spacing).sum::<f32>();
+
+    (!lengths.is_empty() && end > max)
+        .then(|| ((max - start) / lengths.len() as f32 - spacing).max(0.0))
+}
+
+/// The spacing stretched so that the `count` elements with the given lengths
+/// fill `length`, never less than `spacing`.
+#[allow(clippy::cast_precision_loss)]
+fn justified_spacing(
+    lengths: impl Iterator<Item = f32>,
+    count: usize,
+    length: f32,
+    spacing: f32,
+) -> f32 {
+    if count < 2 || !length.is_finite() {
+        return spacing;
+    }
+
+    ((length - lengths.sum::<f32>()) / (count - 1) as f32).max(spacing)
+}
+
 /// An optional directional attribute of the [`Wrap`](crate::Wrap).
 pub mod direction {
     /// An vertical direction of the [`Wrap`](crate::Wrap).
@@ -531,3 +694,38 @@ pub mod direction {
     #[derive(Debug)]
     pub struct Horizontal;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{break_lines, justified_spacing, shrunk_length};
+
+    #[test]
+    fn break_lines_test() {
+        let lengths = [40.0, 40.0, 40.0, 40.0, 40.0];
+
+        assert_eq!(
+            break_lines(&lengths, 0.0, 100.0, 5.0, 0),
+            vec![0..2, 2..4, 4..5]
+        );
+        assert_eq!(break_lines(&lengths, 0.0, 100.0, 5.0, 3), vec![0..3, 3..5]);
+        assert_eq!(break_lines(&[], 0.0, 100.0, 5.0, 3), vec![]);
+    }
+
+    #[test]
+    fn shrunk_length_test() {
+        assert_eq!(shrunk_length(&[40.0, 40.0], 0.0, 100.0, 5.0), None);
+        assert_eq!(
+            shrunk_length(&[40.0, 40.0, 40.0, 40.0], 0.0, 100.0, 5.0),
+            Some(20.0)
+        );
+    }
+
+    #[test]
+    fn justified_spacing_test() {
+        let lengths = [30.0, 30.0, 30.0];
+
+        assert!((justified_spacing(lengths.into_iter(), 3, 100.0, 2.0) - 5.0).abs() < f32::EPSILON);
+        assert!((justified_spacing(lengths.into_iter(), 3, 80.0, 2.0) - 2.0).abs() < f32::EPSILON);
+        assert!((justified_spacing([30.0].into_iter(), 1, 100.0, 2.0) - 2.0).abs() < f32::EPSILON);
+    }
+}
@@ -4,17 +4,26 @@
 use super::colors;
 
 use iced_widget::{
-    core::{Background, Color},
+    core::{
+        gradient::{ColorStop, Linear},
+        Background, Color, Gradient,
+    },
     style::Theme,
 };
+
+/// The factor the colors of a pressed [`Badge`](crate::native::badge::Badge) are darkened by.
+const PRESSED_DARKEN_FACTOR: f32 = 0.85;
+
 /// The appearance of a [`Badge`](crate::native::badge::Badge).
 #[derive(Clone, Copy, Debug)]
 pub struct Appearance {
-    /// The background of the [`Badge`](crate::native::badge::Badge).
+    /// The background of the [`Badge`](crate::native::badge::Badge), a color or a
+    /// gradient.
     pub background: Background,
 
     /// The border radius of the [`Badge`](crate::native::badge::Badge).
-    /// If no radius is specified the default one will be used.
+    /// If no radius is specified the default one derived from the height will be used.
+    /// It is limited to half of the width and the height.
     pub border_radius: Option<f32>,
 
     /// The border with of the [`Badge`](crate::native::badge::Badge).
@@ -25,6 +34,10 @@ pub struct Appearance {
 
     /// The default text color of the [`Badge`](crate::native::badge::Badge).
     pub text_color: Color,
+
+    /// The color of the icon of the [`Badge`](crate::native::badge::Badge).
+    /// If no color is specified the text color will be used.
+    pub icon_color: Option<Color>,
 }
 
 /// The appearance of a [`Badge`](crate::native::badge::Badge).
@@ -38,6 +51,11 @@ pub trait StyleSheet {
     fn hovered(&self, style: &Self::Style) -> Appearance {
         self.active(style)
     }
+
+    /// The appearance when a pressable [`Badge`](crate::native::badge::Badge) is pressed.
+    fn pressed(&self, style: &Self::Style) -> Appearance {
+        self.hovered(style)
+    }
 }
 
 impl std::default::Default for Appearance {
@@ -48,6 +66,7 @@ impl std::default::Default for Appearance {
             border_width: 1.0,
             border_color: Some([0.8, 0.8, 0.8].into()),
             text_color: Color::BLACK,
+            icon_color: None,
         }
     }
 }
@@ -110,4 +129,37 @@ impl StyleSheet for Theme {
 
         self.active(style)
     }
+
+    fn pressed(&self, style: &Self::Style) -> Appearance {
+        if let BadgeStyles::Custom(custom) = style {
+            return custom.pressed(self);
+        }
+
+        let hovered = self.hovered(style);
+        let darken = |color: Color| Color {
+            r: color.r * PRESSED_DARKEN_FACTOR,
+            g: color.g * PRESSED_DARKEN_FACTOR,
+            b: color.b * PRESSED_DARKEN_FACTOR,
+            ..color
+        };
+
+        Appearance {
+            background: match hovered.background {
+                Background::Color(color) => Background::Color(darken(color)),
+                Background::Gradient(Gradient::Linear(linear)) => {
+                    Background::Gradient(Gradient::Linear(Linear {
+                        stops: linear.stops.map(|stop| {
+                            stop.map(|stop| ColorStop {
+                                color: darken(stop.color),
+                                ..stop
+                            })
+                        }),
+                        ..linear
+                    }))
+                }
+            },
+            border_color: hovered.border_color.map(darken),
+            ..hovered
+        }
+    }
 }
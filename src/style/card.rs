@@ -4,7 +4,7 @@
 
 use super::colors;
 use iced_widget::{
-    core::{Background, Color},
+    core::{border::Radius, Background, Color, Shadow},
     style::Theme,
 };
 
@@ -15,7 +15,9 @@ pub struct Appearance {
     pub background: Background,
 
     /// The border radius of the [`Card`](crate::native::card::Card).
-    pub border_radius: f32,
+    ///
+    /// The head uses the top corners and the foot the bottom corners.
+    pub border_radius: Radius,
 
     /// The border width of the [`Card`](crate::native::card::Card).
     pub border_width: f32,
@@ -23,6 +25,9 @@ pub struct Appearance {
     /// The border color of the [`Card`](crate::native::card::Card).
     pub border_color: Color,
 
+    /// The shadow of the [`Card`](crate::native::card::Card).
+    pub shadow: Shadow,
+
     /// The background of the head of the [`Card`](crate::native::card::Card).
     pub head_background: Background,
 
@@ -43,6 +48,10 @@ pub struct Appearance {
 
     /// The color of the close icon of the [`Card`](crate::native::card::Card).
     pub close_color: Color,
+
+    /// The color covering the body and the foot of a loading
+    /// [`Card`](crate::native::card::Card).
+    pub loading_scrim: Color,
 }
 
 /// The appearance of a [`Card`](crate::native::card::Card).
@@ -51,6 +60,16 @@ pub trait StyleSheet {
     type Style: Default;
     /// The normal appearance of a [`Card`](crate::native::card::Card).
     fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance when a pressable [`Card`](crate::native::card::Card) is hovered.
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when a pressable [`Card`](crate::native::card::Card) is pressed.
+    fn pressed(&self, style: &Self::Style) -> Appearance {
+        self.hovered(style)
+    }
 }
 
 #[derive(Default)]
@@ -119,15 +138,46 @@ impl StyleSheet for Theme {
             CardStyles::Custom(custom) => custom.active(self),
         }
     }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        if let CardStyles::Custom(custom) = style {
+            return custom.hovered(self);
+        }
+
+        let active = self.active(style);
+
+        Appearance {
+            border_width: active.border_width + 1.0,
+            ..active
+        }
+    }
+
+    fn pressed(&self, style: &Self::Style) -> Appearance {
+        if let CardStyles::Custom(custom) = style {
+            return custom.pressed(self);
+        }
+
+        let hovered = self.hovered(style);
+
+        Appearance {
+            body_background: Color {
+                a: 0.05,
+                ..Color::BLACK
+            }
+            .into(),
+            ..hovered
+        }
+    }
 }
 
 impl Default for Appearance {
     fn default() -> Self {
         Self {
             background: Color::WHITE.into(),
-            border_radius: 10.0,
+            border_radius: 10.0.into(),
             border_width: 1.0,
             border_color: [0.87, 0.87, 0.87].into(),
+            shadow: Shadow::default(),
             head_background: Background::Color([0.87, 0.87, 0.87].into()),
             head_text_color: Color::BLACK,
             body_background: Color::TRANSPARENT.into(),
@@ -135,6 +185,7 @@ impl Default for Appearance {
             foot_background: Color::TRANSPARENT.into(),
             foot_text_color: Color::BLACK,
             close_color: Color::BLACK,
+            loading_scrim: Color::from_rgba(1.0, 1.0, 1.0, 0.6),
         }
     }
 }
@@ -52,6 +52,17 @@ pub trait StyleSheet {
     /// The appearance when something is focused of the
     /// [`ColorPicker`](crate::native::ColorPicker).
     fn focused(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance while the hex code typed into the
+    /// [`ColorPicker`](crate::native::ColorPicker) can't be parsed.
+    ///
+    /// Its bar border color is drawn around the hex field.
+    fn invalid(&self, style: &Self::Style) -> Appearance {
+        Appearance {
+            bar_border_color: Color::from_rgb(0.8, 0.2, 0.2),
+            ..self.active(style)
+        }
+    }
 }
 
 /// The default appearance of the [`ColorPicker`](crate::native::ColorPicker).
@@ -120,4 +131,15 @@ impl StyleSheet for Theme {
             ..self.active(style)
         }
     }
+
+    fn invalid(&self, style: &Self::Style) -> Appearance {
+        if let ColorPickerStyles::Custom(custom) = style {
+            return custom.invalid(self);
+        }
+
+        Appearance {
+            bar_border_color: self.extended_palette().danger.base.color,
+            ..self.active(style)
+        }
+    }
 }
@@ -13,14 +13,36 @@ use iced_widget::{
 pub struct Appearance {
     /// The background of the [`ContextMenu`](crate::native::ContextMenu).
     ///
-    /// This is used to color the backdrop of the modal.
+    /// This is used to color the backdrop of the modal, or only the area behind the
+    /// content of the menu once a `backdrop` is set.
     pub background: Background,
+    /// The backdrop of the [`ContextMenu`](crate::native::ContextMenu).
+    ///
+    /// This is used to dim the whole area behind an open menu in place of the
+    /// `background`, which then only fills the menu.
+    pub backdrop: Option<Background>,
+    /// The text color of an [`Item`](crate::native::context_menu::Item).
+    pub item_text_color: Color,
+    /// The text color of a hovered [`Item`](crate::native::context_menu::Item).
+    pub item_hovered_text_color: Color,
+    /// The background of a hovered [`Item`](crate::native::context_menu::Item).
+    pub item_hovered_background: Background,
+    /// The text color of a disabled [`Item`](crate::native::context_menu::Item).
+    pub item_disabled_text_color: Color,
+    /// The color of a separator [`Item`](crate::native::context_menu::Item).
+    pub separator_color: Color,
 }
 
 impl Default for Appearance {
     fn default() -> Self {
         Self {
             background: Background::Color([0.87, 0.87, 0.87, 0.30].into()),
+            backdrop: None,
+            item_text_color: Color::BLACK,
+            item_hovered_text_color: Color::WHITE,
+            item_hovered_background: Background::Color([0.0, 0.5, 1.0].into()),
+            item_disabled_text_color: [0.6, 0.6, 0.6].into(),
+            separator_color: [0.7, 0.7, 0.7].into(),
         }
     }
 }
@@ -61,6 +83,12 @@ impl StyleSheet for Theme {
                 ..palette.background.base.color
             }
             .into(),
+            backdrop: None,
+            item_text_color: palette.background.base.text,
+            item_hovered_text_color: palette.primary.weak.text,
+            item_hovered_background: palette.primary.weak.color.into(),
+            item_disabled_text_color: palette.background.strong.color,
+            separator_color: palette.background.strong.color,
         }
     }
 }
@@ -54,6 +54,25 @@ pub trait StyleSheet {
     /// The appearance when something is focused of the
     /// [`DatePicker`](crate::native::DatePicker).
     fn focused(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of the days that can't be picked of the
+    /// [`DatePicker`](crate::native::DatePicker).
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        faded(&self.active(style))
+    }
+
+    /// The appearance of the day focused with the keyboard in the
+    /// [`DatePicker`](crate::native::DatePicker).
+    fn focused_day(&self, style: &Self::Style) -> Appearance {
+        let focused = self.focused(style);
+
+        Appearance {
+            border_radius: focused.border_radius,
+            border_width: focused.border_width,
+            border_color: focused.border_color,
+            ..self.selected(style)
+        }
+    }
 }
 
 /// The default appearance of the [`DatePicker`](crate::native::DatePicker).
@@ -135,4 +154,45 @@ impl StyleSheet for Theme {
             ..self.active(style)
         }
     }
+
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        if let DatePickerStyle::Custom(custom) = style {
+            return custom.disabled(self);
+        }
+
+        faded(&self.active(style))
+    }
+
+    fn focused_day(&self, style: &Self::Style) -> Appearance {
+        if let DatePickerStyle::Custom(custom) = style {
+            return custom.focused_day(self);
+        }
+
+        let palette = self.extended_palette();
+        let focused = self.focused(style);
+
+        Appearance {
+            day_background: palette.primary.strong.color.into(),
+            text_color: palette.primary.strong.text,
+            border_radius: focused.border_radius,
+            border_width: focused.border_width,
+            border_color: focused.border_color,
+            ..self.active(style)
+        }
+    }
+}
+
+/// The faded appearance of the days that can't be picked, from the active one.
+fn faded(active: &Appearance) -> Appearance {
+    Appearance {
+        text_color: Color {
+            a: active.text_color.a * 0.3,
+            ..active.text_color
+        },
+        text_attenuated_color: Color {
+            a: active.text_attenuated_color.a * 0.3,
+            ..active.text_attenuated_color
+        },
+        ..*active
+    }
 }
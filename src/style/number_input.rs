@@ -14,6 +14,12 @@ pub struct Appearance {
     pub button_background: Option<Background>,
     /// The Color of the arrows of [`NumberInput`](crate::native::number_input::NumberInput).
     pub icon_color: Color,
+    /// The color of the prefix and suffix of the [`NumberInput`](crate::native::number_input::NumberInput).
+    pub affix_color: Color,
+    /// The color of the border drawn around the text of the [`NumberInput`](crate::native::number_input::NumberInput), if any.
+    pub border_color: Option<Color>,
+    /// The background drawn over the text of the [`NumberInput`](crate::native::number_input::NumberInput), if any.
+    pub background: Option<Background>,
 }
 
 impl Default for Appearance {
@@ -21,6 +27,9 @@ impl Default for Appearance {
         Self {
             button_background: None,
             icon_color: Color::BLACK,
+            affix_color: Color::from_rgb(0.5, 0.5, 0.5),
+            border_color: None,
+            background: None,
         }
     }
 }
@@ -37,6 +46,16 @@ pub trait StyleSheet {
 
     /// The appearance when the [`NumberInput`](crate::native::number_input::NumberInput) is disabled.
     fn disabled(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance while the text typed into the [`NumberInput`](crate::native::number_input::NumberInput)
+    /// can't be parsed or is out of bounds.
+    fn invalid(&self, style: &Self::Style) -> Appearance {
+        Appearance {
+            border_color: Some(Color::from_rgb(0.8, 0.2, 0.2)),
+            background: Some(Color::from_rgba(0.8, 0.2, 0.2, 0.1).into()),
+            ..self.active(style)
+        }
+    }
 }
 
 #[derive(Default)]
@@ -68,6 +87,9 @@ impl StyleSheet for Theme {
         Appearance {
             button_background: Some(palette.primary.strong.color.into()),
             icon_color: palette.primary.strong.text,
+            affix_color: palette.background.strong.color,
+            border_color: None,
+            background: None,
         }
     }
 
@@ -98,6 +120,26 @@ impl StyleSheet for Theme {
                 a: active.icon_color.a * 0.5,
                 ..active.icon_color
             },
+            affix_color: Color {
+                a: active.affix_color.a * 0.5,
+                ..active.affix_color
+            },
+            ..active
+        }
+    }
+
+    /// The appearance while the text typed into the [`NumberInput`](crate::native::number_input::NumberInput)
+    /// can't be parsed or is out of bounds.
+    fn invalid(&self, style: &Self::Style) -> Appearance {
+        if let NumberInputStyles::Custom(custom) = style {
+            return custom.invalid(self);
+        }
+
+        let danger = self.extended_palette().danger.base.color;
+        Appearance {
+            border_color: Some(danger),
+            background: Some(Color { a: 0.1, ..danger }.into()),
+            ..self.active(style)
         }
     }
 }
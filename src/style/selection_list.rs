@@ -28,6 +28,10 @@ pub struct Appearance {
     pub selected_text_color: Color,
     /// The List Label Text Select Background Color
     pub selected_background: Background,
+    /// The color of the text matching the filter
+    pub highlight_text_color: Color,
+    /// The background of the text matching the filter
+    pub highlight_background: Background,
 }
 
 impl std::default::Default for Appearance {
@@ -41,6 +45,8 @@ impl std::default::Default for Appearance {
             hovered_background: Background::Color([0.0, 0.5, 1.0].into()),
             selected_text_color: Color::WHITE,
             selected_background: Background::Color([0.2, 0.5, 0.8].into()),
+            highlight_text_color: Color::BLACK,
+            highlight_background: Background::Color([1.0, 0.85, 0.3].into()),
         }
     }
 }
@@ -87,6 +93,8 @@ impl StyleSheet for Theme {
             hovered_background: palette.primary.weak.color.into(),
             selected_text_color: palette.primary.strong.text,
             selected_background: palette.primary.strong.color.into(),
+            highlight_text_color: palette.secondary.weak.text,
+            highlight_background: palette.secondary.weak.color.into(),
             ..Appearance::default()
         }
     }
@@ -28,18 +28,31 @@ pub struct Appearance {
     pub divider_border_color: Color,
 }
 
+/// The status of the divider of a [`Split`](crate::native::split::Split).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Status {
+    /// The divider is neither hovered nor dragged.
+    #[default]
+    Active,
+    /// The cursor is over the divider.
+    Hovered,
+    /// The divider is dragged.
+    Dragged,
+    /// The divider can't be dragged.
+    Disabled,
+}
+
 /// The appearance of a [`Split`](crate::native::split::Split).
 #[allow(missing_docs, clippy::missing_docs_in_private_items)]
 pub trait StyleSheet {
     type Style: Default;
-    /// The normal appearance of a [`Split`](crate::native::split::Split).
-    fn active(&self, style: &Self::Style) -> Appearance;
-
-    /// The appearance when the [`Split`](crate::native::split::Split) is hovered.
-    fn hovered(&self, style: &Self::Style) -> Appearance;
-
-    /// The appearance when the divider of the [`Split`](crate::native::split::Split) is dragged
-    fn dragged(&self, style: &Self::Style) -> Appearance;
+    /// The appearance of a [`Split`](crate::native::split::Split) with the given [`Status`]
+    /// of its divider.
+    ///
+    /// The divider is drawn with the appearance of its [`Status`], the rest of the
+    /// [`Split`](crate::native::split::Split) with the one of [`Status::Active`], or of
+    /// [`Status::Disabled`] when the divider can't be dragged.
+    fn appearance(&self, style: &Self::Style, status: Status) -> Appearance;
 }
 
 /// The default appearance of the [`Split`](crate::native::split::Split).
@@ -76,46 +89,29 @@ impl std::default::Default for Appearance {
 
 impl StyleSheet for Theme {
     type Style = SplitStyles;
-    fn active(&self, style: &Self::Style) -> Appearance {
+    fn appearance(&self, style: &Self::Style, status: Status) -> Appearance {
         if let SplitStyles::Custom(custom) = style {
-            return custom.active(self);
+            return custom.appearance(self, status);
         }
 
         let palette = self.extended_palette();
-
-        Appearance {
+        let active = Appearance {
             divider_background: palette.background.base.color.into(),
             divider_border_color: palette.background.weak.color,
             border_color: palette.background.base.color,
             ..Appearance::default()
-        }
-    }
-
-    fn hovered(&self, style: &Self::Style) -> Appearance {
-        if let SplitStyles::Custom(custom) = style {
-            return custom.hovered(self);
-        }
-
-        let palette = self.extended_palette();
-        let active = self.active(style);
-
-        Appearance {
-            divider_background: palette.background.strong.color.into(),
-            ..active
-        }
-    }
-
-    fn dragged(&self, style: &Self::Style) -> Appearance {
-        if let SplitStyles::Custom(custom) = style {
-            return custom.dragged(self);
-        }
-
-        let palette = self.extended_palette();
-        let active = self.active(style);
-
-        Appearance {
-            divider_background: palette.background.weak.color.into(),
-            ..active
+        };
+
+        match status {
+            Status::Active | Status::Disabled => active,
+            Status::Hovered => Appearance {
+                divider_background: palette.background.strong.color.into(),
+                ..active
+            },
+            Status::Dragged => Appearance {
+                divider_background: palette.background.weak.color.into(),
+                ..active
+            },
         }
     }
 }
@@ -11,4 +11,8 @@ pub enum StyleState {
     Hovered,
     /// Use the focused style
     Focused,
+    /// Use the disabled style
+    Disabled,
+    /// Use the style of the item moved with the keyboard, e.g. the focused day of a calendar
+    FocusedItem,
 }
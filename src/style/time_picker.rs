@@ -45,6 +45,30 @@ pub struct Appearance {
     /// The with of the hands of the clock of the
     /// [`TimePicker](crate::native::TimePicker).
     pub clock_hand_width: f32,
+
+    /// The color of the hour hand of the clock of the
+    /// [`TimePicker`](crate::native::TimePicker), the `clock_hand_color` if
+    /// `None`.
+    pub hour_hand_color: Option<Color>,
+
+    /// The color of the minute hand of the clock of the
+    /// [`TimePicker`](crate::native::TimePicker), the `clock_hand_color` if
+    /// `None`.
+    pub minute_hand_color: Option<Color>,
+
+    /// The color of the second hand of the clock of the
+    /// [`TimePicker`](crate::native::TimePicker), the `clock_hand_color` if
+    /// `None`.
+    pub second_hand_color: Option<Color>,
+
+    /// The color of the tick marks between the numbers on the clock of the
+    /// [`TimePicker`](crate::native::TimePicker), the `clock_dots_color` if
+    /// `None`.
+    pub tick_color: Option<Color>,
+
+    /// The background of the picked period of the AM/PM toggle of the
+    /// [`TimePicker`](crate::native::TimePicker), if any.
+    pub period_button_selected_background: Option<Color>,
 }
 
 /// The appearance of a [`TimePicker`](crate::native::TimePicker).
@@ -65,6 +89,43 @@ pub trait StyleSheet {
     /// The appearance when something is focused of the
     /// [`TimePicker`](crate::native::TimePicker).
     fn focused(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of the times that can't be picked of the
+    /// [`TimePicker`](crate::native::TimePicker).
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        let active = self.active(style);
+
+        Appearance {
+            text_color: Color {
+                a: active.text_color.a * 0.3,
+                ..active.text_color
+            },
+            clock_number_color: Color {
+                a: active.clock_number_color.a * 0.3,
+                ..active.clock_number_color
+            },
+            clock_dots_color: Color {
+                a: active.clock_dots_color.a * 0.3,
+                ..active.clock_dots_color
+            },
+            tick_color: active.tick_color.map(|color| Color {
+                a: color.a * 0.3,
+                ..color
+            }),
+            ..active
+        }
+    }
+
+    /// The appearance while the time typed into the
+    /// [`TimePicker`](crate::native::TimePicker) can't be parsed.
+    ///
+    /// Its border color is drawn around the text field.
+    fn invalid(&self, style: &Self::Style) -> Appearance {
+        Appearance {
+            border_color: Color::from_rgb(0.8, 0.2, 0.2),
+            ..self.active(style)
+        }
+    }
 }
 
 /// The style appearance of the [`TimePicker`](crate::native::TimePicker)
@@ -105,6 +166,11 @@ impl StyleSheet for Theme {
             clock_dots_color: [0.87, 0.87, 0.87].into(),
             clock_hand_color: [0.87, 0.87, 0.87].into(),
             clock_hand_width: 3.0,
+            hour_hand_color: None,
+            minute_hand_color: None,
+            second_hand_color: None,
+            tick_color: None,
+            period_button_selected_background: None,
         }
     }
 
@@ -143,6 +209,46 @@ impl StyleSheet for Theme {
 
         Appearance {
             border_color: Color::from_rgb(0.5, 0.5, 0.5),
+            clock_hand_color: self.extended_palette().primary.strong.color,
+            ..self.active(style)
+        }
+    }
+
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        if let TimePickerStyle::Custom(custom) = style {
+            return custom.disabled(self);
+        }
+
+        let active = self.active(style);
+
+        Appearance {
+            text_color: Color {
+                a: active.text_color.a * 0.3,
+                ..active.text_color
+            },
+            clock_number_color: Color {
+                a: active.clock_number_color.a * 0.3,
+                ..active.clock_number_color
+            },
+            clock_dots_color: Color {
+                a: active.clock_dots_color.a * 0.3,
+                ..active.clock_dots_color
+            },
+            tick_color: active.tick_color.map(|color| Color {
+                a: color.a * 0.3,
+                ..color
+            }),
+            ..active
+        }
+    }
+
+    fn invalid(&self, style: &Self::Style) -> Appearance {
+        if let TimePickerStyle::Custom(custom) = style {
+            return custom.invalid(self);
+        }
+
+        Appearance {
+            border_color: self.extended_palette().danger.base.color,
             ..self.active(style)
         }
     }
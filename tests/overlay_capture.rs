@@ -0,0 +1,339 @@
+//! Checks that the events handled by the overlays never reach a button right
+//! underneath them.
+#![cfg(all(feature = "modal", feature = "context_menu"))]
+
+use iced_aw::{ContextMenu, Modal};
+use iced_widget::{
+    core::{
+        clipboard, event, keyboard,
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        renderer::Null,
+        touch,
+        widget::Tree,
+        Event, Layout, Length, Point, Rectangle, Shell, Size, Vector,
+    },
+    Button, Scrollable, Space,
+};
+
+type Element<'a> = iced_widget::core::Element<'a, Message, iced_widget::Theme, Null>;
+
+/// The size of the window.
+const SIZE: Size = Size::new(400.0, 300.0);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Message {
+    Underlay,
+    Overlay,
+    Backdrop,
+    Esc,
+    Scrolled,
+    Toggled(bool),
+}
+
+/// A user interface delivering the events the way the runtime does: to the
+/// overlay first, then to the widgets underneath if the overlay ignored them.
+struct Ui<'a> {
+    root: Element<'a>,
+    tree: Tree,
+}
+
+impl<'a> Ui<'a> {
+    fn new(root: impl Into<Element<'a>>) -> Self {
+        let root = root.into();
+        let tree = Tree::new(&root);
+
+        Self { root, tree }
+    }
+
+    fn layout(&mut self) -> Node {
+        self.root
+            .as_widget()
+            .layout(&mut self.tree, &Null, &Limits::new(Size::ZERO, SIZE))
+    }
+
+    /// The bounds of the content of the open overlay.
+    fn overlay_content(&mut self) -> Option<Rectangle> {
+        let base = self.layout();
+        let mut overlay =
+            self.root
+                .as_widget_mut()
+                .overlay(&mut self.tree, Layout::new(&base), &Null)?;
+        let layout = overlay.layout(&Null, SIZE, Vector::ZERO);
+
+        let content = Layout::new(&layout).children().next()?.bounds();
+
+        Some(content)
+    }
+
+    fn event(&mut self, event: Event, position: Point) -> Vec<Message> {
+        let cursor = Cursor::Available(position);
+        let base = self.layout();
+        let mut messages = Vec::new();
+
+        let (status, base_cursor) =
+            match self
+                .root
+                .as_widget_mut()
+                .overlay(&mut self.tree, Layout::new(&base), &Null)
+            {
+                Some(mut overlay) => {
+                    let layout = overlay.layout(&Null, SIZE, Vector::ZERO);
+                    let status = overlay.on_event(
+                        event.clone(),
+                        Layout::new(&layout),
+                        cursor,
+                        &Null,
+                        &mut clipboard::Null,
+                        &mut Shell::new(&mut messages),
+                    );
+                    let base_cursor = if overlay.is_over(Layout::new(&layout), &Null, position) {
+                        Cursor::Unavailable
+                    } else {
+                        cursor
+                    };
+
+                    (status, base_cursor)
+                }
+                None => (event::Status::Ignored, cursor),
+            };
+
+        if status == event::Status::Ignored {
+            let _ = self.root.as_widget_mut().on_event(
+                &mut self.tree,
+                event,
+                Layout::new(&base),
+                base_cursor,
+                &Null,
+                &mut clipboard::Null,
+                &mut Shell::new(&mut messages),
+                &Rectangle::with_size(SIZE),
+            );
+        }
+
+        messages
+    }
+
+    fn click(&mut self, button: mouse::Button, position: Point) -> Vec<Message> {
+        let mut messages = self.event(
+            Event::Mouse(mouse::Event::CursorMoved { position }),
+            position,
+        );
+        messages.extend(self.event(Event::Mouse(mouse::Event::ButtonPressed(button)), position));
+        messages.extend(self.event(Event::Mouse(mouse::Event::ButtonReleased(button)), position));
+        messages
+    }
+
+    fn tap(&mut self, position: Point) -> Vec<Message> {
+        let id = touch::Finger(0);
+        let mut messages = self.event(
+            Event::Touch(touch::Event::FingerPressed { id, position }),
+            position,
+        );
+        messages.extend(self.event(
+            Event::Touch(touch::Event::FingerLifted { id, position }),
+            position,
+        ));
+        messages
+    }
+
+    fn scroll(&mut self, position: Point) -> Vec<Message> {
+        self.event(
+            Event::Mouse(mouse::Event::WheelScrolled {
+                delta: mouse::ScrollDelta::Lines { x: 0.0, y: -1.0 },
+            }),
+            position,
+        )
+    }
+
+    fn key(&mut self, key: keyboard::key::Named) -> Vec<Message> {
+        self.event(
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                location: keyboard::Location::Standard,
+                modifiers: keyboard::Modifiers::empty(),
+                text: None,
+            }),
+            Point::ORIGIN,
+        )
+    }
+}
+
+/// A button covering the whole window.
+fn underlay<'a>() -> Element<'a> {
+    Button::new(Space::new(Length::Fill, Length::Fill))
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .on_press(Message::Underlay)
+        .into()
+}
+
+/// A scrollable covering the whole window, with content taller than it.
+fn scrollable<'a>() -> Element<'a> {
+    Scrollable::new(Space::new(Length::Fill, 1000.0))
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .on_scroll(|_| Message::Scrolled)
+        .into()
+}
+
+/// A small button for the content of the overlays.
+fn item<'a>() -> Element<'a> {
+    Button::new(Space::new(20, 20))
+        .on_press(Message::Overlay)
+        .into()
+}
+
+#[test]
+fn underlay_test() {
+    let mut ui = Ui::new(underlay());
+
+    assert_eq!(
+        ui.click(mouse::Button::Left, Point::new(10.0, 10.0)),
+        vec![Message::Underlay]
+    );
+}
+
+#[test]
+fn modal_test() {
+    let mut ui = Ui::new(
+        Modal::new(underlay(), Some(item()))
+            .backdrop(Message::Backdrop)
+            .on_esc(Message::Esc),
+    );
+    let content = ui.overlay_content().expect("The modal should be open");
+
+    assert_eq!(
+        ui.click(mouse::Button::Left, content.center()),
+        vec![Message::Overlay]
+    );
+    assert_eq!(
+        ui.click(mouse::Button::Left, Point::new(10.0, 10.0)),
+        vec![Message::Backdrop]
+    );
+    assert_eq!(
+        ui.click(mouse::Button::Right, Point::new(10.0, 10.0)),
+        vec![]
+    );
+    assert_eq!(ui.tap(content.center()), vec![Message::Overlay]);
+    assert_eq!(ui.tap(Point::new(10.0, 10.0)), vec![Message::Backdrop]);
+    assert_eq!(ui.key(keyboard::key::Named::Escape), vec![Message::Esc]);
+    assert_eq!(ui.key(keyboard::key::Named::Enter), vec![]);
+}
+
+#[test]
+fn modal_without_messages_test() {
+    let mut ui = Ui::new(Modal::new(underlay(), Some(item())));
+    let content = ui.overlay_content().expect("The modal should be open");
+
+    assert_eq!(
+        ui.click(
+            mouse::Button::Left,
+            content.position() - Vector::new(5.0, 5.0)
+        ),
+        vec![]
+    );
+    assert_eq!(ui.tap(Point::new(10.0, 10.0)), vec![]);
+    assert_eq!(ui.key(keyboard::key::Named::Escape), vec![]);
+}
+
+#[test]
+fn context_menu_test() {
+    let mut ui = Ui::new(ContextMenu::new(underlay(), |_| item()));
+    let position = Point::new(100.0, 100.0);
+
+    // Opening the menu
+    assert_eq!(ui.click(mouse::Button::Right, position), vec![]);
+    let content = ui.overlay_content().expect("The menu should be open");
+
+    // Pressing an item closes the menu
+    assert_eq!(
+        ui.click(mouse::Button::Left, content.center()),
+        vec![Message::Overlay]
+    );
+    assert_eq!(ui.overlay_content(), None);
+
+    // Pressing outside closes the menu
+    assert_eq!(ui.click(mouse::Button::Right, position), vec![]);
+    assert_eq!(
+        ui.click(mouse::Button::Left, Point::new(390.0, 290.0)),
+        vec![]
+    );
+    assert_eq!(ui.overlay_content(), None);
+
+    // Middle clicks and scrolling stay in the menu
+    assert_eq!(ui.click(mouse::Button::Right, position), vec![]);
+    let content = ui.overlay_content().expect("The menu should be open");
+    assert_eq!(ui.click(mouse::Button::Middle, content.center()), vec![]);
+    assert_eq!(
+        ui.event(
+            Event::Mouse(mouse::Event::WheelScrolled {
+                delta: mouse::ScrollDelta::Lines { x: 0.0, y: 1.0 },
+            }),
+            content.center(),
+        ),
+        vec![]
+    );
+
+    // Tapping an item closes the menu
+    let content = ui.overlay_content().expect("The menu should be open");
+    assert_eq!(ui.tap(content.center()), vec![Message::Overlay]);
+    assert_eq!(ui.overlay_content(), None);
+
+    // Escape closes the menu
+    assert_eq!(ui.click(mouse::Button::Right, position), vec![]);
+    assert_eq!(ui.key(keyboard::key::Named::Escape), vec![]);
+    assert_eq!(ui.overlay_content(), None);
+
+    // The underlay works again once the menu is closed
+    assert_eq!(
+        ui.click(mouse::Button::Left, position),
+        vec![Message::Underlay]
+    );
+}
+
+#[test]
+fn context_menu_scroll_test() {
+    let mut ui = Ui::new(ContextMenu::new(scrollable(), |_| item()));
+    let position = Point::new(100.0, 100.0);
+
+    // The underlay scrolls while the menu is closed
+    assert_eq!(ui.scroll(position), vec![Message::Scrolled]);
+
+    // Scrolling over the open menu leaves the underlay where it is
+    assert_eq!(ui.click(mouse::Button::Right, position), vec![]);
+    let content = ui.overlay_content().expect("The menu should be open");
+    assert_eq!(ui.scroll(content.center()), vec![]);
+    assert_eq!(ui.scroll(Point::new(390.0, 290.0)), vec![]);
+    assert!(ui.overlay_content().is_some());
+}
+
+#[test]
+fn context_menu_toggle_test() {
+    let mut ui = Ui::new(ContextMenu::new(underlay(), |_| item()).on_toggle(Message::Toggled));
+    let position = Point::new(100.0, 100.0);
+
+    // Opening the menu
+    assert_eq!(
+        ui.click(mouse::Button::Right, position),
+        vec![Message::Toggled(true)]
+    );
+
+    // Pressing outside closes the menu
+    assert_eq!(
+        ui.click(mouse::Button::Left, Point::new(390.0, 290.0)),
+        vec![Message::Toggled(false)]
+    );
+    assert_eq!(ui.overlay_content(), None);
+
+    // Escape closes the menu
+    assert_eq!(
+        ui.click(mouse::Button::Right, position),
+        vec![Message::Toggled(true)]
+    );
+    assert_eq!(
+        ui.key(keyboard::key::Named::Escape),
+        vec![Message::Toggled(false)]
+    );
+    assert_eq!(ui.overlay_content(), None);
+}